@@ -0,0 +1,125 @@
+use crate::{Unit, UnitType};
+
+/// When to switch to a larger unit.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Threshold {
+    Ratio(f64),
+    MaxValue(u16),
+}
+
+/// Options for finding an appropriate unit with [`Byte::get_appropriate_unit_with_options`](struct.Byte.html#method.get_appropriate_unit_with_options) or [`Bit::get_appropriate_unit_with_options`](struct.Bit.html#method.get_appropriate_unit_with_options).
+///
+/// By default, a unit is chosen as soon as the size reaches it, which is the same as `get_appropriate_unit`. Tools such as `ls -h` switch a bit earlier, so that `"0.98 GiB"` is written instead of `"1003 MiB"`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "byte")]
+/// # {
+/// use byte_unit::{AppropriateUnitOptions, Byte, UnitType};
+///
+/// let byte = Byte::from_u64(1003 << 20);
+///
+/// let options = AppropriateUnitOptions::new(UnitType::Binary);
+///
+/// assert_eq!(
+///     "1003 MiB",
+///     byte.get_appropriate_unit_with_options(&options).to_string()
+/// );
+///
+/// let options = options.max_value(1000);
+///
+/// assert_eq!(
+///     "0.98 GiB",
+///     format!("{:.2}", byte.get_appropriate_unit_with_options(&options))
+/// );
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AppropriateUnitOptions {
+    unit_type: UnitType,
+    threshold: Threshold,
+}
+
+impl Default for AppropriateUnitOptions {
+    /// `unit_type` is set to `UnitType::Both`.
+    #[inline]
+    fn default() -> Self {
+        Self::new(UnitType::Both)
+    }
+}
+
+impl AppropriateUnitOptions {
+    /// Create a new `AppropriateUnitOptions` instance which chooses a unit of `unit_type` as soon as the size reaches it.
+    #[inline]
+    pub const fn new(unit_type: UnitType) -> Self {
+        Self {
+            unit_type,
+            threshold: Threshold::Ratio(1.0),
+        }
+    }
+
+    /// Set the unit type.
+    #[inline]
+    pub const fn unit_type(mut self, unit_type: UnitType) -> Self {
+        self.unit_type = unit_type;
+
+        self
+    }
+
+    /// Choose a unit once the size reaches `ratio` times the unit, e.g. `0.95` to write `"0.95 GiB"` instead of `"972.8 MiB"`. The default ratio is `1.0`. It replaces the threshold set by [`AppropriateUnitOptions::max_value`](#method.max_value).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is not a positive finite number.
+    #[inline]
+    pub fn threshold(mut self, ratio: f64) -> Self {
+        assert!(ratio.is_finite() && ratio > 0.0, "the ratio must be a positive finite number");
+
+        self.threshold = Threshold::Ratio(ratio);
+
+        self
+    }
+
+    /// Choose a unit once the value in the next smaller unit of the same kind would reach `max_value`, e.g. `1000` so that at most three digits are written before the decimal point. It replaces the threshold set by [`AppropriateUnitOptions::threshold`](#method.threshold).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_value` is zero.
+    #[inline]
+    pub const fn max_value(mut self, max_value: u16) -> Self {
+        assert!(max_value > 0, "the maximum value must not be zero");
+
+        self.threshold = Threshold::MaxValue(max_value);
+
+        self
+    }
+
+    /// Find the largest unit among `units`, which are sorted from the smallest one, whose threshold is reached by `size`. `unit_size` gives the size of a unit in the same measure (bits or bytes) as `size`.
+    pub(crate) fn find_unit(
+        &self,
+        size: u128,
+        units: &[Unit],
+        unit_size: impl Fn(Unit) -> u128,
+    ) -> Option<Unit> {
+        self.unit_type.find_largest_unit(units, |unit| {
+            let unit_size = unit_size(unit);
+
+            match self.threshold {
+                Threshold::Ratio(ratio) => {
+                    // exact for the default threshold
+                    if ratio == 1.0 {
+                        size >= unit_size
+                    } else {
+                        size as f64 >= unit_size as f64 * ratio
+                    }
+                },
+                Threshold::MaxValue(max_value) => {
+                    let base = if unit.is_binary_multiples() { 1024 } else { 1000 };
+
+                    size >= unit_size / base * max_value as u128
+                },
+            }
+        })
+    }
+}
@@ -0,0 +1,123 @@
+//! Conversions between slices of sizes and [Apache Arrow](https://docs.rs/arrow-array) arrays, for exporting sizes into columnar formats, such as Parquet files and dataframes, and reading them back.
+//!
+//! A size can be stored in a `UInt64Array`, which every Arrow implementation reads natively, or in a `Decimal128Array` with a scale of `0`, which holds every size even if the `u128` feature is enabled.
+
+use arrow_array::{Decimal128Array, UInt64Array};
+use rust_decimal::prelude::*;
+
+use crate::{Byte, ExceededBoundsError};
+
+/// The precision of the arrays created by [`to_decimal128_array`], which is the largest one a `Decimal128Array` supports.
+pub const DECIMAL128_PRECISION: u8 = 38;
+
+/// Convert `bytes` to a `UInt64Array` of the numbers of bytes.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{arrow::to_uint64_array, Byte};
+///
+/// let sizes = [512, 1024, 1536].map(Byte::from_u64);
+///
+/// let array = to_uint64_array(&sizes).unwrap();
+///
+/// assert_eq!(&[512, 1024, 1536], array.values().as_ref());
+/// ```
+///
+/// # Points to Note
+///
+/// * If any size is larger than `u64::MAX` bytes, which is only possible if the `u128` feature is enabled, this function will return `Err(ExceededBoundsError)`.
+pub fn to_uint64_array(bytes: &[Byte]) -> Result<UInt64Array, ExceededBoundsError> {
+    let values = bytes.iter().map(|byte| byte.as_u64_checked()).collect::<Option<Vec<u64>>>();
+
+    values.map(UInt64Array::from).ok_or(ExceededBoundsError)
+}
+
+/// Convert `bytes` to a `Decimal128Array` of the numbers of bytes, with the precision [`DECIMAL128_PRECISION`] and a scale of `0`.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{arrow::to_decimal128_array, Byte};
+///
+/// let sizes = [512, 1024, 1536].map(Byte::from_u64);
+///
+/// let array = to_decimal128_array(&sizes);
+///
+/// assert_eq!(&[512, 1024, 1536], array.values().as_ref());
+/// assert_eq!(0, array.scale());
+/// ```
+pub fn to_decimal128_array(bytes: &[Byte]) -> Decimal128Array {
+    Decimal128Array::from_iter_values(bytes.iter().map(|byte| byte.as_u128() as i128))
+        .with_precision_and_scale(DECIMAL128_PRECISION, 0)
+        .unwrap()
+}
+
+/// Convert a `UInt64Array` of numbers of bytes to sizes. A null slot becomes `None`.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{arrow::from_uint64_array, Byte};
+///
+/// let array = [Some(1024), None].into_iter().collect();
+///
+/// assert_eq!(
+///     vec![Some(Byte::from_u64(1024)), None],
+///     from_uint64_array(&array)
+/// );
+/// ```
+pub fn from_uint64_array(array: &UInt64Array) -> Vec<Option<Byte>> {
+    array.iter().map(|value| value.map(Byte::from_u64)).collect()
+}
+
+/// Convert a `Decimal128Array` of numbers of bytes to sizes. A null slot becomes `None`.
+///
+/// # Examples
+///
+/// ```
+/// use arrow_array::Decimal128Array;
+/// use byte_unit::{arrow::from_decimal128_array, Byte};
+///
+/// let array = Decimal128Array::from(vec![Some(10245), None])
+///     .with_precision_and_scale(10, 1)
+///     .unwrap();
+///
+/// assert_eq!(
+///     vec![Some(Byte::from_u64(1025)), None],
+///     from_decimal128_array(&array).unwrap()
+/// );
+/// ```
+///
+/// # Points to Note
+///
+/// * The fractional part will be rounded up, the same as [`Byte::from_decimal`](../struct.Byte.html#method.from_decimal) does.
+/// * If any value is negative or too large for a `Byte` instance, or the scale of the array is larger than `28`, this function will return `Err(ExceededBoundsError)`.
+pub fn from_decimal128_array(
+    array: &Decimal128Array,
+) -> Result<Vec<Option<Byte>>, ExceededBoundsError> {
+    let scale = array.scale();
+
+    array
+        .iter()
+        .map(|value| match value {
+            Some(value) => from_decimal128(value, scale).map(Some).ok_or(ExceededBoundsError),
+            None => Ok(None),
+        })
+        .collect()
+}
+
+#[inline]
+fn from_decimal128(value: i128, scale: i8) -> Option<Byte> {
+    match scale {
+        0 => Byte::from_i128(value),
+        1.. => match Decimal::try_from_i128_with_scale(value, scale as u32) {
+            Ok(value) => Byte::from_decimal(value),
+            Err(_) => None,
+        },
+        _ => match 10i128.checked_pow(scale.unsigned_abs() as u32) {
+            Some(factor) => Byte::from_i128(value.checked_mul(factor)?),
+            None => None,
+        },
+    }
+}
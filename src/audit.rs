@@ -0,0 +1,120 @@
+//! Helpers for finding size strings which are interpreted differently by different conventions, e.g. before migrating configuration files from one tool to another.
+//!
+//! The following conventions are considered:
+//!
+//! * **SI**, how [`Byte::parse_str`](../struct.Byte.html#method.parse_str) parses a string with `ignore_case` enabled. `"10M"` and `"10MB"` are 10<sup>7</sup> bytes and `"10Mi"` and `"10MiB"` are 10 * 2<sup>20</sup> bytes.
+//! * **JEDEC**, where the decimal prefixes of bytes are binary multiples, as in many operating systems and memory specifications. `"10M"`, `"10MB"` and `"10MiB"` are all 10 * 2<sup>20</sup> bytes.
+//! * **Kubernetes**, the quantity format of Kubernetes resources, as [`Byte::parse_k8s_quantity`](../struct.Byte.html#method.parse_k8s_quantity) parses it. `"10M"` is 10<sup>7</sup> bytes and `"10Mi"` is 10 * 2<sup>20</sup> bytes, while `"10MB"` and `"10 Mi"` are invalid.
+
+use crate::{
+    common::{parse_value, scan_size},
+    Byte, Unit,
+};
+
+/// The sizes represented by a string under each convention. `None` means the string is invalid under that convention.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Interpretations {
+    /// The size under the SI convention.
+    pub si:         Option<Byte>,
+    /// The size under the JEDEC convention.
+    pub jedec:      Option<Byte>,
+    /// The size under the Kubernetes convention.
+    pub kubernetes: Option<Byte>,
+}
+
+/// The result of [`classify`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Ambiguity {
+    /// The string is invalid under every convention.
+    Invalid,
+    /// Every convention which accepts the string agrees on the size.
+    Unambiguous(Byte),
+    /// At least two conventions accept the string but disagree on the size.
+    Ambiguous(Interpretations),
+}
+
+/// Get the sizes represented by a string under each convention. See the [module-level documentation](./index.html).
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{audit::interpret, Byte};
+///
+/// let interpretations = interpret("10M");
+///
+/// assert_eq!(Some(Byte::from_u64(10_000_000)), interpretations.si);
+/// assert_eq!(Some(Byte::from_u64(10 << 20)), interpretations.jedec);
+/// assert_eq!(Some(Byte::from_u64(10_000_000)), interpretations.kubernetes);
+/// ```
+pub fn interpret<S: AsRef<str>>(s: S) -> Interpretations {
+    let s = s.as_ref().trim();
+
+    Interpretations {
+        si:         Byte::parse_str(s, true).ok(),
+        jedec:      parse_jedec(s),
+        kubernetes: Byte::parse_k8s_quantity(s).ok(),
+    }
+}
+
+/// Find out whether a string would be interpreted differently under the SI, JEDEC and Kubernetes conventions. See the [module-level documentation](./index.html).
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{
+///     audit::{classify, Ambiguity},
+///     Byte,
+/// };
+///
+/// assert!(matches!(classify("10MB"), Ambiguity::Ambiguous(_)));
+/// assert!(matches!(classify("10M"), Ambiguity::Ambiguous(_)));
+/// assert_eq!(
+///     Ambiguity::Unambiguous(Byte::from_u64(10 << 20)),
+///     classify("10Mi")
+/// );
+/// assert_eq!(Ambiguity::Unambiguous(Byte::from_u64(1024)), classify("1024"));
+/// assert_eq!(Ambiguity::Invalid, classify("ten megabytes"));
+/// ```
+pub fn classify<S: AsRef<str>>(s: S) -> Ambiguity {
+    let interpretations = interpret(s);
+
+    let mut sizes = [interpretations.si, interpretations.jedec, interpretations.kubernetes]
+        .into_iter()
+        .flatten();
+
+    match sizes.next() {
+        Some(first) => {
+            if sizes.all(|size| size == first) {
+                Ambiguity::Unambiguous(first)
+            } else {
+                Ambiguity::Ambiguous(interpretations)
+            }
+        },
+        None => Ambiguity::Invalid,
+    }
+}
+
+fn parse_jedec(s: &str) -> Option<Byte> {
+    let (value_length, _) = scan_size(s.as_bytes());
+
+    if value_length == 0 {
+        return None;
+    }
+
+    let value = parse_value(&s[..value_length]).ok()?;
+    let mut unit = Unit::parse_str(&s[value_length..], true, true).ok()?;
+
+    if !unit.is_bit() && !unit.is_binary_multiples() && unit != Unit::B {
+        let mut factor = unit.as_bytes_u128();
+        let mut exponent = 0;
+
+        while factor > 1 {
+            factor /= 1000;
+            exponent += 1;
+        }
+
+        unit = Unit::from_bytes_factor(1 << (10 * exponent))?;
+    }
+
+    Byte::from_decimal_with_unit(value, unit)
+}
@@ -0,0 +1,134 @@
+//! Types for reporting the results of benchmarks, e.g. the bandwidth of a routine which processes a buffer.
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use byte_unit::{bench::Throughput, Byte};
+//!
+//! let throughput =
+//!     Throughput::new(Byte::from_u64(5 << 30), Duration::from_millis(2135));
+//!
+//! assert_eq!("2.34 GiB/s", throughput.to_string());
+//! ```
+
+use core::{
+    fmt::{self, Display, Formatter},
+    time::Duration,
+};
+
+use crate::{
+    format::{fmt_value, fmt_with_unit},
+    Byte, ByteRate, Unit, UnitType,
+};
+
+/// The amount of data processed in a measured duration.
+///
+/// The `Display` implementation writes the bandwidth in binary units with 2 digits after the decimal point by default, as benchmark harnesses usually do.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use byte_unit::{bench::Throughput, Byte};
+///
+/// let throughput =
+///     Throughput::new(Byte::from_u64(1 << 20), Duration::from_millis(250));
+///
+/// assert_eq!("4.00 MiB/s", format!("{throughput}"));
+/// assert_eq!("4 MiB/s", format!("{throughput:#}"));
+/// assert_eq!("4.000 MiB/s", format!("{throughput:.3}"));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Throughput {
+    /// The amount of data processed.
+    pub bytes:    Byte,
+    /// The duration of the processing.
+    pub duration: Duration,
+}
+
+impl Throughput {
+    /// Create a new `Throughput` instance.
+    #[inline]
+    pub const fn new(bytes: Byte, duration: Duration) -> Self {
+        Self {
+            bytes,
+            duration,
+        }
+    }
+
+    /// Retrieve the number of bytes processed per second as a floating-point number. If the duration is zero, the result is infinite (or NaN if no bytes are processed).
+    #[inline]
+    pub fn bytes_per_second(&self) -> f64 {
+        self.bytes.as_u128() as f64 / self.duration.as_secs_f64()
+    }
+
+    /// Retrieve the bandwidth as a `ByteRate` instance, rounded up. If the duration is zero or the result exceeds `Byte::MAX`, this method will return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use byte_unit::{bench::Throughput, Byte, ByteRate};
+    ///
+    /// let throughput =
+    ///     Throughput::new(Byte::from_u64(3000), Duration::from_millis(1500));
+    ///
+    /// assert_eq!(
+    ///     Some(ByteRate::per_second(Byte::from_u64(2000))),
+    ///     throughput.rate()
+    /// );
+    /// ```
+    #[inline]
+    pub fn rate(&self) -> Option<ByteRate> {
+        ByteRate::from_duration(self.bytes, self.duration)
+    }
+
+    /// Retrieve the number of bytes as `u64`, which is what `criterion::Throughput::Bytes` expects for the bytes processed per iteration. The result is saturated at `u64::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use byte_unit::{bench::Throughput, Byte};
+    ///
+    /// let throughput =
+    ///     Throughput::new(Byte::from_u64(4096), Duration::from_micros(3));
+    ///
+    /// assert_eq!(4096, throughput.criterion_bytes());
+    /// ```
+    #[inline]
+    pub fn criterion_bytes(&self) -> u64 {
+        self.bytes.as_u64()
+    }
+}
+
+impl Display for Throughput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let bytes_per_second = self.bytes_per_second();
+
+        let unit = if bytes_per_second.is_finite() {
+            UnitType::Binary
+                .find_largest_unit(Unit::get_multiples_bytes(), |unit| {
+                    bytes_per_second >= unit.as_bytes_u128() as f64
+                })
+                .unwrap_or(Unit::B)
+        } else {
+            Unit::B
+        };
+
+        let value = bytes_per_second / unit.as_bytes_u128() as f64;
+        let precision = f.precision().unwrap_or(2);
+
+        if f.alternate() {
+            fmt_value(f, value, unit, Some(precision), true)?;
+        } else {
+            // unlike a size, a rate in bytes per second can be fractional, so the precision applies to `B/s` as well
+            fmt_with_unit(f, format_args!("{value:.precision$}"), Some(unit))?;
+        }
+
+        f.write_str("/s")
+    }
+}
@@ -1,4 +1,6 @@
 use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::string::String;
 
 use super::{AdjustedBit, Bit, Unit, UnitType};
 use crate::ParseError;
@@ -14,7 +16,7 @@ impl From<Bit> for AdjustedBit {
 impl From<AdjustedBit> for f64 {
     #[inline]
     fn from(value: AdjustedBit) -> Self {
-        value.get_value()
+        value.value
     }
 }
 
@@ -36,8 +38,30 @@ impl FromStr for AdjustedBit {
     type Err = ParseError;
 
     /// * `unit_type` is set to `UnitType::Both`. See [`Bit::get_appropriate_unit`](./struct.Bit.html#method.get_appropriate_unit).
+    /// * To keep the unit written in the string, use [`AdjustedBit::parse_str_keep_unit`](./struct.AdjustedBit.html#method.parse_str_keep_unit).
     #[inline]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Bit::parse_str(s)?.get_appropriate_unit(UnitType::Both))
     }
 }
+
+impl TryFrom<&str> for AdjustedBit {
+    type Error = ParseError;
+
+    /// See [`FromStr`](#impl-FromStr-for-AdjustedBit).
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<String> for AdjustedBit {
+    type Error = ParseError;
+
+    /// See [`FromStr`](#impl-FromStr-for-AdjustedBit).
+    #[inline]
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
@@ -0,0 +1,119 @@
+use core::fmt::{self, Display, Formatter};
+
+use super::AdjustedBit;
+use crate::{
+    format::{fmt_value, fmt_value_with_unit},
+    FormatOptions,
+};
+
+/// Generated from the [`AdjustedBit::display_with`](./struct.AdjustedBit.html#method.display_with) method.
+#[derive(Debug, Clone, Copy)]
+pub struct AdjustedBitDisplay {
+    adjusted_bit: AdjustedBit,
+    options:      FormatOptions,
+}
+
+impl Display for AdjustedBitDisplay {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_value_with_unit(f, self.adjusted_bit.value, self.adjusted_bit.unit, &self.options)
+    }
+}
+
+/// Generated from the [`AdjustedBit::value_display`](./struct.AdjustedBit.html#method.value_display) method.
+#[derive(Debug, Clone, Copy)]
+pub struct AdjustedBitValueDisplay {
+    adjusted_bit: AdjustedBit,
+    precision:    Option<usize>,
+}
+
+impl Display for AdjustedBitValueDisplay {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_value(f, self.adjusted_bit.value, self.adjusted_bit.unit, self.precision, false)
+    }
+}
+
+/// Methods for formatting with options.
+impl AdjustedBit {
+    /// Create a value which implements `Display` for this `AdjustedBit` instance with the given options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, FormatOptions, FormatStyle, Unit};
+    ///
+    /// let adjusted_bit = Bit::from_u64(1536).get_adjusted_unit(Unit::Kibit);
+    ///
+    /// assert_eq!(
+    ///     "1.5 Kib",
+    ///     adjusted_bit.display_with(FormatOptions::new()).to_string()
+    /// );
+    /// assert_eq!(
+    ///     "1.5Kib",
+    ///     adjusted_bit
+    ///         .display_with(FormatOptions::new().style(FormatStyle::Compact))
+    ///         .to_string()
+    /// );
+    /// assert_eq!(
+    ///     "1.5 kibibits",
+    ///     adjusted_bit
+    ///         .display_with(FormatOptions::new().style(FormatStyle::Verbose))
+    ///         .to_string()
+    /// );
+    /// assert_eq!(
+    ///     "1.5 kibibits",
+    ///     adjusted_bit
+    ///         .display_with(
+    ///             FormatOptions::new().style(FormatStyle::Verbose).precision(2)
+    ///         )
+    ///         .to_string()
+    /// );
+    /// assert_eq!(
+    ///     "1.50 kibibits",
+    ///     adjusted_bit
+    ///         .display_with(
+    ///             FormatOptions::new()
+    ///                 .style(FormatStyle::Verbose)
+    ///                 .precision(2)
+    ///                 .keep_trailing_zeros(true)
+    ///         )
+    ///         .to_string()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn display_with(self, options: FormatOptions) -> AdjustedBitDisplay {
+        AdjustedBitDisplay {
+            adjusted_bit: self,
+            options,
+        }
+    }
+
+    /// Create a value which implements `Display` for the value of this `AdjustedBit` instance without the unit. The value is formatted in the same way as the `Display` implementation of `AdjustedBit` does with the given precision, so the number shown is consistent with the combined output.
+    ///
+    /// The width, the alignment (right-aligned by default) and the `#` flag of the formatter are respected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, UnitType};
+    ///
+    /// let adjusted_bit =
+    ///     Bit::from_u64(10000).get_appropriate_unit(UnitType::Binary);
+    ///
+    /// let value = adjusted_bit.value_display(Some(2));
+    ///
+    /// assert_eq!("9.77", value.to_string());
+    /// assert_eq!(
+    ///     format!("{adjusted_bit:.2}"),
+    ///     format!("{value} {}", adjusted_bit.unit_str())
+    /// );
+    /// ```
+    #[inline]
+    pub const fn value_display(self, precision: Option<usize>) -> AdjustedBitValueDisplay {
+        AdjustedBitValueDisplay {
+            adjusted_bit: self,
+            precision,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 mod built_in_traits;
+mod display;
 #[cfg(feature = "rocket")]
 mod rocket_traits;
 #[cfg(feature = "serde")]
@@ -6,11 +7,13 @@ mod serde_traits;
 
 use core::{
     cmp::Ordering,
-    fmt::{self, Alignment, Display, Formatter, Write},
+    fmt::{self, Display, Formatter},
 };
 
+pub use display::*;
+
 use super::{Bit, Unit};
-use crate::{common::round_fractional_part_f64, UnitType};
+use crate::{format::fmt_value, AppropriateUnitOptions, ParseError, UnitType};
 
 /// Generated from the [`Bit::get_adjusted_unit`](./struct.Bit.html#method.get_adjusted_unit) method or the the [`Bit::get_appropriate_unit`](./struct.Bit.html#method.get_appropriate_unit) method.
 ///
@@ -94,99 +97,30 @@ impl Display for AdjustedBit {
     ///
     /// // with a width, right alignment, more spaces between the value and the unit
     /// assert_eq!("  9.77 Kib", format!("{adjusted_bit_based_2:>+10.2}"));
+    ///
+    /// // with a fill character, or padded with zeros, like integers
+    /// assert_eq!("*9.77* Kib", format!("{adjusted_bit_based_2:*^10.2}"));
+    /// assert_eq!("009.77 Kib", format!("{adjusted_bit_based_2:010.2}"));
     /// assert_eq!(" 10.00  Kb", format!("{adjusted_bit_based_10:>+10.2}"));
     ///
-    /// // no spaces between the value and the unit
+    /// // no spaces between the value and the unit (see also `FormatStyle::Compact`)
     /// assert_eq!("9.765625Kib", format!("{adjusted_bit_based_2:-}"));
     /// assert_eq!("10Kb", format!("{adjusted_bit_based_10:-}"));
     /// ```
+    #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let Self {
-            value,
-            unit,
-        } = self;
-        let handle_basic_precision = |precision: usize, f: &mut Formatter<'_>| -> fmt::Result {
-            if f.alternate() {
-                let value = round_fractional_part_f64(*value, precision);
-
-                f.write_fmt(format_args!("{value}"))
-            } else if matches!(unit, Unit::Bit | Unit::B) {
-                f.write_fmt(format_args!("{value}"))
-            } else {
-                f.write_fmt(format_args!("{value:.precision$}"))
-            }
-        };
-
-        let space_length = if f.sign_plus() {
-            4 - unit.as_str().len()
-        } else if f.sign_minus() {
-            0
-        } else {
-            1
-        };
-
-        if let Some(mut width) = f.width() {
-            let l = unit.as_str().len() + space_length;
-
-            if let Some(precision) = f.precision() {
-                if width > l + 1 {
-                    width -= l;
-
-                    let alignment = f.align().unwrap_or(Alignment::Left);
-
-                    if f.alternate() {
-                        let value = round_fractional_part_f64(*value, precision);
-
-                        match alignment {
-                            Alignment::Left | Alignment::Center => {
-                                f.write_fmt(format_args!("{value:<width$}"))?
-                            },
-                            Alignment::Right => f.write_fmt(format_args!("{value:>width$}"))?,
-                        }
-                    } else {
-                        match alignment {
-                            Alignment::Left | Alignment::Center => {
-                                f.write_fmt(format_args!("{value:<width$.precision$}"))?
-                            },
-                            Alignment::Right => {
-                                f.write_fmt(format_args!("{value:>width$.precision$}"))?
-                            },
-                        }
-                    }
-                } else {
-                    handle_basic_precision(precision, f)?;
-                }
-            } else if width > l + 1 {
-                width -= l;
-
-                let alignment = f.align().unwrap_or(Alignment::Left);
-
-                match alignment {
-                    Alignment::Left | Alignment::Center => {
-                        f.write_fmt(format_args!("{value:<width$}"))?
-                    },
-                    Alignment::Right => f.write_fmt(format_args!("{value:>width$}"))?,
-                }
-            } else {
-                f.write_fmt(format_args!("{value}"))?;
-            }
-        } else if let Some(precision) = f.precision() {
-            handle_basic_precision(precision, f)?;
-        } else {
-            f.write_fmt(format_args!("{value}"))?;
-        }
-
-        for _ in 0..space_length {
-            f.write_char(' ')?;
-        }
-
-        f.write_fmt(format_args!("{unit}"))
+        fmt_value(f, self.value, self.unit, f.precision(), true)
     }
 }
 
 /// Methods for getting values.
 impl AdjustedBit {
     /// Get the value.
+    #[cfg_attr(
+        feature = "strict",
+        deprecated(note = "floating-point numbers are lossy, use `Bit::get_adjusted_unit_exact` \
+                           or `Bit::get_adjusted_unit_rounded` instead")
+    )]
     #[inline]
     pub const fn get_value(&self) -> f64 {
         self.value
@@ -198,6 +132,12 @@ impl AdjustedBit {
         self.unit
     }
 
+    /// Get the string of the unit. See [`Unit::as_str`](./enum.Unit.html#method.as_str).
+    #[inline]
+    pub const fn unit_str(&self) -> &'static str {
+        self.unit.as_str()
+    }
+
     /// Create a new `Bit` instance from this `AdjustedBit` instance.
     ///
     /// # Examples
@@ -217,12 +157,112 @@ impl AdjustedBit {
     /// # Points to Note
     ///
     /// * The result may not be logically equal to the original `Bit` instance due to the accuracy of floating-point numbers.
+    #[allow(deprecated)]
     #[inline]
     pub fn get_bit(&self) -> Bit {
         Bit::from_f64_with_unit(self.value, self.unit).unwrap()
     }
 }
 
+/// Associated functions for parsing strings.
+impl AdjustedBit {
+    /// Create a new `AdjustedBit` instance from a string, keeping the unit written in it, e.g. to echo a configuration back to its user as they wrote it. See [`Bit::parse_str`](./struct.Bit.html#method.parse_str).
+    ///
+    /// Unlike the `FromStr` implementation, which picks an appropriate unit, `"2048 Kibit"` stays in Kibit instead of becoming `"2 Mib"`. A string without a unit is in bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{AdjustedBit, Unit};
+    ///
+    /// let adjusted_bit = AdjustedBit::parse_str_keep_unit("2048 Kibit").unwrap();
+    ///
+    /// assert_eq!(Unit::Kibit, adjusted_bit.get_unit());
+    /// assert_eq!("2048 Kib", adjusted_bit.to_string());
+    ///
+    /// // `FromStr` picks an appropriate unit
+    /// assert_eq!(
+    ///     "2 Mib",
+    ///     "2048 Kibit".parse::<AdjustedBit>().unwrap().to_string()
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_str_keep_unit<S: AsRef<str>>(s: S) -> Result<Self, ParseError> {
+        let (bit, unit) = Bit::parse_str_and_unit(s.as_ref())?;
+
+        Ok(bit.get_adjusted_unit(unit))
+    }
+}
+
+/// Methods for formatting.
+#[cfg(feature = "std")]
+impl AdjustedBit {
+    /// Format the value of this `AdjustedBit` instance without the unit. See [`AdjustedBit::value_display`](#method.value_display).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, UnitType};
+    ///
+    /// let adjusted_bit =
+    ///     Bit::from_u64(10000).get_appropriate_unit(UnitType::Binary);
+    ///
+    /// assert_eq!("9.765625", adjusted_bit.to_value_string(None));
+    /// assert_eq!("9.77", adjusted_bit.to_value_string(Some(2)));
+    /// assert_eq!("Kib", adjusted_bit.unit_str());
+    /// ```
+    #[inline]
+    pub fn to_value_string(&self, precision: Option<usize>) -> String {
+        self.value_display(precision).to_string()
+    }
+
+    /// Format this `AdjustedBit` instance into a string which is exactly `width` characters long. The value is right-aligned, and its precision is reduced as needed. If even the integer part cannot fit, a larger unit of the same kind is chosen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, Unit};
+    ///
+    /// let adjusted_bit = Bit::from_u64(1500).get_adjusted_unit(Unit::Kbit);
+    ///
+    /// assert_eq!("1.5000 Kb", adjusted_bit.format_fixed_width(9).unwrap());
+    /// assert_eq!(" 2 Kb", adjusted_bit.format_fixed_width(5).unwrap());
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If `width` is too small for any unit, this method will return `None`.
+    #[inline]
+    pub fn format_fixed_width(&self, width: usize) -> Option<String> {
+        crate::format::format_fixed_width(self.value, self.unit, width)
+    }
+
+    /// Format this `AdjustedBit` instance into a string for a column of a table, which is `width` characters long. The value is right-aligned and written with `precision` digits after the decimal point, and the unit is right-aligned in a field of 3 characters, so that the values and the units of all rows line up. This is the same as the `{:>+width$.precision$}` format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, UnitType};
+    ///
+    /// let rows = [1610612736, 1027813786, 12288].map(|n| {
+    ///     Bit::from_u64(n)
+    ///         .get_appropriate_unit(UnitType::Binary)
+    ///         .format_aligned(9, 1)
+    /// });
+    ///
+    /// assert_eq!(["  1.5 Gib", "980.2 Mib", " 12.0 Kib"], rows);
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * The precision is not applied to values in bits or bytes.
+    /// * If `width` is too small, the string is longer than `width`, as the `Display` implementations of the standard library do.
+    #[inline]
+    pub fn format_aligned(&self, width: usize, precision: usize) -> String {
+        format!("{self:>+width$.precision$}")
+    }
+}
+
 /// Associated functions for generating `AdjustedBit`.
 impl Bit {
     /// Adjust the unit and value for this `Bit` instance.
@@ -253,8 +293,7 @@ impl Bit {
         let bit_v = self.as_u128();
 
         let value = match unit {
-            Unit::Bit => (bit_v << 3) as f64,
-            Unit::B => bit_v as f64,
+            Unit::Bit => bit_v as f64,
             _ => bit_v as f64 / unit.as_bits_u128() as f64,
         };
 
@@ -287,23 +326,89 @@ impl Bit {
     ///
     /// assert_eq!("48.48480224609375 Mib", adjusted_bit.to_string());
     /// ```
+    ///
+    /// Use `UnitType::Decimal` for telecom-style reporting, which only steps by 1000 and never picks binary units like `Kibit`.
+    ///
+    /// ```
+    /// use byte_unit::{Bit, Unit, UnitType};
+    ///
+    /// let bit = Bit::from_u64(1024 * 1024);
+    ///
+    /// let adjusted_bit = bit.get_appropriate_unit(UnitType::Decimal);
+    ///
+    /// assert_eq!(Unit::Mbit, adjusted_bit.get_unit());
+    /// assert_eq!("1.05 Mb", format!("{adjusted_bit:.2}"));
+    /// ```
     pub fn get_appropriate_unit(&self, unit_type: UnitType) -> AdjustedBit {
-        let a = Unit::get_multiples_bits();
+        let bits_v = self.as_u128();
 
-        let (skip, step) = match unit_type {
-            UnitType::Binary => (0, 2),
-            UnitType::Decimal => (1, 2),
-            UnitType::Both => (0, 1),
-        };
+        let unit = unit_type
+            .find_largest_unit(Unit::get_multiples_bits(), |unit| bits_v >= unit.as_bits_u128())
+            .unwrap_or(Unit::Bit);
 
-        let bits_v = self.as_u128();
+        self.get_adjusted_unit(unit)
+    }
 
-        for unit in a.iter().rev().skip(skip).step_by(step) {
-            if bits_v >= unit.as_bits_u128() {
-                return self.get_adjusted_unit(*unit);
-            }
-        }
+    /// Find the appropriate unit and value for this `Bit` instance, with a custom threshold for switching to a larger unit. See [`AppropriateUnitOptions`](./struct.AppropriateUnitOptions.html).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{AppropriateUnitOptions, Bit, UnitType};
+    ///
+    /// let bit = Bit::from_u64(980_000_000);
+    ///
+    /// let options = AppropriateUnitOptions::new(UnitType::Decimal).max_value(950);
+    ///
+    /// assert_eq!(
+    ///     "0.98 Gb",
+    ///     bit.get_appropriate_unit_with_options(&options).to_string()
+    /// );
+    /// ```
+    #[inline]
+    pub fn get_appropriate_unit_with_options(
+        &self,
+        options: &AppropriateUnitOptions,
+    ) -> AdjustedBit {
+        let unit = options
+            .find_unit(self.as_u128(), Unit::get_multiples_bits(), Unit::as_bits_u128)
+            .unwrap_or(Unit::Bit);
+
+        self.get_adjusted_unit(unit)
+    }
+}
+
+/// Associated functions for generating `AdjustedBit` from a `Byte` instance.
+#[cfg(feature = "byte")]
+impl crate::Byte {
+    /// Find the appropriate bit unit and value for this `Byte` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, UnitType};
+    ///
+    /// let byte = Byte::from_u64(1_500_000);
+    ///
+    /// assert_eq!(
+    ///     "1.5 MB",
+    ///     byte.get_appropriate_unit(UnitType::Decimal).to_string()
+    /// );
+    /// assert_eq!(
+    ///     "12 Mb",
+    ///     byte.get_appropriate_bit_unit(UnitType::Decimal).to_string()
+    /// );
+    /// ```
+    pub fn get_appropriate_bit_unit(&self, unit_type: UnitType) -> AdjustedBit {
+        let bits_v = self.as_u128() << 3;
+
+        let unit = unit_type
+            .find_largest_unit(Unit::get_multiples_bits(), |unit| bits_v >= unit.as_bits_u128())
+            .unwrap_or(Unit::Bit);
 
-        self.get_adjusted_unit(Unit::B)
+        AdjustedBit {
+            value: bits_v as f64 / unit.as_bits_u128() as f64,
+            unit,
+        }
     }
 }
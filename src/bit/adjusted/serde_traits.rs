@@ -10,6 +10,7 @@ use serde::{
 };
 
 use super::AdjustedBit;
+use crate::serde::invalid_str;
 
 impl Serialize for AdjustedBit {
     #[inline]
@@ -43,7 +44,7 @@ impl<'de> Deserialize<'de> for AdjustedBit {
             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
             where
                 E: DeError, {
-                AdjustedBit::from_str(v).map_err(DeError::custom)
+                AdjustedBit::from_str(v).map_err(|error| invalid_str(v, &self, error))
             }
         }
 
@@ -0,0 +1,46 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use super::{AdjustedBit, Bit};
+use crate::Unit;
+
+impl<'a> Arbitrary<'a> for Bit {
+    /// Generate a boundary value (`0`, `1`, `Bit::MAX - 1` or `Bit::MAX`), an exact multiple of a unit (e.g. `5 Mbit`), or any value in the full range.
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let max = Bit::MAX.as_u128();
+
+        let size = match u.choose_index(3)? {
+            0 => *u.choose(&[0, 1, max - 1, max])?,
+            1 => {
+                let unit = Unit::arbitrary(u)?;
+
+                let factor = unit.as_bits_u128();
+
+                match factor.checked_mul(u.int_in_range(0..=1024)?) {
+                    Some(size) if size <= max => size,
+                    _ => factor,
+                }
+            },
+            _ => u.int_in_range(0..=max)?,
+        };
+
+        // the size is not greater than `max`
+        Ok(Bit::from_u128_unchecked(size))
+    }
+
+    #[inline]
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, Some(32))
+    }
+}
+
+impl<'a> Arbitrary<'a> for AdjustedBit {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Bit::arbitrary(u)?.get_adjusted_unit(Unit::arbitrary(u)?))
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(Bit::size_hint(depth), Unit::size_hint(depth))
+    }
+}
@@ -1,7 +1,17 @@
-use core::{cmp::Ordering, str::FromStr};
-
-use super::Bit;
-use crate::{ExceededBoundsError, ParseError, TryFromIntError};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::{self, Binary, Formatter, LowerHex, Octal, UpperHex},
+    iter::Sum,
+    num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+    str::FromStr,
+};
+#[cfg(feature = "std")]
+use std::string::String;
+
+use super::{Bit, BitRepr};
+use crate::{ExceededBoundsError, NonZeroError, ParseError, TryFromIntError};
 
 impl TryFrom<u128> for Bit {
     type Error = ExceededBoundsError;
@@ -122,6 +132,7 @@ impl TryFrom<isize> for Bit {
 impl TryFrom<f64> for Bit {
     type Error = ExceededBoundsError;
 
+    #[allow(deprecated)]
     #[inline]
     fn try_from(value: f64) -> Result<Self, Self::Error> {
         Bit::from_f64(value).ok_or(ExceededBoundsError)
@@ -131,12 +142,73 @@ impl TryFrom<f64> for Bit {
 impl TryFrom<f32> for Bit {
     type Error = ExceededBoundsError;
 
+    #[allow(deprecated)]
     #[inline]
     fn try_from(value: f32) -> Result<Self, Self::Error> {
         Bit::from_f32(value).ok_or(ExceededBoundsError)
     }
 }
 
+impl TryFrom<NonZeroU128> for Bit {
+    type Error = ExceededBoundsError;
+
+    #[inline]
+    fn try_from(value: NonZeroU128) -> Result<Self, Self::Error> {
+        Bit::try_from(value.get())
+    }
+}
+
+impl From<NonZeroU64> for Bit {
+    #[inline]
+    fn from(value: NonZeroU64) -> Self {
+        Bit::from_u64(value.get())
+    }
+}
+
+impl From<NonZeroU32> for Bit {
+    #[inline]
+    fn from(value: NonZeroU32) -> Self {
+        Bit::from_u64(value.get() as u64)
+    }
+}
+
+impl From<NonZeroU16> for Bit {
+    #[inline]
+    fn from(value: NonZeroU16) -> Self {
+        Bit::from_u64(value.get() as u64)
+    }
+}
+
+impl From<NonZeroU8> for Bit {
+    #[inline]
+    fn from(value: NonZeroU8) -> Self {
+        Bit::from_u64(value.get() as u64)
+    }
+}
+
+impl From<NonZeroUsize> for Bit {
+    #[inline]
+    fn from(value: NonZeroUsize) -> Self {
+        Bit::from(value.get())
+    }
+}
+
+impl TryFrom<Wrapping<u128>> for Bit {
+    type Error = ExceededBoundsError;
+
+    #[inline]
+    fn try_from(value: Wrapping<u128>) -> Result<Self, Self::Error> {
+        Bit::try_from(value.0)
+    }
+}
+
+impl From<Wrapping<u64>> for Bit {
+    #[inline]
+    fn from(value: Wrapping<u64>) -> Self {
+        Bit::from_u64(value.0)
+    }
+}
+
 impl From<Bit> for u128 {
     #[inline]
     fn from(bit: Bit) -> Self {
@@ -196,6 +268,93 @@ impl TryFrom<Bit> for usize {
     }
 }
 
+impl TryFrom<Bit> for NonZeroU128 {
+    type Error = NonZeroError;
+
+    #[inline]
+    fn try_from(bit: Bit) -> Result<Self, Self::Error> {
+        NonZeroU128::new(bit.as_u128()).ok_or(NonZeroError::Zero)
+    }
+}
+
+impl TryFrom<Bit> for NonZeroU64 {
+    type Error = NonZeroError;
+
+    #[inline]
+    fn try_from(bit: Bit) -> Result<Self, Self::Error> {
+        match bit.as_u64_checked() {
+            Some(n) => NonZeroU64::new(n).ok_or(NonZeroError::Zero),
+            None => Err(NonZeroError::ExceededBounds),
+        }
+    }
+}
+
+impl From<Bit> for Wrapping<u128> {
+    #[inline]
+    fn from(bit: Bit) -> Self {
+        Wrapping(bit.as_u128())
+    }
+}
+
+impl AsRef<BitRepr> for Bit {
+    #[inline]
+    fn as_ref(&self) -> &BitRepr {
+        &self.0
+    }
+}
+
+impl Borrow<BitRepr> for Bit {
+    #[inline]
+    fn borrow(&self) -> &BitRepr {
+        &self.0
+    }
+}
+
+impl Binary for Bit {
+    /// Formats the size in bits as an integer in base 2, honoring every flag, like `u128`.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Binary::fmt(&self.0, f)
+    }
+}
+
+impl Octal for Bit {
+    /// Formats the size in bits as an integer in base 8, honoring every flag, like `u128`.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Octal::fmt(&self.0, f)
+    }
+}
+
+impl LowerHex for Bit {
+    /// Formats the size in bits as an integer in base 16 with lowercase letters, honoring every flag, like `u128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// let bit = Bit::from_u64(4096);
+    ///
+    /// assert_eq!("0x1000", format!("{bit:#x}"));
+    /// assert_eq!("0x00001000", format!("{bit:#010x}"));
+    /// assert_eq!("0o10000", format!("{bit:#o}"));
+    /// assert_eq!("1000000000000", format!("{bit:b}"));
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl UpperHex for Bit {
+    /// Formats the size in bits as an integer in base 16 with uppercase letters, honoring every flag, like `u128`.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        UpperHex::fmt(&self.0, f)
+    }
+}
+
 impl FromStr for Bit {
     type Err = ParseError;
 
@@ -205,6 +364,27 @@ impl FromStr for Bit {
     }
 }
 
+impl TryFrom<&str> for Bit {
+    type Error = ParseError;
+
+    /// See [`FromStr`](#impl-FromStr-for-Bit).
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<String> for Bit {
+    type Error = ParseError;
+
+    /// See [`FromStr`](#impl-FromStr-for-Bit).
+    #[inline]
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
 impl PartialEq<u64> for Bit {
     #[cfg(feature = "u128")]
     #[inline]
@@ -316,3 +496,121 @@ impl PartialOrd<Bit> for u128 {
         self.partial_cmp(&(other.0 as u128))
     }
 }
+
+impl Add for Bit {
+    type Output = Bit;
+
+    /// # Panics
+    ///
+    /// Panics if the result exceeds `Bit::MAX`. See [`Bit::checked_add`](Bit::checked_add).
+    #[inline]
+    fn add(self, rhs: Bit) -> Bit {
+        self.checked_add(rhs).expect("attempt to add with overflow")
+    }
+}
+
+impl AddAssign for Bit {
+    #[inline]
+    fn add_assign(&mut self, rhs: Bit) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sum for Bit {
+    /// # Panics
+    ///
+    /// Panics if the result exceeds `Bit::MAX`. See [`Bit::checked_sum`](Bit::checked_sum).
+    #[inline]
+    fn sum<I: Iterator<Item = Bit>>(iter: I) -> Bit {
+        iter.fold(Bit::MIN, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Bit> for Bit {
+    /// # Panics
+    ///
+    /// Panics if the result exceeds `Bit::MAX`. See [`Bit::checked_sum`](Bit::checked_sum).
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Bit>>(iter: I) -> Bit {
+        iter.copied().sum()
+    }
+}
+
+impl Sub for Bit {
+    type Output = Bit;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is larger than `self`. See [`Bit::checked_sub`](Bit::checked_sub).
+    #[inline]
+    fn sub(self, rhs: Bit) -> Bit {
+        self.checked_sub(rhs).expect("attempt to subtract with overflow")
+    }
+}
+
+impl SubAssign for Bit {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Bit) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<u64> for Bit {
+    type Output = Bit;
+
+    /// # Panics
+    ///
+    /// Panics if the result exceeds `Bit::MAX`. See [`Bit::checked_mul`](Bit::checked_mul).
+    #[inline]
+    fn mul(self, rhs: u64) -> Bit {
+        self.checked_mul(rhs).expect("attempt to multiply with overflow")
+    }
+}
+
+impl Mul<Bit> for u64 {
+    type Output = Bit;
+
+    /// # Panics
+    ///
+    /// Panics if the result exceeds `Bit::MAX`. See [`Bit::checked_mul`](Bit::checked_mul).
+    #[inline]
+    fn mul(self, rhs: Bit) -> Bit {
+        rhs * self
+    }
+}
+
+impl MulAssign<u64> for Bit {
+    #[inline]
+    fn mul_assign(&mut self, rhs: u64) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<u64> for Bit {
+    type Output = Bit;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero. See [`Bit::checked_div`](Bit::checked_div).
+    #[inline]
+    fn div(self, rhs: u64) -> Bit {
+        self.checked_div(rhs).expect("attempt to divide by zero")
+    }
+}
+
+impl DivAssign<u64> for Bit {
+    #[inline]
+    fn div_assign(&mut self, rhs: u64) {
+        *self = *self / rhs;
+    }
+}
+
+#[cfg(feature = "byte")]
+impl TryFrom<crate::Byte> for Bit {
+    type Error = ExceededBoundsError;
+
+    #[inline]
+    fn try_from(byte: crate::Byte) -> Result<Self, Self::Error> {
+        byte.as_bit().ok_or(ExceededBoundsError)
+    }
+}
@@ -0,0 +1,13 @@
+use clap::builder::ValueParserFactory;
+
+use super::Bit;
+use crate::clap::BitValueParser;
+
+impl ValueParserFactory for Bit {
+    type Parser = BitValueParser;
+
+    #[inline]
+    fn value_parser() -> Self::Parser {
+        BitValueParser::new()
+    }
+}
@@ -1,7 +1,7 @@
 use rust_decimal::prelude::*;
 
 use super::Bit;
-use crate::{common::is_zero_remainder_decimal, Unit};
+use crate::{common::is_zero_remainder_decimal, Precision, Unit, UnitPreference};
 
 /// Associated functions for building `Bit` instances using `Decimal`.
 impl Bit {
@@ -64,6 +64,7 @@ impl Bit {
     /// * The calculated bit will be rounded up.
     #[inline]
     pub fn from_decimal_with_unit(size: Decimal, unit: Unit) -> Option<Self> {
+        #[allow(clippy::question_mark)]
         let v = {
             match unit {
                 Unit::Bit => size,
@@ -120,42 +121,155 @@ impl Bit {
     /// # Points to Note
     ///
     /// * `precision` should be smaller or equal to `26` if the `u128` feature is enabled, otherwise `19`. The typical `precision` is `3`.
+    /// * `precision` is clamped to [`Precision::MAX_DIGITS`](./enum.Precision.html#associatedconstant.MAX_DIGITS).
     #[inline]
-    pub fn get_recoverable_unit(
+    pub fn get_recoverable_unit(self, allow_in_bytes: bool, precision: usize) -> (Decimal, Unit) {
+        self.get_recoverable_unit_with_preference(
+            allow_in_bytes,
+            precision,
+            UnitPreference::Largest,
+        )
+    }
+
+    /// Find the appropriate unit and value that can be used to recover back to this `Bit` instance precisely, like [`Bit::get_recoverable_unit`](#method.get_recoverable_unit), but the largest unit among the preferred ones is chosen if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, Unit, UnitPreference};
+    ///
+    /// let bit = Bit::from_u64(2048000);
+    ///
+    /// assert_eq!(
+    ///     (2.048f64.try_into().unwrap(), Unit::Mbit),
+    ///     bit.get_recoverable_unit_with_preference(
+    ///         false,
+    ///         3,
+    ///         UnitPreference::Largest
+    ///     )
+    /// );
+    /// assert_eq!(
+    ///     (2000.into(), Unit::Kibit),
+    ///     bit.get_recoverable_unit_with_preference(
+    ///         false,
+    ///         3,
+    ///         UnitPreference::Binary
+    ///     )
+    /// );
+    /// ```
+    pub fn get_recoverable_unit_with_preference(
         self,
         allow_in_bytes: bool,
-        mut precision: usize,
+        precision: usize,
+        preference: UnitPreference,
     ) -> (Decimal, Unit) {
         let bits_v = self.as_u128();
         let bits_vd = Decimal::from(bits_v);
 
         let a = if allow_in_bytes { Unit::get_multiples() } else { Unit::get_multiples_bits() };
-        let mut i = a.len() - 1;
 
-        if precision >= 28 {
-            precision = 28;
-        }
+        let precision = Precision::Digits(precision).digits();
 
-        loop {
-            let unit = a[i];
+        let find = |preferred_only: bool| {
+            a.iter()
+                .rev()
+                .filter(|unit| !preferred_only || preference.is_preferred(**unit))
+                .find_map(|&unit| {
+                    let unit_v = unit.as_bits_u128();
 
-            let unit_v = unit.as_bits_u128();
+                    if bits_v >= unit_v {
+                        is_zero_remainder_decimal(bits_vd, Decimal::from(unit_v), precision)
+                            .map(|quotient| (quotient, unit))
+                    } else {
+                        None
+                    }
+                })
+        };
 
-            if bits_v >= unit_v {
-                let unit_vd = Decimal::from(unit_v);
+        find(true).or_else(|| find(false)).unwrap_or((bits_vd, Unit::Bit))
+    }
 
-                if let Some(quotient) = is_zero_remainder_decimal(bits_vd, unit_vd, precision) {
-                    return (quotient, unit);
-                }
-            }
+    /// Adjust the unit and value for this `Bit` instance precisely. Unlike the [`Bit::get_adjusted_unit`](#method.get_adjusted_unit) method, this method does not involve any floating-point number, so the result is consistent on all targets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, Unit};
+    ///
+    /// let bit = Bit::parse_str("50.84 Mb").unwrap();
+    ///
+    /// let (value, unit) = bit.get_adjusted_unit_exact(Unit::Mibit);
+    ///
+    /// assert_eq!("48.48480224609375", value.to_string());
+    /// assert_eq!(Unit::Mibit, unit);
+    /// ```
+    ///
+    /// ```
+    /// use byte_unit::{Bit, Unit};
+    ///
+    /// let bit = Bit::from_u64(1000);
+    ///
+    /// assert_eq!((125.into(), Unit::B), bit.get_adjusted_unit_exact(Unit::B));
+    /// assert_eq!((1.into(), Unit::Kbit), bit.get_adjusted_unit_exact(Unit::Kbit));
+    /// ```
+    #[inline]
+    pub fn get_adjusted_unit_exact(self, unit: Unit) -> (Decimal, Unit) {
+        let bits_vd = Decimal::from(self.as_u128());
 
-            if i == 0 {
-                break;
-            }
+        let value = match unit {
+            Unit::Bit => bits_vd,
+            _ => (bits_vd / Decimal::from(unit.as_bits_u128())).normalize(),
+        };
 
-            i -= 1;
-        }
+        (value, unit)
+    }
+
+    /// Adjust the unit and value for this `Bit` instance precisely, and round the value to the given precision. The third element of the returned tuple is `true` if the value is exact, or `false` if it has been rounded, e.g. to be written as `"≈ 1.519 Kib"`.
+    ///
+    /// The value is rounded half away from zero, and the unnecessary trailing zeros are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, Precision, Unit};
+    ///
+    /// let bit = Bit::from_u64(1555);
+    ///
+    /// let (value, unit, exact) =
+    ///     bit.get_adjusted_unit_rounded(Unit::Kibit, Precision::Digits(3));
+    ///
+    /// assert_eq!("1.519", value.to_string());
+    /// assert_eq!(Unit::Kibit, unit);
+    /// assert!(!exact);
+    ///
+    /// let (value, _, exact) =
+    ///     bit.get_adjusted_unit_rounded(Unit::Kibit, Precision::Max);
+    ///
+    /// assert_eq!("1.5185546875", value.to_string());
+    /// assert!(exact);
+    /// ```
+    #[inline]
+    pub fn get_adjusted_unit_rounded(
+        self,
+        unit: Unit,
+        precision: Precision,
+    ) -> (Decimal, Unit, bool) {
+        let bits_vd = Decimal::from(self.as_u128());
+
+        let (value, unit) = self.get_adjusted_unit_exact(unit);
+
+        let value = value
+            .round_dp_with_strategy(
+                precision.digits() as u32,
+                RoundingStrategy::MidpointAwayFromZero,
+            )
+            .normalize();
+
+        let recovered = match unit {
+            Unit::Bit => Some(value),
+            _ => value.checked_mul(Decimal::from(unit.as_bits_u128())),
+        };
 
-        (bits_vd, Unit::Bit)
+        (value, unit, recovered == Some(bits_vd))
     }
 }
@@ -0,0 +1,173 @@
+use core::fmt::{self, Display, Formatter};
+
+use super::Bit;
+use crate::{
+    format::fmt_decimal_with_unit, FormatOptions, FormatStyle, Precision, Unit, UnitPreference,
+};
+
+/// Generated from the [`Bit::display`](./struct.Bit.html#method.display) method or the [`Bit::display_with`](./struct.Bit.html#method.display_with) method. The options can be changed with the builder methods, so nothing is formatted until it is written.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{Bit, Unit};
+///
+/// let bit = Bit::from_u64(1_500_000);
+///
+/// assert_eq!(
+///     "1.43Mib",
+///     bit.display().unit(Unit::Mibit).precision(2).no_space().to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BitDisplay {
+    bit:     Bit,
+    options: FormatOptions,
+    unit:    Option<Unit>,
+}
+
+impl Display for BitDisplay {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (value, unit) = match self.unit {
+            Some(unit) => match self.options.precision {
+                Some(precision) => {
+                    let (value, unit, _) =
+                        self.bit.get_adjusted_unit_rounded(unit, Precision::Digits(precision));
+
+                    (value, unit)
+                },
+                None => self.bit.get_adjusted_unit_exact(unit),
+            },
+            None => self.bit.get_recoverable_unit_with_preference(
+                false,
+                self.options.precision.unwrap_or(3),
+                UnitPreference::global(),
+            ),
+        };
+
+        fmt_decimal_with_unit(f, value, unit, &self.options)
+    }
+}
+
+/// Methods for setting the options.
+impl BitDisplay {
+    /// Set the unit. By default, the largest unit which can represent the size within the precision is chosen. With a fixed unit, the value is rounded to the precision if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, Unit};
+    ///
+    /// let bit = Bit::from_u64(1_500_000);
+    ///
+    /// assert_eq!("1.5 Mb", bit.display().to_string());
+    /// assert_eq!("1500 Kb", bit.display().unit(Unit::Kbit).to_string());
+    /// assert_eq!(
+    ///     "1.430511474609375 Mib",
+    ///     bit.display().unit(Unit::Mibit).to_string()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn unit(mut self, unit: Unit) -> Self {
+        self.unit = Some(unit);
+
+        self
+    }
+
+    /// Set the maximum number of digits after the decimal point. See [`FormatOptions::precision`](./struct.FormatOptions.html#method.precision).
+    #[inline]
+    pub const fn precision(mut self, precision: usize) -> Self {
+        self.options = self.options.precision(precision);
+
+        self
+    }
+
+    /// Set whether to keep the trailing zeros. See [`FormatOptions::keep_trailing_zeros`](./struct.FormatOptions.html#method.keep_trailing_zeros).
+    #[inline]
+    pub const fn keep_trailing_zeros(mut self, keep_trailing_zeros: bool) -> Self {
+        self.options = self.options.keep_trailing_zeros(keep_trailing_zeros);
+
+        self
+    }
+
+    /// Set the style of the unit. See [`FormatStyle`](./enum.FormatStyle.html).
+    #[inline]
+    pub const fn style(mut self, style: FormatStyle) -> Self {
+        self.options = self.options.style(style);
+
+        self
+    }
+
+    /// Write no spaces between the value and the unit, which is the same as `style(FormatStyle::Compact)`.
+    #[inline]
+    pub const fn no_space(self) -> Self {
+        self.style(FormatStyle::Compact)
+    }
+}
+
+/// Methods for formatting with options.
+impl Bit {
+    /// Create a value which implements `Display` for this `Bit` instance with the default options, which can be changed by its builder methods. Like the alternate form (`{:#}`) of the `Display` implementation, the largest unit which can represent the size within the precision (the default is `3`) is chosen unless a unit is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, Unit};
+    ///
+    /// let bit = Bit::from_u64(10240);
+    ///
+    /// assert_eq!(format!("{bit:#}"), bit.display().to_string());
+    /// assert_eq!(
+    ///     "0.01 Mib",
+    ///     bit.display().unit(Unit::Mibit).precision(2).to_string()
+    /// );
+    /// assert_eq!(
+    ///     "10.00Kib",
+    ///     bit.display()
+    ///         .precision(2)
+    ///         .keep_trailing_zeros(true)
+    ///         .no_space()
+    ///         .to_string()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn display(self) -> BitDisplay {
+        self.display_with(FormatOptions::new())
+    }
+
+    /// Create a value which implements `Display` for this `Bit` instance with the given options. Like the alternate form (`{:#}`) of the `Display` implementation, the largest unit which can represent the size within the precision (the default is `3`) is chosen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, FormatOptions, FormatStyle};
+    ///
+    /// let bit = Bit::from_u64(10240);
+    ///
+    /// assert_eq!(
+    ///     format!("{bit:#}"),
+    ///     bit.display_with(FormatOptions::new()).to_string()
+    /// );
+    /// assert_eq!(
+    ///     "10Kib",
+    ///     bit.display_with(FormatOptions::new().style(FormatStyle::Compact))
+    ///         .to_string()
+    /// );
+    /// assert_eq!(
+    ///     "10.00 Kib",
+    ///     bit.display_with(
+    ///         FormatOptions::new().precision(2).keep_trailing_zeros(true)
+    ///     )
+    ///     .to_string()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn display_with(self, options: FormatOptions) -> BitDisplay {
+        BitDisplay {
+            bit: self,
+            options,
+            unit: None,
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use super::Bit;
+
+/// Methods for interpolating between `Bit` instances, e.g. to animate a size bar.
+impl Bit {
+    /// Linearly interpolate between this `Bit` instance and `other`, rounding to the nearest bit. **0** is `self`, and **1** is `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// let a = Bit::from_u64(1000);
+    /// let b = Bit::from_u64(2000);
+    ///
+    /// assert_eq!(Bit::from_u64(1250), a.lerp(b, 0.25));
+    /// assert_eq!(Bit::from_u64(1750), b.lerp(a, 0.25));
+    /// assert_eq!(b, a.lerp(b, 1.5));
+    /// assert_eq!(a, a.lerp(b, f64::NAN));
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * `t` is clamped to the range from **0** to **1**, and `NaN` is treated as **0**, so the result is always between `self` and `other`. Use [`Bit::checked_lerp`](#method.checked_lerp) to reject such values instead.
+    #[inline]
+    pub fn lerp(self, other: Bit, t: f64) -> Bit {
+        let t = if t >= 1.0 {
+            1.0
+        } else if t > 0.0 {
+            t
+        } else {
+            // negative numbers and NaN
+            0.0
+        };
+
+        let (a, b) = (self.as_u128(), other.as_u128());
+
+        // the offset never exceeds the distance, because `t` is at most 1
+        let offset = |distance: u128| ((distance as f64 * t + 0.5) as u128).min(distance);
+
+        let v = if a <= b { a + offset(b - a) } else { a - offset(a - b) };
+
+        Bit::from_u128(v).unwrap()
+    }
+
+    /// Linearly interpolate between this `Bit` instance and `other`. If `t` is `NaN` or not in the range from **0** to **1**, this function will return `None`. See [`Bit::lerp`](#method.lerp).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// let a = Bit::from_u64(1000);
+    /// let b = Bit::from_u64(2000);
+    ///
+    /// assert_eq!(Some(Bit::from_u64(1500)), a.checked_lerp(b, 0.5));
+    /// assert_eq!(None, a.checked_lerp(b, -0.1));
+    /// assert_eq!(None, a.checked_lerp(b, f64::NAN));
+    /// ```
+    #[inline]
+    pub fn checked_lerp(self, other: Bit, t: f64) -> Option<Bit> {
+        if (0.0..=1.0).contains(&t) {
+            Some(self.lerp(other, t))
+        } else {
+            None
+        }
+    }
+
+    /// Move this `Bit` instance towards `target` by the fraction `alpha` of the remaining distance, i.e. exponential smoothing.
+    ///
+    /// Unless `alpha` is **0**, it moves by at least 1 bit, so that calling this function repeatedly always reaches `target`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// let target = Bit::from_u64(1000);
+    ///
+    /// let mut displayed = Bit::from_u64(0);
+    ///
+    /// displayed = displayed.smooth_towards(target, 0.5);
+    ///
+    /// assert_eq!(Bit::from_u64(500), displayed);
+    ///
+    /// for _ in 0..20 {
+    ///     displayed = displayed.smooth_towards(target, 0.5);
+    /// }
+    ///
+    /// assert_eq!(target, displayed);
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * `alpha` is clamped in the same way as `t` of [`Bit::lerp`](#method.lerp).
+    #[inline]
+    pub fn smooth_towards(self, target: Bit, alpha: f64) -> Bit {
+        let v = self.lerp(target, alpha);
+
+        if v == self && self != target && alpha > 0.0 {
+            if self < target {
+                Bit::from_u128(self.as_u128() + 1).unwrap()
+            } else {
+                Bit::from_u128(self.as_u128() - 1).unwrap()
+            }
+        } else {
+            v
+        }
+    }
+}
@@ -1,35 +1,58 @@
 mod adjusted;
+#[cfg(feature = "arbitrary")]
+mod arbitrary_traits;
 mod built_in_traits;
+#[cfg(feature = "clap")]
+mod clap_traits;
 mod constants;
 mod decimal;
+mod display;
+mod interpolation;
+#[cfg(feature = "num-traits")]
+mod num_traits;
 mod parse;
+#[cfg(feature = "proptest")]
+mod proptest_traits;
+mod rate;
 #[cfg(feature = "rocket")]
 mod rocket_traits;
+#[cfg(feature = "saturating")]
+#[clippy::msrv = "1.74"]
+mod saturating_traits;
+#[cfg(feature = "schemars")]
+mod schemars_traits;
 #[cfg(feature = "serde")]
 mod serde_traits;
+mod signed;
 
-use core::fmt::{self, Alignment, Display, Formatter, Write};
+use core::fmt::{self, Display, Formatter};
 
 pub use adjusted::*;
+pub use display::*;
+pub use rate::*;
 use rust_decimal::prelude::*;
+pub use signed::*;
 
 use crate::{
     common::{ceil_f32, ceil_f64},
-    Unit,
+    format::fmt_with_unit,
+    RoundingMode, Unit, UnitPreference,
 };
 
 #[cfg(feature = "u128")]
 const RONNABIT: u128 = 1_000_000_000_000_000_000_000_000_000; // RB
 
+/// The native integer type backing `Bit`, which is `u128` if the `u128` feature is enabled, or `u64` otherwise. See [`Bit::get`](struct.Bit.html#method.get).
 #[cfg(feature = "u128")]
-#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Default)]
-/// Representing the size in bits.
-pub struct Bit(u128);
+pub type BitRepr = u128;
 
+/// The native integer type backing `Bit`, which is `u128` if the `u128` feature is enabled, or `u64` otherwise. See [`Bit::get`](struct.Bit.html#method.get).
 #[cfg(not(feature = "u128"))]
+pub type BitRepr = u64;
+
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Default)]
 /// Representing the size in bits.
-pub struct Bit(u64);
+pub struct Bit(BitRepr);
 
 impl Display for Bit {
     /// Formats the value using the given formatter.
@@ -72,6 +95,10 @@ impl Display for Bit {
     /// // with a width, right alignment, more spaces between the value and the unit
     /// assert_eq!("    10 Kib", format!("{bit_based_2:>+#10}"));
     /// assert_eq!("    10  Kb", format!("{bit_based_10:>+#10}"));
+    ///
+    /// // with a fill character, or padded with zeros, like integers
+    /// assert_eq!("**10** Kib", format!("{bit_based_2:*^#10}"));
+    /// assert_eq!("000010 Kib", format!("{bit_based_2:#010}"));
     /// ```
     ///
     /// ```
@@ -94,44 +121,15 @@ impl Display for Bit {
         if f.alternate() {
             let precision = f.precision().unwrap_or(3);
 
-            let (mut value, unit) = self.get_recoverable_unit(false, precision);
-
-            value = value.normalize();
-
-            let space_length = if f.sign_plus() {
-                4 - unit.as_str().len()
-            } else if f.sign_minus() {
-                0
-            } else {
-                1
-            };
-
-            if let Some(mut width) = f.width() {
-                let l = unit.as_str().len() + space_length;
+            let (value, unit) = self.get_recoverable_unit_with_preference(
+                false,
+                precision,
+                UnitPreference::global(),
+            );
 
-                if width > l + 1 {
-                    width -= l;
+            let value = value.normalize();
 
-                    let alignment = f.align().unwrap_or(Alignment::Left);
-
-                    match alignment {
-                        Alignment::Left | Alignment::Center => {
-                            f.write_fmt(format_args!("{value:<width$}"))?
-                        },
-                        Alignment::Right => f.write_fmt(format_args!("{value:>width$}"))?,
-                    }
-                } else {
-                    f.write_fmt(format_args!("{value}"))?;
-                }
-            } else {
-                f.write_fmt(format_args!("{value}"))?;
-            }
-
-            for _ in 0..space_length {
-                f.write_char(' ')?;
-            }
-
-            f.write_fmt(format_args!("{unit}"))
+            fmt_with_unit(f, format_args!("{value}"), Some(unit))
         } else {
             Display::fmt(&self.0, f)
         }
@@ -173,30 +171,63 @@ impl Bit {
         }
     }
 
-    /// Create a new `Bit` instance from a size in bits.
+    /// Create a new `Bit` instance from a size in bits without checking the bounds.
     ///
     /// # Examples
     ///
     /// ```
     /// # use byte_unit::Bit;
-    /// let bit = unsafe { Bit::from_u128_unsafe(15000000) }; // 15 Mb
+    /// const BIT: Bit = Bit::from_u128_unchecked(15000000); // 15 Mb
     /// ```
     ///
-    /// # Safety
-    /// You must ensure the input **size** is not too large (the maximum is **10<sup>27</sup> - 1** if the `u128` feature is enabled, or **2<sup>64</sup> - 1** otherwise) on your own.
+    /// # Points to Note
+    ///
+    /// * The caller must ensure the input **size** is not too large (the maximum is **10<sup>27</sup> - 1** if the `u128` feature is enabled, or **2<sup>64</sup> - 1** otherwise). This is a logic contract, not a memory safety one: a size which is too large is truncated, and the arithmetic on the result may be wrong, but no undefined behavior can happen. It is checked by a `debug_assert!`, so it panics in debug builds.
     #[inline]
-    pub const unsafe fn from_u128_unsafe(size: u128) -> Self {
+    pub const fn from_u128_unchecked(size: u128) -> Self {
         #[cfg(feature = "u128")]
         {
+            debug_assert!(size < RONNABIT, "the size is too large");
+
             Bit(size)
         }
 
         #[cfg(not(feature = "u128"))]
         {
+            debug_assert!(size <= u64::MAX as u128, "the size is too large");
+
             Bit(size as u64)
         }
     }
 
+    /// Create a new `Bit` instance from a size in bits.
+    ///
+    /// # Safety
+    /// You must ensure the input **size** is not too large (the maximum is **10<sup>27</sup> - 1** if the `u128` feature is enabled, or **2<sup>64</sup> - 1** otherwise) on your own.
+    #[deprecated(note = "no memory safety is involved, use `Bit::from_u128_unchecked` instead")]
+    #[inline]
+    pub const unsafe fn from_u128_unsafe(size: u128) -> Self {
+        Self::from_u128_unchecked(size)
+    }
+
+    /// Create a new `Bit` instance from a size in bits. If the input **size** is too large, it is clamped to `Bit::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// assert_eq!(Bit::from_u64(15), Bit::new_clamped(15));
+    /// assert_eq!(Bit::MAX, Bit::new_clamped(u128::MAX));
+    /// ```
+    #[inline]
+    pub const fn new_clamped(size: u128) -> Self {
+        match Self::from_u128(size) {
+            Some(bit) => bit,
+            None => Self::MAX,
+        }
+    }
+
     /// Create a new `Bit` instance from a size in bits.
     ///
     /// # Examples
@@ -231,6 +262,10 @@ impl Bit {
     ///
     /// * If the input **size** is too large (the maximum is **10<sup>27</sup> - 1** if the `u128` feature is enabled, or **2<sup>64</sup> - 1** otherwise) or not greater than or equal to **0**, this function will return `None`.
     /// * The fractional part will be rounded up.
+    #[cfg_attr(
+        feature = "strict",
+        deprecated(note = "floating-point numbers are lossy, use `Bit::from_decimal` instead")
+    )]
     #[inline]
     pub fn from_f64(size: f64) -> Option<Self> {
         if size >= 0.0 {
@@ -273,6 +308,10 @@ impl Bit {
     ///
     /// * If the input **size** is too large (the maximum is **10<sup>27</sup> - 1** if the `u128` feature is enabled, or **2<sup>64</sup> - 1** otherwise) or not greater than or equal to **0**, this function will return `None`.
     /// * The fractional part will be rounded up.
+    #[cfg_attr(
+        feature = "strict",
+        deprecated(note = "floating-point numbers are lossy, use `Bit::from_decimal` instead")
+    )]
     #[inline]
     pub fn from_f32(size: f32) -> Option<Self> {
         if size >= 0.0 {
@@ -426,6 +465,12 @@ impl Bit {
     ///
     /// * If the calculated bit is too large or not greater than or equal to **0**, this function will return `None`.
     /// * The calculated bit will be rounded up.
+    #[cfg_attr(
+        feature = "strict",
+        deprecated(
+            note = "floating-point numbers are lossy, use `Bit::from_decimal_with_unit` instead"
+        )
+    )]
     #[inline]
     pub fn from_f64_with_unit(size: f64, unit: Unit) -> Option<Self> {
         match Decimal::from_f64(size) {
@@ -448,6 +493,12 @@ impl Bit {
     ///
     /// * If the calculated bit is too large or not greater than or equal to **0**, this function will return `None`.
     /// * The calculated bit will be rounded up.
+    #[cfg_attr(
+        feature = "strict",
+        deprecated(
+            note = "floating-point numbers are lossy, use `Bit::from_decimal_with_unit` instead"
+        )
+    )]
     #[inline]
     pub fn from_f32_with_unit(size: f32, unit: Unit) -> Option<Self> {
         match Decimal::from_f32(size) {
@@ -503,6 +554,30 @@ impl Bit {
 
 /// Methods for converting a `Bit` instance into a primitive integer.
 impl Bit {
+    /// Retrieve the bit represented by this `Bit` instance as the native integer type, [`BitRepr`](type.BitRepr.html), without any conversion.
+    ///
+    /// Generic numeric code can be written once against `BitRepr` instead of checking whether the `u128` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, BitRepr};
+    ///
+    /// let bit = Bit::from_u64(1024);
+    ///
+    /// let result: BitRepr = bit.get();
+    ///
+    /// assert_eq!(1024, result);
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * `Bit` does not implement `Deref`, because the arithmetic of the integer type does not respect the bounds of `Bit`. Use `AsRef` or `Borrow` to access the integer by reference.
+    #[inline]
+    pub const fn get(self) -> BitRepr {
+        self.0
+    }
+
     /// Retrieve the bit represented by this `Bit` instance.
     ///
     /// # Examples
@@ -648,10 +723,7 @@ impl Bit {
     /// * If the calculated bit is too large, this function will return `None`.
     #[inline]
     pub const fn add(self, rhs: Bit) -> Option<Bit> {
-        match self.0.checked_add(rhs.0) {
-            Some(v) => Some(Bit(v)),
-            None => None,
-        }
+        self.checked_add(rhs)
     }
 
     /// Subtract another `Bit` instance.
@@ -704,7 +776,7 @@ impl Bit {
         #[cfg(feature = "u128")]
         {
             match self.0.checked_mul(rhs as u128) {
-                Some(v) => Some(Bit(v)),
+                Some(v) => Bit::from_u128(v),
                 None => None,
             }
         }
@@ -777,6 +849,482 @@ impl Bit {
     }
 }
 
+/// Methods for calculation with the same semantics as primitive integers.
+///
+/// The arithmetic operators (`+`, `-`, `*`, `/` and their assignment forms) are implemented with these methods and panic on overflow or division by zero, like the operators of primitive integers in debug mode. Use these methods directly to handle the overflow explicitly.
+impl Bit {
+    /// Checked addition. Returns `None` if the result exceeds [`Bit::MAX`](#associatedconstant.MAX).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// assert_eq!(
+    ///     Some(Bit::from_u64(1536)),
+    ///     Bit::from_u64(1024).checked_add(Bit::from_u64(512))
+    /// );
+    /// assert_eq!(None, Bit::MAX.checked_add(Bit::from_u64(1)));
+    /// ```
+    #[inline]
+    pub const fn checked_add(self, rhs: Bit) -> Option<Bit> {
+        match self.as_u128().checked_add(rhs.as_u128()) {
+            Some(v) => Bit::from_u128(v),
+            None => None,
+        }
+    }
+
+    /// Checked subtraction. Returns `None` if `rhs` is larger than this `Bit` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// assert_eq!(
+    ///     Some(Bit::from_u64(512)),
+    ///     Bit::from_u64(1024).checked_sub(Bit::from_u64(512))
+    /// );
+    /// assert_eq!(None, Bit::from_u64(512).checked_sub(Bit::from_u64(1024)));
+    /// ```
+    #[inline]
+    pub const fn checked_sub(self, rhs: Bit) -> Option<Bit> {
+        match self.0.checked_sub(rhs.0) {
+            Some(v) => Some(Bit(v)),
+            None => None,
+        }
+    }
+
+    /// Checked multiplication. Returns `None` if the result exceeds [`Bit::MAX`](#associatedconstant.MAX).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// assert_eq!(Some(Bit::from_u64(4096)), Bit::from_u64(1024).checked_mul(4));
+    /// assert_eq!(None, Bit::MAX.checked_mul(2));
+    /// ```
+    #[inline]
+    pub const fn checked_mul(self, rhs: u64) -> Option<Bit> {
+        match self.as_u128().checked_mul(rhs as u128) {
+            Some(v) => Bit::from_u128(v),
+            None => None,
+        }
+    }
+
+    /// Checked division, rounded down. Returns `None` if `rhs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// assert_eq!(Some(Bit::from_u64(341)), Bit::from_u64(1024).checked_div(3));
+    /// assert_eq!(None, Bit::from_u64(1024).checked_div(0));
+    /// ```
+    #[inline]
+    pub const fn checked_div(self, rhs: u64) -> Option<Bit> {
+        if rhs == 0 {
+            None
+        } else {
+            Bit::from_u128(self.as_u128() / rhs as u128)
+        }
+    }
+
+    /// Saturating addition. Returns [`Bit::MAX`](#associatedconstant.MAX) instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// assert_eq!(Bit::MAX, Bit::MAX.saturating_add(Bit::from_u64(1)));
+    /// ```
+    #[inline]
+    pub const fn saturating_add(self, rhs: Bit) -> Bit {
+        match self.checked_add(rhs) {
+            Some(bit) => bit,
+            None => Bit::MAX,
+        }
+    }
+
+    /// Saturating subtraction. Returns [`Bit::MIN`](#associatedconstant.MIN) instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// assert_eq!(
+    ///     Bit::MIN,
+    ///     Bit::from_u64(512).saturating_sub(Bit::from_u64(1024))
+    /// );
+    /// ```
+    #[inline]
+    pub const fn saturating_sub(self, rhs: Bit) -> Bit {
+        match self.checked_sub(rhs) {
+            Some(bit) => bit,
+            None => Bit::MIN,
+        }
+    }
+
+    /// Saturating multiplication. Returns [`Bit::MAX`](#associatedconstant.MAX) instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// assert_eq!(Bit::MAX, Bit::MAX.saturating_mul(2));
+    /// ```
+    #[inline]
+    pub const fn saturating_mul(self, rhs: u64) -> Bit {
+        match self.checked_mul(rhs) {
+            Some(bit) => bit,
+            None => Bit::MAX,
+        }
+    }
+
+    /// Wrapping addition. Wraps around at the boundary of [`Bit::MAX`](#associatedconstant.MAX), which is not a power of two if the `u128` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// assert_eq!(Bit::from_u64(0), Bit::MAX.wrapping_add(Bit::from_u64(1)));
+    /// ```
+    #[inline]
+    pub const fn wrapping_add(self, rhs: Bit) -> Bit {
+        let modulus = Bit::MAX.as_u128() + 1;
+
+        match Bit::from_u128((self.as_u128() + rhs.as_u128()) % modulus) {
+            Some(bit) => bit,
+            None => unreachable!(),
+        }
+    }
+
+    /// Wrapping subtraction. Wraps around at the boundary of [`Bit::MIN`](#associatedconstant.MIN).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// assert_eq!(Bit::MAX, Bit::from_u64(0).wrapping_sub(Bit::from_u64(1)));
+    /// ```
+    #[inline]
+    pub const fn wrapping_sub(self, rhs: Bit) -> Bit {
+        let modulus = Bit::MAX.as_u128() + 1;
+
+        match Bit::from_u128((self.as_u128() + modulus - rhs.as_u128()) % modulus) {
+            Some(bit) => bit,
+            None => unreachable!(),
+        }
+    }
+
+    /// Checked summation of bits, e.g. from an iterator. Returns `None` if the result exceeds [`Bit::MAX`](#associatedconstant.MAX).
+    ///
+    /// The `Sum` implementations panic on overflow, like the `+` operator. Use this function to handle the overflow explicitly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// let sizes = [Bit::from_u64(1024), Bit::from_u64(512)];
+    ///
+    /// assert_eq!(Some(Bit::from_u64(1536)), Bit::checked_sum(sizes));
+    /// assert_eq!(None, Bit::checked_sum([Bit::MAX, Bit::from_u64(1)]));
+    /// ```
+    #[inline]
+    pub fn checked_sum<I: IntoIterator<Item = Bit>>(iter: I) -> Option<Bit> {
+        iter.into_iter().try_fold(Bit::MIN, Bit::checked_add)
+    }
+
+    /// Saturating summation of bits, e.g. from an iterator. Returns [`Bit::MAX`](#associatedconstant.MAX) if the result exceeds it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// assert_eq!(Bit::MAX, Bit::saturating_sum([Bit::MAX, Bit::from_u64(1)]));
+    /// ```
+    #[inline]
+    pub fn saturating_sum<I: IntoIterator<Item = Bit>>(iter: I) -> Bit {
+        iter.into_iter().fold(Bit::MIN, Bit::saturating_add)
+    }
+}
+
+/// Methods for comparing.
+impl Bit {
+    /// Get the absolute difference between this `Bit` instance and another `Bit` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// assert_eq!(
+    ///     Bit::from_u64(24),
+    ///     Bit::from_u64(1000).abs_diff(Bit::from_u64(1024))
+    /// );
+    /// ```
+    #[inline]
+    pub const fn abs_diff(self, other: Bit) -> Bit {
+        Bit(self.0.abs_diff(other.0))
+    }
+
+    /// Check whether this `Bit` instance differs from another `Bit` instance by at most `tolerance`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// let expected = Bit::from_u64(1048576);
+    ///
+    /// assert!(Bit::from_u64(1048570).within(expected, Bit::from_u64(16)));
+    /// assert!(!Bit::from_u64(1048500).within(expected, Bit::from_u64(16)));
+    /// ```
+    #[inline]
+    pub const fn within(self, other: Bit, tolerance: Bit) -> bool {
+        self.0.abs_diff(other.0) <= tolerance.0
+    }
+
+    /// Get the smaller one of this `Bit` instance and another `Bit` instance. Unlike `Ord::min`, this method can be used in constant expressions.
+    #[inline]
+    pub const fn min(self, other: Bit) -> Bit {
+        if self.0 <= other.0 {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Get the larger one of this `Bit` instance and another `Bit` instance. Unlike `Ord::max`, this method can be used in constant expressions.
+    #[inline]
+    pub const fn max(self, other: Bit) -> Bit {
+        if self.0 >= other.0 {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Restrict this `Bit` instance to the range from `min` to `max`. Unlike `Ord::clamp`, this method can be used in constant expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// const LIMIT: Bit = Bit::from_u64(1 << 40).clamp(Bit::MEBIBIT, Bit::GIBIBIT);
+    ///
+    /// assert_eq!(Bit::GIBIBIT, LIMIT);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`.
+    #[inline]
+    pub const fn clamp(self, min: Bit, max: Bit) -> Bit {
+        assert!(min.0 <= max.0, "min must not be greater than max");
+
+        self.max(min).min(max)
+    }
+
+    /// Get the difference between this `Bit` instance and another `Bit` instance relative to the larger one, from `0.0` (equal) to `1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// assert_eq!(0.25, Bit::from_u64(750).relative_diff(Bit::from_u64(1000)));
+    /// assert_eq!(0.25, Bit::from_u64(1000).relative_diff(Bit::from_u64(750)));
+    /// assert_eq!(0.0, Bit::from_u64(0).relative_diff(Bit::from_u64(0)));
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If both are zero, this function will return `0.0`.
+    #[inline]
+    pub fn relative_diff(self, other: Bit) -> f64 {
+        let max = self.0.max(other.0);
+
+        if max == 0 {
+            return 0.0;
+        }
+
+        self.0.abs_diff(other.0) as f64 / max as f64
+    }
+}
+
+/// Methods for rounding.
+impl Bit {
+    /// Round this `Bit` instance to a multiple of the given unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, RoundingMode, Unit};
+    ///
+    /// let bit = Bit::from_u64(1500);
+    ///
+    /// assert_eq!(
+    ///     Some(Bit::from_u64(2000)),
+    ///     bit.round_to_unit(Unit::Kbit, RoundingMode::Up)
+    /// );
+    /// assert_eq!(
+    ///     Some(Bit::from_u64(1000)),
+    ///     bit.round_to_unit(Unit::Kbit, RoundingMode::Down)
+    /// );
+    /// assert_eq!(
+    ///     Some(Bit::from_u64(2000)),
+    ///     bit.round_to_unit(Unit::Kbit, RoundingMode::Nearest)
+    /// );
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If the rounded size is too large, this method will return `None`.
+    #[inline]
+    pub const fn round_to_unit(self, unit: Unit, rounding_mode: RoundingMode) -> Option<Bit> {
+        self.round_to_multiple(unit.as_bits_u128(), rounding_mode)
+    }
+
+    /// Round this `Bit` instance to a multiple of `step` bits. Returns `None` if `step` is zero or the rounded size is too large.
+    #[inline]
+    pub(crate) const fn round_to_multiple(
+        self,
+        step: u128,
+        rounding_mode: RoundingMode,
+    ) -> Option<Bit> {
+        if step == 0 {
+            return None;
+        }
+
+        match rounding_mode.round_u128(self.as_u128(), step) {
+            Some(v) => Bit::from_u128(v),
+            None => None,
+        }
+    }
+}
+
+/// Methods for alignment.
+impl Bit {
+    /// Round this `Bit` instance up to a multiple of `alignment`, which can be a power of two, such as a 4 KiB page, or any other block size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// let page = Bit::from_u64(4096);
+    ///
+    /// assert_eq!(Some(Bit::from_u64(8192)), Bit::from_u64(4097).align_up(page));
+    /// assert_eq!(Some(Bit::from_u64(4096)), Bit::from_u64(4096).align_up(page));
+    /// assert_eq!(
+    ///     Some(Bit::from_u64(1500)),
+    ///     Bit::from_u64(1001).align_up(Bit::from_u64(500))
+    /// );
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If `alignment` is zero or the aligned size is too large, this method will return `None`.
+    #[inline]
+    pub const fn align_up(self, alignment: Bit) -> Option<Bit> {
+        self.round_to_multiple(alignment.as_u128(), RoundingMode::Up)
+    }
+
+    /// Round this `Bit` instance down to a multiple of `alignment`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// let sector = Bit::from_u64(512);
+    ///
+    /// assert_eq!(
+    ///     Some(Bit::from_u64(1024)),
+    ///     Bit::from_u64(1535).align_down(sector)
+    /// );
+    /// assert_eq!(Some(Bit::from_u64(0)), Bit::from_u64(511).align_down(sector));
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If `alignment` is zero, this method will return `None`.
+    #[inline]
+    pub const fn align_down(self, alignment: Bit) -> Option<Bit> {
+        let alignment = alignment.as_u128();
+
+        if alignment == 0 {
+            return None;
+        }
+
+        let v = self.as_u128();
+
+        Some(Bit::from_u128_unchecked(v - v % alignment))
+    }
+
+    /// Check whether this `Bit` instance is a multiple of `alignment`. Nothing is aligned to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// let page = Bit::from_u64(4096);
+    ///
+    /// assert!(Bit::from_u64(8192).is_aligned_to(page));
+    /// assert!(Bit::from_u64(0).is_aligned_to(page));
+    /// assert!(!Bit::from_u64(4097).is_aligned_to(page));
+    /// assert!(!Bit::from_u64(0).is_aligned_to(Bit::from_u64(0)));
+    /// ```
+    #[inline]
+    pub const fn is_aligned_to(self, alignment: Bit) -> bool {
+        let alignment = alignment.as_u128();
+
+        alignment != 0 && self.as_u128() % alignment == 0
+    }
+
+    /// Get the smallest power of two which is not less than this `Bit` instance. The next power of two of zero is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// assert_eq!(
+    ///     Some(Bit::from_u64(4096)),
+    ///     Bit::from_u64(3000).next_power_of_two()
+    /// );
+    /// assert_eq!(
+    ///     Some(Bit::from_u64(4096)),
+    ///     Bit::from_u64(4096).next_power_of_two()
+    /// );
+    /// assert_eq!(Some(Bit::from_u64(1)), Bit::from_u64(0).next_power_of_two());
+    /// assert_eq!(None, Bit::MAX.next_power_of_two());
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If the power of two is too large, this method will return `None`.
+    #[inline]
+    pub const fn next_power_of_two(self) -> Option<Bit> {
+        match self.as_u128().checked_next_power_of_two() {
+            Some(v) => Bit::from_u128(v),
+            None => None,
+        }
+    }
+}
+
 /// Methods for finding an unit.
 impl Bit {
     /// Obtain the largest unit which is the greatest factor of this `Bit` instance.
@@ -815,6 +1363,18 @@ impl Bit {
     /// assert_eq!(24, n);
     /// assert_eq!(Unit::Mbit, unit);
     /// ```
+    ///
+    /// This method can be used in const contexts.
+    ///
+    /// ```
+    /// use byte_unit::{Bit, Unit};
+    ///
+    /// const LIMIT: Bit = Bit::from_u64(24000000);
+    /// const EXACT: (u128, Unit) = LIMIT.get_exact_unit(false);
+    /// const UNIT: &str = EXACT.1.as_str();
+    ///
+    /// assert_eq!("Mb", UNIT);
+    /// ```
     #[inline]
     pub const fn get_exact_unit(self, allow_in_bytes: bool) -> (u128, Unit) {
         let bits_v = self.as_u128();
@@ -841,3 +1401,25 @@ impl Bit {
         (bits_v, Unit::Bit)
     }
 }
+
+/// Methods for converting a `Byte` instance into a `Bit` instance.
+#[cfg(feature = "byte")]
+impl crate::Byte {
+    /// Convert this `Byte` instance into a `Bit` instance. Return `None` if the number of bits exceeds the range of `Bit`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, Byte};
+    ///
+    /// assert_eq!(Some(Bit::from_u64(12000)), Byte::from_u64(1500).as_bit());
+    /// assert_eq!(None, Byte::MAX.as_bit());
+    /// ```
+    #[inline]
+    pub const fn as_bit(self) -> Option<Bit> {
+        match self.as_u128().checked_mul(8) {
+            Some(bits) => Bit::from_u128(bits),
+            None => None,
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use core::ops::Mul;
+
+use num_traits::{
+    Bounded, CheckedAdd, CheckedMul, CheckedSub, FromPrimitive, One, SaturatingAdd, SaturatingMul,
+    SaturatingSub, ToPrimitive, Zero,
+};
+
+use super::Bit;
+
+impl Mul for Bit {
+    type Output = Bit;
+
+    /// Multiply the number of bits by the number of bits of `rhs`, as a raw product of two numbers. For example, 2 Kb * 3 Kb is 6000000 bits, not a size with a squared unit.
+    ///
+    /// This is only implemented because the `One`, `CheckedMul` and `SaturatingMul` traits of `num-traits` need it, and `One` makes 1 bit the multiplicative identity. To scale a size, multiply it by an integer instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result exceeds `Bit::MAX`.
+    #[inline]
+    fn mul(self, rhs: Bit) -> Bit {
+        CheckedMul::checked_mul(&self, &rhs).expect("attempt to multiply with overflow")
+    }
+}
+
+impl Zero for Bit {
+    #[inline]
+    fn zero() -> Self {
+        Bit::MIN
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.as_u128() == 0
+    }
+}
+
+impl One for Bit {
+    /// 1 bit.
+    #[inline]
+    fn one() -> Self {
+        Bit::from_u64(1)
+    }
+}
+
+impl Bounded for Bit {
+    #[inline]
+    fn min_value() -> Self {
+        Bit::MIN
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Bit::MAX
+    }
+}
+
+impl CheckedAdd for Bit {
+    #[inline]
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        Bit::checked_add(*self, *v)
+    }
+}
+
+impl CheckedSub for Bit {
+    #[inline]
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        Bit::checked_sub(*self, *v)
+    }
+}
+
+impl CheckedMul for Bit {
+    #[inline]
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        self.as_u128().checked_mul(v.as_u128()).and_then(Bit::from_u128)
+    }
+}
+
+impl SaturatingAdd for Bit {
+    #[inline]
+    fn saturating_add(&self, v: &Self) -> Self {
+        Bit::saturating_add(*self, *v)
+    }
+}
+
+impl SaturatingSub for Bit {
+    #[inline]
+    fn saturating_sub(&self, v: &Self) -> Self {
+        Bit::saturating_sub(*self, *v)
+    }
+}
+
+impl SaturatingMul for Bit {
+    #[inline]
+    fn saturating_mul(&self, v: &Self) -> Self {
+        CheckedMul::checked_mul(self, v).unwrap_or(Bit::MAX)
+    }
+}
+
+impl ToPrimitive for Bit {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.as_u128().to_i64()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.as_u128().to_u64()
+    }
+
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        self.as_u128().to_i128()
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        Some(self.as_u128())
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        self.as_u128().to_f64()
+    }
+}
+
+impl FromPrimitive for Bit {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        Bit::try_from(n).ok()
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Bit::from_u64(n))
+    }
+
+    #[inline]
+    fn from_i128(n: i128) -> Option<Self> {
+        Bit::try_from(n).ok()
+    }
+
+    #[inline]
+    fn from_u128(n: u128) -> Option<Self> {
+        Bit::from_u128(n)
+    }
+
+    /// The value is rounded up, like [`Bit::from_f64`](Bit::from_f64).
+    #[allow(deprecated)]
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        Bit::from_f64(n)
+    }
+}
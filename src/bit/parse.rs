@@ -1,35 +1,51 @@
+use core::ops::RangeBounds;
+
 use rust_decimal::prelude::*;
 
 use super::Bit;
-use crate::{common::get_char_from_bytes, unit::parse::read_xib, ParseError, ValueParseError};
+#[cfg(feature = "std")]
+use crate::{common::parse_lenient, ParseWarning};
+use crate::{
+    common::{
+        check_range, get_char_from_bytes, get_not_number_error, parse_value, read_space,
+        read_value_exponent,
+    },
+    unit::parse::{parse_integer_and_unit_const, read_xib},
+    ParseError, ParseOptions, RangeParseError, Unit, ValueParseError,
+};
 
 /// Associated functions for parsing strings.
 impl Bit {
     /// Create a new `Bit` instance from a string.
-    /// The string may be `"10"`, `"10B"`, `"10M"`, `"10MB"`, `"10MiB"`, `"80b"`, `"80Mb"`, `"80Mbit"`.
+    /// The string may be `"10"`, `"10B"`, `"10M"`, `"10MB"`, `"10MiB"`, `"80b"`, `"80Mb"`, `"80Mbit"`, or use the full name of the unit, such as `"10 megabytes"`.
     ///
     /// You can ignore the case of **"B"** (bit), which means **b** will still be treated as bits instead of bits.
     ///
+    /// The value and the unit may be separated by spaces, no-break spaces (U+00A0) or thin spaces (U+2009). Negative values, including ones written with the minus sign (U+2212), are rejected with `ValueParseError::NotNumber('-')`.
+    ///
+    /// The value may be written in scientific notation, such as `"1.5e9"` or `"2E3 KiB"`. The exponent must follow the digits directly, so an `"E"` which is not followed by a digit (or a sign and a digit) is still the exa prefix, as in `"1EB"`.
+    ///
     /// # Examples
     ///
     /// ```
     /// # use byte_unit::Bit;
     /// let bit = Bit::parse_str("123Kib").unwrap(); // 123 * 1024 bits
     /// ```
+    #[inline]
     pub fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, ParseError> {
-        let s = s.as_ref().trim();
+        Self::parse_str_and_unit(s.as_ref()).map(|(bit, _)| bit)
+    }
+
+    /// Parse a string like [`Bit::parse_str`](#method.parse_str), and also return the unit written in it.
+    pub(crate) fn parse_str_and_unit(s: &str) -> Result<(Self, Unit), ParseError> {
+        let s = s.trim();
 
         let mut bits = s.bytes();
 
         let mut value = match bits.next() {
             Some(e) => match e {
                 b'0'..=b'9' => Decimal::from(e - b'0'),
-                _ => {
-                    return Err(ValueParseError::NotNumber(unsafe {
-                        get_char_from_bytes(e, bits)
-                    })
-                    .into());
-                },
+                _ => return Err(get_not_number_error(e, bits).into()),
             },
             None => return Err(ValueParseError::NoValue.into()),
         };
@@ -64,17 +80,17 @@ impl Bit {
                                     },
                                     _ => {
                                         if i == 1 {
-                                            return Err(ValueParseError::NotNumber(unsafe {
-                                                get_char_from_bytes(e, bits)
-                                            })
+                                            return Err(ValueParseError::NotNumber(
+                                                get_char_from_bytes(e, bits),
+                                            )
                                             .into());
                                         }
 
                                         match e {
-                                            b' ' => loop {
+                                            _ if read_space(e, &mut bits) => loop {
                                                 match bits.next() {
                                                     Some(e) => match e {
-                                                        b' ' => (),
+                                                        _ if read_space(e, &mut bits) => (),
                                                         _ => break 'outer Some(e),
                                                     },
                                                     None => break 'outer None,
@@ -86,9 +102,9 @@ impl Bit {
                                 },
                                 None => {
                                     if i == 1 {
-                                        return Err(ValueParseError::NotNumber(unsafe {
-                                            get_char_from_bytes(e, bits)
-                                        })
+                                        return Err(ValueParseError::NotNumber(
+                                            get_char_from_bytes(e, bits),
+                                        )
                                         .into());
                                     }
 
@@ -97,10 +113,10 @@ impl Bit {
                             }
                         }
                     },
-                    b' ' => loop {
+                    _ if read_space(e, &mut bits) => loop {
                         match bits.next() {
                             Some(e) => match e {
-                                b' ' => (),
+                                _ if read_space(e, &mut bits) => (),
                                 _ => break 'outer Some(e),
                             },
                             None => break 'outer None,
@@ -112,9 +128,243 @@ impl Bit {
             }
         };
 
+        let (value, e) = read_value_exponent(s, value, e, &mut bits)?;
+
         let unit = read_xib(e, bits, false, false)?;
 
+        match Self::from_decimal_with_unit(value, unit) {
+            Some(bit) => Ok((bit, unit)),
+            None => Err(ValueParseError::ExceededBounds(value).into()),
+        }
+    }
+
+    /// Create a new `Bit` instance from a value string and a unit string, which are parsed separately. See [`Bit::parse_str`](#method.parse_str).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// let bit = Bit::from_value_and_unit_str("1.5", "Kb").unwrap();
+    ///
+    /// assert_eq!(1500, bit.as_u64());
+    /// ```
+    pub fn from_value_and_unit_str<V: AsRef<str>, U: AsRef<str>>(
+        value: V,
+        unit: U,
+    ) -> Result<Self, ParseError> {
+        let value = parse_value(value.as_ref())?;
+        let unit = Unit::parse_str(unit, false, false)?;
+
+        Self::from_decimal_with_unit(value, unit)
+            .ok_or_else(|| ValueParseError::ExceededBounds(value).into())
+    }
+}
+
+/// Associated functions for parsing multiple strings.
+#[cfg(feature = "std")]
+impl Bit {
+    /// Create new `Bit` instances from a slice of strings. See [`Bit::parse_str`](#method.parse_str).
+    ///
+    /// If the `rayon` feature is enabled, the strings are parsed in parallel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_unit::Bit;
+    /// let bits = Bit::parse_many(&["1 Kb", "2Kib", "3"]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     vec![1000, 2048, 3],
+    ///     bits.iter().map(|b| b.as_u64()).collect::<Vec<_>>()
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// # use byte_unit::Bit;
+    /// let (index, _error) =
+    ///     Bit::parse_many(&["1 Kb", "2 Kib", "3 Xb"]).unwrap_err();
+    ///
+    /// assert_eq!(2, index);
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If any string cannot be parsed, this function will return the index of the first invalid string along with its error.
+    pub fn parse_many<S: AsRef<str> + Sync>(
+        strings: &[S],
+    ) -> Result<Vec<Self>, (usize, ParseError)> {
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+
+            let results: Vec<Result<Self, ParseError>> =
+                strings.par_iter().map(Self::parse_str).collect();
+
+            results.into_iter().enumerate().map(|(i, result)| result.map_err(|e| (i, e))).collect()
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            let mut bits = Vec::with_capacity(strings.len());
+
+            for (i, s) in strings.iter().enumerate() {
+                bits.push(Self::parse_str(s).map_err(|e| (i, e))?);
+            }
+
+            Ok(bits)
+        }
+    }
+}
+
+/// Associated functions for parsing strings leniently.
+#[cfg(feature = "std")]
+impl Bit {
+    /// Create a new `Bit` instance from a string, fixing minor mistakes and reporting what was fixed. See [`Bit::parse_str`](#method.parse_str).
+    ///
+    /// The following mistakes can be fixed:
+    ///
+    /// * Stray whitespaces inside the value or the unit, e.g. `"1 5 M b"`.
+    /// * A trailing period, e.g. `"15 Mb."`.
+    /// * A repeated unit, e.g. `"15 Mb Mb"`.
+    ///
+    /// If the string cannot be parsed even after fixing, no warnings are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, ParseWarning};
+    ///
+    /// assert_eq!(
+    ///     (Some(Bit::from_u64(15000000)), vec![ParseWarning::DoubledUnitRemoved]),
+    ///     Bit::parse_str_lenient("15 Mb Mb")
+    /// );
+    ///
+    /// assert_eq!((None, vec![]), Bit::parse_str_lenient("fifteen Mb"));
+    /// ```
+    #[inline]
+    pub fn parse_str_lenient<S: AsRef<str>>(s: S) -> (Option<Self>, Vec<ParseWarning>) {
+        parse_lenient(s.as_ref(), |s| Self::parse_str(s))
+    }
+}
+
+/// Associated functions for parsing strings with options.
+impl Bit {
+    /// Create a new `Bit` instance from a string with options, e.g. to accept digit group separators. See [`Bit::parse_str`](#method.parse_str) and [`ParseOptions`](struct.ParseOptions.html).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().underscores(true);
+    ///
+    /// let bit = Bit::parse_str_with_options("1_500_000 Kb", &options).unwrap();
+    ///
+    /// assert_eq!(1_500_000_000, bit.as_u64());
+    /// ```
+    pub fn parse_str_with_options<S: AsRef<str>>(
+        s: S,
+        options: &ParseOptions,
+    ) -> Result<Self, ParseError> {
+        let s = s.as_ref().trim();
+
+        if !options.composite {
+            return Self::parse_component(s, options);
+        }
+
+        let mut total = Self::MIN;
+        let mut rest = s;
+
+        loop {
+            let (component, next) = options.split_component(rest);
+
+            let size = Self::parse_component(component, options)?;
+
+            total = total.checked_add(size).ok_or_else(|| {
+                ValueParseError::ExceededBounds(
+                    Decimal::from(total.as_u128()) + Decimal::from(size.as_u128()),
+                )
+            })?;
+
+            if next.is_empty() {
+                return Ok(total);
+            }
+
+            rest = next;
+        }
+    }
+
+    /// Parse a single value with its unit, which has been trimmed.
+    fn parse_component(s: &str, options: &ParseOptions) -> Result<Self, ParseError> {
+        let (value, unit) = options.split_value(s);
+
+        let value = options.parse_value(value, s)?;
+        let unit = options.mode.apply(Unit::parse_str(unit, false, false)?, unit);
+
         Self::from_decimal_with_unit(value, unit)
             .ok_or_else(|| ValueParseError::ExceededBounds(value).into())
     }
 }
+
+/// Associated functions for parsing strings with bounds.
+impl Bit {
+    /// Create a new `Bit` instance from a string, and check that it is in the given range. See [`Bit::parse_str`](#method.parse_str).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, RangeParseError};
+    ///
+    /// let range = Bit::from_u64(1_000_000)..; // at least 1 Mb
+    ///
+    /// assert_eq!(
+    ///     Bit::from_u64(100_000_000),
+    ///     Bit::parse_str_in_range("100 Mb", range.clone()).unwrap()
+    /// );
+    ///
+    /// let error = Bit::parse_str_in_range("100 Kb", range).unwrap_err();
+    ///
+    /// assert!(matches!(error, RangeParseError::TooSmall { .. }));
+    /// assert_eq!("100 Kb is less than the minimum of 1 Mb", error.to_string());
+    /// ```
+    #[inline]
+    pub fn parse_str_in_range<S: AsRef<str>, R: RangeBounds<Bit>>(
+        s: S,
+        range: R,
+    ) -> Result<Self, RangeParseError<Self>> {
+        check_range(Self::parse_str(s)?, range)
+    }
+}
+
+/// Associated functions for parsing strings in const contexts.
+impl Bit {
+    /// Create a new `Bit` instance from a string with an integer value and an optional unit, in const contexts, so that sizes in code need neither runtime parsing nor magic numbers.
+    ///
+    /// The unit must be written in the same way as [`Unit::as_str`](enum.Unit.html#method.as_str), e.g. `"Kb"`, `"Mib"` or `"MB"` (megabytes), and the value and the unit may be separated by spaces. Without a unit, the value is in bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Bit;
+    ///
+    /// const LINK_SPEED: Bit = Bit::parse_str_const("100 Mb");
+    ///
+    /// assert_eq!(100000000, LINK_SPEED.as_u64());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string is invalid or the size exceeds `Bit::MAX`. In const contexts, it is a compile error instead.
+    pub const fn parse_str_const(s: &str) -> Self {
+        match parse_integer_and_unit_const(s, Unit::Bit) {
+            Some((value, unit)) => match Self::from_u128_with_unit(value, unit) {
+                Some(bit) => bit,
+                None => panic!("the size exceeds `Bit::MAX`"),
+            },
+            None => panic!(
+                "the size should be an integer followed by an optional unit, such as \"100 Mb\""
+            ),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    prop_oneof,
+    strategy::{BoxedStrategy, Just, Strategy},
+};
+
+use super::{AdjustedBit, Bit};
+use crate::Unit;
+
+impl Arbitrary for Bit {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Bit>;
+
+    /// Generate boundary values (`0`, `1`, `Bit::MAX - 1` and `Bit::MAX`), exact multiples of units (e.g. `5 Mbit`), and any values in the full range.
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        let max = Bit::MAX.as_u128();
+
+        prop_oneof![
+            prop_oneof![Just(0), Just(1), Just(max - 1), Just(max)],
+            (any::<Unit>(), 0..=1024u128).prop_map(move |(unit, n)| {
+                let factor = unit.as_bits_u128();
+
+                match factor.checked_mul(n) {
+                    Some(size) if size <= max => size,
+                    _ => factor,
+                }
+            }),
+            0..=max,
+        ]
+        // the size is not greater than `max`
+        .prop_map(Bit::from_u128_unchecked)
+        .boxed()
+    }
+}
+
+impl Arbitrary for AdjustedBit {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<AdjustedBit>;
+
+    #[inline]
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (any::<Bit>(), any::<Unit>()).prop_map(|(byte, unit)| byte.get_adjusted_unit(unit)).boxed()
+    }
+}
@@ -0,0 +1,414 @@
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    ops::{Div, Mul},
+    str::FromStr,
+    time::Duration,
+};
+
+use super::{AdjustedBit, Bit};
+use crate::{
+    common::div_ceil_u128,
+    rate::{split_rate, NANOS_PER_SEC},
+    OverheadProfile, RateParseError, RateUnitType, TimeUnit,
+};
+
+/// Representing a rate in bits, i.e. a size per a time unit, such as `100 Mb/s`.
+///
+/// Rates with different time units are compared by their sizes per second, so `60 b/min` is equal to `1 b/s`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use byte_unit::{Bit, BitRate, RateUnitType};
+///
+/// let rate = Bit::from_u64(200_000_000) / Duration::from_secs(2);
+///
+/// assert_eq!(BitRate::parse_str("100 Mbps").unwrap(), rate);
+/// assert_eq!("100 Mb/s", format!("{rate:#}"));
+/// assert_eq!(
+///     "95.37 Mib/s",
+///     format!("{:.2}", rate.get_appropriate_unit(RateUnitType::Binary))
+/// );
+///
+/// assert_eq!(Bit::from_u64(6_000_000_000), rate * Duration::from_secs(60));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BitRate {
+    size:      Bit,
+    time_unit: TimeUnit,
+}
+
+/// Associated functions for building `BitRate` instances.
+impl BitRate {
+    /// Create a new `BitRate` instance from a size and a time unit.
+    #[inline]
+    pub const fn new(size: Bit, time_unit: TimeUnit) -> Self {
+        Self {
+            size,
+            time_unit,
+        }
+    }
+
+    /// Create a new `BitRate` instance from a size per second.
+    #[inline]
+    pub const fn per_second(size: Bit) -> Self {
+        Self::new(size, TimeUnit::Second)
+    }
+
+    /// Create a new `BitRate` instance per second from a size transferred in a duration. The result is rounded up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use byte_unit::{Bit, BitRate};
+    ///
+    /// assert_eq!(
+    ///     Some(BitRate::per_second(Bit::from_u64(4))),
+    ///     BitRate::from_duration(Bit::from_u64(10), Duration::from_secs(3))
+    /// );
+    /// assert_eq!(None, BitRate::from_duration(Bit::from_u64(10), Duration::ZERO));
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If the duration is zero or the result exceeds `Bit::MAX`, this function will return `None`.
+    #[inline]
+    pub fn from_duration(size: Bit, duration: Duration) -> Option<Self> {
+        let nanos = duration.as_nanos();
+
+        if nanos == 0 {
+            return None;
+        }
+
+        let size = div_ceil_u128(size.as_u128().checked_mul(NANOS_PER_SEC)?, nanos);
+
+        Bit::from_u128(size).map(Self::per_second)
+    }
+}
+
+/// Methods for getting values.
+impl BitRate {
+    /// Retrieve the size per time unit.
+    #[inline]
+    pub const fn get_size(self) -> Bit {
+        self.size
+    }
+
+    /// Retrieve the time unit.
+    #[inline]
+    pub const fn get_time_unit(self) -> TimeUnit {
+        self.time_unit
+    }
+
+    /// Retrieve the size per second as a floating-point number of bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, BitRate, TimeUnit};
+    ///
+    /// let rate = BitRate::new(Bit::from_u64(90), TimeUnit::Minute);
+    ///
+    /// assert_eq!(1.5, rate.as_f64_per_second());
+    /// ```
+    #[inline]
+    pub fn as_f64_per_second(self) -> f64 {
+        self.size.as_u128() as f64 / self.time_unit.as_secs() as f64
+    }
+}
+
+/// Methods for arithmetic with durations.
+impl BitRate {
+    /// Calculate the size transferred in a duration at this rate. The result is rounded up. If it exceeds `Bit::MAX`, this method will return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use byte_unit::{Bit, BitRate, TimeUnit};
+    ///
+    /// let rate = BitRate::new(Bit::from_u64(3600), TimeUnit::Hour);
+    ///
+    /// assert_eq!(
+    ///     Some(Bit::from_u64(2)),
+    ///     rate.checked_mul_duration(Duration::from_millis(1500))
+    /// );
+    /// assert_eq!(
+    ///     None,
+    ///     BitRate::per_second(Bit::MAX)
+    ///         .checked_mul_duration(Duration::from_secs(2))
+    /// );
+    /// ```
+    #[inline]
+    pub fn checked_mul_duration(self, duration: Duration) -> Option<Bit> {
+        let size = div_ceil_u128(
+            self.size.as_u128().checked_mul(duration.as_nanos())?,
+            self.time_unit.as_secs() as u128 * NANOS_PER_SEC,
+        );
+
+        Bit::from_u128(size)
+    }
+}
+
+/// Methods for protocol overhead.
+impl BitRate {
+    /// Convert this line rate into the expected payload throughput (goodput) after the protocol overhead of `profile`. The result is rounded down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{BitRate, OverheadProfile, RateUnitType};
+    ///
+    /// let line_rate = BitRate::parse_str("10 Gbps").unwrap();
+    /// let goodput = line_rate.apply_overhead(OverheadProfile::ETHERNET_IPV4_TCP);
+    ///
+    /// assert_eq!(
+    ///     "9.49 Gb/s",
+    ///     format!("{:.2}", goodput.get_appropriate_unit(RateUnitType::Decimal))
+    /// );
+    /// ```
+    #[inline]
+    pub const fn apply_overhead(self, profile: OverheadProfile) -> Self {
+        Self::new(
+            Bit::from_u128_unchecked(profile.goodput_u128(self.size.as_u128())),
+            self.time_unit,
+        )
+    }
+
+    /// Convert this payload throughput into the line rate needed to carry it with the protocol overhead of `profile`. The result is rounded up. If it exceeds `Bit::MAX`, this method will return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, BitRate, OverheadProfile};
+    ///
+    /// let goodput = BitRate::parse_str("1 Gbps").unwrap();
+    ///
+    /// assert_eq!(
+    ///     Some(BitRate::per_second(Bit::from_u64(1_053_424_658))),
+    ///     goodput.required_line_rate(OverheadProfile::ETHERNET_IPV4_TCP)
+    /// );
+    /// ```
+    #[inline]
+    pub const fn required_line_rate(self, profile: OverheadProfile) -> Option<Self> {
+        match profile.line_rate_u128(self.size.as_u128()) {
+            Some(size) => match Bit::from_u128(size) {
+                Some(size) => Some(Self::new(size, self.time_unit)),
+                None => None,
+            },
+            None => None,
+        }
+    }
+}
+
+/// Associated functions for parsing strings.
+impl BitRate {
+    /// Create a new `BitRate` instance from a string. The string consists of a size, which can be parsed by [`Bit::parse_str`](struct.Bit.html#method.parse_str), and a time unit after a slash (see [`TimeUnit::parse_str`](enum.TimeUnit.html#method.parse_str)), or a trailing **"ps"** which means per second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, BitRate};
+    ///
+    /// let rate = BitRate::parse_str("100 Mbps").unwrap();
+    ///
+    /// assert_eq!(Bit::from_u64(100_000_000), rate.get_size());
+    /// ```
+    ///
+    /// ```
+    /// use byte_unit::{Bit, BitRate, TimeUnit};
+    ///
+    /// let rate = BitRate::parse_str("12.5 MB/h").unwrap(); // 12.5 * 10^6 * 8 b/h
+    ///
+    /// assert_eq!(Bit::from_u64(100_000_000), rate.get_size());
+    /// assert_eq!(TimeUnit::Hour, rate.get_time_unit());
+    /// ```
+    pub fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, RateParseError> {
+        let (size, time_unit) = split_rate(s.as_ref(), false).ok_or(RateParseError::NoTimeUnit)?;
+
+        let time_unit = TimeUnit::parse_str(time_unit).ok_or(RateParseError::UnknownTimeUnit)?;
+
+        Ok(Self::new(Bit::parse_str(size)?, time_unit))
+    }
+}
+
+/// Methods for finding an appropriate unit.
+impl BitRate {
+    /// Find the appropriate unit and value for the size of this `BitRate` instance. The time unit is kept. `RateUnitType::DecimalBitsOnly` is the same as `RateUnitType::Decimal`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, BitRate, RateUnitType};
+    ///
+    /// let rate = BitRate::per_second(Bit::from_u64(1024 * 1024));
+    ///
+    /// assert_eq!(
+    ///     "1.05 Mb/s",
+    ///     format!(
+    ///         "{:.2}",
+    ///         rate.get_appropriate_unit(RateUnitType::DecimalBitsOnly)
+    ///     )
+    /// );
+    /// assert_eq!(
+    ///     "1 Mib/s",
+    ///     rate.get_appropriate_unit(RateUnitType::Binary).to_string()
+    /// );
+    /// ```
+    #[inline]
+    pub fn get_appropriate_unit(&self, rate_unit_type: RateUnitType) -> AdjustedBitRate {
+        AdjustedBitRate {
+            size:      self.size.get_appropriate_unit(rate_unit_type.unit_type()),
+            time_unit: self.time_unit,
+        }
+    }
+}
+
+impl PartialEq for BitRate {
+    #[inline]
+    fn eq(&self, other: &BitRate) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for BitRate {}
+
+impl PartialOrd for BitRate {
+    #[inline]
+    fn partial_cmp(&self, other: &BitRate) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BitRate {
+    #[inline]
+    fn cmp(&self, other: &BitRate) -> Ordering {
+        // cannot overflow, `Bit::MAX` is less than 2^90 and the time units are less than 2^17 seconds
+        let a = self.size.as_u128() * other.time_unit.as_secs() as u128;
+        let b = other.size.as_u128() * self.time_unit.as_secs() as u128;
+
+        a.cmp(&b)
+    }
+}
+
+impl Display for BitRate {
+    /// Write the size in the same way as the `Display` implementation of `Bit`, followed by the time unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, BitRate, TimeUnit};
+    ///
+    /// let rate = BitRate::new(Bit::from_u64(1536), TimeUnit::Minute);
+    ///
+    /// assert_eq!("1536/min", rate.to_string());
+    /// assert_eq!("1.5 Kib/min", format!("{rate:#}"));
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.size, f)?;
+
+        f.write_fmt(format_args!("/{}", self.time_unit))
+    }
+}
+
+impl FromStr for BitRate {
+    type Err = RateParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BitRate::parse_str(s)
+    }
+}
+
+impl Div<Duration> for Bit {
+    type Output = BitRate;
+
+    /// See [`BitRate::from_duration`](struct.BitRate.html#method.from_duration).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the duration is zero or the result exceeds `Bit::MAX`.
+    #[inline]
+    fn div(self, rhs: Duration) -> BitRate {
+        BitRate::from_duration(self, rhs).expect("attempt to divide by a zero duration or overflow")
+    }
+}
+
+impl Mul<Duration> for BitRate {
+    type Output = Bit;
+
+    /// See [`BitRate::checked_mul_duration`](#method.checked_mul_duration).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result exceeds `Bit::MAX`.
+    #[inline]
+    fn mul(self, rhs: Duration) -> Bit {
+        self.checked_mul_duration(rhs).expect("attempt to multiply with overflow")
+    }
+}
+
+impl Mul<BitRate> for Duration {
+    type Output = Bit;
+
+    /// See [`BitRate::checked_mul_duration`](struct.BitRate.html#method.checked_mul_duration).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result exceeds `Bit::MAX`.
+    #[inline]
+    fn mul(self, rhs: BitRate) -> Bit {
+        rhs * self
+    }
+}
+
+/// The size of a `BitRate` instance with an appropriate unit, created by [`BitRate::get_appropriate_unit`](struct.BitRate.html#method.get_appropriate_unit).
+#[derive(Debug, Clone, Copy)]
+pub struct AdjustedBitRate {
+    size:      AdjustedBit,
+    time_unit: TimeUnit,
+}
+
+/// Methods for getting values.
+impl AdjustedBitRate {
+    /// Retrieve the adjusted size per time unit.
+    #[inline]
+    pub const fn get_size(&self) -> AdjustedBit {
+        self.size
+    }
+
+    /// Retrieve the time unit.
+    #[inline]
+    pub const fn get_time_unit(&self) -> TimeUnit {
+        self.time_unit
+    }
+}
+
+impl Display for AdjustedBitRate {
+    /// Write the size in the same way as the `Display` implementation of `AdjustedBit`, followed by the time unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, BitRate, UnitType};
+    ///
+    /// let rate = BitRate::per_second(Bit::from_u64(10000));
+    ///
+    /// let adjusted_rate = rate.get_appropriate_unit(UnitType::Binary.into());
+    ///
+    /// assert_eq!("9.77 Kib/s", format!("{adjusted_rate:.2}"));
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.size, f)?;
+
+        f.write_fmt(format_args!("/{}", self.time_unit))
+    }
+}
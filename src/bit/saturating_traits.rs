@@ -0,0 +1,35 @@
+use core::num::Saturating;
+
+use super::Bit;
+use crate::ExceededBoundsError;
+
+impl TryFrom<Saturating<u128>> for Bit {
+    type Error = ExceededBoundsError;
+
+    #[inline]
+    fn try_from(value: Saturating<u128>) -> Result<Self, Self::Error> {
+        Bit::try_from(value.0)
+    }
+}
+
+impl From<Saturating<u64>> for Bit {
+    #[inline]
+    fn from(value: Saturating<u64>) -> Self {
+        Bit::from_u64(value.0)
+    }
+}
+
+impl From<Bit> for Saturating<u128> {
+    #[inline]
+    fn from(bit: Bit) -> Self {
+        Saturating(bit.as_u128())
+    }
+}
+
+impl From<Bit> for Saturating<u64> {
+    /// See [`Bit::as_u64`](#method.as_u64).
+    #[inline]
+    fn from(bit: Bit) -> Self {
+        Saturating(bit.as_u64())
+    }
+}
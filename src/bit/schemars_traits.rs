@@ -0,0 +1,23 @@
+use alloc::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use super::Bit;
+use crate::serde::SIZE_PATTERN;
+
+impl JsonSchema for Bit {
+    #[inline]
+    fn schema_name() -> Cow<'static, str> {
+        "Bit".into()
+    }
+
+    #[inline]
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": SIZE_PATTERN,
+            "description": "A size in bits, written as a non-negative number optionally followed by a unit, such as \"100 Mbit\" or \"1.5 GiB\". A number without a unit is in bits.",
+            "examples": ["100 Mbit", "1.5 GiB", "8192"]
+        })
+    }
+}
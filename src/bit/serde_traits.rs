@@ -12,6 +12,7 @@ use serde::{
 use super::Bit;
 #[cfg(feature = "u128")]
 use super::RONNABIT;
+use crate::serde::{invalid_str, Canonical};
 
 impl Serialize for Bit {
     #[inline]
@@ -19,7 +20,7 @@ impl Serialize for Bit {
     where
         S: Serializer, {
         if serializer.is_human_readable() {
-            serializer.serialize_str(format!("{self:#}").as_str())
+            serializer.collect_str(&Canonical(*self))
         } else {
             serializer.serialize_u128(self.as_u128())
         }
@@ -54,6 +55,29 @@ impl<'de> Deserialize<'de> for Bit {
                 }
             }
 
+            #[inline]
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: DeError, {
+                Ok(Bit::from_u64(v))
+            }
+
+            #[inline]
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+            where
+                E: DeError, {
+                Bit::try_from(v).map_err(|_| DeError::invalid_value(Unexpected::Signed(v), &self))
+            }
+
+            #[inline]
+            fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+            where
+                E: DeError, {
+                Bit::try_from(v).map_err(|_| {
+                    DeError::invalid_value(Unexpected::Other(format!("{v}").as_str()), &self)
+                })
+            }
+
             #[inline]
             fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
             where
@@ -67,7 +91,7 @@ impl<'de> Deserialize<'de> for Bit {
             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
             where
                 E: DeError, {
-                Bit::from_str(v).map_err(DeError::custom)
+                Bit::from_str(v).map_err(|error| invalid_str(v, &self, error))
             }
         }
 
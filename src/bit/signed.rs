@@ -0,0 +1,306 @@
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    ops::Neg,
+    str::FromStr,
+};
+
+use super::Bit;
+use crate::{format::fmt_signed_with_unit, ExceededBoundsError, ParseError, UnitPreference};
+
+/// Representing a signed difference of sizes in bits, e.g. the growth of a directory.
+///
+/// The absolute value is never greater than `Bit::MAX`, so every `Bit` instance can be converted into a `SignedBit` instance, and so can the difference of any two `Bit` instances.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{Bit, SignedBit};
+///
+/// let before = Bit::from_u64(1_500_000_000);
+/// let after = Bit::from_u64(1_150_000_000);
+///
+/// let delta = after.signed_sub(before);
+///
+/// assert_eq!(-350_000_000, delta.as_i128());
+/// assert_eq!("-350 Mb", format!("{delta:#}"));
+/// assert_eq!("+350 Mb", format!("{:#}", -delta));
+/// ```
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Default)]
+pub struct SignedBit(i128);
+
+/// Constants of `SignedBit`.
+impl SignedBit {
+    /// `Bit::MAX`.
+    pub const MAX: SignedBit = SignedBit(Bit::MAX.as_u128() as i128);
+    /// The negative of `Bit::MAX`.
+    pub const MIN: SignedBit = SignedBit(-(Bit::MAX.as_u128() as i128));
+    /// 0 bit.
+    pub const ZERO: SignedBit = SignedBit(0);
+}
+
+/// Associated functions for building `SignedBit` instances.
+impl SignedBit {
+    /// Create a new `SignedBit` instance from a size in bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, SignedBit};
+    ///
+    /// assert_eq!(Some(SignedBit::ZERO), SignedBit::from_i128(0));
+    /// assert_eq!(None, SignedBit::from_i128(i128::MIN));
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If the absolute value of the input is greater than `Bit::MAX`, this function will return `None`.
+    #[inline]
+    pub const fn from_i128(size: i128) -> Option<Self> {
+        if size.unsigned_abs() <= Bit::MAX.as_u128() {
+            Some(Self(size))
+        } else {
+            None
+        }
+    }
+
+    /// Create a new `SignedBit` instance from a size in bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::SignedBit;
+    ///
+    /// assert_eq!(-15, SignedBit::from_i64(-15).as_i128());
+    /// ```
+    #[inline]
+    pub const fn from_i64(size: i64) -> Self {
+        Self(size as i128)
+    }
+
+    /// Create a new `SignedBit` instance from a `Bit` instance and a sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, SignedBit};
+    ///
+    /// assert_eq!(
+    ///     SignedBit::from_i64(-15),
+    ///     SignedBit::from_bit(Bit::from_u64(15), true)
+    /// );
+    /// ```
+    #[inline]
+    pub const fn from_bit(bit: Bit, negative: bool) -> Self {
+        let size = bit.as_u128() as i128;
+
+        if negative {
+            Self(-size)
+        } else {
+            Self(size)
+        }
+    }
+}
+
+/// Methods for converting a `SignedBit` instance into a primitive integer or a `Bit` instance.
+impl SignedBit {
+    /// Retrieve the size in bits.
+    #[inline]
+    pub const fn as_i128(self) -> i128 {
+        self.0
+    }
+
+    /// Retrieve the absolute value as a `Bit` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, SignedBit};
+    ///
+    /// assert_eq!(Bit::from_u64(15), SignedBit::from_i64(-15).abs());
+    /// ```
+    #[inline]
+    pub const fn abs(self) -> Bit {
+        match Bit::from_u128(self.0.unsigned_abs()) {
+            Some(bit) => bit,
+            None => unreachable!(),
+        }
+    }
+
+    /// Retrieve the sign, **-1**, **0** or **1**.
+    #[inline]
+    pub const fn signum(self) -> i8 {
+        self.0.signum() as i8
+    }
+
+    /// Whether the size is less than zero.
+    #[inline]
+    pub const fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    /// Whether the size is greater than zero.
+    #[inline]
+    pub const fn is_positive(self) -> bool {
+        self.0 > 0
+    }
+}
+
+/// Methods for arithmetic with the same semantics as primitive integers.
+impl SignedBit {
+    /// Add another `SignedBit` instance. If the absolute value of the result is greater than `Bit::MAX`, this function will return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::SignedBit;
+    ///
+    /// assert_eq!(
+    ///     Some(SignedBit::from_i64(-5)),
+    ///     SignedBit::from_i64(10).checked_add(SignedBit::from_i64(-15))
+    /// );
+    /// assert_eq!(None, SignedBit::MAX.checked_add(SignedBit::from_i64(1)));
+    /// ```
+    #[inline]
+    pub const fn checked_add(self, rhs: SignedBit) -> Option<SignedBit> {
+        // cannot overflow, the absolute values are less than 2^127 - 1
+        Self::from_i128(self.0 + rhs.0)
+    }
+
+    /// Subtract another `SignedBit` instance. If the absolute value of the result is greater than `Bit::MAX`, this function will return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::SignedBit;
+    ///
+    /// assert_eq!(
+    ///     Some(SignedBit::from_i64(25)),
+    ///     SignedBit::from_i64(10).checked_sub(SignedBit::from_i64(-15))
+    /// );
+    /// assert_eq!(None, SignedBit::MIN.checked_sub(SignedBit::from_i64(1)));
+    /// ```
+    #[inline]
+    pub const fn checked_sub(self, rhs: SignedBit) -> Option<SignedBit> {
+        Self::from_i128(self.0 - rhs.0)
+    }
+}
+
+/// Associated functions for parsing strings.
+impl SignedBit {
+    /// Create a new `SignedBit` instance from a string. The string may start with a sign, **"-"** (or **"−"**) or **"+"**, followed by a string which can be parsed by [`Bit::parse_str`](struct.Bit.html#method.parse_str).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::SignedBit;
+    ///
+    /// let delta = SignedBit::parse_str("-350 Mb").unwrap(); // -350 * 10^6 b
+    ///
+    /// assert_eq!(-350_000_000, delta.as_i128());
+    /// ```
+    ///
+    /// ```
+    /// use byte_unit::SignedBit;
+    ///
+    /// let delta = SignedBit::parse_str("+2 Kib").unwrap(); // 2 * 2^10 b
+    ///
+    /// assert_eq!(2048, delta.as_i128());
+    /// ```
+    pub fn parse_str<S: AsRef<str>>(s: S) -> Result<Self, ParseError> {
+        let s = s.as_ref().trim();
+
+        let (negative, s) = match s.strip_prefix(['-', '\u{2212}']) {
+            Some(s) => (true, s),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        Bit::parse_str(s).map(|bit| Self::from_bit(bit, negative))
+    }
+}
+
+impl Display for SignedBit {
+    /// Write the size with an explicit sign, **"+"** or **"-"**, unless it is zero. The flags work in the same way as the `Display` implementation of `Bit`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let sign = match self.0.cmp(&0) {
+            Ordering::Less => "-",
+            Ordering::Equal => "",
+            Ordering::Greater => "+",
+        };
+
+        if f.alternate() {
+            let precision = f.precision().unwrap_or(3);
+
+            let (value, unit) = self.abs().get_recoverable_unit_with_preference(
+                false,
+                precision,
+                UnitPreference::global(),
+            );
+
+            let value = value.normalize();
+
+            fmt_signed_with_unit(f, sign, format_args!("{value}"), Some(unit))
+        } else {
+            fmt_signed_with_unit(f, sign, format_args!("{}", self.0.unsigned_abs()), None)
+        }
+    }
+}
+
+impl FromStr for SignedBit {
+    type Err = ParseError;
+
+    /// See [`SignedBit::parse_str`](#method.parse_str).
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SignedBit::parse_str(s)
+    }
+}
+
+impl Neg for SignedBit {
+    type Output = SignedBit;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl From<Bit> for SignedBit {
+    #[inline]
+    fn from(value: Bit) -> Self {
+        Self::from_bit(value, false)
+    }
+}
+
+impl TryFrom<SignedBit> for Bit {
+    type Error = ExceededBoundsError;
+
+    #[inline]
+    fn try_from(value: SignedBit) -> Result<Self, Self::Error> {
+        if value.is_negative() {
+            Err(ExceededBoundsError)
+        } else {
+            Ok(value.abs())
+        }
+    }
+}
+
+/// Methods for finding the difference between `Bit` instances.
+impl Bit {
+    /// Subtract another `Bit` instance, allowing the result to be negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, SignedBit};
+    ///
+    /// assert_eq!(
+    ///     SignedBit::from_i64(-512),
+    ///     Bit::from_u64(512).signed_sub(Bit::from_u64(1024))
+    /// );
+    /// ```
+    #[inline]
+    pub const fn signed_sub(self, rhs: Bit) -> SignedBit {
+        SignedBit(self.as_u128() as i128 - rhs.as_u128() as i128)
+    }
+}
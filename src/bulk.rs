@@ -0,0 +1,85 @@
+//! Conversions of many sizes at once, for exporting large arrays of sizes into dataframes or plotting buffers.
+//!
+//! The factor of the unit is computed once for a whole slice, and each value is written into a buffer provided by the caller, so no allocation is needed.
+
+use rust_decimal::prelude::*;
+
+use crate::{Byte, Unit};
+
+/// Convert each size in `bytes` to a value in `unit`, and write it into the same position of `out`. Each value is the same as [`AdjustedByte::get_value`](../struct.AdjustedByte.html#method.get_value) of [`Byte::get_adjusted_unit`](../struct.Byte.html#method.get_adjusted_unit) would be.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{bulk::to_unit_values, Byte, Unit};
+///
+/// let sizes = [512, 1024, 1536].map(Byte::from_u64);
+///
+/// let mut values = [0.0; 3];
+///
+/// to_unit_values(&sizes, Unit::KiB, &mut values);
+///
+/// assert_eq!([0.5, 1.0, 1.5], values);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `bytes` and `out` have different lengths.
+pub fn to_unit_values(bytes: &[Byte], unit: Unit, out: &mut [f64]) {
+    assert_eq!(bytes.len(), out.len(), "the input and the output must have the same length");
+
+    let pairs = bytes.iter().zip(out.iter_mut());
+
+    match unit {
+        Unit::Bit => pairs.for_each(|(byte, value)| *value = (byte.as_u128() << 3) as f64),
+        Unit::B => pairs.for_each(|(byte, value)| *value = byte.as_u128() as f64),
+        _ => {
+            let divisor = unit.as_bytes_u128() as f64;
+
+            pairs.for_each(|(byte, value)| *value = byte.as_u128() as f64 / divisor)
+        },
+    }
+}
+
+/// Convert each size in `bytes` to an exact value in `unit`, and write it into the same position of `out`. Each value is the same as the first element returned by [`Byte::get_adjusted_unit_exact`](../struct.Byte.html#method.get_adjusted_unit_exact).
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{
+///     bulk::to_unit_decimals, rust_decimal::Decimal, Byte, Unit,
+/// };
+///
+/// let sizes = [1_000, 1_500, 1_234_567].map(Byte::from_u64);
+///
+/// let mut values = [Decimal::ZERO; 3];
+///
+/// to_unit_decimals(&sizes, Unit::KB, &mut values);
+///
+/// assert_eq!(["1", "1.5", "1234.567"], values.map(|value| value.to_string()));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `bytes` and `out` have different lengths.
+pub fn to_unit_decimals(bytes: &[Byte], unit: Unit, out: &mut [Decimal]) {
+    assert_eq!(bytes.len(), out.len(), "the input and the output must have the same length");
+
+    let pairs = bytes.iter().zip(out.iter_mut());
+
+    match unit {
+        Unit::Bit => {
+            let eight = Decimal::from(8u8);
+
+            pairs.for_each(|(byte, value)| *value = Decimal::from(byte.as_u128()) * eight)
+        },
+        Unit::B => pairs.for_each(|(byte, value)| *value = Decimal::from(byte.as_u128())),
+        _ => {
+            let divisor = Decimal::from(unit.as_bytes_u128());
+
+            pairs.for_each(|(byte, value)| {
+                *value = (Decimal::from(byte.as_u128()) / divisor).normalize()
+            })
+        },
+    }
+}
@@ -1,4 +1,6 @@
 use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::string::String;
 
 use super::{AdjustedByte, Byte, Unit, UnitType};
 use crate::ParseError;
@@ -14,7 +16,7 @@ impl From<Byte> for AdjustedByte {
 impl From<AdjustedByte> for f64 {
     #[inline]
     fn from(value: AdjustedByte) -> Self {
-        value.get_value()
+        value.value
     }
 }
 
@@ -37,8 +39,30 @@ impl FromStr for AdjustedByte {
 
     /// * `ignore_case` is set to `false`. See [`Byte::parse_str`](./struct.Byte.html#method.parse_str).
     /// * `unit_type` is set to `UnitType::Both`. See [`Byte::get_appropriate_unit`](./struct.Byte.html#method.get_appropriate_unit).
+    /// * To keep the unit written in the string, use [`AdjustedByte::parse_str_keep_unit`](./struct.AdjustedByte.html#method.parse_str_keep_unit).
     #[inline]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Byte::parse_str(s, false)?.get_appropriate_unit(UnitType::Both))
     }
 }
+
+impl TryFrom<&str> for AdjustedByte {
+    type Error = ParseError;
+
+    /// See [`FromStr`](#impl-FromStr-for-AdjustedByte).
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<String> for AdjustedByte {
+    type Error = ParseError;
+
+    /// See [`FromStr`](#impl-FromStr-for-AdjustedByte).
+    #[inline]
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
@@ -0,0 +1,131 @@
+use core::fmt::{self, Display, Formatter};
+
+use super::AdjustedByte;
+use crate::{
+    format::{fmt_value, fmt_value_with_unit},
+    FormatOptions,
+};
+
+/// Generated from the [`AdjustedByte::display_with`](./struct.AdjustedByte.html#method.display_with) method.
+#[derive(Debug, Clone, Copy)]
+pub struct AdjustedByteDisplay {
+    adjusted_byte: AdjustedByte,
+    options:       FormatOptions,
+}
+
+impl Display for AdjustedByteDisplay {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_value_with_unit(f, self.adjusted_byte.value, self.adjusted_byte.unit, &self.options)
+    }
+}
+
+/// Generated from the [`AdjustedByte::value_display`](./struct.AdjustedByte.html#method.value_display) method.
+#[derive(Debug, Clone, Copy)]
+pub struct AdjustedByteValueDisplay {
+    adjusted_byte: AdjustedByte,
+    precision:     Option<usize>,
+}
+
+impl Display for AdjustedByteValueDisplay {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_value(f, self.adjusted_byte.value, self.adjusted_byte.unit, self.precision, false)
+    }
+}
+
+/// Methods for formatting with options.
+impl AdjustedByte {
+    /// Create a value which implements `Display` for this `AdjustedByte` instance with the given options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, FormatOptions, FormatStyle, Unit};
+    ///
+    /// let adjusted_byte = Byte::from_u64(1536).get_adjusted_unit(Unit::KiB);
+    ///
+    /// assert_eq!(
+    ///     "1.5 KiB",
+    ///     adjusted_byte.display_with(FormatOptions::new()).to_string()
+    /// );
+    /// assert_eq!(
+    ///     "1.5KiB",
+    ///     adjusted_byte
+    ///         .display_with(FormatOptions::new().style(FormatStyle::Compact))
+    ///         .to_string()
+    /// );
+    /// assert_eq!(
+    ///     "1.5 kibibytes",
+    ///     adjusted_byte
+    ///         .display_with(FormatOptions::new().style(FormatStyle::Verbose))
+    ///         .to_string()
+    /// );
+    /// assert_eq!(
+    ///     "1.5 kibibytes",
+    ///     adjusted_byte
+    ///         .display_with(
+    ///             FormatOptions::new().style(FormatStyle::Verbose).precision(2)
+    ///         )
+    ///         .to_string()
+    /// );
+    /// assert_eq!(
+    ///     "1.50 kibibytes",
+    ///     adjusted_byte
+    ///         .display_with(
+    ///             FormatOptions::new()
+    ///                 .style(FormatStyle::Verbose)
+    ///                 .precision(2)
+    ///                 .keep_trailing_zeros(true)
+    ///         )
+    ///         .to_string()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn display_with(self, options: FormatOptions) -> AdjustedByteDisplay {
+        AdjustedByteDisplay {
+            adjusted_byte: self,
+            options,
+        }
+    }
+
+    /// Create a value which implements `Display` for the value of this `AdjustedByte` instance without the unit. The value is formatted in the same way as the `Display` implementation of `AdjustedByte` does with the given precision, so the number shown is consistent with the combined output.
+    ///
+    /// The width, the alignment (right-aligned by default) and the `#` flag of the formatter are respected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, UnitType};
+    ///
+    /// let adjusted_byte =
+    ///     Byte::from_u64(10000).get_appropriate_unit(UnitType::Binary);
+    ///
+    /// let value = adjusted_byte.value_display(Some(2));
+    ///
+    /// assert_eq!("9.77", value.to_string());
+    /// assert_eq!(
+    ///     format!("{adjusted_byte:.2}"),
+    ///     format!("{value} {}", adjusted_byte.unit_str())
+    /// );
+    ///
+    /// // the same flags as the `Display` implementation of `AdjustedByte`
+    /// assert_eq!("    9.77", format!("{value:>8}"));
+    /// assert_eq!(
+    ///     "10",
+    ///     format!(
+    ///         "{:#}",
+    ///         Byte::from_u64(10240)
+    ///             .get_appropriate_unit(UnitType::Binary)
+    ///             .value_display(Some(2))
+    ///     )
+    /// );
+    /// ```
+    #[inline]
+    pub const fn value_display(self, precision: Option<usize>) -> AdjustedByteValueDisplay {
+        AdjustedByteValueDisplay {
+            adjusted_byte: self,
+            precision,
+        }
+    }
+}
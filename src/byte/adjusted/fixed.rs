@@ -0,0 +1,195 @@
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+};
+
+use super::appropriate_unit;
+use crate::{format::fmt_with_unit, Byte, Unit, UnitType};
+
+/// The maximum `PRECISION` of `FixedAdjustedByte`, which keeps every comparison within `u128`.
+pub const FIXED_ADJUSTED_MAX_PRECISION: usize = 9;
+
+/// Generated from the [`Byte::get_adjusted_unit_fixed`](./struct.Byte.html#method.get_adjusted_unit_fixed) method or the [`Byte::get_appropriate_unit_fixed`](./struct.Byte.html#method.get_appropriate_unit_fixed) method.
+///
+/// The value is rounded half away from zero to `PRECISION` fractional digits once, and kept as an integer scaled by 10<sup>`PRECISION`</sup>. It is always written with exactly `PRECISION` fractional digits, and two instances are equal only if they are written the same, so it suits snapshot tests of user interfaces, where comparing `f64` values is fragile.
+///
+/// `PRECISION` must not exceed [`FIXED_ADJUSTED_MAX_PRECISION`], which is checked at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{Byte, FixedAdjustedByte, Unit, UnitType};
+///
+/// let a: FixedAdjustedByte<2> =
+///     Byte::from_u64(1_234_567).get_appropriate_unit_fixed(UnitType::Binary);
+/// let b: FixedAdjustedByte<2> =
+///     Byte::from_u64(1_234_000).get_appropriate_unit_fixed(UnitType::Binary);
+///
+/// assert_eq!("1.18 MiB", a.to_string());
+/// assert_eq!(118, a.get_scaled_value());
+/// assert_eq!(a, b);
+///
+/// let c: FixedAdjustedByte<2> =
+///     Byte::from_u64(1_240_000).get_adjusted_unit_fixed(Unit::MiB);
+///
+/// assert_eq!("1.18 MiB", c.to_string());
+/// assert!(
+///     a < Byte::from_u64(1_250_000).get_adjusted_unit_fixed::<2>(Unit::MiB)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedAdjustedByte<const PRECISION: usize> {
+    scaled: u128,
+    unit:   Unit,
+}
+
+impl<const PRECISION: usize> FixedAdjustedByte<PRECISION> {
+    /// 10<sup>`PRECISION`</sup>. Evaluating it checks `PRECISION` at compile time.
+    const SCALE: u128 = {
+        assert!(
+            PRECISION <= FIXED_ADJUSTED_MAX_PRECISION,
+            "the precision of FixedAdjustedByte must not exceed FIXED_ADJUSTED_MAX_PRECISION"
+        );
+
+        10u128.pow(PRECISION as u32)
+    };
+
+    /// The value scaled by 10<sup>`PRECISION`</sup> multiplied by the number of bits in the unit, which is proportional to the size written.
+    #[inline]
+    const fn scaled_bits(&self) -> u128 {
+        self.scaled * self.unit.as_bits_u128()
+    }
+}
+
+impl<const PRECISION: usize> PartialOrd for FixedAdjustedByte<PRECISION> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const PRECISION: usize> Ord for FixedAdjustedByte<PRECISION> {
+    /// Compares the sizes written, exactly. If they are the same but in different units, such as `"1.000 KB"` and `"1000.000 B"`, the one in the smaller unit comes first.
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.scaled_bits().cmp(&other.scaled_bits()).then(self.unit.cmp(&other.unit))
+    }
+}
+
+impl<const PRECISION: usize> Display for FixedAdjustedByte<PRECISION> {
+    /// Formats the value using the given formatter. The width, the fill character, the alignment and the `+`, `-` and `0` flags work the same as for `AdjustedByte`. The precision of the formatter is ignored, because the value always has exactly `PRECISION` fractional digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, FixedAdjustedByte, Unit};
+    ///
+    /// let adjusted_byte: FixedAdjustedByte<3> =
+    ///     Byte::from_u64(1536).get_adjusted_unit_fixed(Unit::KiB);
+    ///
+    /// assert_eq!("1.500 KiB", adjusted_byte.to_string());
+    /// assert_eq!("1.500KiB", format!("{adjusted_byte:-}"));
+    /// assert_eq!("  1.500 KiB", format!("{adjusted_byte:>11}"));
+    ///
+    /// let adjusted_byte: FixedAdjustedByte<0> =
+    ///     Byte::from_u64(1536).get_adjusted_unit_fixed(Unit::KiB);
+    ///
+    /// assert_eq!("2 KiB", adjusted_byte.to_string());
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let integer = self.scaled / Self::SCALE;
+
+        if PRECISION == 0 {
+            fmt_with_unit(f, format_args!("{integer}"), Some(self.unit))
+        } else {
+            let fraction = self.scaled % Self::SCALE;
+
+            fmt_with_unit(f, format_args!("{integer}.{fraction:0PRECISION$}"), Some(self.unit))
+        }
+    }
+}
+
+/// Methods for getting values.
+impl<const PRECISION: usize> FixedAdjustedByte<PRECISION> {
+    /// Get the value scaled by 10<sup>`PRECISION`</sup>, e.g. `150` for `"1.50 KiB"`.
+    #[inline]
+    pub const fn get_scaled_value(&self) -> u128 {
+        self.scaled
+    }
+
+    /// Get the unit.
+    #[inline]
+    pub const fn get_unit(&self) -> Unit {
+        self.unit
+    }
+}
+
+/// Associated functions for generating `FixedAdjustedByte`.
+impl Byte {
+    /// Adjust the unit for this `Byte` instance, rounding the value half away from zero to `PRECISION` fractional digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, Unit};
+    ///
+    /// let byte = Byte::from_u64(1_500_000);
+    ///
+    /// assert_eq!(
+    ///     "1.43 MiB",
+    ///     byte.get_adjusted_unit_fixed::<2>(Unit::MiB).to_string()
+    /// );
+    /// assert_eq!(
+    ///     "1.5000 MB",
+    ///     byte.get_adjusted_unit_fixed::<4>(Unit::MB).to_string()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn get_adjusted_unit_fixed<const PRECISION: usize>(
+        self,
+        unit: Unit,
+    ) -> FixedAdjustedByte<PRECISION> {
+        let scale = FixedAdjustedByte::<PRECISION>::SCALE;
+
+        let divisor = unit.as_bits_u128();
+        let bits = self.as_u128() << 3;
+
+        let remainder = bits % divisor * scale;
+
+        let mut scaled = bits / divisor * scale + remainder / divisor;
+
+        // round half away from zero
+        let remainder = remainder % divisor;
+
+        if remainder != 0 && remainder >= divisor - remainder {
+            scaled += 1;
+        }
+
+        FixedAdjustedByte {
+            scaled,
+            unit,
+        }
+    }
+
+    /// Find the appropriate unit for this `Byte` instance, the same as [`Byte::get_appropriate_unit`](#method.get_appropriate_unit) does, rounding the value half away from zero to `PRECISION` fractional digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, UnitType};
+    ///
+    /// let byte = Byte::from_u64(50_840_000);
+    ///
+    /// assert_eq!(
+    ///     "48.485 MiB",
+    ///     byte.get_appropriate_unit_fixed::<3>(UnitType::Binary).to_string()
+    /// );
+    /// ```
+    #[inline]
+    pub fn get_appropriate_unit_fixed<const PRECISION: usize>(
+        &self,
+        unit_type: UnitType,
+    ) -> FixedAdjustedByte<PRECISION> {
+        self.get_adjusted_unit_fixed(appropriate_unit(self.as_u128(), unit_type))
+    }
+}
@@ -0,0 +1,254 @@
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter, Write},
+};
+
+use super::{appropriate_unit, AdjustedByte};
+use crate::{format::fmt_with_unit, Byte, Unit, UnitType};
+
+/// The maximum number of digits of the fractional part. The fractional part of a value always terminates, because the divisor of a unit only has the prime factors 2 and 5, and the largest one has fewer than 96 bits.
+const MAX_FRACTION_DIGITS: usize = 96;
+
+/// Generated from the [`Byte::get_adjusted_unit_integer`](./struct.Byte.html#method.get_adjusted_unit_integer) method or the [`Byte::get_appropriate_unit_integer`](./struct.Byte.html#method.get_appropriate_unit_integer) method.
+///
+/// Unlike `AdjustedByte`, the value is kept as an integer quotient and remainder, and written digit by digit with integer arithmetic, so it is exact for any size and needs no floating-point operations.
+#[derive(Debug, Clone, Copy)]
+pub struct IntegerAdjustedByte {
+    byte: Byte,
+    unit: Unit,
+}
+
+impl PartialEq for IntegerAdjustedByte {
+    #[inline]
+    fn eq(&self, other: &IntegerAdjustedByte) -> bool {
+        self.byte.eq(&other.byte)
+    }
+}
+
+impl Eq for IntegerAdjustedByte {}
+
+impl PartialOrd for IntegerAdjustedByte {
+    #[inline]
+    fn partial_cmp(&self, other: &IntegerAdjustedByte) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for IntegerAdjustedByte {
+    #[inline]
+    fn cmp(&self, other: &IntegerAdjustedByte) -> Ordering {
+        self.byte.cmp(&other.byte)
+    }
+}
+
+/// The integer part and the digits of the fractional part of a value.
+struct Digits<'a> {
+    integer:  u128,
+    fraction: &'a [u8],
+    zeros:    usize,
+}
+
+impl Display for Digits<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("{}", self.integer))?;
+
+        if self.fraction.is_empty() && self.zeros == 0 {
+            return Ok(());
+        }
+
+        f.write_char('.')?;
+
+        for digit in self.fraction {
+            f.write_char((b'0' + digit) as char)?;
+        }
+
+        for _ in 0..self.zeros {
+            f.write_char('0')?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for IntegerAdjustedByte {
+    /// Formats the value using the given formatter. The flags work the same as for `AdjustedByte`, and the value is rounded half away from zero if a precision is given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, UnitType};
+    ///
+    /// let byte = Byte::from_u64(10000);
+    ///
+    /// let adjusted_byte = byte.get_appropriate_unit_integer(UnitType::Binary);
+    ///
+    /// assert_eq!("9.765625 KiB", adjusted_byte.to_string());
+    /// assert_eq!("9.77 KiB", format!("{adjusted_byte:.2}"));
+    /// assert_eq!("9.8 KiB", format!("{adjusted_byte:#.1}"));
+    /// assert_eq!("  9.77 KiB", format!("{adjusted_byte:>10.2}"));
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let divisor = self.unit.as_bits_u128();
+
+        let bits = self.byte.as_u128() << 3;
+
+        let mut integer = bits / divisor;
+        let mut remainder = bits % divisor;
+
+        let limit = match f.precision() {
+            Some(precision) => precision.min(MAX_FRACTION_DIGITS),
+            None => MAX_FRACTION_DIGITS,
+        };
+
+        let mut fraction = [0u8; MAX_FRACTION_DIGITS];
+        let mut length = 0;
+
+        while remainder != 0 && length < limit {
+            remainder *= 10;
+
+            fraction[length] = (remainder / divisor) as u8;
+            remainder %= divisor;
+
+            length += 1;
+        }
+
+        // round half away from zero
+        if remainder != 0 && remainder >= divisor - remainder {
+            let mut i = length;
+
+            loop {
+                if i == 0 {
+                    integer += 1;
+
+                    break;
+                }
+
+                i -= 1;
+
+                if fraction[i] == 9 {
+                    fraction[i] = 0;
+                } else {
+                    fraction[i] += 1;
+
+                    break;
+                }
+            }
+        }
+
+        let zeros = match f.precision() {
+            Some(precision) if !f.alternate() && !matches!(self.unit, Unit::Bit | Unit::B) => {
+                precision - length
+            },
+            _ => {
+                while length > 0 && fraction[length - 1] == 0 {
+                    length -= 1;
+                }
+
+                0
+            },
+        };
+
+        fmt_with_unit(
+            f,
+            format_args!("{}", Digits {
+                integer,
+                fraction: &fraction[..length],
+                zeros,
+            }),
+            Some(self.unit),
+        )
+    }
+}
+
+/// Methods for getting values.
+impl IntegerAdjustedByte {
+    /// Get the integer part of the value, which is the quotient of the size divided by the unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, Unit};
+    ///
+    /// let adjusted_byte =
+    ///     Byte::from_u64(1536).get_adjusted_unit_integer(Unit::KiB);
+    ///
+    /// assert_eq!(1, adjusted_byte.get_integer_part());
+    /// assert_eq!(Byte::from_u64(512), adjusted_byte.get_remainder());
+    /// ```
+    #[inline]
+    pub const fn get_integer_part(&self) -> u128 {
+        (self.byte.as_u128() << 3) / self.unit.as_bits_u128()
+    }
+
+    /// Get the remainder of the size divided by the unit. If the unit is smaller than a byte, the remainder is always zero.
+    #[inline]
+    pub const fn get_remainder(&self) -> Byte {
+        Byte::from_u128_unchecked(((self.byte.as_u128() << 3) % self.unit.as_bits_u128()) >> 3)
+    }
+
+    /// Get the unit.
+    #[inline]
+    pub const fn get_unit(&self) -> Unit {
+        self.unit
+    }
+
+    /// Get the `Byte` instance which this `IntegerAdjustedByte` instance is generated from.
+    #[inline]
+    pub const fn get_byte(&self) -> Byte {
+        self.byte
+    }
+
+    /// Convert this `IntegerAdjustedByte` instance to an `AdjustedByte` instance with the same unit.
+    #[inline]
+    pub fn to_adjusted_byte(&self) -> AdjustedByte {
+        self.byte.get_adjusted_unit(self.unit)
+    }
+}
+
+/// Associated functions for generating `IntegerAdjustedByte`.
+impl Byte {
+    /// Adjust the unit for this `Byte` instance, keeping the value exact without floating-point operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, Unit};
+    ///
+    /// // 2^53 + 1 bytes cannot be represented by `f64`
+    /// let byte = Byte::from_u64((1 << 53) + 1);
+    ///
+    /// assert_eq!(
+    ///     "9007199254740993 B",
+    ///     byte.get_adjusted_unit_integer(Unit::B).to_string()
+    /// );
+    /// assert_eq!(
+    ///     "8.00000000000000088817841970012523233890533447265625 PiB",
+    ///     byte.get_adjusted_unit_integer(Unit::PiB).to_string()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn get_adjusted_unit_integer(self, unit: Unit) -> IntegerAdjustedByte {
+        IntegerAdjustedByte {
+            byte: self,
+            unit,
+        }
+    }
+
+    /// Find the appropriate unit for this `Byte` instance, the same as [`Byte::get_appropriate_unit`](#method.get_appropriate_unit) does, keeping the value exact without floating-point operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, UnitType};
+    ///
+    /// let byte = Byte::from_u64(50_840_000);
+    ///
+    /// let adjusted_byte = byte.get_appropriate_unit_integer(UnitType::Binary);
+    ///
+    /// assert_eq!("48.48480224609375 MiB", adjusted_byte.to_string());
+    /// ```
+    #[inline]
+    pub fn get_appropriate_unit_integer(&self, unit_type: UnitType) -> IntegerAdjustedByte {
+        self.get_adjusted_unit_integer(appropriate_unit(self.as_u128(), unit_type))
+    }
+}
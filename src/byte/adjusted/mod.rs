@@ -1,4 +1,7 @@
 mod built_in_traits;
+mod display;
+mod fixed;
+mod integer;
 #[cfg(feature = "rocket")]
 mod rocket_traits;
 #[cfg(feature = "serde")]
@@ -6,11 +9,15 @@ mod serde_traits;
 
 use core::{
     cmp::Ordering,
-    fmt::{self, Alignment, Display, Formatter, Write},
+    fmt::{self, Display, Formatter},
 };
 
+pub use display::*;
+pub use fixed::*;
+pub use integer::*;
+
 use super::{Byte, Unit};
-use crate::{common::round_fractional_part_f64, UnitType};
+use crate::{format::fmt_value, AppropriateUnitOptions, ParseError, UnitType};
 
 /// Generated from the [`Byte::get_adjusted_unit`](./struct.Byte.html#method.get_adjusted_unit) method or the the [`Byte::get_appropriate_unit`](./struct.Byte.html#method.get_appropriate_unit) method.
 ///
@@ -94,99 +101,31 @@ impl Display for AdjustedByte {
     ///
     /// // with a width, right alignment, more spaces between the value and the unit
     /// assert_eq!("  9.77 KiB", format!("{adjusted_byte_based_2:>+10.2}"));
+    ///
+    /// // with a fill character, or padded with zeros, like integers
+    /// assert_eq!("*9.77* KiB", format!("{adjusted_byte_based_2:*^10.2}"));
+    /// assert_eq!("009.77 KiB", format!("{adjusted_byte_based_2:010.2}"));
     /// assert_eq!(" 10.00  KB", format!("{adjusted_byte_based_10:>+10.2}"));
     ///
-    /// // no spaces between the value and the unit
+    /// // no spaces between the value and the unit (see also `FormatStyle::Compact`)
     /// assert_eq!("9.765625KiB", format!("{adjusted_byte_based_2:-}"));
     /// assert_eq!("10KB", format!("{adjusted_byte_based_10:-}"));
     /// ```
+    #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let Self {
-            value,
-            unit,
-        } = self;
-        let handle_basic_precision = |precision: usize, f: &mut Formatter<'_>| -> fmt::Result {
-            if f.alternate() {
-                let value = round_fractional_part_f64(*value, precision);
-
-                f.write_fmt(format_args!("{value}"))
-            } else if matches!(unit, Unit::Bit | Unit::B) {
-                f.write_fmt(format_args!("{value}"))
-            } else {
-                f.write_fmt(format_args!("{value:.precision$}"))
-            }
-        };
-
-        let space_length = if f.sign_plus() {
-            4 - unit.as_str().len()
-        } else if f.sign_minus() {
-            0
-        } else {
-            1
-        };
-
-        if let Some(mut width) = f.width() {
-            let l = unit.as_str().len() + space_length;
-
-            if let Some(precision) = f.precision() {
-                if width > l + 1 {
-                    width -= l;
-
-                    let alignment = f.align().unwrap_or(Alignment::Left);
-
-                    if f.alternate() {
-                        let value = round_fractional_part_f64(*value, precision);
-
-                        match alignment {
-                            Alignment::Left | Alignment::Center => {
-                                f.write_fmt(format_args!("{value:<width$}"))?
-                            },
-                            Alignment::Right => f.write_fmt(format_args!("{value:>width$}"))?,
-                        }
-                    } else {
-                        match alignment {
-                            Alignment::Left | Alignment::Center => {
-                                f.write_fmt(format_args!("{value:<width$.precision$}"))?
-                            },
-                            Alignment::Right => {
-                                f.write_fmt(format_args!("{value:>width$.precision$}"))?
-                            },
-                        }
-                    }
-                } else {
-                    handle_basic_precision(precision, f)?;
-                }
-            } else if width > l + 1 {
-                width -= l;
-
-                let alignment = f.align().unwrap_or(Alignment::Left);
-
-                match alignment {
-                    Alignment::Left | Alignment::Center => {
-                        f.write_fmt(format_args!("{value:<width$}"))?
-                    },
-                    Alignment::Right => f.write_fmt(format_args!("{value:>width$}"))?,
-                }
-            } else {
-                f.write_fmt(format_args!("{value}"))?;
-            }
-        } else if let Some(precision) = f.precision() {
-            handle_basic_precision(precision, f)?;
-        } else {
-            f.write_fmt(format_args!("{value}"))?;
-        }
-
-        for _ in 0..space_length {
-            f.write_char(' ')?;
-        }
-
-        f.write_fmt(format_args!("{unit}"))
+        fmt_value(f, self.value, self.unit, f.precision(), true)
     }
 }
 
 /// Methods for getting values.
 impl AdjustedByte {
     /// Get the value.
+    #[cfg_attr(
+        feature = "strict",
+        deprecated(note = "floating-point numbers are lossy, use \
+                           `Byte::get_adjusted_unit_exact` or `Byte::get_adjusted_unit_rounded` \
+                           instead")
+    )]
     #[inline]
     pub const fn get_value(&self) -> f64 {
         self.value
@@ -198,6 +137,12 @@ impl AdjustedByte {
         self.unit
     }
 
+    /// Get the string of the unit. See [`Unit::as_str`](./enum.Unit.html#method.as_str).
+    #[inline]
+    pub const fn unit_str(&self) -> &'static str {
+        self.unit.as_str()
+    }
+
     /// Create a new `Byte` instance from this `AdjustedByte` instance.
     ///
     /// # Examples
@@ -217,12 +162,124 @@ impl AdjustedByte {
     /// # Points to Note
     ///
     /// * The result may not be logically equal to the original `Byte` instance due to the accuracy of floating-point numbers.
+    #[allow(deprecated)]
     #[inline]
     pub fn get_byte(&self) -> Byte {
         Byte::from_f64_with_unit(self.value, self.unit).unwrap()
     }
 }
 
+/// Associated functions for parsing strings.
+impl AdjustedByte {
+    /// Create a new `AdjustedByte` instance from a string, keeping the unit written in it, e.g. to echo a configuration back to its user as they wrote it. See [`Byte::parse_str`](./struct.Byte.html#method.parse_str).
+    ///
+    /// Unlike the `FromStr` implementation, which picks an appropriate unit, `"2048 KiB"` stays in KiB instead of becoming `"2 MiB"`. A string without a unit is in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{AdjustedByte, Unit};
+    ///
+    /// let adjusted_byte =
+    ///     AdjustedByte::parse_str_keep_unit("2048 KiB", false).unwrap();
+    ///
+    /// assert_eq!(Unit::KiB, adjusted_byte.get_unit());
+    /// assert_eq!("2048 KiB", adjusted_byte.to_string());
+    ///
+    /// // `FromStr` picks an appropriate unit
+    /// assert_eq!(
+    ///     "2 MiB",
+    ///     "2048 KiB".parse::<AdjustedByte>().unwrap().to_string()
+    /// );
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * The value is calculated from the parsed size, so a value of bits which is not a multiple of 8 is rounded as in `Byte::parse_str`.
+    #[inline]
+    pub fn parse_str_keep_unit<S: AsRef<str>>(s: S, ignore_case: bool) -> Result<Self, ParseError> {
+        let (byte, unit) = Byte::parse_str_and_unit(s.as_ref(), ignore_case)?;
+
+        Ok(byte.get_adjusted_unit(unit))
+    }
+}
+
+/// Methods for formatting.
+#[cfg(feature = "std")]
+impl AdjustedByte {
+    /// Format the value of this `AdjustedByte` instance without the unit. See [`AdjustedByte::value_display`](#method.value_display).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, UnitType};
+    ///
+    /// let adjusted_byte =
+    ///     Byte::from_u64(10000).get_appropriate_unit(UnitType::Binary);
+    ///
+    /// assert_eq!("9.765625", adjusted_byte.to_value_string(None));
+    /// assert_eq!("9.77", adjusted_byte.to_value_string(Some(2)));
+    /// assert_eq!("KiB", adjusted_byte.unit_str());
+    /// ```
+    #[inline]
+    pub fn to_value_string(&self, precision: Option<usize>) -> String {
+        self.value_display(precision).to_string()
+    }
+
+    /// Format this `AdjustedByte` instance into a string which is exactly `width` characters long. The value is right-aligned, and its precision is reduced as needed. If even the integer part cannot fit, a larger unit of the same kind is chosen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, Unit};
+    ///
+    /// let adjusted_byte = Byte::from_u64(1536).get_adjusted_unit(Unit::KiB);
+    ///
+    /// assert_eq!("1.5000 KiB", adjusted_byte.format_fixed_width(10).unwrap());
+    /// assert_eq!("1.5 KiB", adjusted_byte.format_fixed_width(7).unwrap());
+    /// assert_eq!(" 2 KiB", adjusted_byte.format_fixed_width(6).unwrap());
+    ///
+    /// // a larger unit is chosen when the value cannot fit
+    /// let adjusted_byte =
+    ///     Byte::from_u64(1023 * 1024).get_adjusted_unit(Unit::KiB);
+    ///
+    /// assert_eq!("1.0 MiB", adjusted_byte.format_fixed_width(7).unwrap());
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If `width` is too small for any unit, this method will return `None`.
+    #[inline]
+    pub fn format_fixed_width(&self, width: usize) -> Option<String> {
+        crate::format::format_fixed_width(self.value, self.unit, width)
+    }
+
+    /// Format this `AdjustedByte` instance into a string for a column of a table, which is `width` characters long. The value is right-aligned and written with `precision` digits after the decimal point, and the unit is right-aligned in a field of 3 characters, so that the values and the units of all rows line up. This is the same as the `{:>+width$.precision$}` format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, UnitType};
+    ///
+    /// let rows = [1610612736, 1027813786, 12288, 512].map(|n| {
+    ///     Byte::from_u64(n)
+    ///         .get_appropriate_unit(UnitType::Binary)
+    ///         .format_aligned(9, 1)
+    /// });
+    ///
+    /// assert_eq!(["  1.5 GiB", "980.2 MiB", " 12.0 KiB", "  512   B"], rows);
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * The precision is not applied to values in bits or bytes.
+    /// * If `width` is too small, the string is longer than `width`, as the `Display` implementations of the standard library do.
+    #[inline]
+    pub fn format_aligned(&self, width: usize, precision: usize) -> String {
+        format!("{self:>+width$.precision$}")
+    }
+}
+
 /// Associated functions for generating `AdjustedByte`.
 impl Byte {
     /// Adjust the unit and value for this `Byte` instance.
@@ -287,23 +344,116 @@ impl Byte {
     ///
     /// assert_eq!("48.48480224609375 MiB", adjusted_byte.to_string());
     /// ```
+    #[inline]
     pub fn get_appropriate_unit(&self, unit_type: UnitType) -> AdjustedByte {
-        let a = Unit::get_multiples_bytes();
+        self.get_adjusted_unit(appropriate_unit(self.as_u128(), unit_type))
+    }
 
-        let (skip, step) = match unit_type {
-            UnitType::Binary => (0, 2),
-            UnitType::Decimal => (1, 2),
-            UnitType::Both => (0, 1),
-        };
+    /// Find the appropriate unit and value for this `Byte` instance, with a custom threshold for switching to a larger unit. See [`AppropriateUnitOptions`](./struct.AppropriateUnitOptions.html).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{AppropriateUnitOptions, Byte, UnitType};
+    ///
+    /// let byte = Byte::from_u64(973 << 20);
+    ///
+    /// let options = AppropriateUnitOptions::new(UnitType::Binary).threshold(0.95);
+    ///
+    /// assert_eq!(
+    ///     "0.95 GiB",
+    ///     format!("{:.2}", byte.get_appropriate_unit_with_options(&options))
+    /// );
+    /// ```
+    #[inline]
+    pub fn get_appropriate_unit_with_options(
+        &self,
+        options: &AppropriateUnitOptions,
+    ) -> AdjustedByte {
+        let unit = options
+            .find_unit(self.as_u128(), Unit::get_multiples_bytes(), Unit::as_bytes_u128)
+            .unwrap_or(Unit::B);
+
+        self.get_adjusted_unit(unit)
+    }
 
+    /// Find the appropriate unit and value for this `Byte` instance, considering bit units (e.g. `Mbit`) as well as byte units.
+    ///
+    /// The largest unit which is not greater than this `Byte` instance is chosen, so a bit unit is chosen only if the next byte unit is too large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, Unit, UnitType};
+    ///
+    /// let byte = Byte::from_u64(375000);
+    ///
+    /// let adjusted_byte =
+    ///     byte.get_appropriate_unit_allowing_bits(UnitType::Decimal);
+    ///
+    /// assert_eq!(Unit::Mbit, adjusted_byte.get_unit());
+    /// assert_eq!("3 Mb", adjusted_byte.to_string());
+    /// ```
+    ///
+    /// ```
+    /// use byte_unit::{Byte, UnitType};
+    ///
+    /// let byte = Byte::from_u64(3000000);
+    ///
+    /// let adjusted_byte =
+    ///     byte.get_appropriate_unit_allowing_bits(UnitType::Decimal);
+    ///
+    /// assert_eq!("3 MB", adjusted_byte.to_string());
+    /// ```
+    pub fn get_appropriate_unit_allowing_bits(&self, unit_type: UnitType) -> AdjustedByte {
         let bytes_v = self.as_u128();
 
-        for unit in a.iter().rev().skip(skip).step_by(step) {
-            if bytes_v >= unit.as_bytes_u128() {
-                return self.get_adjusted_unit(*unit);
-            }
-        }
+        let unit = unit_type
+            .find_largest_unit(Unit::get_multiples(), |unit| bytes_v >= unit.as_bytes_u128())
+            .unwrap_or(Unit::B);
 
-        self.get_adjusted_unit(Unit::B)
+        self.get_adjusted_unit(unit)
+    }
+}
+
+/// Find the largest byte unit of the unit type which is not greater than `bytes_v`.
+fn appropriate_unit(bytes_v: u128, unit_type: UnitType) -> Unit {
+    unit_type
+        .find_largest_unit(Unit::get_multiples_bytes(), |unit| bytes_v >= unit.as_bytes_u128())
+        .unwrap_or(Unit::B)
+}
+
+/// Associated functions for generating `AdjustedByte` from a `Bit` instance.
+#[cfg(feature = "bit")]
+impl crate::Bit {
+    /// Find the appropriate byte unit and value for this `Bit` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, UnitType};
+    ///
+    /// let bit = Bit::from_u64(12_000_000);
+    ///
+    /// assert_eq!(
+    ///     "12 Mb",
+    ///     bit.get_appropriate_unit(UnitType::Decimal).to_string()
+    /// );
+    /// assert_eq!(
+    ///     "1.5 MB",
+    ///     bit.get_appropriate_byte_unit(UnitType::Decimal).to_string()
+    /// );
+    /// ```
+    pub fn get_appropriate_byte_unit(&self, unit_type: UnitType) -> AdjustedByte {
+        let bits_v = self.as_u128();
+
+        let unit = unit_type
+            .find_largest_unit(Unit::get_multiples_bytes(), |unit| bits_v >= unit.as_bits_u128())
+            .unwrap_or(Unit::B);
+
+        AdjustedByte {
+            value: bits_v as f64 / unit.as_bits_u128() as f64,
+            unit,
+        }
     }
 }
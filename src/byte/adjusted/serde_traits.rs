@@ -10,6 +10,7 @@ use serde::{
 };
 
 use super::AdjustedByte;
+use crate::serde::invalid_str;
 
 impl Serialize for AdjustedByte {
     #[inline]
@@ -43,7 +44,7 @@ impl<'de> Deserialize<'de> for AdjustedByte {
             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
             where
                 E: DeError, {
-                AdjustedByte::from_str(v).map_err(DeError::custom)
+                AdjustedByte::from_str(v).map_err(|error| invalid_str(v, &self, error))
             }
         }
 
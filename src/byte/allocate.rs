@@ -0,0 +1,62 @@
+use std::vec::Vec;
+
+use super::Byte;
+
+/// Methods for splitting.
+impl Byte {
+    /// Split this `Byte` instance into shares proportional to the given weights. The shares always add up to this `Byte` instance exactly, and the bytes which cannot be split evenly are given to the shares with the largest remainders (the largest remainder method). Ties go to the earlier shares, so the result is deterministic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// let budget = Byte::from_u64(1000);
+    ///
+    /// assert_eq!(
+    ///     vec![500, 300, 200],
+    ///     budget
+    ///         .allocate_weighted(&[5, 3, 2])
+    ///         .iter()
+    ///         .map(|b| b.as_u64())
+    ///         .collect::<Vec<_>>()
+    /// );
+    /// assert_eq!(
+    ///     vec![334, 333, 333],
+    ///     budget
+    ///         .allocate_weighted(&[1, 1, 1])
+    ///         .iter()
+    ///         .map(|b| b.as_u64())
+    ///         .collect::<Vec<_>>()
+    /// );
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If `weights` is empty or all the weights are zero, every share is zero.
+    pub fn allocate_weighted(&self, weights: &[u32]) -> Vec<Byte> {
+        let total = self.as_u128();
+        let weight_sum = weights.iter().map(|&w| w as u128).sum::<u128>();
+
+        if weight_sum == 0 {
+            return vec![Byte::MIN; weights.len()];
+        }
+
+        // `total` is smaller than 2^90 and every weight is smaller than 2^32, so this cannot overflow
+        let products = weights.iter().map(|&w| total * w as u128);
+
+        let mut shares: Vec<u128> = products.clone().map(|p| p / weight_sum).collect();
+        let mut remainders: Vec<(u128, usize)> =
+            products.enumerate().map(|(i, p)| (p % weight_sum, i)).collect();
+
+        let leftover = total - shares.iter().sum::<u128>();
+
+        remainders.sort_unstable_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+        for &(_, i) in remainders.iter().take(leftover as usize) {
+            shares[i] += 1;
+        }
+
+        shares.into_iter().map(|share| Byte::from_u128(share).unwrap()).collect()
+    }
+}
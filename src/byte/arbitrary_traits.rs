@@ -0,0 +1,49 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use super::{AdjustedByte, Byte};
+use crate::Unit;
+
+impl<'a> Arbitrary<'a> for Byte {
+    /// Generate a boundary value (`0`, `1`, `Byte::MAX - 1` or `Byte::MAX`), an exact multiple of a unit (e.g. `5 MiB`), or any value in the full range.
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let max = Byte::MAX.as_u128();
+
+        let size = match u.choose_index(3)? {
+            0 => *u.choose(&[0, 1, max - 1, max])?,
+            1 => {
+                let unit = Unit::arbitrary(u)?;
+
+                let factor = match unit {
+                    Unit::Bit => 1,
+                    _ => unit.as_bytes_u128(),
+                };
+
+                match factor.checked_mul(u.int_in_range(0..=1024)?) {
+                    Some(size) if size <= max => size,
+                    _ => factor,
+                }
+            },
+            _ => u.int_in_range(0..=max)?,
+        };
+
+        // the size is not greater than `max`
+        Ok(Byte::from_u128_unchecked(size))
+    }
+
+    #[inline]
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, Some(32))
+    }
+}
+
+impl<'a> Arbitrary<'a> for AdjustedByte {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Byte::arbitrary(u)?.get_adjusted_unit(Unit::arbitrary(u)?))
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(Byte::size_hint(depth), Unit::size_hint(depth))
+    }
+}
@@ -0,0 +1,181 @@
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::Deref,
+};
+
+use super::Byte;
+use crate::ExceededBoundsError;
+
+/// A `Byte` instance which is not greater than `MAX` bytes, so that a cap is enforced by the type, e.g. `BoundedByte<{ u32::MAX as u128 }>` for a chunk size which must fit in `u32`.
+///
+/// If `MAX` is greater than [`Byte::MAX`](struct.Byte.html#associatedconstant.MAX), the cap is `Byte::MAX`.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{BoundedByte, Byte};
+///
+/// type ChunkSize = BoundedByte<{ u32::MAX as u128 }>;
+///
+/// let chunk_size = ChunkSize::try_from(Byte::from_u64(64 << 20)).unwrap();
+///
+/// assert_eq!(64 << 20, chunk_size.as_u64() as u32);
+/// assert!(ChunkSize::try_from(Byte::from_u64(1 << 32)).is_err());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct BoundedByte<const MAX: u128>(Byte);
+
+impl<const MAX: u128> BoundedByte<MAX> {
+    /// The largest `BoundedByte` instance.
+    pub const MAX: Self = {
+        if MAX < Byte::MAX.as_u128() {
+            Self(Byte::from_u128_unchecked(MAX))
+        } else {
+            Self(Byte::MAX)
+        }
+    };
+    /// 0 byte.
+    pub const MIN: Self = Self(Byte::MIN);
+}
+
+/// Associated functions for building `BoundedByte` instances.
+impl<const MAX: u128> BoundedByte<MAX> {
+    /// Create a new `BoundedByte` instance from a `Byte` instance.
+    ///
+    /// # Points to Note
+    ///
+    /// * If the size is greater than `MAX`, this function will return `None`.
+    #[inline]
+    pub const fn new(byte: Byte) -> Option<Self> {
+        if byte.as_u128() <= Self::MAX.0.as_u128() {
+            Some(Self(byte))
+        } else {
+            None
+        }
+    }
+
+    /// Create a new `BoundedByte` instance from a `Byte` instance, clamping the size to `MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{BoundedByte, Byte};
+    ///
+    /// assert_eq!(
+    ///     Byte::from_u64(4096),
+    ///     BoundedByte::<4096>::new_saturating(Byte::from_u64(5000)).get_byte()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn new_saturating(byte: Byte) -> Self {
+        match Self::new(byte) {
+            Some(bounded_byte) => bounded_byte,
+            None => Self::MAX,
+        }
+    }
+
+    /// Create a new `BoundedByte` instance from a size in bytes.
+    ///
+    /// # Points to Note
+    ///
+    /// * If the size is greater than `MAX` or too large for `Byte`, this function will return `None`.
+    #[inline]
+    pub const fn from_u128(size: u128) -> Option<Self> {
+        match Byte::from_u128(size) {
+            Some(byte) => Self::new(byte),
+            None => None,
+        }
+    }
+
+    /// Create a new `BoundedByte` instance from a size in bytes.
+    ///
+    /// # Points to Note
+    ///
+    /// * If the size is greater than `MAX`, this function will return `None`.
+    #[inline]
+    pub const fn from_u64(size: u64) -> Option<Self> {
+        Self::new(Byte::from_u64(size))
+    }
+}
+
+/// Methods for getting values and arithmetic.
+impl<const MAX: u128> BoundedByte<MAX> {
+    /// Get the `Byte` instance.
+    #[inline]
+    pub const fn get_byte(self) -> Byte {
+        self.0
+    }
+
+    /// Add a `Byte` instance.
+    ///
+    /// # Points to Note
+    ///
+    /// * If the sum is greater than `MAX`, this function will return `None`.
+    #[inline]
+    pub const fn add(self, rhs: Byte) -> Option<Self> {
+        match self.0.add(rhs) {
+            Some(byte) => Self::new(byte),
+            None => None,
+        }
+    }
+
+    /// Subtract a `Byte` instance.
+    ///
+    /// # Points to Note
+    ///
+    /// * If the right-hand side is bigger then this `BoundedByte` instance, this function will return `None`.
+    #[inline]
+    pub const fn subtract(self, rhs: Byte) -> Option<Self> {
+        match self.0.subtract(rhs) {
+            Some(byte) => Some(Self(byte)),
+            None => None,
+        }
+    }
+}
+
+impl<const MAX: u128> Deref for BoundedByte<MAX> {
+    type Target = Byte;
+
+    #[inline]
+    fn deref(&self) -> &Byte {
+        &self.0
+    }
+}
+
+impl<const MAX: u128> Display for BoundedByte<MAX> {
+    /// Formats the value in the same way as `Byte`.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<const MAX: u128> TryFrom<Byte> for BoundedByte<MAX> {
+    type Error = ExceededBoundsError;
+
+    #[inline]
+    fn try_from(value: Byte) -> Result<Self, Self::Error> {
+        Self::new(value).ok_or(ExceededBoundsError)
+    }
+}
+
+impl<const MAX: u128> From<BoundedByte<MAX>> for Byte {
+    #[inline]
+    fn from(value: BoundedByte<MAX>) -> Self {
+        value.0
+    }
+}
+
+impl<const MAX: u128> PartialEq<Byte> for BoundedByte<MAX> {
+    #[inline]
+    fn eq(&self, other: &Byte) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl<const MAX: u128> PartialEq<BoundedByte<MAX>> for Byte {
+    #[inline]
+    fn eq(&self, other: &BoundedByte<MAX>) -> bool {
+        self.eq(&other.0)
+    }
+}
@@ -0,0 +1,148 @@
+use super::Byte;
+use crate::BudgetError;
+
+/// A ledger of a limited amount of bytes, e.g. for admission control of memory or uploads.
+///
+/// An amount goes through the following states:
+///
+/// * **available** → **reserved** by [`ByteBudget::reserve`](#method.reserve), e.g. when an upload is accepted.
+/// * **reserved** → **committed** by [`ByteBudget::commit`](#method.commit), e.g. when the data is actually stored.
+/// * **reserved** → **available** by [`ByteBudget::cancel`](#method.cancel), e.g. when an upload is aborted or turns out smaller.
+/// * **committed** → **available** by [`ByteBudget::release`](#method.release), e.g. when the data is deleted.
+///
+/// The reserved amount and the committed amount never exceed the capacity in total.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{Byte, ByteBudget};
+///
+/// let mut budget = ByteBudget::new(Byte::from_u64(1000));
+///
+/// budget.reserve(Byte::from_u64(600)).unwrap();
+/// assert!(budget.reserve(Byte::from_u64(600)).is_err());
+///
+/// budget.commit(Byte::from_u64(500)).unwrap();
+/// budget.cancel(Byte::from_u64(100)).unwrap();
+///
+/// assert_eq!(Byte::from_u64(0), budget.reserved());
+/// assert_eq!(Byte::from_u64(500), budget.committed());
+/// assert_eq!(Byte::from_u64(500), budget.available());
+///
+/// budget.release(Byte::from_u64(500)).unwrap();
+///
+/// assert_eq!(Byte::from_u64(1000), budget.available());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ByteBudget {
+    capacity:  Byte,
+    reserved:  Byte,
+    committed: Byte,
+}
+
+/// Associated functions for building `ByteBudget` instances.
+impl ByteBudget {
+    /// Create a new `ByteBudget` instance with nothing reserved or committed.
+    #[inline]
+    pub const fn new(capacity: Byte) -> Self {
+        Self {
+            capacity,
+            reserved: Byte::MIN,
+            committed: Byte::MIN,
+        }
+    }
+}
+
+/// Methods for getting the amounts.
+impl ByteBudget {
+    /// Get the capacity.
+    #[inline]
+    pub const fn capacity(&self) -> Byte {
+        self.capacity
+    }
+
+    /// Get the reserved amount.
+    #[inline]
+    pub const fn reserved(&self) -> Byte {
+        self.reserved
+    }
+
+    /// Get the committed amount.
+    #[inline]
+    pub const fn committed(&self) -> Byte {
+        self.committed
+    }
+
+    /// Get the amount which is neither reserved nor committed.
+    #[inline]
+    pub const fn available(&self) -> Byte {
+        self.capacity.saturating_sub(self.reserved).saturating_sub(self.committed)
+    }
+}
+
+/// Methods for the transitions. A failed transition leaves the budget unchanged.
+impl ByteBudget {
+    /// Reserve an available amount.
+    #[inline]
+    pub fn reserve(&mut self, amount: Byte) -> Result<(), BudgetError> {
+        let available = self.available();
+
+        if amount > available {
+            return Err(BudgetError::InsufficientAvailable {
+                requested: amount,
+                available,
+            });
+        }
+
+        self.reserved += amount;
+
+        Ok(())
+    }
+
+    /// Commit a reserved amount.
+    #[inline]
+    pub fn commit(&mut self, amount: Byte) -> Result<(), BudgetError> {
+        self.take_reserved(amount)?;
+
+        self.committed += amount;
+
+        Ok(())
+    }
+
+    /// Cancel a reserved amount, making it available again.
+    #[inline]
+    pub fn cancel(&mut self, amount: Byte) -> Result<(), BudgetError> {
+        self.take_reserved(amount)
+    }
+
+    /// Release a committed amount, making it available again.
+    #[inline]
+    pub fn release(&mut self, amount: Byte) -> Result<(), BudgetError> {
+        match self.committed.checked_sub(amount) {
+            Some(committed) => {
+                self.committed = committed;
+
+                Ok(())
+            },
+            None => Err(BudgetError::InsufficientCommitted {
+                requested: amount,
+                committed: self.committed,
+            }),
+        }
+    }
+
+    #[inline]
+    fn take_reserved(&mut self, amount: Byte) -> Result<(), BudgetError> {
+        match self.reserved.checked_sub(amount) {
+            Some(reserved) => {
+                self.reserved = reserved;
+
+                Ok(())
+            },
+            None => Err(BudgetError::InsufficientReserved {
+                requested: amount,
+                reserved:  self.reserved,
+            }),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use super::Byte;
+use crate::Unit;
+
+/// A builder for composing a `Byte` instance from multiple components.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{Byte, ByteBuilder, Unit};
+///
+/// let byte = ByteBuilder::new()
+///     .add(2, Unit::GiB)
+///     .add(512, Unit::MiB)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(Byte::from_u64(2684354560), byte);
+/// ```
+///
+/// ```
+/// use byte_unit::{Byte, ByteBuilder, Unit};
+///
+/// assert_eq!(
+///     None,
+///     ByteBuilder::new().add_byte(Byte::MAX).add(1, Unit::B).build()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteBuilder {
+    byte: Option<Byte>,
+}
+
+impl Default for ByteBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ByteBuilder {
+    /// Create a new `ByteBuilder` instance starting from 0 byte.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            byte: Some(Byte::MIN)
+        }
+    }
+
+    /// Add a size of bytes with a unit.
+    ///
+    /// # Points to Note
+    ///
+    /// * If the accumulated byte is too large, the [`build`](#method.build) method will return `None`.
+    /// * If the input **unit** is `Bit`, the added byte will be rounded up.
+    #[inline]
+    pub const fn add(self, size: u64, unit: Unit) -> Self {
+        match Byte::from_u64_with_unit(size, unit) {
+            Some(byte) => self.add_byte(byte),
+            None => Self {
+                byte: None
+            },
+        }
+    }
+
+    /// Add a `Byte` instance.
+    ///
+    /// # Points to Note
+    ///
+    /// * If the accumulated byte is too large, the [`build`](#method.build) method will return `None`.
+    #[inline]
+    pub const fn add_byte(self, byte: Byte) -> Self {
+        let byte = match self.byte {
+            Some(v) => v.add(byte),
+            None => None,
+        };
+
+        Self {
+            byte,
+        }
+    }
+
+    /// Create the `Byte` instance.
+    ///
+    /// # Points to Note
+    ///
+    /// * If any step overflowed, this function will return `None`.
+    #[inline]
+    pub const fn build(self) -> Option<Byte> {
+        self.byte
+    }
+}
@@ -1,7 +1,17 @@
-use core::{cmp::Ordering, str::FromStr};
-
-use super::Byte;
-use crate::{ExceededBoundsError, ParseError, TryFromIntError};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::{self, Binary, Formatter, LowerHex, Octal, UpperHex},
+    iter::Sum,
+    num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+    str::FromStr,
+};
+#[cfg(feature = "std")]
+use std::string::String;
+
+use super::{Byte, ByteRepr};
+use crate::{ExceededBoundsError, NonZeroError, ParseError, TryFromIntError};
 
 impl TryFrom<u128> for Byte {
     type Error = ExceededBoundsError;
@@ -122,6 +132,7 @@ impl TryFrom<isize> for Byte {
 impl TryFrom<f64> for Byte {
     type Error = ExceededBoundsError;
 
+    #[allow(deprecated)]
     #[inline]
     fn try_from(value: f64) -> Result<Self, Self::Error> {
         Byte::from_f64(value).ok_or(ExceededBoundsError)
@@ -131,12 +142,73 @@ impl TryFrom<f64> for Byte {
 impl TryFrom<f32> for Byte {
     type Error = ExceededBoundsError;
 
+    #[allow(deprecated)]
     #[inline]
     fn try_from(value: f32) -> Result<Self, Self::Error> {
         Byte::from_f32(value).ok_or(ExceededBoundsError)
     }
 }
 
+impl TryFrom<NonZeroU128> for Byte {
+    type Error = ExceededBoundsError;
+
+    #[inline]
+    fn try_from(value: NonZeroU128) -> Result<Self, Self::Error> {
+        Byte::try_from(value.get())
+    }
+}
+
+impl From<NonZeroU64> for Byte {
+    #[inline]
+    fn from(value: NonZeroU64) -> Self {
+        Byte::from_u64(value.get())
+    }
+}
+
+impl From<NonZeroU32> for Byte {
+    #[inline]
+    fn from(value: NonZeroU32) -> Self {
+        Byte::from_u64(value.get() as u64)
+    }
+}
+
+impl From<NonZeroU16> for Byte {
+    #[inline]
+    fn from(value: NonZeroU16) -> Self {
+        Byte::from_u64(value.get() as u64)
+    }
+}
+
+impl From<NonZeroU8> for Byte {
+    #[inline]
+    fn from(value: NonZeroU8) -> Self {
+        Byte::from_u64(value.get() as u64)
+    }
+}
+
+impl From<NonZeroUsize> for Byte {
+    #[inline]
+    fn from(value: NonZeroUsize) -> Self {
+        Byte::from(value.get())
+    }
+}
+
+impl TryFrom<Wrapping<u128>> for Byte {
+    type Error = ExceededBoundsError;
+
+    #[inline]
+    fn try_from(value: Wrapping<u128>) -> Result<Self, Self::Error> {
+        Byte::try_from(value.0)
+    }
+}
+
+impl From<Wrapping<u64>> for Byte {
+    #[inline]
+    fn from(value: Wrapping<u64>) -> Self {
+        Byte::from_u64(value.0)
+    }
+}
+
 impl From<Byte> for u128 {
     #[inline]
     fn from(byte: Byte) -> Self {
@@ -196,6 +268,93 @@ impl TryFrom<Byte> for usize {
     }
 }
 
+impl TryFrom<Byte> for NonZeroU128 {
+    type Error = NonZeroError;
+
+    #[inline]
+    fn try_from(byte: Byte) -> Result<Self, Self::Error> {
+        NonZeroU128::new(byte.as_u128()).ok_or(NonZeroError::Zero)
+    }
+}
+
+impl TryFrom<Byte> for NonZeroU64 {
+    type Error = NonZeroError;
+
+    #[inline]
+    fn try_from(byte: Byte) -> Result<Self, Self::Error> {
+        match byte.as_u64_checked() {
+            Some(n) => NonZeroU64::new(n).ok_or(NonZeroError::Zero),
+            None => Err(NonZeroError::ExceededBounds),
+        }
+    }
+}
+
+impl From<Byte> for Wrapping<u128> {
+    #[inline]
+    fn from(byte: Byte) -> Self {
+        Wrapping(byte.as_u128())
+    }
+}
+
+impl AsRef<ByteRepr> for Byte {
+    #[inline]
+    fn as_ref(&self) -> &ByteRepr {
+        &self.0
+    }
+}
+
+impl Borrow<ByteRepr> for Byte {
+    #[inline]
+    fn borrow(&self) -> &ByteRepr {
+        &self.0
+    }
+}
+
+impl Binary for Byte {
+    /// Formats the size in bytes as an integer in base 2, honoring every flag, like `u128`.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Binary::fmt(&self.0, f)
+    }
+}
+
+impl Octal for Byte {
+    /// Formats the size in bytes as an integer in base 8, honoring every flag, like `u128`.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Octal::fmt(&self.0, f)
+    }
+}
+
+impl LowerHex for Byte {
+    /// Formats the size in bytes as an integer in base 16 with lowercase letters, honoring every flag, like `u128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// let byte = Byte::from_u64(4096);
+    ///
+    /// assert_eq!("0x1000", format!("{byte:#x}"));
+    /// assert_eq!("0x00001000", format!("{byte:#010x}"));
+    /// assert_eq!("0o10000", format!("{byte:#o}"));
+    /// assert_eq!("1000000000000", format!("{byte:b}"));
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl UpperHex for Byte {
+    /// Formats the size in bytes as an integer in base 16 with uppercase letters, honoring every flag, like `u128`.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        UpperHex::fmt(&self.0, f)
+    }
+}
+
 impl FromStr for Byte {
     type Err = ParseError;
 
@@ -206,6 +365,27 @@ impl FromStr for Byte {
     }
 }
 
+impl TryFrom<&str> for Byte {
+    type Error = ParseError;
+
+    /// See [`FromStr`](#impl-FromStr-for-Byte).
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<String> for Byte {
+    type Error = ParseError;
+
+    /// See [`FromStr`](#impl-FromStr-for-Byte).
+    #[inline]
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
 impl PartialEq<u64> for Byte {
     #[cfg(feature = "u128")]
     #[inline]
@@ -317,3 +497,125 @@ impl PartialOrd<Byte> for u128 {
         self.partial_cmp(&(other.0 as u128))
     }
 }
+
+impl Add for Byte {
+    type Output = Byte;
+
+    /// # Panics
+    ///
+    /// Panics if the result exceeds `Byte::MAX`. See [`Byte::checked_add`](Byte::checked_add).
+    #[inline]
+    fn add(self, rhs: Byte) -> Byte {
+        self.checked_add(rhs).expect("attempt to add with overflow")
+    }
+}
+
+impl AddAssign for Byte {
+    #[inline]
+    fn add_assign(&mut self, rhs: Byte) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sum for Byte {
+    /// # Panics
+    ///
+    /// Panics if the result exceeds `Byte::MAX`. See [`Byte::checked_sum`](Byte::checked_sum).
+    #[inline]
+    fn sum<I: Iterator<Item = Byte>>(iter: I) -> Byte {
+        iter.fold(Byte::MIN, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Byte> for Byte {
+    /// # Panics
+    ///
+    /// Panics if the result exceeds `Byte::MAX`. See [`Byte::checked_sum`](Byte::checked_sum).
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Byte>>(iter: I) -> Byte {
+        iter.copied().sum()
+    }
+}
+
+impl Sub for Byte {
+    type Output = Byte;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is larger than `self`. See [`Byte::checked_sub`](Byte::checked_sub).
+    #[inline]
+    fn sub(self, rhs: Byte) -> Byte {
+        self.checked_sub(rhs).expect("attempt to subtract with overflow")
+    }
+}
+
+impl SubAssign for Byte {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Byte) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<u64> for Byte {
+    type Output = Byte;
+
+    /// # Panics
+    ///
+    /// Panics if the result exceeds `Byte::MAX`. See [`Byte::checked_mul`](Byte::checked_mul).
+    #[inline]
+    fn mul(self, rhs: u64) -> Byte {
+        self.checked_mul(rhs).expect("attempt to multiply with overflow")
+    }
+}
+
+impl Mul<Byte> for u64 {
+    type Output = Byte;
+
+    /// # Panics
+    ///
+    /// Panics if the result exceeds `Byte::MAX`. See [`Byte::checked_mul`](Byte::checked_mul).
+    #[inline]
+    fn mul(self, rhs: Byte) -> Byte {
+        rhs * self
+    }
+}
+
+impl MulAssign<u64> for Byte {
+    #[inline]
+    fn mul_assign(&mut self, rhs: u64) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<u64> for Byte {
+    type Output = Byte;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero. See [`Byte::checked_div`](Byte::checked_div).
+    #[inline]
+    fn div(self, rhs: u64) -> Byte {
+        self.checked_div(rhs).expect("attempt to divide by zero")
+    }
+}
+
+impl DivAssign<u64> for Byte {
+    #[inline]
+    fn div_assign(&mut self, rhs: u64) {
+        *self = *self / rhs;
+    }
+}
+
+#[cfg(feature = "bit")]
+impl TryFrom<crate::Bit> for Byte {
+    type Error = crate::NotWholeBytesError;
+
+    #[inline]
+    fn try_from(bit: crate::Bit) -> Result<Self, Self::Error> {
+        if bit.as_u128() % 8 == 0 {
+            Ok(bit.as_byte_floor())
+        } else {
+            Err(crate::NotWholeBytesError)
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::Mul,
+    time::Duration,
+};
+
+use super::Byte;
+use crate::{common::div_ceil_u128, format::fmt_value, rate::NANOS_PER_SEC, UnitType};
+
+/// The period by which a [`ByteSeconds`](struct.ByteSeconds.html) instance is written, like the **"month"** of **"GiB·month"** on a cloud bill.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum UsagePeriod {
+    /// 1 second, **"s"**.
+    Second,
+    /// 3600 seconds, **"h"**.
+    #[default]
+    Hour,
+    /// 86400 seconds, **"d"**.
+    Day,
+    /// 730 hours, **"month"**, which is the average month (365 × 24 / 12 hours) used by cloud providers.
+    Month,
+}
+
+impl UsagePeriod {
+    /// Retrieve the number of seconds.
+    #[inline]
+    pub const fn as_secs(self) -> u64 {
+        match self {
+            Self::Second => 1,
+            Self::Hour => 3600,
+            Self::Day => 86400,
+            Self::Month => 730 * 3600,
+        }
+    }
+
+    /// Retrieve the symbol.
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Second => "s",
+            Self::Hour => "h",
+            Self::Day => "d",
+            Self::Month => "month",
+        }
+    }
+}
+
+impl Display for UsagePeriod {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Representing a size stored over time in byte-seconds, such as the usage of a storage bucket billed in GiB-months.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use byte_unit::{Byte, ByteSeconds, Unit, UsagePeriod};
+///
+/// const HOUR: Duration = Duration::from_secs(3600);
+///
+/// let usage = (Byte::from_u64_with_unit(2, Unit::GiB).unwrap()
+///     * (HOUR * 730))
+///     .add(Byte::from_u64_with_unit(3, Unit::GiB).unwrap() * (HOUR * 365))
+///     .unwrap();
+///
+/// assert_eq!(
+///     "3.5 GiB·month",
+///     usage.display_per(UsagePeriod::Month).to_string()
+/// );
+/// assert_eq!("2.5 TiB·h", format!("{usage:#.2}"));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ByteSeconds(u128);
+
+/// Associated functions for building `ByteSeconds` instances.
+impl ByteSeconds {
+    /// Create a new `ByteSeconds` instance from a number of byte-seconds.
+    #[inline]
+    pub const fn from_byte_seconds(byte_seconds: u128) -> Self {
+        Self(byte_seconds)
+    }
+
+    /// Create a new `ByteSeconds` instance from a size which is stored for a duration. The result is rounded up to a whole byte-second.
+    ///
+    /// # Points to Note
+    ///
+    /// * If the result is too large, this function will return `None`.
+    #[inline]
+    pub const fn new(size: Byte, duration: Duration) -> Option<Self> {
+        let size = size.as_u128();
+
+        let byte_seconds = match size.checked_mul(duration.as_secs() as u128) {
+            Some(byte_seconds) => byte_seconds,
+            None => return None,
+        };
+
+        // the size is smaller than 2^90, and the nanoseconds are smaller than 2^30
+        let fraction = div_ceil_u128(size * duration.subsec_nanos() as u128, NANOS_PER_SEC);
+
+        match byte_seconds.checked_add(fraction) {
+            Some(byte_seconds) => Some(Self(byte_seconds)),
+            None => None,
+        }
+    }
+}
+
+/// Methods for getting values and arithmetic.
+impl ByteSeconds {
+    /// Retrieve the number of byte-seconds.
+    #[inline]
+    pub const fn as_byte_seconds(self) -> u128 {
+        self.0
+    }
+
+    /// Add another `ByteSeconds` instance. If the sum is too large, this method will return `None`.
+    #[inline]
+    pub const fn add(self, rhs: ByteSeconds) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(byte_seconds) => Some(Self(byte_seconds)),
+            None => None,
+        }
+    }
+
+    /// Prorate this usage for a part of the period in which it is accounted, e.g. the 10 days of a 30-day month for which a customer has the service. The result is rounded up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use byte_unit::{Byte, ByteSeconds};
+    ///
+    /// const DAY: Duration = Duration::from_secs(86400);
+    ///
+    /// let usage = Byte::from_u64(300) * (DAY * 30);
+    ///
+    /// assert_eq!(
+    ///     Some(Byte::from_u64(100) * (DAY * 30)),
+    ///     usage.prorate(DAY * 10, DAY * 30)
+    /// );
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If `whole` is zero or the result is too large, this method will return `None`.
+    #[inline]
+    pub fn prorate(self, part: Duration, whole: Duration) -> Option<Self> {
+        let whole = whole.as_nanos();
+
+        if whole == 0 {
+            return None;
+        }
+
+        self.0
+            .checked_mul(part.as_nanos())
+            .map(|byte_seconds| Self(div_ceil_u128(byte_seconds, whole)))
+    }
+
+    /// Calculate the average size per period, e.g. the GiB-months which is the unit of a bill. The result is rounded up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use byte_unit::{Byte, UsagePeriod};
+    ///
+    /// let usage = Byte::from_u64(7200) * Duration::from_secs(1800);
+    ///
+    /// assert_eq!(Some(Byte::from_u64(3600)), usage.size_per(UsagePeriod::Hour));
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If the result exceeds `Byte::MAX`, this method will return `None`.
+    #[inline]
+    pub const fn size_per(self, period: UsagePeriod) -> Option<Byte> {
+        Byte::from_u128(div_ceil_u128(self.0, period.as_secs() as u128))
+    }
+
+    /// Create a displayable structure which writes the average size per period, with a binary unit, e.g. **"3.5 GiB·month"**.
+    #[inline]
+    pub const fn display_per(self, period: UsagePeriod) -> ByteSecondsDisplay {
+        ByteSecondsDisplay {
+            byte_seconds: self,
+            period,
+        }
+    }
+}
+
+impl Display for ByteSeconds {
+    /// Writes the average size per hour, like [`ByteSeconds::display_per`](#method.display_per) with `UsagePeriod::Hour`.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.display_per(UsagePeriod::Hour), f)
+    }
+}
+
+impl Mul<Duration> for Byte {
+    type Output = ByteSeconds;
+
+    /// See [`ByteSeconds::new`](struct.ByteSeconds.html#method.new).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is too large.
+    #[inline]
+    fn mul(self, rhs: Duration) -> ByteSeconds {
+        ByteSeconds::new(self, rhs).expect("attempt to multiply with overflow")
+    }
+}
+
+impl Mul<Byte> for Duration {
+    type Output = ByteSeconds;
+
+    /// See [`ByteSeconds::new`](struct.ByteSeconds.html#method.new).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is too large.
+    #[inline]
+    fn mul(self, rhs: Byte) -> ByteSeconds {
+        rhs * self
+    }
+}
+
+/// Generated from the [`ByteSeconds::display_per`](struct.ByteSeconds.html#method.display_per) method.
+///
+/// The precision and the flags of the formatter are applied to the size in the same way as `AdjustedByte` does.
+#[derive(Debug, Clone, Copy)]
+pub struct ByteSecondsDisplay {
+    byte_seconds: ByteSeconds,
+    period:       UsagePeriod,
+}
+
+impl Display for ByteSecondsDisplay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let value = self.byte_seconds.0 as f64 / self.period.as_secs() as f64;
+
+        // only the unit is chosen with the rounded size
+        let unit = Byte::from_u128(value as u128)
+            .unwrap_or(Byte::MAX)
+            .get_appropriate_unit(UnitType::Binary)
+            .get_unit();
+
+        fmt_value(f, value / unit.as_bytes_u128() as f64, unit, f.precision(), true)?;
+
+        f.write_fmt(format_args!("·{}", self.period))
+    }
+}
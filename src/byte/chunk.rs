@@ -0,0 +1,182 @@
+use super::Byte;
+use crate::{common::div_ceil_u128, ChunkBoundsError};
+
+/// The validated bounds of the chunk sizes for content-defined chunking (CDC), e.g. FastCDC. Created by [`Byte::cdc_chunk_bounds`](./struct.Byte.html#method.cdc_chunk_bounds).
+///
+/// The invariants are:
+///
+/// * `0 < min ≤ avg ≤ max`
+/// * `avg` is a power of two, so that a cut point can be found with a bit mask.
+/// * `max` fits in `usize`, so that a chunk can be buffered in memory.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ChunkBounds {
+    min: Byte,
+    avg: Byte,
+    max: Byte,
+}
+
+/// Associated functions for building `ChunkBounds` instances.
+impl ChunkBounds {
+    /// Create a new `ChunkBounds` instance. See [`Byte::cdc_chunk_bounds`](./struct.Byte.html#method.cdc_chunk_bounds).
+    #[inline]
+    pub const fn new(min: Byte, avg: Byte, max: Byte) -> Result<Self, ChunkBoundsError> {
+        let (min_v, avg_v, max_v) = (min.as_u128(), avg.as_u128(), max.as_u128());
+
+        if min_v == 0 {
+            return Err(ChunkBoundsError::ZeroMinimum);
+        }
+
+        if min_v > avg_v || avg_v > max_v {
+            return Err(ChunkBoundsError::NotOrdered);
+        }
+
+        if !avg_v.is_power_of_two() {
+            return Err(ChunkBoundsError::AverageNotPowerOfTwo);
+        }
+
+        if max_v > usize::MAX as u128 {
+            return Err(ChunkBoundsError::MaximumTooLarge);
+        }
+
+        Ok(Self {
+            min,
+            avg,
+            max,
+        })
+    }
+
+    /// Create a new `ChunkBounds` instance from the average size, with the minimum size of a quarter of it and the maximum size of four times it, as suggested by FastCDC.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, ChunkBounds};
+    ///
+    /// let bounds = ChunkBounds::from_avg(Byte::from_u64(8192)).unwrap();
+    ///
+    /// assert_eq!(2048, bounds.min().as_u64());
+    /// assert_eq!(32768, bounds.max().as_u64());
+    /// ```
+    #[inline]
+    pub const fn from_avg(avg: Byte) -> Result<Self, ChunkBoundsError> {
+        let avg_v = avg.as_u128();
+
+        let max = match avg_v.checked_mul(4) {
+            Some(v) => match Byte::from_u128(v) {
+                Some(max) => max,
+                None => return Err(ChunkBoundsError::MaximumTooLarge),
+            },
+            None => return Err(ChunkBoundsError::MaximumTooLarge),
+        };
+
+        let min = match Byte::from_u128(avg_v / 4) {
+            Some(min) => min,
+            None => return Err(ChunkBoundsError::ZeroMinimum),
+        };
+
+        Self::new(min, avg, max)
+    }
+}
+
+/// Methods for getting the bounds.
+impl ChunkBounds {
+    /// Get the minimum chunk size.
+    #[inline]
+    pub const fn min(&self) -> Byte {
+        self.min
+    }
+
+    /// Get the average chunk size.
+    #[inline]
+    pub const fn avg(&self) -> Byte {
+        self.avg
+    }
+
+    /// Get the maximum chunk size.
+    #[inline]
+    pub const fn max(&self) -> Byte {
+        self.max
+    }
+}
+
+/// Methods for chunking.
+impl ChunkBounds {
+    /// Get the number of bits of the mask to find a cut point, which is log<sub>2</sub> of the average chunk size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, ChunkBounds};
+    ///
+    /// let bounds = ChunkBounds::from_avg(Byte::from_u64(8192)).unwrap();
+    ///
+    /// assert_eq!(13, bounds.mask_bits());
+    /// ```
+    #[inline]
+    pub const fn mask_bits(&self) -> u32 {
+        self.avg.as_u128().trailing_zeros()
+    }
+
+    /// Check whether a chunk of `len` bytes is within the bounds. Only the last chunk of a stream may be shorter than the minimum size.
+    #[inline]
+    pub const fn contains(&self, len: Byte) -> bool {
+        let len = len.as_u128();
+
+        len >= self.min.as_u128() && len <= self.max.as_u128()
+    }
+
+    /// Get the maximum number of chunks of a stream of `total` bytes, which is reached when every chunk but the last one has the minimum size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, ChunkBounds};
+    ///
+    /// let bounds = ChunkBounds::from_avg(Byte::from_u64(8192)).unwrap();
+    ///
+    /// assert_eq!(0, bounds.max_chunk_count(Byte::from_u64(0)));
+    /// assert_eq!(1, bounds.max_chunk_count(Byte::from_u64(100)));
+    /// assert_eq!(512, bounds.max_chunk_count(Byte::from_u64(1 << 20)));
+    /// ```
+    #[inline]
+    pub const fn max_chunk_count(&self, total: Byte) -> u128 {
+        div_ceil_u128(total.as_u128(), self.min.as_u128())
+    }
+}
+
+/// Associated functions for building `ChunkBounds` instances.
+impl Byte {
+    /// Create a new [`ChunkBounds`](./struct.ChunkBounds.html) instance for content-defined chunking, validating that `0 < min ≤ avg ≤ max`, that `avg` is a power of two and that `max` fits in `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, ChunkBoundsError};
+    ///
+    /// let bounds = Byte::cdc_chunk_bounds(
+    ///     Byte::from_u64(16 << 10),
+    ///     Byte::from_u64(64 << 10),
+    ///     Byte::from_u64(256 << 10),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(16, bounds.mask_bits());
+    ///
+    /// assert_eq!(
+    ///     Err(ChunkBoundsError::AverageNotPowerOfTwo),
+    ///     Byte::cdc_chunk_bounds(
+    ///         Byte::from_u64(16000),
+    ///         Byte::from_u64(64000),
+    ///         Byte::from_u64(256000),
+    ///     )
+    /// );
+    /// ```
+    #[inline]
+    pub const fn cdc_chunk_bounds(
+        min: Byte,
+        avg: Byte,
+        max: Byte,
+    ) -> Result<ChunkBounds, ChunkBoundsError> {
+        ChunkBounds::new(min, avg, max)
+    }
+}
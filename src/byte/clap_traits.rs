@@ -0,0 +1,14 @@
+use clap::builder::ValueParserFactory;
+
+use super::Byte;
+use crate::clap::ByteValueParser;
+
+impl ValueParserFactory for Byte {
+    type Parser = ByteValueParser;
+
+    /// `ignore_case` is set to `false`. See [`ByteValueParser`](clap/struct.ByteValueParser.html).
+    #[inline]
+    fn value_parser() -> Self::Parser {
+        ByteValueParser::new()
+    }
+}
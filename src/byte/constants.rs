@@ -86,6 +86,14 @@ impl Byte {
     /// 1 Yibit = 2<sup>77</sup> bytes.
     pub const YOBIBIT: Byte = Byte::YOBIBYTE.div_8();
 
+    /// 2 MiB, the default size of huge pages on x86-64 and AArch64 (with 4 KiB base pages).
+    pub const HUGE_PAGE_2MIB: Byte = Byte(2 << 20);
+    /// 1 GiB, the size of gigantic huge pages on x86-64 and AArch64 (with 4 KiB base pages).
+    pub const HUGE_PAGE_1GIB: Byte = Byte(1 << 30);
+
+    /// 512 bytes, the size of the blocks of tar archives.
+    pub const TAR_BLOCK: Byte = Byte(512);
+
     /// 0 byte.
     pub const MIN: Byte = Byte(0);
     /// **10<sup>27</sup> - 1** bytes if the `u128` feature is enabled, or **2<sup>64</sup> - 1** otherwise.
@@ -0,0 +1,399 @@
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "std")]
+use std::{
+    io::{self, IoSlice, IoSliceMut, Read, Write},
+    sync::Arc,
+};
+
+use super::Byte;
+
+/// Types which accumulate the number of bytes flowing through a stream, such as [`ByteCounter`] and [`AtomicByteCounter`].
+pub trait CountBytes {
+    /// Add `n` bytes.
+    fn add_bytes(&mut self, n: u64);
+
+    /// Retrieve the number of bytes added so far.
+    fn get_bytes(&self) -> Byte;
+}
+
+/// A counter of bytes for a single thread, which saturates at `u64::MAX` bytes.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{Byte, ByteCounter};
+///
+/// let mut counter = ByteCounter::new();
+///
+/// for chunk in [&b"hello"[..], &b", world"[..]] {
+///     counter.add(chunk.len() as u64);
+/// }
+///
+/// assert_eq!(Byte::from_u64(12), counter.get());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ByteCounter {
+    count: u64,
+}
+
+impl ByteCounter {
+    /// Create a new `ByteCounter` instance starting at zero.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            count: 0
+        }
+    }
+
+    /// Add `n` bytes, saturating at `u64::MAX`.
+    #[inline]
+    pub fn add(&mut self, n: u64) {
+        self.count = self.count.saturating_add(n);
+    }
+
+    /// Retrieve the number of bytes added so far.
+    #[inline]
+    pub const fn get(&self) -> Byte {
+        Byte::from_u64(self.count)
+    }
+
+    /// Retrieve the number of bytes added so far, and start again from zero.
+    #[inline]
+    pub fn reset(&mut self) -> Byte {
+        let count = self.count;
+
+        self.count = 0;
+
+        Byte::from_u64(count)
+    }
+}
+
+impl CountBytes for ByteCounter {
+    #[inline]
+    fn add_bytes(&mut self, n: u64) {
+        self.add(n);
+    }
+
+    #[inline]
+    fn get_bytes(&self) -> Byte {
+        self.get()
+    }
+}
+
+impl From<ByteCounter> for Byte {
+    #[inline]
+    fn from(counter: ByteCounter) -> Self {
+        counter.get()
+    }
+}
+
+/// A counter of bytes built on `AtomicU64`, which can be shared across threads, e.g. through a `static` item or an `Arc`.
+///
+/// Every operation uses the relaxed memory ordering, so a counter is cheap to increment in a hot loop, but it does not synchronize other memory accesses.
+///
+/// # Examples
+///
+/// ```
+/// use std::thread;
+///
+/// use byte_unit::{AtomicByteCounter, Byte};
+///
+/// static TRANSFERRED: AtomicByteCounter = AtomicByteCounter::new();
+///
+/// thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| TRANSFERRED.add(1024));
+///     }
+/// });
+///
+/// assert_eq!(Byte::from_u64(4096), TRANSFERRED.get());
+/// ```
+#[cfg(target_has_atomic = "64")]
+#[derive(Debug, Default)]
+pub struct AtomicByteCounter {
+    count: AtomicU64,
+}
+
+#[cfg(target_has_atomic = "64")]
+impl AtomicByteCounter {
+    /// Create a new `AtomicByteCounter` instance starting at zero.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            count: AtomicU64::new(0)
+        }
+    }
+
+    /// Add `n` bytes.
+    ///
+    /// # Points to Note
+    ///
+    /// * Unlike `ByteCounter`, the counter wraps around after `u64::MAX` bytes (16 EiB) instead of saturating, so that an increment is a single atomic instruction.
+    #[inline]
+    pub fn add(&self, n: u64) {
+        self.count.fetch_add(n, Ordering::Relaxed);
+    }
+
+    /// Retrieve a snapshot of the number of bytes added so far.
+    #[inline]
+    pub fn get(&self) -> Byte {
+        Byte::from_u64(self.count.load(Ordering::Relaxed))
+    }
+
+    /// Retrieve the number of bytes added so far, and start again from zero, in a single atomic operation.
+    #[inline]
+    pub fn reset(&self) -> Byte {
+        Byte::from_u64(self.count.swap(0, Ordering::Relaxed))
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl CountBytes for AtomicByteCounter {
+    #[inline]
+    fn add_bytes(&mut self, n: u64) {
+        self.add(n);
+    }
+
+    #[inline]
+    fn get_bytes(&self) -> Byte {
+        self.get()
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl CountBytes for &AtomicByteCounter {
+    #[inline]
+    fn add_bytes(&mut self, n: u64) {
+        self.add(n);
+    }
+
+    #[inline]
+    fn get_bytes(&self) -> Byte {
+        self.get()
+    }
+}
+
+#[cfg(all(feature = "std", target_has_atomic = "64"))]
+impl CountBytes for Arc<AtomicByteCounter> {
+    #[inline]
+    fn add_bytes(&mut self, n: u64) {
+        self.add(n);
+    }
+
+    #[inline]
+    fn get_bytes(&self) -> Byte {
+        self.get()
+    }
+}
+
+/// An `io::Write` adapter which counts the bytes written to the inner writer.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+///
+/// use byte_unit::{Byte, CountingWriter};
+///
+/// let mut writer = CountingWriter::new(Vec::new());
+///
+/// writer.write_all(b"hello, world").unwrap();
+///
+/// assert_eq!(Byte::from_u64(12), writer.get_count());
+/// ```
+///
+/// A shared counter can be used to sum up several streams, even on different threads.
+///
+/// ```
+/// use std::{io::Write, sync::Arc};
+///
+/// use byte_unit::{AtomicByteCounter, Byte, CountingWriter};
+///
+/// let counter = Arc::new(AtomicByteCounter::new());
+///
+/// let mut a = CountingWriter::with_counter(Vec::new(), counter.clone());
+/// let mut b = CountingWriter::with_counter(Vec::new(), counter.clone());
+///
+/// a.write_all(b"hello").unwrap();
+/// b.write_all(b", world").unwrap();
+///
+/// assert_eq!(Byte::from_u64(12), counter.get());
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct CountingWriter<W, C = ByteCounter> {
+    inner:   W,
+    counter: C,
+}
+
+#[cfg(feature = "std")]
+impl<W> CountingWriter<W> {
+    /// Create a new `CountingWriter` instance with a new `ByteCounter`.
+    #[inline]
+    pub const fn new(inner: W) -> Self {
+        Self::with_counter(inner, ByteCounter::new())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W, C: CountBytes> CountingWriter<W, C> {
+    /// Create a new `CountingWriter` instance which adds the written bytes to `counter`.
+    #[inline]
+    pub const fn with_counter(inner: W, counter: C) -> Self {
+        Self {
+            inner,
+            counter,
+        }
+    }
+
+    /// Retrieve the number of bytes counted so far.
+    #[inline]
+    pub fn get_count(&self) -> Byte {
+        self.counter.get_bytes()
+    }
+
+    /// Retrieve the counter.
+    #[inline]
+    pub const fn get_counter(&self) -> &C {
+        &self.counter
+    }
+
+    /// Retrieve a reference to the inner writer.
+    #[inline]
+    pub const fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Retrieve a mutable reference to the inner writer. Bytes written through it directly are not counted.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwrap this `CountingWriter` instance, returning the inner writer and the counter.
+    #[inline]
+    pub fn into_parts(self) -> (W, C) {
+        (self.inner, self.counter)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: Write, C: CountBytes> Write for CountingWriter<W, C> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+
+        self.counter.add_bytes(n as u64);
+
+        Ok(n)
+    }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        let n = self.inner.write_vectored(bufs)?;
+
+        self.counter.add_bytes(n as u64);
+
+        Ok(n)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// An `io::Read` adapter which counts the bytes read from the inner reader.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{self, Read};
+///
+/// use byte_unit::{Byte, CountingReader};
+///
+/// let mut reader = CountingReader::new(&b"hello, world"[..]);
+///
+/// io::copy(&mut reader, &mut io::sink()).unwrap();
+///
+/// assert_eq!(Byte::from_u64(12), reader.get_count());
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct CountingReader<R, C = ByteCounter> {
+    inner:   R,
+    counter: C,
+}
+
+#[cfg(feature = "std")]
+impl<R> CountingReader<R> {
+    /// Create a new `CountingReader` instance with a new `ByteCounter`.
+    #[inline]
+    pub const fn new(inner: R) -> Self {
+        Self::with_counter(inner, ByteCounter::new())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R, C: CountBytes> CountingReader<R, C> {
+    /// Create a new `CountingReader` instance which adds the read bytes to `counter`.
+    #[inline]
+    pub const fn with_counter(inner: R, counter: C) -> Self {
+        Self {
+            inner,
+            counter,
+        }
+    }
+
+    /// Retrieve the number of bytes counted so far.
+    #[inline]
+    pub fn get_count(&self) -> Byte {
+        self.counter.get_bytes()
+    }
+
+    /// Retrieve the counter.
+    #[inline]
+    pub const fn get_counter(&self) -> &C {
+        &self.counter
+    }
+
+    /// Retrieve a reference to the inner reader.
+    #[inline]
+    pub const fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Retrieve a mutable reference to the inner reader. Bytes read through it directly are not counted.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwrap this `CountingReader` instance, returning the inner reader and the counter.
+    #[inline]
+    pub fn into_parts(self) -> (R, C) {
+        (self.inner, self.counter)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read, C: CountBytes> Read for CountingReader<R, C> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+
+        self.counter.add_bytes(n as u64);
+
+        Ok(n)
+    }
+
+    #[inline]
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        let n = self.inner.read_vectored(bufs)?;
+
+        self.counter.add_bytes(n as u64);
+
+        Ok(n)
+    }
+}
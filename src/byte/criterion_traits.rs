@@ -0,0 +1,30 @@
+use criterion::Throughput;
+
+use super::Byte;
+
+impl From<Byte> for Throughput {
+    /// Create a `Throughput::Bytes` instance for benchmarks which process this size per iteration. The size is saturated at `u64::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, Unit};
+    /// use criterion::Throughput;
+    ///
+    /// let bytes = Byte::from_u64_with_unit(4, Unit::KiB).unwrap();
+    ///
+    /// assert!(matches!(bytes.into(), Throughput::Bytes(4096)));
+    /// ```
+    #[inline]
+    fn from(byte: Byte) -> Self {
+        Throughput::Bytes(byte.as_u64())
+    }
+}
+
+impl From<crate::bench::Throughput> for Throughput {
+    /// Create a `Throughput::Bytes` instance from the processed size. The duration is measured by criterion instead.
+    #[inline]
+    fn from(throughput: crate::bench::Throughput) -> Self {
+        Throughput::from(throughput.bytes)
+    }
+}
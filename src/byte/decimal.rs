@@ -1,7 +1,7 @@
 use rust_decimal::prelude::*;
 
 use super::Byte;
-use crate::{common::is_zero_remainder_decimal, Unit};
+use crate::{common::is_zero_remainder_decimal, Precision, Unit, UnitPreference};
 
 const DECIMAL_EIGHT: Decimal = Decimal::from_parts(8, 0, 0, false, 0);
 
@@ -66,6 +66,7 @@ impl Byte {
     /// * The calculated byte will be rounded up.
     #[inline]
     pub fn from_decimal_with_unit(size: Decimal, unit: Unit) -> Option<Self> {
+        #[allow(clippy::question_mark)]
         let v = {
             match unit {
                 Unit::Bit => (size / DECIMAL_EIGHT).ceil(),
@@ -123,42 +124,172 @@ impl Byte {
     /// # Points to Note
     ///
     /// * `precision` should be smaller or equal to `26` if the `u128` feature is enabled, otherwise `19`. The typical `precision` is `3`.
+    /// * `precision` is clamped to [`Precision::MAX_DIGITS`](./enum.Precision.html#associatedconstant.MAX_DIGITS).
     #[inline]
-    pub fn get_recoverable_unit(
+    pub fn get_recoverable_unit(self, allow_in_bits: bool, precision: usize) -> (Decimal, Unit) {
+        self.get_recoverable_unit_with_preference(allow_in_bits, precision, UnitPreference::Largest)
+    }
+
+    /// Find the appropriate unit and value that can be used to recover back to this `Byte` instance precisely, like [`Byte::get_recoverable_unit`](#method.get_recoverable_unit), but the largest unit among the preferred ones is chosen if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, Unit, UnitPreference};
+    ///
+    /// let byte = Byte::from_u64(2048000);
+    ///
+    /// assert_eq!(
+    ///     (2.048f64.try_into().unwrap(), Unit::MB),
+    ///     byte.get_recoverable_unit_with_preference(
+    ///         false,
+    ///         3,
+    ///         UnitPreference::Largest
+    ///     )
+    /// );
+    /// assert_eq!(
+    ///     (2000.into(), Unit::KiB),
+    ///     byte.get_recoverable_unit_with_preference(
+    ///         false,
+    ///         3,
+    ///         UnitPreference::Binary
+    ///     )
+    /// );
+    /// ```
+    pub fn get_recoverable_unit_with_preference(
         self,
         allow_in_bits: bool,
-        mut precision: usize,
+        precision: usize,
+        preference: UnitPreference,
     ) -> (Decimal, Unit) {
         let bytes_v = self.as_u128();
         let bytes_vd = Decimal::from(bytes_v);
 
         let a = if allow_in_bits { Unit::get_multiples() } else { Unit::get_multiples_bytes() };
-        let mut i = a.len() - 1;
 
-        if precision >= 28 {
-            precision = 28;
-        }
+        let precision = Precision::Digits(precision).digits();
 
-        loop {
-            let unit = a[i];
+        let find = |preferred_only: bool| {
+            a.iter()
+                .rev()
+                .filter(|unit| !preferred_only || preference.is_preferred(**unit))
+                .find_map(|&unit| {
+                    let unit_v = unit.as_bytes_u128();
 
-            let unit_v = unit.as_bytes_u128();
+                    if bytes_v >= unit_v {
+                        is_zero_remainder_decimal(bytes_vd, Decimal::from(unit_v), precision)
+                            .map(|quotient| (quotient, unit))
+                    } else {
+                        None
+                    }
+                })
+        };
 
-            if bytes_v >= unit_v {
-                let unit_vd = Decimal::from(unit_v);
+        find(true).or_else(|| find(false)).unwrap_or((bytes_vd, Unit::B))
+    }
 
-                if let Some(quotient) = is_zero_remainder_decimal(bytes_vd, unit_vd, precision) {
-                    return (quotient, unit);
-                }
-            }
+    /// Adjust the unit and value for this `Byte` instance precisely. Unlike the [`Byte::get_adjusted_unit`](#method.get_adjusted_unit) method, this method does not involve any floating-point number, so the result is consistent on all targets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, Unit};
+    ///
+    /// let byte = Byte::parse_str("50.84 MB", true).unwrap();
+    ///
+    /// let (value, unit) = byte.get_adjusted_unit_exact(Unit::MiB);
+    ///
+    /// assert_eq!("48.48480224609375", value.to_string());
+    /// assert_eq!(Unit::MiB, unit);
+    /// ```
+    ///
+    /// ```
+    /// use byte_unit::{Byte, Unit};
+    ///
+    /// let byte = Byte::from_u64(125);
+    ///
+    /// assert_eq!(
+    ///     (1000.into(), Unit::Bit),
+    ///     byte.get_adjusted_unit_exact(Unit::Bit)
+    /// );
+    /// assert_eq!(
+    ///     (1.into(), Unit::Kbit),
+    ///     byte.get_adjusted_unit_exact(Unit::Kbit)
+    /// );
+    /// ```
+    #[inline]
+    pub fn get_adjusted_unit_exact(self, unit: Unit) -> (Decimal, Unit) {
+        let bytes_vd = Decimal::from(self.as_u128());
 
-            if i == 0 {
-                break;
-            }
+        let value = match unit {
+            Unit::Bit => bytes_vd * DECIMAL_EIGHT,
+            Unit::B => bytes_vd,
+            _ => (bytes_vd / Decimal::from(unit.as_bytes_u128())).normalize(),
+        };
 
-            i -= 1;
-        }
+        (value, unit)
+    }
+
+    /// Adjust the unit and value for this `Byte` instance precisely, and round the value to the given precision. The third element of the returned tuple is `true` if the value is exact, or `false` if it has been rounded, e.g. to be written as `"≈ 1.519 KiB"`.
+    ///
+    /// The value is rounded half away from zero, and the unnecessary trailing zeros are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, Precision, Unit};
+    ///
+    /// let byte = Byte::from_u64(1555);
+    ///
+    /// let (value, unit, exact) =
+    ///     byte.get_adjusted_unit_rounded(Unit::KiB, Precision::Digits(3));
+    ///
+    /// assert_eq!("1.519", value.to_string());
+    /// assert_eq!(Unit::KiB, unit);
+    /// assert!(!exact);
+    ///
+    /// let (value, _, exact) =
+    ///     byte.get_adjusted_unit_rounded(Unit::KiB, Precision::Max);
+    ///
+    /// assert_eq!("1.5185546875", value.to_string());
+    /// assert!(exact);
+    /// ```
+    ///
+    /// ```
+    /// use byte_unit::{Byte, Precision, Unit};
+    ///
+    /// // 1 byte in YiB needs more than `Precision::MAX_DIGITS` digits
+    /// # #[cfg(feature = "u128")]
+    /// # {
+    /// let (_, _, exact) =
+    ///     Byte::from_u64(1).get_adjusted_unit_rounded(Unit::YiB, Precision::Max);
+    ///
+    /// assert!(!exact);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn get_adjusted_unit_rounded(
+        self,
+        unit: Unit,
+        precision: Precision,
+    ) -> (Decimal, Unit, bool) {
+        let bytes_vd = Decimal::from(self.as_u128());
+
+        let (value, unit) = self.get_adjusted_unit_exact(unit);
+
+        let value = value
+            .round_dp_with_strategy(
+                precision.digits() as u32,
+                RoundingStrategy::MidpointAwayFromZero,
+            )
+            .normalize();
+
+        let recovered = match unit {
+            Unit::Bit => value.checked_div(DECIMAL_EIGHT),
+            Unit::B => Some(value),
+            _ => value.checked_mul(Decimal::from(unit.as_bytes_u128())),
+        };
 
-        (bytes_vd, Unit::B)
+        (value, unit, recovered == Some(bytes_vd))
     }
 }
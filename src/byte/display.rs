@@ -0,0 +1,223 @@
+use core::fmt::{self, Display, Formatter};
+#[cfg(feature = "std")]
+use std::string::String;
+
+use super::Byte;
+use crate::{
+    format::fmt_decimal_with_unit, FormatOptions, FormatStyle, Precision, Unit, UnitPreference,
+};
+
+/// Generated from the [`Byte::display`](./struct.Byte.html#method.display) method or the [`Byte::display_with`](./struct.Byte.html#method.display_with) method. The options can be changed with the builder methods, so nothing is formatted until it is written.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{Byte, Unit};
+///
+/// let byte = Byte::from_u64(1_500_000);
+///
+/// assert_eq!(
+///     "1.43MiB",
+///     byte.display().unit(Unit::MiB).precision(2).no_space().to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ByteDisplay {
+    byte:    Byte,
+    options: FormatOptions,
+    unit:    Option<Unit>,
+}
+
+impl Display for ByteDisplay {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (value, unit) = match self.unit {
+            Some(unit) => match self.options.precision {
+                Some(precision) => {
+                    let (value, unit, _) =
+                        self.byte.get_adjusted_unit_rounded(unit, Precision::Digits(precision));
+
+                    (value, unit)
+                },
+                None => self.byte.get_adjusted_unit_exact(unit),
+            },
+            None => self.byte.get_recoverable_unit_with_preference(
+                false,
+                self.options.precision.unwrap_or(3),
+                UnitPreference::global(),
+            ),
+        };
+
+        fmt_decimal_with_unit(f, value, unit, &self.options)
+    }
+}
+
+/// Methods for setting the options.
+impl ByteDisplay {
+    /// Set the unit. By default, the largest unit which can represent the size within the precision is chosen. With a fixed unit, the value is rounded to the precision if it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, Unit};
+    ///
+    /// let byte = Byte::from_u64(1_500_000);
+    ///
+    /// assert_eq!("1.5 MB", byte.display().to_string());
+    /// assert_eq!("1500 KB", byte.display().unit(Unit::KB).to_string());
+    /// assert_eq!(
+    ///     "1.430511474609375 MiB",
+    ///     byte.display().unit(Unit::MiB).to_string()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn unit(mut self, unit: Unit) -> Self {
+        self.unit = Some(unit);
+
+        self
+    }
+
+    /// Set the maximum number of digits after the decimal point. See [`FormatOptions::precision`](./struct.FormatOptions.html#method.precision).
+    #[inline]
+    pub const fn precision(mut self, precision: usize) -> Self {
+        self.options = self.options.precision(precision);
+
+        self
+    }
+
+    /// Set whether to keep the trailing zeros. See [`FormatOptions::keep_trailing_zeros`](./struct.FormatOptions.html#method.keep_trailing_zeros).
+    #[inline]
+    pub const fn keep_trailing_zeros(mut self, keep_trailing_zeros: bool) -> Self {
+        self.options = self.options.keep_trailing_zeros(keep_trailing_zeros);
+
+        self
+    }
+
+    /// Set the style of the unit. See [`FormatStyle`](./enum.FormatStyle.html).
+    #[inline]
+    pub const fn style(mut self, style: FormatStyle) -> Self {
+        self.options = self.options.style(style);
+
+        self
+    }
+
+    /// Write no spaces between the value and the unit, which is the same as `style(FormatStyle::Compact)`.
+    #[inline]
+    pub const fn no_space(self) -> Self {
+        self.style(FormatStyle::Compact)
+    }
+}
+
+/// Methods for formatting with options.
+impl Byte {
+    /// Create a value which implements `Display` for this `Byte` instance with the default options, which can be changed by its builder methods. Like the alternate form (`{:#}`) of the `Display` implementation, the largest unit which can represent the size within the precision (the default is `3`) is chosen unless a unit is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, Unit};
+    ///
+    /// let byte = Byte::from_u64(10240);
+    ///
+    /// assert_eq!(format!("{byte:#}"), byte.display().to_string());
+    /// assert_eq!(
+    ///     "0.01 MiB",
+    ///     byte.display().unit(Unit::MiB).precision(2).to_string()
+    /// );
+    /// assert_eq!(
+    ///     "10.00KiB",
+    ///     byte.display()
+    ///         .precision(2)
+    ///         .keep_trailing_zeros(true)
+    ///         .no_space()
+    ///         .to_string()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn display(self) -> ByteDisplay {
+        self.display_with(FormatOptions::new())
+    }
+
+    /// Create a value which implements `Display` for this `Byte` instance with the given options. Like the alternate form (`{:#}`) of the `Display` implementation, the largest unit which can represent the size within the precision (the default is `3`) is chosen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, FormatOptions, FormatStyle};
+    ///
+    /// let byte = Byte::from_u64(10240);
+    ///
+    /// assert_eq!(
+    ///     format!("{byte:#}"),
+    ///     byte.display_with(FormatOptions::new()).to_string()
+    /// );
+    /// assert_eq!(
+    ///     "10KiB",
+    ///     byte.display_with(FormatOptions::new().style(FormatStyle::Compact))
+    ///         .to_string()
+    /// );
+    /// assert_eq!(
+    ///     "10.00 KiB",
+    ///     byte.display_with(
+    ///         FormatOptions::new().precision(2).keep_trailing_zeros(true)
+    ///     )
+    ///     .to_string()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn display_with(self, options: FormatOptions) -> ByteDisplay {
+        ByteDisplay {
+            byte: self,
+            options,
+            unit: None,
+        }
+    }
+}
+
+/// Methods for describing sizes in blocks.
+impl Byte {
+    /// Describe this `Byte` instance as the number of whole blocks and the remainder, e.g. for diagnostics about a partial block at the end of a file or a device.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// let byte = Byte::from_u64(12 * 4096 + 3072);
+    ///
+    /// assert_eq!(
+    ///     "12 blocks of 4 KiB (+3 KiB)",
+    ///     byte.describe_in(Byte::from_u64(4096))
+    /// );
+    /// assert_eq!(
+    ///     "1 block of 512 B",
+    ///     Byte::from_u64(512).describe_in(Byte::from_u64(512))
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `block` is zero.
+    #[cfg(feature = "std")]
+    pub fn describe_in(self, block: Byte) -> String {
+        let block_v = block.as_u128();
+
+        assert!(block_v > 0, "the block size must not be zero");
+
+        let bytes_v = self.as_u128();
+
+        let count = bytes_v / block_v;
+        let remainder = bytes_v % block_v;
+
+        let noun = if count == 1 { "block" } else { "blocks" };
+
+        if remainder == 0 {
+            format!("{count} {noun} of {block:#}")
+        } else {
+            // the remainder is less than `block`
+            let remainder = Byte::from_u128(remainder).unwrap();
+
+            format!("{count} {noun} of {block:#} (+{remainder:#})")
+        }
+    }
+}
@@ -0,0 +1,127 @@
+#[cfg(all(feature = "os", unix))]
+use std::{ffi::CString, io, os::unix::ffi::OsStrExt, path::Path};
+
+use super::Byte;
+
+/// Associated functions for building `Byte` instances from file system statistics.
+impl Byte {
+    /// Create a new `Byte` instance from a number of blocks and the block size, such as the `f_blocks` and `f_frsize` fields of the `statvfs` structure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// let byte = Byte::from_statvfs_blocks(262144, 4096).unwrap();
+    ///
+    /// assert_eq!(1073741824, byte.as_u64());
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If the product is too large (the maximum is **10<sup>27</sup> - 1** if the `u128` feature is enabled, or **2<sup>64</sup> - 1** otherwise), this function will return `None`.
+    #[inline]
+    pub const fn from_statvfs_blocks(blocks: u64, frsize: u64) -> Option<Self> {
+        Byte::from_u128(blocks as u128 * frsize as u128)
+    }
+}
+
+/// The usage of a file system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FsUsage {
+    /// The size of the file system.
+    pub total:     Byte,
+    /// The free space, including the space reserved for privileged users.
+    pub free:      Byte,
+    /// The space available to unprivileged users.
+    pub available: Byte,
+}
+
+impl FsUsage {
+    /// Create a new `FsUsage` instance from block counts and the block size, such as the `f_blocks`, `f_bfree`, `f_bavail` and `f_frsize` fields of the `statvfs` structure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, FsUsage};
+    ///
+    /// let usage = FsUsage::from_statvfs_blocks(1000, 300, 250, 4096).unwrap();
+    ///
+    /// assert_eq!(Byte::from_u64(4096000), usage.total);
+    /// assert_eq!(Byte::from_u64(1228800), usage.free);
+    /// assert_eq!(Byte::from_u64(1024000), usage.available);
+    /// assert_eq!(Byte::from_u64(2867200), usage.used());
+    /// ```
+    #[inline]
+    pub const fn from_statvfs_blocks(
+        blocks: u64,
+        bfree: u64,
+        bavail: u64,
+        frsize: u64,
+    ) -> Option<Self> {
+        let total = match Byte::from_statvfs_blocks(blocks, frsize) {
+            Some(byte) => byte,
+            None => return None,
+        };
+        let free = match Byte::from_statvfs_blocks(bfree, frsize) {
+            Some(byte) => byte,
+            None => return None,
+        };
+        let available = match Byte::from_statvfs_blocks(bavail, frsize) {
+            Some(byte) => byte,
+            None => return None,
+        };
+
+        Some(FsUsage {
+            total,
+            free,
+            available,
+        })
+    }
+
+    /// Retrieve the usage of the file system containing the given path by calling `statvfs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::FsUsage;
+    ///
+    /// let usage = FsUsage::from_path("/").unwrap();
+    ///
+    /// assert!(usage.available <= usage.free);
+    /// assert!(usage.free <= usage.total);
+    /// ```
+    #[cfg(all(feature = "os", unix))]
+    pub fn from_path<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = CString::new(path.as_ref().as_os_str().as_bytes())
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+
+        let mut stat = core::mem::MaybeUninit::<libc::statvfs>::uninit();
+
+        // SAFETY: `path` is a NUL-terminated string which outlives the call, and `stat` points to writable memory large enough for a `statvfs` structure.
+        if unsafe { libc::statvfs(path.as_ptr(), stat.as_mut_ptr()) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // SAFETY: `statvfs` returned 0, so it has filled in the whole structure.
+        let stat = unsafe { stat.assume_init() };
+
+        #[allow(clippy::unnecessary_cast)]
+        Self::from_statvfs_blocks(
+            stat.f_blocks as u64,
+            stat.f_bfree as u64,
+            stat.f_bavail as u64,
+            stat.f_frsize as u64,
+        )
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, crate::ExceededBoundsError))
+    }
+
+    /// Get the used space, which is `total - free`.
+    #[inline]
+    pub const fn used(&self) -> Byte {
+        match self.total.subtract(self.free) {
+            Some(byte) => byte,
+            None => Byte::MIN,
+        }
+    }
+}
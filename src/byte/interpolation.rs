@@ -0,0 +1,109 @@
+use super::Byte;
+
+/// Methods for interpolating between `Byte` instances, e.g. to animate a size bar.
+impl Byte {
+    /// Linearly interpolate between this `Byte` instance and `other`, rounding to the nearest byte. **0** is `self`, and **1** is `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// let a = Byte::from_u64(1000);
+    /// let b = Byte::from_u64(2000);
+    ///
+    /// assert_eq!(Byte::from_u64(1250), a.lerp(b, 0.25));
+    /// assert_eq!(Byte::from_u64(1750), b.lerp(a, 0.25));
+    /// assert_eq!(b, a.lerp(b, 1.5));
+    /// assert_eq!(a, a.lerp(b, f64::NAN));
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * `t` is clamped to the range from **0** to **1**, and `NaN` is treated as **0**, so the result is always between `self` and `other`. Use [`Byte::checked_lerp`](#method.checked_lerp) to reject such values instead.
+    #[inline]
+    pub fn lerp(self, other: Byte, t: f64) -> Byte {
+        let t = if t >= 1.0 {
+            1.0
+        } else if t > 0.0 {
+            t
+        } else {
+            // negative numbers and NaN
+            0.0
+        };
+
+        let (a, b) = (self.as_u128(), other.as_u128());
+
+        // the offset never exceeds the distance, because `t` is at most 1
+        let offset = |distance: u128| ((distance as f64 * t + 0.5) as u128).min(distance);
+
+        let v = if a <= b { a + offset(b - a) } else { a - offset(a - b) };
+
+        Byte::from_u128(v).unwrap()
+    }
+
+    /// Linearly interpolate between this `Byte` instance and `other`. If `t` is `NaN` or not in the range from **0** to **1**, this function will return `None`. See [`Byte::lerp`](#method.lerp).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// let a = Byte::from_u64(1000);
+    /// let b = Byte::from_u64(2000);
+    ///
+    /// assert_eq!(Some(Byte::from_u64(1500)), a.checked_lerp(b, 0.5));
+    /// assert_eq!(None, a.checked_lerp(b, -0.1));
+    /// assert_eq!(None, a.checked_lerp(b, f64::NAN));
+    /// ```
+    #[inline]
+    pub fn checked_lerp(self, other: Byte, t: f64) -> Option<Byte> {
+        if (0.0..=1.0).contains(&t) {
+            Some(self.lerp(other, t))
+        } else {
+            None
+        }
+    }
+
+    /// Move this `Byte` instance towards `target` by the fraction `alpha` of the remaining distance, i.e. exponential smoothing.
+    ///
+    /// Unless `alpha` is **0**, it moves by at least 1 byte, so that calling this function repeatedly always reaches `target`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// let target = Byte::from_u64(1000);
+    ///
+    /// let mut displayed = Byte::from_u64(0);
+    ///
+    /// displayed = displayed.smooth_towards(target, 0.5);
+    ///
+    /// assert_eq!(Byte::from_u64(500), displayed);
+    ///
+    /// for _ in 0..20 {
+    ///     displayed = displayed.smooth_towards(target, 0.5);
+    /// }
+    ///
+    /// assert_eq!(target, displayed);
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * `alpha` is clamped in the same way as `t` of [`Byte::lerp`](#method.lerp).
+    #[inline]
+    pub fn smooth_towards(self, target: Byte, alpha: f64) -> Byte {
+        let v = self.lerp(target, alpha);
+
+        if v == self && self != target && alpha > 0.0 {
+            if self < target {
+                Byte::from_u128(self.as_u128() + 1).unwrap()
+            } else {
+                Byte::from_u128(self.as_u128() - 1).unwrap()
+            }
+        } else {
+            v
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use core::fmt::{self, Display, Formatter};
+
+use super::Byte;
+use crate::UnitType;
+
+/// A number of objects with their total size, e.g. the files in a directory or the objects in a storage bucket.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{Byte, Inventory};
+///
+/// let inventory = Inventory::default()
+///     .add(Byte::from_u64(3000))
+///     .unwrap()
+///     .add(Byte::from_u64(1000))
+///     .unwrap();
+///
+/// assert_eq!(2, inventory.objects);
+/// assert_eq!(Byte::from_u64(4000), inventory.bytes);
+/// assert_eq!(Some(Byte::from_u64(2000)), inventory.mean_object_size());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Inventory {
+    /// The number of objects.
+    pub objects: u64,
+    /// The total size of the objects.
+    pub bytes:   Byte,
+}
+
+impl Inventory {
+    /// Create a new `Inventory` instance.
+    #[inline]
+    pub const fn new(objects: u64, bytes: Byte) -> Self {
+        Self {
+            objects,
+            bytes,
+        }
+    }
+
+    /// Add an object of the given size.
+    ///
+    /// # Points to Note
+    ///
+    /// * If the number of objects or the total size is too large, this function will return `None`.
+    #[inline]
+    pub const fn add(self, bytes: Byte) -> Option<Self> {
+        self.merge(Self::new(1, bytes))
+    }
+
+    /// Add the objects of another `Inventory` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, Inventory};
+    ///
+    /// let a = Inventory::new(10, Byte::from_u64(5000));
+    /// let b = Inventory::new(5, Byte::from_u64(1000));
+    ///
+    /// assert_eq!(Some(Inventory::new(15, Byte::from_u64(6000))), a.merge(b));
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If the number of objects or the total size is too large, this function will return `None`.
+    #[inline]
+    pub const fn merge(self, other: Inventory) -> Option<Self> {
+        let objects = match self.objects.checked_add(other.objects) {
+            Some(objects) => objects,
+            None => return None,
+        };
+
+        match self.bytes.add(other.bytes) {
+            Some(bytes) => Some(Self::new(objects, bytes)),
+            None => None,
+        }
+    }
+
+    /// Calculate the mean size of the objects, rounded down. If there are no objects, this function will return `None`.
+    #[inline]
+    pub const fn mean_object_size(self) -> Option<Byte> {
+        if self.objects == 0 {
+            return None;
+        }
+
+        // the quotient is not greater than `self.bytes`
+        Some(Byte::from_u128_unchecked(self.bytes.as_u128() / self.objects as u128))
+    }
+}
+
+impl Display for Inventory {
+    /// Write the number of objects, abbreviated with `K`, `M`, `B` (billion), `T` or `Q` (quadrillion) and rounded to one digit after the decimal point, and the total size in binary units with the same precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, Inventory, Unit};
+    ///
+    /// let bytes = Byte::from_f64_with_unit(3.4, Unit::TiB).unwrap();
+    ///
+    /// assert_eq!(
+    ///     "1.2M objects, 3.4 TiB",
+    ///     Inventory::new(1_234_567, bytes).to_string()
+    /// );
+    /// assert_eq!(
+    ///     "1 object, 512 B",
+    ///     Inventory::new(1, Byte::from_u64(512)).to_string()
+    /// );
+    /// assert_eq!("0 objects, 0 B", Inventory::default().to_string());
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        const SUFFIXES: [&str; 5] = ["K", "M", "B", "T", "Q"];
+
+        let objects = self.objects;
+
+        if objects < 1000 {
+            f.write_fmt(format_args!("{objects}"))?;
+        } else {
+            let objects = objects as u128;
+
+            let mut i = 0;
+            let mut divisor = 1000u128;
+
+            let tenths = loop {
+                let tenths = (objects * 10 + divisor / 2) / divisor;
+
+                // e.g. 999950 is written as 1M instead of 1000K
+                if tenths < 10_000 || i == SUFFIXES.len() - 1 {
+                    break tenths;
+                }
+
+                i += 1;
+                divisor *= 1000;
+            };
+
+            if tenths % 10 == 0 {
+                f.write_fmt(format_args!("{}{}", tenths / 10, SUFFIXES[i]))?;
+            } else {
+                f.write_fmt(format_args!("{}.{}{}", tenths / 10, tenths % 10, SUFFIXES[i]))?;
+            }
+        }
+
+        let noun = if objects == 1 { "object" } else { "objects" };
+
+        f.write_fmt(format_args!(
+            " {noun}, {:#.1}",
+            self.bytes.get_appropriate_unit(UnitType::Binary)
+        ))
+    }
+}
@@ -0,0 +1,68 @@
+use rust_decimal::prelude::*;
+use serde_json::Value;
+
+use super::Byte;
+use crate::JsonValueError;
+
+/// Associated functions for reading dynamic JSON values.
+impl Byte {
+    /// Create a new `Byte` instance from a [`serde_json::Value`](https://docs.rs/serde_json/latest/serde_json/enum.Value.html), which is either a number of bytes or a string accepted by [`Byte::parse_str`](#method.parse_str) with `ignore_case` set to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    /// use serde_json::json;
+    ///
+    /// let config = json!({ "cache": "64 MiB", "buffer": 4096 });
+    ///
+    /// assert_eq!(
+    ///     Byte::from_u64(64 << 20),
+    ///     Byte::from_json_value(&config["cache"]).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     Byte::from_u64(4096),
+    ///     Byte::from_json_value(&config["buffer"]).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     "expected a number or a string, found null",
+    ///     Byte::from_json_value(&config["missing"]).unwrap_err().to_string()
+    /// );
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * The fractional part of a number will be rounded up.
+    /// * If the value is a negative or too large number, this function will return `Err(JsonValueError::ExceededBounds)`.
+    /// * If the value is neither a number nor a string, this function will return `Err(JsonValueError::InvalidType)`.
+    pub fn from_json_value(value: &Value) -> Result<Self, JsonValueError> {
+        match value {
+            Value::Number(n) => {
+                let byte = if let Some(n) = n.as_u64() {
+                    Some(Byte::from_u64(n))
+                } else if n.is_i64() {
+                    None
+                } else {
+                    n.as_f64().and_then(Decimal::from_f64).and_then(Byte::from_decimal)
+                };
+
+                byte.ok_or(JsonValueError::ExceededBounds)
+            },
+            Value::String(s) => Ok(Byte::parse_str(s, false)?),
+            Value::Null => Err(JsonValueError::InvalidType("null")),
+            Value::Bool(_) => Err(JsonValueError::InvalidType("a boolean")),
+            Value::Array(_) => Err(JsonValueError::InvalidType("an array")),
+            Value::Object(_) => Err(JsonValueError::InvalidType("an object")),
+        }
+    }
+}
+
+impl TryFrom<&Value> for Byte {
+    type Error = JsonValueError;
+
+    /// See [`Byte::from_json_value`](#method.from_json_value).
+    #[inline]
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        Byte::from_json_value(value)
+    }
+}
@@ -0,0 +1,254 @@
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::string::String;
+
+use rust_decimal::prelude::*;
+
+use super::Byte;
+use crate::{
+    common::{get_char_from_bytes, read_exponent, scale_by_power_of_ten},
+    ParseError, UnitParseError, ValueParseError,
+};
+
+/// The serialization format of a Kubernetes resource quantity, which is decided by the suffix of the quantity string.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum K8sQuantityFormat {
+    /// Powers of 10 with a decimal SI suffix or no suffix, e.g. `"1500k"` and `"2G"`.
+    #[default]
+    DecimalSI,
+    /// Powers of 2 with a binary SI suffix, e.g. `"512Mi"` and `"2Gi"`.
+    BinarySI,
+    /// Powers of 10 with an exponent, e.g. `"1e6"`.
+    DecimalExponent,
+}
+
+const DECIMAL_SUFFIXES: [&str; 7] = ["", "k", "M", "G", "T", "P", "E"];
+const BINARY_SUFFIXES: [&str; 7] = ["", "Ki", "Mi", "Gi", "Ti", "Pi", "Ei"];
+
+/// Associated functions for parsing Kubernetes quantities.
+impl Byte {
+    /// Create a new `Byte` instance from a Kubernetes resource quantity, such as `"512Mi"`, `"2G"` or `"1e6"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// assert_eq!(
+    ///     Byte::from_u64(512 << 20),
+    ///     Byte::parse_k8s_quantity("512Mi").unwrap()
+    /// );
+    /// assert_eq!(
+    ///     Byte::from_u64(1_000_000),
+    ///     Byte::parse_k8s_quantity("1e6").unwrap()
+    /// );
+    /// assert!(Byte::parse_k8s_quantity("512MiB").is_err());
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * See [`Byte::parse_k8s_quantity_with_format`](#method.parse_k8s_quantity_with_format).
+    #[inline]
+    pub fn parse_k8s_quantity<S: AsRef<str>>(s: S) -> Result<Self, ParseError> {
+        Self::parse_k8s_quantity_with_format(s).map(|(byte, _)| byte)
+    }
+
+    /// Create a new `Byte` instance from a Kubernetes resource quantity, along with its format, so that it can be serialized back with [`Byte::to_k8s_quantity`](#method.to_k8s_quantity).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, K8sQuantityFormat};
+    ///
+    /// let (byte, format) =
+    ///     Byte::parse_k8s_quantity_with_format("1024Mi").unwrap();
+    ///
+    /// assert_eq!(Byte::from_u64(1 << 30), byte);
+    /// assert_eq!(K8sQuantityFormat::BinarySI, format);
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * No whitespace is allowed, and the suffixes are case-sensitive. `"k"` is 10<sup>3</sup> while `"Ki"` is 2<sup>10</sup>.
+    /// * The suffixes smaller than 1, such as `"m"` (milli), are not supported.
+    /// * Like Kubernetes does for the integer value of a quantity, a fractional number of bytes is rounded up.
+    pub fn parse_k8s_quantity_with_format<S: AsRef<str>>(
+        s: S,
+    ) -> Result<(Self, K8sQuantityFormat), ParseError> {
+        let s = s.as_ref();
+
+        let s = match s.as_bytes().first() {
+            Some(b'+') => &s[1..],
+            Some(b'-') => return Err(ValueParseError::NotNumber('-').into()),
+            _ => s,
+        };
+
+        let number_length = s.bytes().take_while(|e| e.is_ascii_digit() || *e == b'.').count();
+        let (number, suffix) = s.split_at(number_length);
+
+        let value = parse_number(number)?;
+
+        let (value, format) = match suffix {
+            "" => (value, K8sQuantityFormat::DecimalSI),
+            _ if is_exponent(suffix) => {
+                (scale_by_exponent(value, &suffix[1..])?, K8sQuantityFormat::DecimalExponent)
+            },
+            _ => {
+                let (multiplier, format) = match DECIMAL_SUFFIXES.iter().position(|e| *e == suffix)
+                {
+                    Some(exponent) => (1000u128.pow(exponent as u32), K8sQuantityFormat::DecimalSI),
+                    None => match BINARY_SUFFIXES.iter().position(|e| *e == suffix) {
+                        Some(exponent) => (1 << (10 * exponent), K8sQuantityFormat::BinarySI),
+                        None => return Err(get_suffix_error(suffix).into()),
+                    },
+                };
+
+                let value = value
+                    .checked_mul(Decimal::from(multiplier))
+                    .ok_or(ValueParseError::ExceededBounds(value))?;
+
+                (value, format)
+            },
+        };
+
+        match Self::from_decimal(value.ceil()) {
+            Some(byte) => Ok((byte, format)),
+            None => Err(ValueParseError::ExceededBounds(value).into()),
+        }
+    }
+}
+
+/// Methods for serializing to Kubernetes quantities.
+impl Byte {
+    /// Serialize this `Byte` instance to a Kubernetes resource quantity in the canonical form of the given format, which is the same as what Kubernetes writes back.
+    ///
+    /// * `DecimalSI` uses the largest decimal SI suffix (up to `"E"`) by which the size is divisible, e.g. `"1500k"`.
+    /// * `BinarySI` uses the largest binary SI suffix (up to `"Ei"`) by which the size is divisible, e.g. `"1536Mi"`. Sizes smaller than 1024 bytes are written in `DecimalSI`.
+    /// * `DecimalExponent` uses the largest exponent which is a multiple of 3, e.g. `"1500e3"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, K8sQuantityFormat};
+    ///
+    /// let byte = Byte::from_u64(1 << 30);
+    ///
+    /// assert_eq!("1Gi", byte.to_k8s_quantity(K8sQuantityFormat::BinarySI));
+    /// assert_eq!(
+    ///     "1073741824",
+    ///     byte.to_k8s_quantity(K8sQuantityFormat::DecimalSI)
+    /// );
+    /// assert_eq!(
+    ///     "2G",
+    ///     Byte::from_u64(2_000_000_000)
+    ///         .to_k8s_quantity(K8sQuantityFormat::DecimalSI)
+    /// );
+    /// assert_eq!(
+    ///     "1e6",
+    ///     Byte::from_u64(1_000_000)
+    ///         .to_k8s_quantity(K8sQuantityFormat::DecimalExponent)
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_k8s_quantity(self, format: K8sQuantityFormat) -> String {
+        let mut value = self.as_u128();
+
+        if value == 0 {
+            return String::from("0");
+        }
+
+        let (factor, suffixes) = match format {
+            K8sQuantityFormat::BinarySI if value >= 1024 => (1024, BINARY_SUFFIXES),
+            K8sQuantityFormat::DecimalExponent => {
+                let mut exponent = 0;
+
+                while value % 1000 == 0 {
+                    value /= 1000;
+                    exponent += 3;
+                }
+
+                return if exponent == 0 {
+                    format!("{value}")
+                } else {
+                    format!("{value}e{exponent}")
+                };
+            },
+            _ => (1000, DECIMAL_SUFFIXES),
+        };
+
+        let mut exponent = 0;
+
+        while exponent < suffixes.len() - 1 && value % factor == 0 {
+            value /= factor;
+            exponent += 1;
+        }
+
+        format!("{value}{suffix}", suffix = suffixes[exponent])
+    }
+}
+
+/// Parse the number part of a quantity, which may start or end with a decimal point, e.g. `".5"` and `"5."`.
+fn parse_number(number: &str) -> Result<Decimal, ValueParseError> {
+    let mut points = number.bytes().enumerate().filter(|(_, e)| *e == b'.').map(|(i, _)| i);
+
+    if let (Some(_), Some(i)) = (points.next(), points.next()) {
+        return Err(ValueParseError::NotNumber(number.as_bytes()[i] as char));
+    }
+
+    if number.bytes().all(|e| e == b'.') {
+        return Err(ValueParseError::NoValue);
+    }
+
+    Decimal::from_str(number).map_err(|_| ValueParseError::NumberTooLong)
+}
+
+/// Check whether the suffix is an exponent, e.g. `"e6"` or `"E-3"`. A sole `"E"` means exa.
+#[inline]
+fn is_exponent(suffix: &str) -> bool {
+    matches!(suffix.as_bytes(), [b'e' | b'E', e, ..] if e.is_ascii_digit() || matches!(e, b'+' | b'-'))
+}
+
+/// Multiply the value by 10 to the power of the exponent.
+fn scale_by_exponent(value: Decimal, exponent: &str) -> Result<Decimal, ValueParseError> {
+    let mut bytes = exponent.bytes();
+
+    match read_exponent(&mut bytes) {
+        Some(exponent) => match bytes.next() {
+            Some(e) => Err(ValueParseError::NotNumber(get_char_from_bytes(e, bytes))),
+            None => {
+                scale_by_power_of_ten(value, exponent).ok_or(ValueParseError::ExceededBounds(value))
+            },
+        },
+        None => Err(ValueParseError::NoValue),
+    }
+}
+
+/// Find the first incorrect character of an unknown suffix.
+fn get_suffix_error(suffix: &str) -> UnitParseError {
+    let mut chars = suffix.chars();
+
+    // the suffix is not empty
+    let first = chars.next().unwrap();
+
+    let (character, expected_characters, also_expect_no_character) = match first {
+        'K' => match chars.next() {
+            Some('i') => (chars.next().unwrap(), &[][..], true),
+            Some(c) => (c, &['i'][..], false),
+            None => ('K', &['k'][..], false),
+        },
+        'M' | 'G' | 'T' | 'P' | 'E' => match chars.next() {
+            Some('i') => (chars.next().unwrap(), &[][..], true),
+            Some(c) => (c, &['i'][..], true),
+            None => unreachable!(),
+        },
+        'k' => (chars.next().unwrap(), &[][..], true),
+        'e' if suffix.len() == 1 => ('e', &['+', '-'][..], false),
+        _ => (first, &['k', 'K', 'M', 'G', 'T', 'P', 'E', 'e'][..], true),
+    };
+
+    UnitParseError {
+        character,
+        expected_characters,
+        also_expect_no_character,
+    }
+}
@@ -1,35 +1,106 @@
 mod adjusted;
+#[cfg(feature = "std")]
+mod allocate;
+#[cfg(feature = "arbitrary")]
+mod arbitrary_traits;
+mod bounded;
+mod budget;
+mod builder;
 mod built_in_traits;
+mod byte_seconds;
+mod chunk;
+#[cfg(feature = "clap")]
+mod clap_traits;
 mod constants;
+mod counter;
+#[cfg(feature = "criterion")]
+mod criterion_traits;
 mod decimal;
+mod display;
+mod fs;
+mod interpolation;
+mod inventory;
+#[cfg(feature = "json")]
+mod json;
+mod kubernetes;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+#[cfg(feature = "rayon")]
+mod parallel;
 mod parse;
+mod parser;
+#[cfg(feature = "polars")]
+#[clippy::msrv = "1.85"]
+mod polars_traits;
+#[cfg(feature = "indicatif")]
+mod progress;
+#[cfg(feature = "proptest")]
+mod proptest_traits;
+mod quota;
+mod rate;
 #[cfg(feature = "rocket")]
 mod rocket_traits;
+#[cfg(feature = "saturating")]
+#[clippy::msrv = "1.74"]
+mod saturating_traits;
+#[cfg(feature = "schemars")]
+mod schemars_traits;
 #[cfg(feature = "serde")]
 mod serde_traits;
+mod signed;
+#[cfg(feature = "std")]
+mod size_report;
+#[cfg(feature = "sysinfo")]
+mod sysinfo_traits;
+mod time_bucket;
 
-use core::fmt::{self, Alignment, Display, Formatter, Write};
+use core::fmt::{self, Display, Formatter};
 
 pub use adjusted::*;
+pub use bounded::*;
+pub use budget::*;
+pub use builder::*;
+pub use byte_seconds::*;
+pub use chunk::*;
+pub use counter::*;
+pub use display::*;
+pub use fs::*;
+pub use inventory::*;
+pub use kubernetes::*;
+#[cfg(feature = "rayon")]
+pub use parallel::*;
+pub use parser::*;
+#[cfg(feature = "indicatif")]
+pub use progress::*;
+pub use rate::*;
 use rust_decimal::prelude::*;
+pub use signed::*;
+#[cfg(feature = "std")]
+pub use size_report::*;
+#[cfg(feature = "sysinfo")]
+pub use sysinfo_traits::*;
+pub use time_bucket::*;
 
 use crate::{
-    common::{ceil_f32, ceil_f64},
-    Unit,
+    common::{ceil_f32, ceil_f64, div_ceil_u128},
+    format::fmt_with_unit,
+    RoundingMode, Unit, UnitPreference,
 };
 
 #[cfg(feature = "u128")]
 const RONNABYTE: u128 = 1_000_000_000_000_000_000_000_000_000; // RB
 
+/// The native integer type backing `Byte`, which is `u128` if the `u128` feature is enabled, or `u64` otherwise. See [`Byte::get`](struct.Byte.html#method.get).
 #[cfg(feature = "u128")]
-#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Default)]
-/// Representing the size in bytes.
-pub struct Byte(u128);
+pub type ByteRepr = u128;
 
+/// The native integer type backing `Byte`, which is `u128` if the `u128` feature is enabled, or `u64` otherwise. See [`Byte::get`](struct.Byte.html#method.get).
 #[cfg(not(feature = "u128"))]
+pub type ByteRepr = u64;
+
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Default)]
 /// Representing the size in bytes.
-pub struct Byte(u64);
+pub struct Byte(ByteRepr);
 
 impl Display for Byte {
     /// Formats the value using the given formatter.
@@ -72,6 +143,10 @@ impl Display for Byte {
     /// // with a width, right alignment, more spaces between the value and the unit
     /// assert_eq!("    10 KiB", format!("{byte_based_2:>+#10}"));
     /// assert_eq!("    10  KB", format!("{byte_based_10:>+#10}"));
+    ///
+    /// // with a fill character, or padded with zeros, like integers
+    /// assert_eq!("**10** KiB", format!("{byte_based_2:*^#10}"));
+    /// assert_eq!("000010 KiB", format!("{byte_based_2:#010}"));
     /// ```
     ///
     /// ```
@@ -94,44 +169,15 @@ impl Display for Byte {
         if f.alternate() {
             let precision = f.precision().unwrap_or(3);
 
-            let (mut value, unit) = self.get_recoverable_unit(false, precision);
-
-            value = value.normalize();
-
-            let space_length = if f.sign_plus() {
-                4 - unit.as_str().len()
-            } else if f.sign_minus() {
-                0
-            } else {
-                1
-            };
+            let (value, unit) = self.get_recoverable_unit_with_preference(
+                false,
+                precision,
+                UnitPreference::global(),
+            );
 
-            if let Some(mut width) = f.width() {
-                let l = unit.as_str().len() + space_length;
+            let value = value.normalize();
 
-                if width > l + 1 {
-                    width -= l;
-
-                    let alignment = f.align().unwrap_or(Alignment::Left);
-
-                    match alignment {
-                        Alignment::Left | Alignment::Center => {
-                            f.write_fmt(format_args!("{value:<width$}"))?
-                        },
-                        Alignment::Right => f.write_fmt(format_args!("{value:>width$}"))?,
-                    }
-                } else {
-                    f.write_fmt(format_args!("{value}"))?;
-                }
-            } else {
-                f.write_fmt(format_args!("{value}"))?;
-            }
-
-            for _ in 0..space_length {
-                f.write_char(' ')?;
-            }
-
-            f.write_fmt(format_args!("{unit}"))
+            fmt_with_unit(f, format_args!("{value}"), Some(unit))
         } else {
             Display::fmt(&self.0, f)
         }
@@ -173,30 +219,63 @@ impl Byte {
         }
     }
 
-    /// Create a new `Byte` instance from a size in bytes.
+    /// Create a new `Byte` instance from a size in bytes without checking the bounds.
     ///
     /// # Examples
     ///
     /// ```
     /// # use byte_unit::Byte;
-    /// let byte = unsafe { Byte::from_u128_unsafe(15000000) }; // 15 MB
+    /// const BYTE: Byte = Byte::from_u128_unchecked(15000000); // 15 MB
     /// ```
     ///
-    /// # Safety
-    /// You must ensure the input **size** is not too large (the maximum is **10<sup>27</sup> - 1** if the `u128` feature is enabled, or **2<sup>64</sup> - 1** otherwise) on your own.
+    /// # Points to Note
+    ///
+    /// * The caller must ensure the input **size** is not too large (the maximum is **10<sup>27</sup> - 1** if the `u128` feature is enabled, or **2<sup>64</sup> - 1** otherwise). This is a logic contract, not a memory safety one: a size which is too large is truncated, and the arithmetic on the result may be wrong, but no undefined behavior can happen. It is checked by a `debug_assert!`, so it panics in debug builds.
     #[inline]
-    pub const unsafe fn from_u128_unsafe(size: u128) -> Self {
+    pub const fn from_u128_unchecked(size: u128) -> Self {
         #[cfg(feature = "u128")]
         {
+            debug_assert!(size < RONNABYTE, "the size is too large");
+
             Byte(size)
         }
 
         #[cfg(not(feature = "u128"))]
         {
+            debug_assert!(size <= u64::MAX as u128, "the size is too large");
+
             Byte(size as u64)
         }
     }
 
+    /// Create a new `Byte` instance from a size in bytes.
+    ///
+    /// # Safety
+    /// You must ensure the input **size** is not too large (the maximum is **10<sup>27</sup> - 1** if the `u128` feature is enabled, or **2<sup>64</sup> - 1** otherwise) on your own.
+    #[deprecated(note = "no memory safety is involved, use `Byte::from_u128_unchecked` instead")]
+    #[inline]
+    pub const unsafe fn from_u128_unsafe(size: u128) -> Self {
+        Self::from_u128_unchecked(size)
+    }
+
+    /// Create a new `Byte` instance from a size in bytes. If the input **size** is too large, it is clamped to `Byte::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// assert_eq!(Byte::from_u64(15), Byte::new_clamped(15));
+    /// assert_eq!(Byte::MAX, Byte::new_clamped(u128::MAX));
+    /// ```
+    #[inline]
+    pub const fn new_clamped(size: u128) -> Self {
+        match Self::from_u128(size) {
+            Some(byte) => byte,
+            None => Self::MAX,
+        }
+    }
+
     /// Create a new `Byte` instance from a size in bytes.
     ///
     /// # Examples
@@ -231,6 +310,10 @@ impl Byte {
     ///
     /// * If the input **size** is too large (the maximum is **10<sup>27</sup> - 1** if the `u128` feature is enabled, or **2<sup>64</sup> - 1** otherwise) or not greater than or equal to **0**, this function will return `None`.
     /// * The fractional part will be rounded up.
+    #[cfg_attr(
+        feature = "strict",
+        deprecated(note = "floating-point numbers are lossy, use `Byte::from_decimal` instead")
+    )]
     #[inline]
     pub fn from_f64(size: f64) -> Option<Self> {
         if size >= 0.0 {
@@ -273,6 +356,10 @@ impl Byte {
     ///
     /// * If the input **size** is too large (the maximum is **10<sup>27</sup> - 1** if the `u128` feature is enabled, or **2<sup>64</sup> - 1** otherwise) or not greater than or equal to **0**, this function will return `None`.
     /// * The fractional part will be rounded up.
+    #[cfg_attr(
+        feature = "strict",
+        deprecated(note = "floating-point numbers are lossy, use `Byte::from_decimal` instead")
+    )]
     #[inline]
     pub fn from_f32(size: f32) -> Option<Self> {
         if size >= 0.0 {
@@ -441,6 +528,12 @@ impl Byte {
     ///
     /// * If the calculated byte is too large or not greater than or equal to **0**, this function will return `None`.
     /// * The calculated byte will be rounded up.
+    #[cfg_attr(
+        feature = "strict",
+        deprecated(
+            note = "floating-point numbers are lossy, use `Byte::from_decimal_with_unit` instead"
+        )
+    )]
     #[inline]
     pub fn from_f64_with_unit(size: f64, unit: Unit) -> Option<Self> {
         match Decimal::from_f64(size) {
@@ -463,6 +556,12 @@ impl Byte {
     ///
     /// * If the calculated byte is too large or not greater than or equal to **0**, this function will return `None`.
     /// * The calculated byte will be rounded up.
+    #[cfg_attr(
+        feature = "strict",
+        deprecated(
+            note = "floating-point numbers are lossy, use `Byte::from_decimal_with_unit` instead"
+        )
+    )]
     #[inline]
     pub fn from_f32_with_unit(size: f32, unit: Unit) -> Option<Self> {
         match Decimal::from_f32(size) {
@@ -520,6 +619,30 @@ impl Byte {
 
 /// Methods for converting a `Byte` instance into a primitive integer.
 impl Byte {
+    /// Retrieve the byte represented by this `Byte` instance as the native integer type, [`ByteRepr`](type.ByteRepr.html), without any conversion.
+    ///
+    /// Generic numeric code can be written once against `ByteRepr` instead of checking whether the `u128` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, ByteRepr};
+    ///
+    /// let byte = Byte::from_u64(1024);
+    ///
+    /// let result: ByteRepr = byte.get();
+    ///
+    /// assert_eq!(1024, result);
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * `Byte` does not implement `Deref`, because the arithmetic of the integer type does not respect the bounds of `Byte`. Use `AsRef` or `Borrow` to access the integer by reference.
+    #[inline]
+    pub const fn get(self) -> ByteRepr {
+        self.0
+    }
+
     /// Retrieve the byte represented by this `Byte` instance.
     ///
     /// # Examples
@@ -665,10 +788,7 @@ impl Byte {
     /// * If the calculated byte is too large, this function will return `None`.
     #[inline]
     pub const fn add(self, rhs: Byte) -> Option<Byte> {
-        match self.0.checked_add(rhs.0) {
-            Some(v) => Some(Byte(v)),
-            None => None,
-        }
+        self.checked_add(rhs)
     }
 
     /// Subtract another `Byte` instance.
@@ -721,7 +841,7 @@ impl Byte {
         #[cfg(feature = "u128")]
         {
             match self.0.checked_mul(rhs as u128) {
-                Some(v) => Some(Byte(v)),
+                Some(v) => Byte::from_u128(v),
                 None => None,
             }
         }
@@ -794,6 +914,622 @@ impl Byte {
     }
 }
 
+/// Methods for calculation with the same semantics as primitive integers.
+///
+/// The arithmetic operators (`+`, `-`, `*`, `/` and their assignment forms) are implemented with these methods and panic on overflow or division by zero, like the operators of primitive integers in debug mode. Use these methods directly to handle the overflow explicitly.
+impl Byte {
+    /// Checked addition. Returns `None` if the result exceeds [`Byte::MAX`](#associatedconstant.MAX).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// assert_eq!(
+    ///     Some(Byte::from_u64(1536)),
+    ///     Byte::from_u64(1024).checked_add(Byte::from_u64(512))
+    /// );
+    /// assert_eq!(None, Byte::MAX.checked_add(Byte::from_u64(1)));
+    /// ```
+    #[inline]
+    pub const fn checked_add(self, rhs: Byte) -> Option<Byte> {
+        match self.as_u128().checked_add(rhs.as_u128()) {
+            Some(v) => Byte::from_u128(v),
+            None => None,
+        }
+    }
+
+    /// Checked subtraction. Returns `None` if `rhs` is larger than this `Byte` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// assert_eq!(
+    ///     Some(Byte::from_u64(512)),
+    ///     Byte::from_u64(1024).checked_sub(Byte::from_u64(512))
+    /// );
+    /// assert_eq!(None, Byte::from_u64(512).checked_sub(Byte::from_u64(1024)));
+    /// ```
+    #[inline]
+    pub const fn checked_sub(self, rhs: Byte) -> Option<Byte> {
+        match self.0.checked_sub(rhs.0) {
+            Some(v) => Some(Byte(v)),
+            None => None,
+        }
+    }
+
+    /// Checked multiplication. Returns `None` if the result exceeds [`Byte::MAX`](#associatedconstant.MAX).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// assert_eq!(Some(Byte::from_u64(4096)), Byte::from_u64(1024).checked_mul(4));
+    /// assert_eq!(None, Byte::MAX.checked_mul(2));
+    /// ```
+    #[inline]
+    pub const fn checked_mul(self, rhs: u64) -> Option<Byte> {
+        match self.as_u128().checked_mul(rhs as u128) {
+            Some(v) => Byte::from_u128(v),
+            None => None,
+        }
+    }
+
+    /// Checked division, rounded down. Returns `None` if `rhs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// assert_eq!(Some(Byte::from_u64(341)), Byte::from_u64(1024).checked_div(3));
+    /// assert_eq!(None, Byte::from_u64(1024).checked_div(0));
+    /// ```
+    #[inline]
+    pub const fn checked_div(self, rhs: u64) -> Option<Byte> {
+        if rhs == 0 {
+            None
+        } else {
+            Byte::from_u128(self.as_u128() / rhs as u128)
+        }
+    }
+
+    /// Saturating addition. Returns [`Byte::MAX`](#associatedconstant.MAX) instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// assert_eq!(Byte::MAX, Byte::MAX.saturating_add(Byte::from_u64(1)));
+    /// ```
+    #[inline]
+    pub const fn saturating_add(self, rhs: Byte) -> Byte {
+        match self.checked_add(rhs) {
+            Some(byte) => byte,
+            None => Byte::MAX,
+        }
+    }
+
+    /// Saturating subtraction. Returns [`Byte::MIN`](#associatedconstant.MIN) instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// assert_eq!(
+    ///     Byte::MIN,
+    ///     Byte::from_u64(512).saturating_sub(Byte::from_u64(1024))
+    /// );
+    /// ```
+    #[inline]
+    pub const fn saturating_sub(self, rhs: Byte) -> Byte {
+        match self.checked_sub(rhs) {
+            Some(byte) => byte,
+            None => Byte::MIN,
+        }
+    }
+
+    /// Saturating multiplication. Returns [`Byte::MAX`](#associatedconstant.MAX) instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// assert_eq!(Byte::MAX, Byte::MAX.saturating_mul(2));
+    /// ```
+    #[inline]
+    pub const fn saturating_mul(self, rhs: u64) -> Byte {
+        match self.checked_mul(rhs) {
+            Some(byte) => byte,
+            None => Byte::MAX,
+        }
+    }
+
+    /// Wrapping addition. Wraps around at the boundary of [`Byte::MAX`](#associatedconstant.MAX), which is not a power of two if the `u128` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// assert_eq!(Byte::from_u64(0), Byte::MAX.wrapping_add(Byte::from_u64(1)));
+    /// ```
+    #[inline]
+    pub const fn wrapping_add(self, rhs: Byte) -> Byte {
+        let modulus = Byte::MAX.as_u128() + 1;
+
+        match Byte::from_u128((self.as_u128() + rhs.as_u128()) % modulus) {
+            Some(byte) => byte,
+            None => unreachable!(),
+        }
+    }
+
+    /// Wrapping subtraction. Wraps around at the boundary of [`Byte::MIN`](#associatedconstant.MIN).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// assert_eq!(Byte::MAX, Byte::from_u64(0).wrapping_sub(Byte::from_u64(1)));
+    /// ```
+    #[inline]
+    pub const fn wrapping_sub(self, rhs: Byte) -> Byte {
+        let modulus = Byte::MAX.as_u128() + 1;
+
+        match Byte::from_u128((self.as_u128() + modulus - rhs.as_u128()) % modulus) {
+            Some(byte) => byte,
+            None => unreachable!(),
+        }
+    }
+
+    /// Checked summation of bytes, e.g. from an iterator. Returns `None` if the result exceeds [`Byte::MAX`](#associatedconstant.MAX).
+    ///
+    /// The `Sum` implementations panic on overflow, like the `+` operator. Use this function to handle the overflow explicitly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// let sizes = [Byte::from_u64(1024), Byte::from_u64(512)];
+    ///
+    /// assert_eq!(Some(Byte::from_u64(1536)), Byte::checked_sum(sizes));
+    /// assert_eq!(None, Byte::checked_sum([Byte::MAX, Byte::from_u64(1)]));
+    /// ```
+    #[inline]
+    pub fn checked_sum<I: IntoIterator<Item = Byte>>(iter: I) -> Option<Byte> {
+        iter.into_iter().try_fold(Byte::MIN, Byte::checked_add)
+    }
+
+    /// Saturating summation of bytes, e.g. from an iterator. Returns [`Byte::MAX`](#associatedconstant.MAX) if the result exceeds it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// assert_eq!(Byte::MAX, Byte::saturating_sum([Byte::MAX, Byte::from_u64(1)]));
+    /// ```
+    #[inline]
+    pub fn saturating_sum<I: IntoIterator<Item = Byte>>(iter: I) -> Byte {
+        iter.into_iter().fold(Byte::MIN, Byte::saturating_add)
+    }
+}
+
+/// Methods for comparing.
+impl Byte {
+    /// Get the absolute difference between this `Byte` instance and another `Byte` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// assert_eq!(
+    ///     Byte::from_u64(24),
+    ///     Byte::from_u64(1000).abs_diff(Byte::from_u64(1024))
+    /// );
+    /// ```
+    #[inline]
+    pub const fn abs_diff(self, other: Byte) -> Byte {
+        Byte(self.0.abs_diff(other.0))
+    }
+
+    /// Check whether this `Byte` instance differs from another `Byte` instance by at most `tolerance`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// let expected = Byte::from_u64(1048576);
+    ///
+    /// assert!(Byte::from_u64(1048570).within(expected, Byte::from_u64(16)));
+    /// assert!(!Byte::from_u64(1048500).within(expected, Byte::from_u64(16)));
+    /// ```
+    #[inline]
+    pub const fn within(self, other: Byte, tolerance: Byte) -> bool {
+        self.0.abs_diff(other.0) <= tolerance.0
+    }
+
+    /// Get the smaller one of this `Byte` instance and another `Byte` instance. Unlike `Ord::min`, this method can be used in constant expressions.
+    #[inline]
+    pub const fn min(self, other: Byte) -> Byte {
+        if self.0 <= other.0 {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Get the larger one of this `Byte` instance and another `Byte` instance. Unlike `Ord::max`, this method can be used in constant expressions.
+    #[inline]
+    pub const fn max(self, other: Byte) -> Byte {
+        if self.0 >= other.0 {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Restrict this `Byte` instance to the range from `min` to `max`. Unlike `Ord::clamp`, this method can be used in constant expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// const LIMIT: Byte =
+    ///     Byte::from_u64(1 << 40).clamp(Byte::MEBIBYTE, Byte::GIBIBYTE);
+    ///
+    /// assert_eq!(Byte::GIBIBYTE, LIMIT);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`.
+    #[inline]
+    pub const fn clamp(self, min: Byte, max: Byte) -> Byte {
+        assert!(min.0 <= max.0, "min must not be greater than max");
+
+        self.max(min).min(max)
+    }
+
+    /// Get the difference between this `Byte` instance and another `Byte` instance relative to the larger one, from `0.0` (equal) to `1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// assert_eq!(0.25, Byte::from_u64(750).relative_diff(Byte::from_u64(1000)));
+    /// assert_eq!(0.25, Byte::from_u64(1000).relative_diff(Byte::from_u64(750)));
+    /// assert_eq!(0.0, Byte::from_u64(0).relative_diff(Byte::from_u64(0)));
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If both are zero, this function will return `0.0`.
+    #[inline]
+    pub fn relative_diff(self, other: Byte) -> f64 {
+        let max = self.0.max(other.0);
+
+        if max == 0 {
+            return 0.0;
+        }
+
+        self.0.abs_diff(other.0) as f64 / max as f64
+    }
+}
+
+/// Methods for rounding.
+impl Byte {
+    /// Round this `Byte` instance to a multiple of the given unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, RoundingMode, Unit};
+    ///
+    /// let byte = Byte::from_u64(1600000000); // about 1.49 GiB
+    ///
+    /// assert_eq!(
+    ///     Some(Byte::from_u64(2147483648)),
+    ///     byte.round_to_unit(Unit::GiB, RoundingMode::Up)
+    /// );
+    /// assert_eq!(
+    ///     Some(Byte::from_u64(1073741824)),
+    ///     byte.round_to_unit(Unit::GiB, RoundingMode::Down)
+    /// );
+    /// assert_eq!(
+    ///     Some(Byte::from_u64(1073741824)),
+    ///     byte.round_to_unit(Unit::GiB, RoundingMode::Nearest)
+    /// );
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * Since a `Byte` instance is always a whole number of bytes, rounding to `Unit::Bit` returns the instance as it is.
+    /// * If the rounded size is too large, this method will return `None`.
+    #[inline]
+    pub const fn round_to_unit(self, unit: Unit, rounding_mode: RoundingMode) -> Option<Byte> {
+        let step = match unit {
+            Unit::Bit => return Some(self),
+            _ => unit.as_bytes_u128(),
+        };
+
+        self.round_to_multiple(step, rounding_mode)
+    }
+
+    /// Round this `Byte` instance to a multiple of `step` bytes. Returns `None` if `step` is zero or the rounded size is too large.
+    #[inline]
+    pub(crate) const fn round_to_multiple(
+        self,
+        step: u128,
+        rounding_mode: RoundingMode,
+    ) -> Option<Byte> {
+        if step == 0 {
+            return None;
+        }
+
+        match rounding_mode.round_u128(self.as_u128(), step) {
+            Some(v) => Byte::from_u128(v),
+            None => None,
+        }
+    }
+}
+
+/// Methods for huge pages.
+impl Byte {
+    /// Count the huge pages needed to hold this size, e.g. for setting `vm.nr_hugepages` or the `hugepages` of a VM. A partially used page is counted as a whole page.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// let shared_buffers = Byte::parse_str("8.5 GiB", true).unwrap();
+    ///
+    /// assert_eq!(
+    ///     Some(4352),
+    ///     shared_buffers.count_hugepages(Byte::HUGE_PAGE_2MIB)
+    /// );
+    /// assert_eq!(Some(9), shared_buffers.count_hugepages(Byte::HUGE_PAGE_1GIB));
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If `page` is zero, this method will return `None`.
+    #[inline]
+    pub const fn count_hugepages(self, page: Byte) -> Option<u128> {
+        let page = page.as_u128();
+
+        if page == 0 {
+            return None;
+        }
+
+        Some(div_ceil_u128(self.as_u128(), page))
+    }
+
+    /// Round this `Byte` instance to a multiple of the huge page size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, RoundingMode};
+    ///
+    /// let memory = Byte::parse_str("4.2 GiB", true).unwrap();
+    ///
+    /// assert_eq!(
+    ///     Some(Byte::parse_str("5 GiB", true).unwrap()),
+    ///     memory.round_to_hugepages(Byte::HUGE_PAGE_1GIB, RoundingMode::Up)
+    /// );
+    /// assert_eq!(
+    ///     Some(Byte::parse_str("4 GiB", true).unwrap()),
+    ///     memory.round_to_hugepages(Byte::HUGE_PAGE_1GIB, RoundingMode::Nearest)
+    /// );
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If `page` is zero or the rounded size is too large, this method will return `None`.
+    #[inline]
+    pub const fn round_to_hugepages(self, page: Byte, rounding_mode: RoundingMode) -> Option<Byte> {
+        self.round_to_multiple(page.as_u128(), rounding_mode)
+    }
+}
+
+/// Methods for block padding.
+impl Byte {
+    /// Pad this `Byte` instance to a multiple of the block size, e.g. the space taken by a file in an archive or on a device whose blocks are never shared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// let block = Byte::from_u64(4096);
+    ///
+    /// assert_eq!(
+    ///     Some(Byte::from_u64(8192)),
+    ///     Byte::from_u64(5000).padded_to(block)
+    /// );
+    /// assert_eq!(
+    ///     Some(Byte::from_u64(4096)),
+    ///     Byte::from_u64(4096).padded_to(block)
+    /// );
+    /// assert_eq!(Some(Byte::from_u64(0)), Byte::from_u64(0).padded_to(block));
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If `block` is zero or the padded size is too large, this method will return `None`.
+    #[inline]
+    pub const fn padded_to(self, block: Byte) -> Option<Byte> {
+        self.round_to_multiple(block.as_u128(), RoundingMode::Up)
+    }
+
+    /// Count the 512-byte blocks which the content of a file of this size takes in a tar archive. A partially used block is counted as a whole block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// assert_eq!(0, Byte::from_u64(0).tar_blocks());
+    /// assert_eq!(1, Byte::from_u64(1).tar_blocks());
+    /// assert_eq!(3, Byte::from_u64(1025).tar_blocks());
+    /// ```
+    #[inline]
+    pub const fn tar_blocks(self) -> u128 {
+        div_ceil_u128(self.as_u128(), Byte::TAR_BLOCK.as_u128())
+    }
+
+    /// Pad this `Byte` instance to a multiple of 512 bytes, which is the space the content of a file of this size takes in a tar archive. The 512-byte header of each entry is not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// let size = Byte::from_u64(1025);
+    ///
+    /// assert_eq!(Some(Byte::from_u64(1536)), size.tar_padded_size());
+    ///
+    /// // with the header
+    /// assert_eq!(
+    ///     Some(Byte::from_u64(2048)),
+    ///     size.tar_padded_size().and_then(|size| size.add(Byte::TAR_BLOCK))
+    /// );
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If the padded size is too large, this method will return `None`.
+    #[inline]
+    pub const fn tar_padded_size(self) -> Option<Byte> {
+        self.padded_to(Byte::TAR_BLOCK)
+    }
+}
+
+/// Methods for alignment.
+impl Byte {
+    /// Round this `Byte` instance up to a multiple of `alignment`, which can be a power of two, such as a 4 KiB page, or any other block size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// let page = Byte::from_u64(4096);
+    ///
+    /// assert_eq!(Some(Byte::from_u64(8192)), Byte::from_u64(4097).align_up(page));
+    /// assert_eq!(Some(Byte::from_u64(4096)), Byte::from_u64(4096).align_up(page));
+    /// assert_eq!(
+    ///     Some(Byte::from_u64(1500)),
+    ///     Byte::from_u64(1001).align_up(Byte::from_u64(500))
+    /// );
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If `alignment` is zero or the aligned size is too large, this method will return `None`.
+    #[inline]
+    pub const fn align_up(self, alignment: Byte) -> Option<Byte> {
+        self.round_to_multiple(alignment.as_u128(), RoundingMode::Up)
+    }
+
+    /// Round this `Byte` instance down to a multiple of `alignment`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// let sector = Byte::from_u64(512);
+    ///
+    /// assert_eq!(
+    ///     Some(Byte::from_u64(1024)),
+    ///     Byte::from_u64(1535).align_down(sector)
+    /// );
+    /// assert_eq!(Some(Byte::from_u64(0)), Byte::from_u64(511).align_down(sector));
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If `alignment` is zero, this method will return `None`.
+    #[inline]
+    pub const fn align_down(self, alignment: Byte) -> Option<Byte> {
+        let alignment = alignment.as_u128();
+
+        if alignment == 0 {
+            return None;
+        }
+
+        let v = self.as_u128();
+
+        Some(Byte::from_u128_unchecked(v - v % alignment))
+    }
+
+    /// Check whether this `Byte` instance is a multiple of `alignment`. Nothing is aligned to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// let page = Byte::from_u64(4096);
+    ///
+    /// assert!(Byte::from_u64(8192).is_aligned_to(page));
+    /// assert!(Byte::from_u64(0).is_aligned_to(page));
+    /// assert!(!Byte::from_u64(4097).is_aligned_to(page));
+    /// assert!(!Byte::from_u64(0).is_aligned_to(Byte::from_u64(0)));
+    /// ```
+    #[inline]
+    pub const fn is_aligned_to(self, alignment: Byte) -> bool {
+        let alignment = alignment.as_u128();
+
+        alignment != 0 && self.as_u128() % alignment == 0
+    }
+
+    /// Get the smallest power of two which is not less than this `Byte` instance. The next power of two of zero is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// assert_eq!(
+    ///     Some(Byte::from_u64(4096)),
+    ///     Byte::from_u64(3000).next_power_of_two()
+    /// );
+    /// assert_eq!(
+    ///     Some(Byte::from_u64(4096)),
+    ///     Byte::from_u64(4096).next_power_of_two()
+    /// );
+    /// assert_eq!(Some(Byte::from_u64(1)), Byte::from_u64(0).next_power_of_two());
+    /// assert_eq!(None, Byte::MAX.next_power_of_two());
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If the power of two is too large, this method will return `None`.
+    #[inline]
+    pub const fn next_power_of_two(self) -> Option<Byte> {
+        match self.as_u128().checked_next_power_of_two() {
+            Some(v) => Byte::from_u128(v),
+            None => None,
+        }
+    }
+}
+
 /// Methods for finding an unit.
 impl Byte {
     /// Obtain the largest unit which is the greatest factor of this `Byte` instance.
@@ -832,6 +1568,18 @@ impl Byte {
     /// assert_eq!(375, n);
     /// assert_eq!(Unit::KB, unit);
     /// ```
+    ///
+    /// This method can be used in const contexts.
+    ///
+    /// ```
+    /// use byte_unit::{Byte, Unit};
+    ///
+    /// const LIMIT: Byte = Byte::from_u64(3145728);
+    /// const EXACT: (u128, Unit) = LIMIT.get_exact_unit(false);
+    /// const UNIT: &str = EXACT.1.as_str();
+    ///
+    /// assert_eq!("MiB", UNIT);
+    /// ```
     #[inline]
     pub const fn get_exact_unit(self, allow_in_bits: bool) -> (u128, Unit) {
         let bytes_v = self.as_u128();
@@ -858,3 +1606,54 @@ impl Byte {
         (bytes_v, Unit::B)
     }
 }
+
+/// Methods for converting a `Bit` instance into a `Byte` instance.
+///
+/// Every `Bit` instance can be converted, because the range of `Byte` is not smaller than the range of `Bit`.
+#[cfg(feature = "bit")]
+impl crate::Bit {
+    /// Convert this `Bit` instance into a `Byte` instance, rounding a partial byte up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, Byte};
+    ///
+    /// assert_eq!(Byte::from_u64(2), Bit::from_u64(9).as_byte_ceil());
+    /// assert_eq!(Byte::from_u64(1), Bit::from_u64(8).as_byte_ceil());
+    /// ```
+    #[inline]
+    pub const fn as_byte_ceil(self) -> Byte {
+        let bits = self.as_u128();
+
+        Self::bits_to_byte(bits / 8 + (bits % 8 != 0) as u128)
+    }
+
+    /// Convert this `Bit` instance into a `Byte` instance, discarding a partial byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Bit, Byte};
+    ///
+    /// assert_eq!(Byte::from_u64(1), Bit::from_u64(15).as_byte_floor());
+    /// assert_eq!(Byte::from_u64(2), Bit::from_u64(16).as_byte_floor());
+    /// ```
+    #[inline]
+    pub const fn as_byte_floor(self) -> Byte {
+        Self::bits_to_byte(self.as_u128() / 8)
+    }
+
+    #[inline]
+    const fn bits_to_byte(bytes: u128) -> Byte {
+        #[cfg(feature = "u128")]
+        {
+            Byte(bytes)
+        }
+
+        #[cfg(not(feature = "u128"))]
+        {
+            Byte(bytes as u64)
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use core::ops::Mul;
+
+use num_traits::{
+    Bounded, CheckedAdd, CheckedMul, CheckedSub, FromPrimitive, One, SaturatingAdd, SaturatingMul,
+    SaturatingSub, ToPrimitive, Zero,
+};
+
+use super::Byte;
+
+impl Mul for Byte {
+    type Output = Byte;
+
+    /// Multiply the number of bytes by the number of bytes of `rhs`, as a raw product of two numbers. For example, 2 KB * 3 KB is 6000000 bytes, not a size with a squared unit.
+    ///
+    /// This is only implemented because the `One`, `CheckedMul` and `SaturatingMul` traits of `num-traits` need it, and `One` makes 1 byte the multiplicative identity. To scale a size, multiply it by an integer instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result exceeds `Byte::MAX`.
+    #[inline]
+    fn mul(self, rhs: Byte) -> Byte {
+        CheckedMul::checked_mul(&self, &rhs).expect("attempt to multiply with overflow")
+    }
+}
+
+impl Zero for Byte {
+    #[inline]
+    fn zero() -> Self {
+        Byte::MIN
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.as_u128() == 0
+    }
+}
+
+impl One for Byte {
+    /// 1 byte.
+    #[inline]
+    fn one() -> Self {
+        Byte::from_u64(1)
+    }
+}
+
+impl Bounded for Byte {
+    #[inline]
+    fn min_value() -> Self {
+        Byte::MIN
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Byte::MAX
+    }
+}
+
+impl CheckedAdd for Byte {
+    #[inline]
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        Byte::checked_add(*self, *v)
+    }
+}
+
+impl CheckedSub for Byte {
+    #[inline]
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        Byte::checked_sub(*self, *v)
+    }
+}
+
+impl CheckedMul for Byte {
+    #[inline]
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        self.as_u128().checked_mul(v.as_u128()).and_then(Byte::from_u128)
+    }
+}
+
+impl SaturatingAdd for Byte {
+    #[inline]
+    fn saturating_add(&self, v: &Self) -> Self {
+        Byte::saturating_add(*self, *v)
+    }
+}
+
+impl SaturatingSub for Byte {
+    #[inline]
+    fn saturating_sub(&self, v: &Self) -> Self {
+        Byte::saturating_sub(*self, *v)
+    }
+}
+
+impl SaturatingMul for Byte {
+    #[inline]
+    fn saturating_mul(&self, v: &Self) -> Self {
+        CheckedMul::checked_mul(self, v).unwrap_or(Byte::MAX)
+    }
+}
+
+impl ToPrimitive for Byte {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.as_u128().to_i64()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.as_u128().to_u64()
+    }
+
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        self.as_u128().to_i128()
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        Some(self.as_u128())
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        self.as_u128().to_f64()
+    }
+}
+
+impl FromPrimitive for Byte {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        Byte::try_from(n).ok()
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Byte::from_u64(n))
+    }
+
+    #[inline]
+    fn from_i128(n: i128) -> Option<Self> {
+        Byte::try_from(n).ok()
+    }
+
+    #[inline]
+    fn from_u128(n: u128) -> Option<Self> {
+        Byte::from_u128(n)
+    }
+
+    /// The value is rounded up, like [`Byte::from_f64`](Byte::from_f64).
+    #[allow(deprecated)]
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        Byte::from_f64(n)
+    }
+}
@@ -0,0 +1,99 @@
+use rayon::prelude::*;
+
+use super::Byte;
+
+/// Statistics of a collection of `Byte` instances, generated from the [`Byte::par_stats`](./struct.Byte.html#method.par_stats) method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteStats {
+    /// The number of `Byte` instances.
+    pub count: usize,
+    /// The sum of all `Byte` instances.
+    pub total: Byte,
+    /// The smallest `Byte` instance.
+    pub min:   Byte,
+    /// The largest `Byte` instance.
+    pub max:   Byte,
+}
+
+impl ByteStats {
+    /// Get the arithmetic mean. The result will be rounded down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// let stats =
+    ///     Byte::par_stats(vec![Byte::from_u64(1), Byte::from_u64(2)]).unwrap();
+    ///
+    /// assert_eq!(1, stats.mean().as_u64());
+    /// ```
+    #[inline]
+    pub fn mean(&self) -> Byte {
+        self.total.divide(self.count).unwrap_or(Byte::MIN)
+    }
+}
+
+/// Associated functions for aggregating `Byte` instances in parallel.
+impl Byte {
+    /// Sum up `Byte` instances in parallel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// let sizes: Vec<Byte> = (1..=100u64).map(Byte::from_u64).collect();
+    ///
+    /// assert_eq!(5050, Byte::par_total(sizes).unwrap().as_u64());
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If the sum is too large, this function will return `None`.
+    #[inline]
+    pub fn par_total<I: IntoParallelIterator<Item = Byte>>(iter: I) -> Option<Byte> {
+        iter.into_par_iter().map(Some).reduce(|| Some(Byte::MIN), |a, b| a?.add(b?))
+    }
+
+    /// Compute the count, sum, minimum and maximum of `Byte` instances in parallel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// let sizes: Vec<Byte> = (1..=100u64).map(Byte::from_u64).collect();
+    ///
+    /// let stats = Byte::par_stats(sizes).unwrap();
+    ///
+    /// assert_eq!(100, stats.count);
+    /// assert_eq!(5050, stats.total.as_u64());
+    /// assert_eq!(1, stats.min.as_u64());
+    /// assert_eq!(100, stats.max.as_u64());
+    /// assert_eq!(50, stats.mean().as_u64());
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If there is no `Byte` instance or the sum is too large, this function will return `None`.
+    pub fn par_stats<I: IntoParallelIterator<Item = Byte>>(iter: I) -> Option<ByteStats> {
+        iter.into_par_iter()
+            .map(|byte| {
+                Some(ByteStats {
+                    count: 1, total: byte, min: byte, max: byte
+                })
+            })
+            .reduce_with(|a, b| {
+                let (a, b) = (a?, b?);
+
+                Some(ByteStats {
+                    count: a.count + b.count,
+                    total: a.total.add(b.total)?,
+                    min:   a.min.min(b.min),
+                    max:   a.max.max(b.max),
+                })
+            })
+            .flatten()
+    }
+}
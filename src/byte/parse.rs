@@ -1,15 +1,30 @@
+use core::ops::RangeBounds;
+
 use rust_decimal::prelude::*;
 
 use super::Byte;
-use crate::{common::get_char_from_bytes, unit::parse::read_xib, ParseError, ValueParseError};
+#[cfg(feature = "std")]
+use crate::{common::parse_lenient, ParseWarning};
+use crate::{
+    common::{
+        check_range, get_char_from_bytes, get_not_number_error, parse_value, read_space,
+        read_value_exponent,
+    },
+    unit::parse::{parse_integer_and_unit_const, read_xib},
+    ParseError, ParseOptions, RangeParseError, Unit, ValueParseError,
+};
 
 /// Associated functions for parsing strings.
 impl Byte {
     /// Create a new `Byte` instance from a string.
-    /// The string may be `"10"`, `"10B"`, `"10M"`, `"10MB"`, `"10MiB"`, `"80b"`, `"80Mb"`, `"80Mbit"`.
+    /// The string may be `"10"`, `"10B"`, `"10M"`, `"10MB"`, `"10MiB"`, `"80b"`, `"80Mb"`, `"80Mbit"`, or use the full name of the unit, such as `"10 megabytes"`.
     ///
     /// You can ignore the case of **"B"** (byte), which means **b** will still be treated as bytes instead of bits.
     ///
+    /// The value and the unit may be separated by spaces, no-break spaces (U+00A0) or thin spaces (U+2009). Negative values, including ones written with the minus sign (U+2212), are rejected with `ValueParseError::NotNumber('-')`.
+    ///
+    /// The value may be written in scientific notation, such as `"1.5e9"` or `"2E3 KiB"`. The exponent must follow the digits directly, so an `"E"` which is not followed by a digit (or a sign and a digit) is still the exa prefix, as in `"1EB"`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -21,20 +36,24 @@ impl Byte {
     /// # use byte_unit::Byte;
     /// let byte = Byte::parse_str("123Kib", false).unwrap(); // 123 * 1024 bits = 123 * 1024 / 8 bytes
     /// ```
+    #[inline]
     pub fn parse_str<S: AsRef<str>>(s: S, ignore_case: bool) -> Result<Self, ParseError> {
-        let s = s.as_ref().trim();
+        Self::parse_str_and_unit(s.as_ref(), ignore_case).map(|(byte, _)| byte)
+    }
+
+    /// Parse a string like [`Byte::parse_str`](#method.parse_str), and also return the unit written in it.
+    pub(crate) fn parse_str_and_unit(
+        s: &str,
+        ignore_case: bool,
+    ) -> Result<(Self, Unit), ParseError> {
+        let s = s.trim();
 
         let mut bytes = s.bytes();
 
         let mut value = match bytes.next() {
             Some(e) => match e {
                 b'0'..=b'9' => Decimal::from(e - b'0'),
-                _ => {
-                    return Err(ValueParseError::NotNumber(unsafe {
-                        get_char_from_bytes(e, bytes)
-                    })
-                    .into());
-                },
+                _ => return Err(get_not_number_error(e, bytes).into()),
             },
             None => return Err(ValueParseError::NoValue.into()),
         };
@@ -69,17 +88,17 @@ impl Byte {
                                     },
                                     _ => {
                                         if i == 1 {
-                                            return Err(ValueParseError::NotNumber(unsafe {
-                                                get_char_from_bytes(e, bytes)
-                                            })
+                                            return Err(ValueParseError::NotNumber(
+                                                get_char_from_bytes(e, bytes),
+                                            )
                                             .into());
                                         }
 
                                         match e {
-                                            b' ' => loop {
+                                            _ if read_space(e, &mut bytes) => loop {
                                                 match bytes.next() {
                                                     Some(e) => match e {
-                                                        b' ' => (),
+                                                        _ if read_space(e, &mut bytes) => (),
                                                         _ => break 'outer Some(e),
                                                     },
                                                     None => break 'outer None,
@@ -91,9 +110,9 @@ impl Byte {
                                 },
                                 None => {
                                     if i == 1 {
-                                        return Err(ValueParseError::NotNumber(unsafe {
-                                            get_char_from_bytes(e, bytes)
-                                        })
+                                        return Err(ValueParseError::NotNumber(
+                                            get_char_from_bytes(e, bytes),
+                                        )
                                         .into());
                                     }
 
@@ -102,10 +121,10 @@ impl Byte {
                             }
                         }
                     },
-                    b' ' => loop {
+                    _ if read_space(e, &mut bytes) => loop {
                         match bytes.next() {
                             Some(e) => match e {
-                                b' ' => (),
+                                _ if read_space(e, &mut bytes) => (),
                                 _ => break 'outer Some(e),
                             },
                             None => break 'outer None,
@@ -117,9 +136,379 @@ impl Byte {
             }
         };
 
+        let (value, e) = read_value_exponent(s, value, e, &mut bytes)?;
+
         let unit = read_xib(e, bytes, ignore_case, true)?;
 
+        match Self::from_decimal_with_unit(value, unit) {
+            Some(byte) => Ok((byte, unit)),
+            None => Err(ValueParseError::ExceededBounds(value).into()),
+        }
+    }
+
+    /// Create a new `Byte` instance from a value string and a unit string, which are parsed separately. See [`Byte::parse_str`](#method.parse_str).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// let byte = Byte::from_value_and_unit_str("1.5", "GiB", true).unwrap();
+    ///
+    /// assert_eq!(1610612736, byte.as_u64());
+    ///
+    /// let byte = Byte::from_value_and_unit_str("10", "", true).unwrap();
+    ///
+    /// assert_eq!(10, byte.as_u64());
+    /// ```
+    pub fn from_value_and_unit_str<V: AsRef<str>, U: AsRef<str>>(
+        value: V,
+        unit: U,
+        ignore_case: bool,
+    ) -> Result<Self, ParseError> {
+        let value = parse_value(value.as_ref())?;
+        let unit = Unit::parse_str(unit, ignore_case, true)?;
+
+        Self::from_decimal_with_unit(value, unit)
+            .ok_or_else(|| ValueParseError::ExceededBounds(value).into())
+    }
+}
+
+/// Associated functions for parsing strings with options.
+impl Byte {
+    /// Create a new `Byte` instance from a string with options, e.g. to accept digit group separators. See [`Byte::parse_str`](#method.parse_str) and [`ParseOptions`](struct.ParseOptions.html).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().underscores(true);
+    ///
+    /// let byte = Byte::parse_str_with_options("1_500_000 KB", &options).unwrap();
+    ///
+    /// assert_eq!(1_500_000_000, byte.as_u64());
+    /// ```
+    pub fn parse_str_with_options<S: AsRef<str>>(
+        s: S,
+        options: &ParseOptions,
+    ) -> Result<Self, ParseError> {
+        let s = s.as_ref().trim();
+
+        if !options.composite {
+            return Self::parse_component(s, options);
+        }
+
+        let mut total = Self::MIN;
+        let mut rest = s;
+
+        loop {
+            let (component, next) = options.split_component(rest);
+
+            let size = Self::parse_component(component, options)?;
+
+            total = total.checked_add(size).ok_or_else(|| {
+                ValueParseError::ExceededBounds(
+                    Decimal::from(total.as_u128()) + Decimal::from(size.as_u128()),
+                )
+            })?;
+
+            if next.is_empty() {
+                return Ok(total);
+            }
+
+            rest = next;
+        }
+    }
+
+    /// Parse a single value with its unit, which has been trimmed.
+    fn parse_component(s: &str, options: &ParseOptions) -> Result<Self, ParseError> {
+        let (value, unit) = options.split_value(s);
+
+        let value = options.parse_value(value, s)?;
+        let unit = options.mode.apply(Unit::parse_str(unit, options.ignore_case, true)?, unit);
+
         Self::from_decimal_with_unit(value, unit)
             .ok_or_else(|| ValueParseError::ExceededBounds(value).into())
     }
 }
+
+/// Associated functions for parsing strings with bounds.
+impl Byte {
+    /// Create a new `Byte` instance from a string, and check that it is in the given range. See [`Byte::parse_str`](#method.parse_str).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, RangeParseError};
+    ///
+    /// let range = Byte::KIBIBYTE..=Byte::from_u64(10 << 30); // 1 KiB to 10 GiB
+    ///
+    /// assert_eq!(
+    ///     Byte::from_u64(64 << 20),
+    ///     Byte::parse_str_in_range("64 MiB", true, range.clone()).unwrap()
+    /// );
+    ///
+    /// let error = Byte::parse_str_in_range("512 B", true, range).unwrap_err();
+    ///
+    /// assert!(matches!(error, RangeParseError::TooSmall { .. }));
+    /// assert_eq!("512 B is less than the minimum of 1 KiB", error.to_string());
+    /// ```
+    pub fn parse_str_in_range<S: AsRef<str>, R: RangeBounds<Byte>>(
+        s: S,
+        ignore_case: bool,
+        range: R,
+    ) -> Result<Self, RangeParseError<Self>> {
+        check_range(Self::parse_str(s, ignore_case)?, range)
+    }
+}
+
+/// Associated functions for parsing strings in const contexts.
+impl Byte {
+    /// Create a new `Byte` instance from a string of ASCII digits, in bytes. This function can be used in const contexts, e.g. with a string from the `env!` macro.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// const CACHE_SIZE: Byte = match Byte::parse_ascii_u64(b"1048576") {
+    ///     Some(byte) => byte,
+    ///     None => panic!("invalid cache size"),
+    /// };
+    ///
+    /// assert_eq!(1048576, CACHE_SIZE.as_u64());
+    ///
+    /// assert_eq!(None, Byte::parse_ascii_u64(b""));
+    /// assert_eq!(None, Byte::parse_ascii_u64(b"1 MiB"));
+    /// assert_eq!(None, Byte::parse_ascii_u64(b"18446744073709551616"));
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * Only the digits `0` to `9` are accepted. Signs, spaces, fractions and units are not.
+    /// * If the value is larger than **2<sup>64</sup> - 1**, this function will return `None`.
+    pub const fn parse_ascii_u64(bytes: &[u8]) -> Option<Self> {
+        if bytes.is_empty() {
+            return None;
+        }
+
+        let mut value = 0u64;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let e = bytes[i];
+
+            if !e.is_ascii_digit() {
+                return None;
+            }
+
+            value = match value.checked_mul(10) {
+                Some(v) => match v.checked_add((e - b'0') as u64) {
+                    Some(v) => v,
+                    None => return None,
+                },
+                None => return None,
+            };
+
+            i += 1;
+        }
+
+        Some(Self::from_u64(value))
+    }
+
+    /// Create a new `Byte` instance from a string with an integer value and an optional unit, in const contexts, so that sizes in code need neither runtime parsing nor magic numbers.
+    ///
+    /// The unit must be written in the same way as [`Unit::as_str`](enum.Unit.html#method.as_str), e.g. `"KB"`, `"MiB"` or `"Mb"` (megabits), and the value and the unit may be separated by spaces. Without a unit, the value is in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// const MAX_UPLOAD: Byte = Byte::parse_str_const("250 MiB");
+    ///
+    /// assert_eq!(262144000, MAX_UPLOAD.as_u64());
+    /// assert_eq!(1000, Byte::parse_str_const("8Kb").as_u64());
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use byte_unit::Byte;
+    ///
+    /// const MAX_UPLOAD: Byte = Byte::parse_str_const("2.5 MiB");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string is invalid or the size exceeds `Byte::MAX`. In const contexts, it is a compile error instead.
+    pub const fn parse_str_const(s: &str) -> Self {
+        match parse_integer_and_unit_const(s, Unit::B) {
+            Some((value, unit)) => match Self::from_u128_with_unit(value, unit) {
+                Some(byte) => byte,
+                None => panic!("the size exceeds `Byte::MAX`"),
+            },
+            None => panic!(
+                "the size should be an integer followed by an optional unit, such as \"250 MiB\""
+            ),
+        }
+    }
+}
+
+/// Associated functions for parsing HTTP header values.
+impl Byte {
+    /// Create a new `Byte` instance from the value of a `Content-Length` header field, following the rules of [RFC 9110](https://www.rfc-editor.org/rfc/rfc9110#name-content-length).
+    ///
+    /// Unlike [`Byte::parse_str`](#method.parse_str), only decimal digits are accepted, apart from optional spaces and horizontal tabs around them. Signs, fractions, units and comma-separated lists (e.g. `"42, 42"`) are all rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// assert_eq!(1024, Byte::parse_content_length("1024").unwrap().as_u64());
+    /// assert_eq!(1024, Byte::parse_content_length(" 1024\t").unwrap().as_u64());
+    ///
+    /// assert!(Byte::parse_content_length("+1024").is_err());
+    /// assert!(Byte::parse_content_length("1 KB").is_err());
+    /// assert!(Byte::parse_content_length("1024, 1024").is_err());
+    /// assert!(Byte::parse_content_length(
+    ///     "99999999999999999999999999999999999999999"
+    /// )
+    /// .is_err());
+    /// ```
+    pub fn parse_content_length<S: AsRef<str>>(header_value: S) -> Result<Self, ParseError> {
+        let s = header_value.as_ref().trim_matches(|c| c == ' ' || c == '\t');
+
+        let mut bytes = s.bytes();
+
+        let mut value = match bytes.next() {
+            Some(e) => match e {
+                b'0'..=b'9' => (e - b'0') as u128,
+                _ => return Err(get_not_number_error(e, bytes).into()),
+            },
+            None => return Err(ValueParseError::NoValue.into()),
+        };
+
+        while let Some(e) = bytes.next() {
+            match e {
+                b'0'..=b'9' => {
+                    value = value
+                        .checked_mul(10)
+                        .and_then(|v| v.checked_add((e - b'0') as u128))
+                        .ok_or(ValueParseError::NumberTooLong)?;
+                },
+                _ => return Err(ValueParseError::NotNumber(get_char_from_bytes(e, bytes)).into()),
+            }
+        }
+
+        Self::from_u128(value).ok_or_else(|| match Decimal::from_u128(value) {
+            Some(value) => ValueParseError::ExceededBounds(value).into(),
+            None => ValueParseError::NumberTooLong.into(),
+        })
+    }
+}
+
+/// Associated functions for parsing multiple strings.
+#[cfg(feature = "std")]
+impl Byte {
+    /// Create new `Byte` instances from a slice of strings. See [`Byte::parse_str`](#method.parse_str).
+    ///
+    /// If the `rayon` feature is enabled, the strings are parsed in parallel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use byte_unit::Byte;
+    /// let bytes = Byte::parse_many(&["1 KB", "2KiB", "3"], true).unwrap();
+    ///
+    /// assert_eq!(
+    ///     vec![1000, 2048, 3],
+    ///     bytes.iter().map(|b| b.as_u64()).collect::<Vec<_>>()
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// # use byte_unit::Byte;
+    /// let (index, _error) =
+    ///     Byte::parse_many(&["1 KB", "2 KiB", "3 XB"], true).unwrap_err();
+    ///
+    /// assert_eq!(2, index);
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If any string cannot be parsed, this function will return the index of the first invalid string along with its error.
+    pub fn parse_many<S: AsRef<str> + Sync>(
+        strings: &[S],
+        ignore_case: bool,
+    ) -> Result<Vec<Self>, (usize, ParseError)> {
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+
+            let results: Vec<Result<Self, ParseError>> =
+                strings.par_iter().map(|s| Self::parse_str(s, ignore_case)).collect();
+
+            results.into_iter().enumerate().map(|(i, result)| result.map_err(|e| (i, e))).collect()
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            let mut bytes = Vec::with_capacity(strings.len());
+
+            for (i, s) in strings.iter().enumerate() {
+                bytes.push(Self::parse_str(s, ignore_case).map_err(|e| (i, e))?);
+            }
+
+            Ok(bytes)
+        }
+    }
+}
+
+/// Associated functions for parsing strings leniently.
+#[cfg(feature = "std")]
+impl Byte {
+    /// Create a new `Byte` instance from a string, fixing minor mistakes and reporting what was fixed. See [`Byte::parse_str`](#method.parse_str).
+    ///
+    /// The following mistakes can be fixed:
+    ///
+    /// * Stray whitespaces inside the value or the unit, e.g. `"1 5 M B"`.
+    /// * A trailing period, e.g. `"15 MB."`.
+    /// * A repeated unit, e.g. `"15 MB MB"`.
+    ///
+    /// If the string cannot be parsed even after fixing, no warnings are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, ParseWarning};
+    ///
+    /// assert_eq!(
+    ///     (Some(Byte::from_u64(15000000)), vec![]),
+    ///     Byte::parse_str_lenient("15 MB", true)
+    /// );
+    ///
+    /// assert_eq!(
+    ///     (Some(Byte::from_u64(15000000)), vec![
+    ///         ParseWarning::TrailingPeriodRemoved
+    ///     ]),
+    ///     Byte::parse_str_lenient("15 MB.", true)
+    /// );
+    ///
+    /// assert_eq!(
+    ///     (Some(Byte::from_u64(15000000)), vec![
+    ///         ParseWarning::WhitespaceRemoved,
+    ///         ParseWarning::DoubledUnitRemoved
+    ///     ]),
+    ///     Byte::parse_str_lenient("1 5 MB MB", true)
+    /// );
+    ///
+    /// assert_eq!((None, vec![]), Byte::parse_str_lenient("fifteen MB", true));
+    /// ```
+    pub fn parse_str_lenient<S: AsRef<str>>(
+        s: S,
+        ignore_case: bool,
+    ) -> (Option<Self>, Vec<ParseWarning>) {
+        parse_lenient(s.as_ref(), |s| Self::parse_str(s, ignore_case))
+    }
+}
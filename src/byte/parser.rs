@@ -0,0 +1,130 @@
+use super::Byte;
+use crate::ParseError;
+#[cfg(feature = "locale")]
+use crate::{common::parse_value_with_decimal_separator, Locale, Unit, ValueParseError};
+
+/// A reusable parser of `Byte` instances, configured once and used for many strings.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{Byte, ByteParser};
+///
+/// let parser = ByteParser::new().ignore_case(true);
+///
+/// assert_eq!(Byte::from_u64(15_000_000), parser.parse("15 mb").unwrap());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ByteParser {
+    ignore_case: bool,
+    #[cfg(feature = "locale")]
+    locale:      Locale,
+}
+
+impl Default for ByteParser {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ByteParser {
+    /// Create a new `ByteParser` instance which parses strings in the same way as [`Byte::parse_str`](struct.Byte.html#method.parse_str) without ignoring case.
+    #[inline]
+    #[rustfmt::skip]
+    pub const fn new() -> Self {
+        Self {
+            ignore_case: false,
+            #[cfg(feature = "locale")]
+            locale:      Locale::En,
+        }
+    }
+
+    /// Set whether to ignore the case of **"B"** (byte). See [`Byte::parse_str`](struct.Byte.html#method.parse_str).
+    #[inline]
+    pub const fn ignore_case(mut self, ignore_case: bool) -> Self {
+        self.ignore_case = ignore_case;
+
+        self
+    }
+
+    /// Set the locale of the strings. The default value is `Locale::En`.
+    ///
+    /// With a locale other than `Locale::En`, the value must use the decimal separator of the locale, and the unit may be written with a symbol of the locale as well as a symbol of this crate. If `ignore_case` is enabled, the symbols of the locale are matched case-insensitively, e.g. `"Мб"` is treated as `"МБ"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, ByteParser, Locale};
+    ///
+    /// let parser = ByteParser::new().with_locale(Locale::Fr);
+    ///
+    /// assert_eq!(Byte::from_u64(1_500_000_000), parser.parse("1,5 Go").unwrap());
+    /// assert_eq!(Byte::from_u64(1_500_000_000), parser.parse("1,5 GB").unwrap());
+    /// assert!(parser.parse("1.5 Go").is_err());
+    /// ```
+    #[cfg(feature = "locale")]
+    #[inline]
+    pub const fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+
+        self
+    }
+
+    /// Create a new `Byte` instance from a string.
+    pub fn parse<S: AsRef<str>>(&self, s: S) -> Result<Byte, ParseError> {
+        #[cfg(feature = "locale")]
+        if self.locale != Locale::En {
+            return self.parse_localized(s.as_ref());
+        }
+
+        Byte::parse_str(s, self.ignore_case)
+    }
+
+    #[cfg(feature = "locale")]
+    fn parse_localized(&self, s: &str) -> Result<Byte, ParseError> {
+        let s = s.trim();
+
+        let decimal_separator = self.locale.decimal_separator();
+
+        let value_length =
+            s.find(|c: char| !c.is_ascii_digit() && c != decimal_separator).unwrap_or(s.len());
+
+        // let the whole string explain why there is no value, e.g. a negative sign
+        let value = if value_length == 0 { s } else { &s[..value_length] };
+
+        let value = parse_value_with_decimal_separator(value, decimal_separator as u8)?;
+
+        let unit = s[value_length..].trim_start_matches([' ', '\u{a0}', '\u{2009}', '\u{202f}']);
+
+        let unit = self.parse_localized_unit(unit)?;
+
+        Byte::from_decimal_with_unit(value, unit)
+            .ok_or_else(|| ValueParseError::ExceededBounds(value).into())
+    }
+
+    #[cfg(feature = "locale")]
+    fn parse_localized_unit(&self, s: &str) -> Result<Unit, ParseError> {
+        let matches = |symbol: &str| {
+            if self.ignore_case {
+                symbol
+                    .chars()
+                    .flat_map(char::to_lowercase)
+                    .eq(s.chars().flat_map(char::to_lowercase))
+            } else {
+                symbol == s
+            }
+        };
+
+        let unit = [Unit::Bit, Unit::B]
+            .iter()
+            .chain(Unit::get_multiples())
+            .copied()
+            .find(|unit| matches(self.locale.unit_symbol(*unit)));
+
+        match unit {
+            Some(unit) => Ok(unit),
+            None => Ok(Unit::parse_str(s, self.ignore_case, true)?),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use polars_core::prelude::AnyValue;
+
+use super::Byte;
+use crate::AnyValueError;
+
+impl From<Byte> for AnyValue<'static> {
+    /// Convert a `Byte` instance to an `AnyValue::UInt64` value, or to an `AnyValue::Int128` value if the `u128` feature is enabled, because Polars has no 128-bit unsigned integer type. Either way, every value of a column has the same type.
+    #[inline]
+    fn from(byte: Byte) -> Self {
+        #[cfg(feature = "u128")]
+        {
+            AnyValue::Int128(byte.as_u128() as i128)
+        }
+
+        #[cfg(not(feature = "u128"))]
+        {
+            AnyValue::UInt64(byte.as_u64())
+        }
+    }
+}
+
+impl TryFrom<AnyValue<'_>> for Byte {
+    type Error = AnyValueError;
+
+    /// Convert an integer `AnyValue` value to a `Byte` instance. Negative integers are not accepted, and values which are not integers, including `AnyValue::Null`, are rejected with `AnyValueError::InvalidType`.
+    #[inline]
+    fn try_from(value: AnyValue<'_>) -> Result<Self, Self::Error> {
+        let byte = match value {
+            AnyValue::UInt8(v) => Some(Byte::from_u64(v as u64)),
+            AnyValue::UInt16(v) => Some(Byte::from_u64(v as u64)),
+            AnyValue::UInt32(v) => Some(Byte::from_u64(v as u64)),
+            AnyValue::UInt64(v) => Some(Byte::from_u64(v)),
+            AnyValue::Int8(v) => Byte::from_i64(v as i64),
+            AnyValue::Int16(v) => Byte::from_i64(v as i64),
+            AnyValue::Int32(v) => Byte::from_i64(v as i64),
+            AnyValue::Int64(v) => Byte::from_i64(v),
+            AnyValue::Int128(v) => Byte::from_i128(v),
+            AnyValue::Null => return Err(AnyValueError::InvalidType("null")),
+            AnyValue::Boolean(_) => return Err(AnyValueError::InvalidType("a boolean")),
+            AnyValue::Float32(_) | AnyValue::Float64(_) => {
+                return Err(AnyValueError::InvalidType("a float"))
+            },
+            AnyValue::String(_) | AnyValue::StringOwned(_) => {
+                return Err(AnyValueError::InvalidType("a string"))
+            },
+            _ => return Err(AnyValueError::InvalidType("a value which is not an integer")),
+        };
+
+        byte.ok_or(AnyValueError::ExceededBounds)
+    }
+}
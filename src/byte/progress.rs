@@ -0,0 +1,99 @@
+use core::fmt::Write;
+
+use indicatif::{style::TemplateError, ProgressState, ProgressStyle};
+use rust_decimal::prelude::*;
+
+use super::Byte;
+use crate::{FormatOptions, UnitType};
+
+/// A helper for making the byte placeholders of [`indicatif`](https://docs.rs/indicatif) progress bars use the formatter of this crate.
+///
+/// The following placeholders of a template are replaced:
+///
+/// * `{bytes}`, the current position.
+/// * `{total_bytes}`, the length, or the current position if the length is unknown.
+/// * `{bytes_per_sec}`, the speed, followed by `/s`.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{ByteProgressStyle, FormatOptions, UnitType};
+/// use indicatif::ProgressBar;
+///
+/// let style = ByteProgressStyle::new()
+///     .unit_type(UnitType::Decimal)
+///     .options(FormatOptions::new().precision(1))
+///     .template("{bytes}/{total_bytes} ({bytes_per_sec})")
+///     .unwrap();
+///
+/// let bar = ProgressBar::hidden().with_style(style);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ByteProgressStyle {
+    unit_type: UnitType,
+    options:   FormatOptions,
+}
+
+impl Default for ByteProgressStyle {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ByteProgressStyle {
+    /// Create a new `ByteProgressStyle` instance with binary units and a precision of 2 digits.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            unit_type: UnitType::Binary, options: FormatOptions::new().precision(2)
+        }
+    }
+
+    /// Set which kind of units to choose from.
+    #[inline]
+    pub const fn unit_type(mut self, unit_type: UnitType) -> Self {
+        self.unit_type = unit_type;
+
+        self
+    }
+
+    /// Set the options for formatting the sizes.
+    #[inline]
+    pub const fn options(mut self, options: FormatOptions) -> Self {
+        self.options = options;
+
+        self
+    }
+
+    /// Replace the byte placeholders of an existing `ProgressStyle` instance.
+    pub fn apply(self, style: ProgressStyle) -> ProgressStyle {
+        style
+            .with_key("bytes", move |state: &ProgressState, w: &mut dyn Write| {
+                self.write_size(w, Byte::from_u64(state.pos()))
+            })
+            .with_key("total_bytes", move |state: &ProgressState, w: &mut dyn Write| {
+                self.write_size(w, Byte::from_u64(state.len().unwrap_or_else(|| state.pos())))
+            })
+            .with_key("bytes_per_sec", move |state: &ProgressState, w: &mut dyn Write| {
+                let per_sec = Decimal::from_f64(state.per_sec()).unwrap_or(Decimal::ZERO);
+
+                self.write_size(w, Byte::from_decimal(per_sec).unwrap_or(Byte::MIN));
+
+                let _ = w.write_str("/s");
+            })
+    }
+
+    /// Create a new `ProgressStyle` instance for a progress bar from a template, with the byte placeholders replaced.
+    #[inline]
+    pub fn template(self, template: &str) -> Result<ProgressStyle, TemplateError> {
+        Ok(self.apply(ProgressStyle::default_bar().template(template)?))
+    }
+
+    #[inline]
+    fn write_size(&self, w: &mut dyn Write, byte: Byte) {
+        let adjusted_byte = byte.get_appropriate_unit(self.unit_type);
+
+        let _ = w.write_fmt(format_args!("{}", adjusted_byte.display_with(self.options)));
+    }
+}
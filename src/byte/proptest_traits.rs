@@ -0,0 +1,47 @@
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    prop_oneof,
+    strategy::{BoxedStrategy, Just, Strategy},
+};
+
+use super::{AdjustedByte, Byte};
+use crate::Unit;
+
+impl Arbitrary for Byte {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Byte>;
+
+    /// Generate boundary values (`0`, `1`, `Byte::MAX - 1` and `Byte::MAX`), exact multiples of units (e.g. `5 MiB`), and any values in the full range.
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        let max = Byte::MAX.as_u128();
+
+        prop_oneof![
+            prop_oneof![Just(0), Just(1), Just(max - 1), Just(max)],
+            (any::<Unit>(), 0..=1024u128).prop_map(move |(unit, n)| {
+                let factor = match unit {
+                    Unit::Bit => 1,
+                    _ => unit.as_bytes_u128(),
+                };
+
+                match factor.checked_mul(n) {
+                    Some(size) if size <= max => size,
+                    _ => factor,
+                }
+            }),
+            0..=max,
+        ]
+        // the size is not greater than `max`
+        .prop_map(Byte::from_u128_unchecked)
+        .boxed()
+    }
+}
+
+impl Arbitrary for AdjustedByte {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<AdjustedByte>;
+
+    #[inline]
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        (any::<Byte>(), any::<Unit>()).prop_map(|(byte, unit)| byte.get_adjusted_unit(unit)).boxed()
+    }
+}
@@ -0,0 +1,125 @@
+#[cfg(feature = "std")]
+use std::string::String;
+
+use super::Byte;
+use crate::{
+    common::{get_char_from_bytes, parse_value},
+    ParseError, Unit, UnitParseError, ValueParseError,
+};
+
+#[cfg(feature = "u128")]
+const QUOTA_UNITS: [(char, Unit); 8] = [
+    ('Y', Unit::YiB),
+    ('Z', Unit::ZiB),
+    ('E', Unit::EiB),
+    ('P', Unit::PiB),
+    ('T', Unit::TiB),
+    ('G', Unit::GiB),
+    ('M', Unit::MiB),
+    ('K', Unit::KiB),
+];
+
+#[cfg(not(feature = "u128"))]
+const QUOTA_UNITS: [(char, Unit); 6] = [
+    ('E', Unit::EiB),
+    ('P', Unit::PiB),
+    ('T', Unit::TiB),
+    ('G', Unit::GiB),
+    ('M', Unit::MiB),
+    ('K', Unit::KiB),
+];
+
+#[cfg(feature = "u128")]
+const QUOTA_UNIT_CHARACTERS: &[char] = &['K', 'M', 'G', 'T', 'P', 'E', 'Z', 'Y'];
+
+#[cfg(not(feature = "u128"))]
+const QUOTA_UNIT_CHARACTERS: &[char] = &['K', 'M', 'G', 'T', 'P', 'E'];
+
+/// Associated functions for the quota syntax of ZFS and XFS.
+impl Byte {
+    /// Create a new `Byte` instance from a string in the quota syntax of ZFS and XFS, such as `"500G"` or `"1.5T"`.
+    ///
+    /// The value can be followed by a single case-insensitive letter, which is always based on **1024**. Unlike [`Byte::parse_str`](#method.parse_str), the `"B"`, `"i"` and `"bit"` suffixes, and spaces between the value and the letter are not allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// assert_eq!(536870912000, Byte::parse_quota_str("500G").unwrap().as_u64());
+    /// assert_eq!(1649267441664, Byte::parse_quota_str("1.5t").unwrap().as_u64());
+    /// assert_eq!(1024, Byte::parse_quota_str("1024").unwrap().as_u64());
+    ///
+    /// assert!(Byte::parse_quota_str("500GB").is_err());
+    /// assert!(Byte::parse_quota_str("500 G").is_err());
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * The calculated byte will be rounded up.
+    pub fn parse_quota_str<S: AsRef<str>>(s: S) -> Result<Self, ParseError> {
+        let s = s.as_ref();
+
+        let (value, suffix) =
+            s.split_at(s.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(s.len()));
+
+        let value = parse_value(value)?;
+
+        let mut bytes = suffix.bytes();
+
+        let unit = match bytes.next() {
+            Some(e) => {
+                let unit = QUOTA_UNITS
+                    .iter()
+                    .find(|(c, _)| *c as u8 == e.to_ascii_uppercase())
+                    .map(|(_, unit)| *unit)
+                    .ok_or_else(|| UnitParseError {
+                        character:                get_char_from_bytes(e, bytes.clone()),
+                        expected_characters:      QUOTA_UNIT_CHARACTERS,
+                        also_expect_no_character: true,
+                    })?;
+
+                if let Some(e) = bytes.next() {
+                    return Err(UnitParseError {
+                        character:                get_char_from_bytes(e, bytes),
+                        expected_characters:      &[],
+                        also_expect_no_character: true,
+                    }
+                    .into());
+                }
+
+                unit
+            },
+            None => Unit::B,
+        };
+
+        Self::from_decimal_with_unit(value, unit)
+            .ok_or_else(|| ValueParseError::ExceededBounds(value).into())
+    }
+
+    /// Format this `Byte` instance in the quota syntax of ZFS and XFS, using the largest letter which can represent the size exactly as an integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Byte;
+    ///
+    /// assert_eq!("500G", Byte::from_u64(536870912000).to_quota_string());
+    /// assert_eq!("1536G", Byte::from_u64(1649267441664).to_quota_string());
+    /// assert_eq!("1000", Byte::from_u64(1000).to_quota_string());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_quota_string(&self) -> String {
+        let bytes_v = self.as_u128();
+
+        for (c, unit) in QUOTA_UNITS.iter() {
+            let unit_v = unit.as_bytes_u128();
+
+            if bytes_v >= unit_v && bytes_v % unit_v == 0 {
+                return format!("{}{c}", bytes_v / unit_v);
+            }
+        }
+
+        format!("{bytes_v}")
+    }
+}
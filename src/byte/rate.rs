@@ -0,0 +1,511 @@
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    ops::{Div, Mul},
+    str::FromStr,
+    time::Duration,
+};
+
+use super::{AdjustedByte, Byte};
+use crate::{
+    common::div_ceil_u128,
+    rate::{split_rate, NANOS_PER_SEC},
+    OverheadProfile, RateParseError, RateUnitType, TimeUnit, Unit, UnitType,
+};
+
+/// Representing a rate in bytes, i.e. a size per a time unit, such as `12.5 MB/s`.
+///
+/// Rates with different time units are compared by their sizes per second, so `60 B/min` is equal to `1 B/s`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use byte_unit::{Byte, ByteRate, RateUnitType};
+///
+/// let rate = Byte::from_u64(25_000_000) / Duration::from_secs(2);
+///
+/// assert_eq!(ByteRate::parse_str("12.5 MB/s", false).unwrap(), rate);
+/// assert_eq!("12.5 MB/s", format!("{rate:#}"));
+/// assert_eq!(
+///     "100 Mb/s",
+///     rate.get_appropriate_unit(RateUnitType::DecimalBitsOnly).to_string()
+/// );
+///
+/// assert_eq!(Byte::from_u64(750_000_000), rate * Duration::from_secs(60));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ByteRate {
+    size:      Byte,
+    time_unit: TimeUnit,
+}
+
+/// Associated functions for building `ByteRate` instances.
+impl ByteRate {
+    /// Create a new `ByteRate` instance from a size and a time unit.
+    #[inline]
+    pub const fn new(size: Byte, time_unit: TimeUnit) -> Self {
+        Self {
+            size,
+            time_unit,
+        }
+    }
+
+    /// Create a new `ByteRate` instance from a size per second.
+    #[inline]
+    pub const fn per_second(size: Byte) -> Self {
+        Self::new(size, TimeUnit::Second)
+    }
+
+    /// Create a new `ByteRate` instance per second from a size transferred in a duration. The result is rounded up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use byte_unit::{Byte, ByteRate};
+    ///
+    /// assert_eq!(
+    ///     Some(ByteRate::per_second(Byte::from_u64(4))),
+    ///     ByteRate::from_duration(Byte::from_u64(10), Duration::from_secs(3))
+    /// );
+    /// assert_eq!(
+    ///     None,
+    ///     ByteRate::from_duration(Byte::from_u64(10), Duration::ZERO)
+    /// );
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If the duration is zero or the result exceeds `Byte::MAX`, this function will return `None`.
+    #[inline]
+    pub fn from_duration(size: Byte, duration: Duration) -> Option<Self> {
+        let nanos = duration.as_nanos();
+
+        if nanos == 0 {
+            return None;
+        }
+
+        let size = div_ceil_u128(size.as_u128().checked_mul(NANOS_PER_SEC)?, nanos);
+
+        Byte::from_u128(size).map(Self::per_second)
+    }
+}
+
+/// Methods for getting values.
+impl ByteRate {
+    /// Retrieve the size per time unit.
+    #[inline]
+    pub const fn get_size(self) -> Byte {
+        self.size
+    }
+
+    /// Retrieve the time unit.
+    #[inline]
+    pub const fn get_time_unit(self) -> TimeUnit {
+        self.time_unit
+    }
+
+    /// Retrieve the size per second as a floating-point number of bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, ByteRate, TimeUnit};
+    ///
+    /// let rate = ByteRate::new(Byte::from_u64(90), TimeUnit::Minute);
+    ///
+    /// assert_eq!(1.5, rate.as_f64_per_second());
+    /// ```
+    #[inline]
+    pub fn as_f64_per_second(self) -> f64 {
+        self.size.as_u128() as f64 / self.time_unit.as_secs() as f64
+    }
+}
+
+/// Methods for arithmetic with durations.
+impl ByteRate {
+    /// Calculate the size transferred in a duration at this rate. The result is rounded up. If it exceeds `Byte::MAX`, this method will return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use byte_unit::{Byte, ByteRate, TimeUnit};
+    ///
+    /// let rate = ByteRate::new(Byte::from_u64(3600), TimeUnit::Hour);
+    ///
+    /// assert_eq!(
+    ///     Some(Byte::from_u64(2)),
+    ///     rate.checked_mul_duration(Duration::from_millis(1500))
+    /// );
+    /// assert_eq!(
+    ///     None,
+    ///     ByteRate::per_second(Byte::MAX)
+    ///         .checked_mul_duration(Duration::from_secs(2))
+    /// );
+    /// ```
+    #[inline]
+    pub fn checked_mul_duration(self, duration: Duration) -> Option<Byte> {
+        let size = div_ceil_u128(
+            self.size.as_u128().checked_mul(duration.as_nanos())?,
+            self.time_unit.as_secs() as u128 * NANOS_PER_SEC,
+        );
+
+        Byte::from_u128(size)
+    }
+}
+
+/// Methods for protocol overhead.
+impl ByteRate {
+    /// Convert this line rate into the expected payload throughput (goodput) after the protocol overhead of `profile`. The result is rounded down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{ByteRate, OverheadProfile, RateUnitType};
+    ///
+    /// let line_rate = ByteRate::parse_str("1.25 GB/s", false).unwrap(); // 10 Gbps
+    /// let goodput = line_rate.apply_overhead(OverheadProfile::ETHERNET_IPV4_TCP);
+    ///
+    /// assert_eq!(
+    ///     "1.19 GB/s",
+    ///     format!("{:.2}", goodput.get_appropriate_unit(RateUnitType::Decimal))
+    /// );
+    /// ```
+    #[inline]
+    pub const fn apply_overhead(self, profile: OverheadProfile) -> Self {
+        Self::new(
+            Byte::from_u128_unchecked(profile.goodput_u128(self.size.as_u128())),
+            self.time_unit,
+        )
+    }
+
+    /// Convert this payload throughput into the line rate needed to carry it with the protocol overhead of `profile`. The result is rounded up. If it exceeds `Byte::MAX`, this method will return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, ByteRate, OverheadProfile};
+    ///
+    /// let goodput = ByteRate::parse_str("125 MB/s", false).unwrap();
+    ///
+    /// assert_eq!(
+    ///     Some(ByteRate::per_second(Byte::from_u64(131_678_083))),
+    ///     goodput.required_line_rate(OverheadProfile::ETHERNET_IPV4_TCP)
+    /// );
+    /// ```
+    #[inline]
+    pub const fn required_line_rate(self, profile: OverheadProfile) -> Option<Self> {
+        match profile.line_rate_u128(self.size.as_u128()) {
+            Some(size) => match Byte::from_u128(size) {
+                Some(size) => Some(Self::new(size, self.time_unit)),
+                None => None,
+            },
+            None => None,
+        }
+    }
+}
+
+/// Associated functions for parsing strings.
+impl ByteRate {
+    /// Create a new `ByteRate` instance from a string. The string consists of a size, which can be parsed by [`Byte::parse_str`](struct.Byte.html#method.parse_str), and a time unit after a slash (see [`TimeUnit::parse_str`](enum.TimeUnit.html#method.parse_str)), or a trailing **"ps"** which means per second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, ByteRate, TimeUnit};
+    ///
+    /// let rate = ByteRate::parse_str("12.5 MB/s", false).unwrap();
+    ///
+    /// assert_eq!(Byte::from_u64(12_500_000), rate.get_size());
+    /// assert_eq!(TimeUnit::Second, rate.get_time_unit());
+    /// ```
+    ///
+    /// ```
+    /// use byte_unit::{Byte, ByteRate};
+    ///
+    /// let rate = ByteRate::parse_str("100 Mbps", false).unwrap(); // 100 * 10^6 / 8 B/s
+    ///
+    /// assert_eq!(Byte::from_u64(12_500_000), rate.get_size());
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If `ignore_case` is `true`, **"b"** in the size means bytes, so `"100 Mbps"` is parsed as `100 MB/s`.
+    pub fn parse_str<S: AsRef<str>>(s: S, ignore_case: bool) -> Result<Self, RateParseError> {
+        let (size, time_unit) =
+            split_rate(s.as_ref(), ignore_case).ok_or(RateParseError::NoTimeUnit)?;
+
+        let time_unit = TimeUnit::parse_str(time_unit).ok_or(RateParseError::UnknownTimeUnit)?;
+
+        Ok(Self::new(Byte::parse_str(size, ignore_case)?, time_unit))
+    }
+}
+
+/// Methods for finding an appropriate unit.
+impl ByteRate {
+    /// Find the appropriate unit and value for the size of this `ByteRate` instance. The time unit is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, ByteRate, RateUnitType};
+    ///
+    /// let rate = ByteRate::per_second(Byte::from_u64(1_250_000));
+    ///
+    /// assert_eq!(
+    ///     "1.25 MB/s",
+    ///     rate.get_appropriate_unit(RateUnitType::Decimal).to_string()
+    /// );
+    /// assert_eq!(
+    ///     "10 Mb/s",
+    ///     rate.get_appropriate_unit(RateUnitType::DecimalBitsOnly).to_string()
+    /// );
+    /// ```
+    pub fn get_appropriate_unit(&self, rate_unit_type: RateUnitType) -> AdjustedByteRate {
+        let size = match rate_unit_type {
+            RateUnitType::DecimalBitsOnly => {
+                let bytes_v = self.size.as_u128();
+
+                let unit = UnitType::Decimal
+                    .find_largest_unit(Unit::get_multiples_bits(), |unit| {
+                        bytes_v >= unit.as_bytes_u128()
+                    })
+                    .unwrap_or(Unit::Bit);
+
+                self.size.get_adjusted_unit(unit)
+            },
+            _ => self.size.get_appropriate_unit(rate_unit_type.unit_type()),
+        };
+
+        AdjustedByteRate {
+            size,
+            time_unit: self.time_unit,
+        }
+    }
+}
+
+impl PartialEq for ByteRate {
+    #[inline]
+    fn eq(&self, other: &ByteRate) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for ByteRate {}
+
+impl PartialOrd for ByteRate {
+    #[inline]
+    fn partial_cmp(&self, other: &ByteRate) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ByteRate {
+    #[inline]
+    fn cmp(&self, other: &ByteRate) -> Ordering {
+        // cannot overflow, `Byte::MAX` is less than 2^90 and the time units are less than 2^17 seconds
+        let a = self.size.as_u128() * other.time_unit.as_secs() as u128;
+        let b = other.size.as_u128() * self.time_unit.as_secs() as u128;
+
+        a.cmp(&b)
+    }
+}
+
+impl Display for ByteRate {
+    /// Write the size in the same way as the `Display` implementation of `Byte`, followed by the time unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, ByteRate, TimeUnit};
+    ///
+    /// let rate = ByteRate::new(Byte::from_u64(1536), TimeUnit::Minute);
+    ///
+    /// assert_eq!("1536/min", rate.to_string());
+    /// assert_eq!("1.5 KiB/min", format!("{rate:#}"));
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.size, f)?;
+
+        f.write_fmt(format_args!("/{}", self.time_unit))
+    }
+}
+
+impl FromStr for ByteRate {
+    type Err = RateParseError;
+
+    /// `ignore_case` is set to `false`. See [`ByteRate::parse_str`](#method.parse_str).
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ByteRate::parse_str(s, false)
+    }
+}
+
+/// Methods for estimating transfers.
+#[cfg(feature = "std")]
+impl Byte {
+    /// Calculate the time needed to transfer this size at a rate in bits, e.g. for estimating the remaining time of a download. The result is rounded up to a nanosecond.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "bit")]
+    /// # {
+    /// use std::time::Duration;
+    ///
+    /// use byte_unit::{BitRate, Byte};
+    ///
+    /// let size = Byte::parse_str("1.5 GB", true).unwrap();
+    /// let rate = BitRate::parse_str("100 Mbps").unwrap();
+    ///
+    /// assert_eq!(Duration::from_secs(120), size.time_to_transfer(rate));
+    /// # }
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If the rate is zero or the result exceeds `Duration::MAX`, this method will return `Duration::MAX`.
+    #[cfg(feature = "bit")]
+    pub fn time_to_transfer(self, rate: crate::BitRate) -> Duration {
+        let rate_bits = rate.get_size().as_u128();
+
+        if rate_bits == 0 {
+            return Duration::MAX;
+        }
+
+        // cannot overflow, `Byte::MAX` is less than 2^90 and the time units are less than 2^17 seconds
+        let bits = (self.as_u128() << 3) * rate.get_time_unit().as_secs() as u128;
+
+        let secs = bits / rate_bits;
+        let nanos = div_ceil_u128((bits % rate_bits) * NANOS_PER_SEC, rate_bits);
+
+        match u64::try_from(secs) {
+            Ok(secs) => Duration::from_secs(secs)
+                .checked_add(Duration::from_nanos(nanos as u64))
+                .unwrap_or(Duration::MAX),
+            Err(_) => Duration::MAX,
+        }
+    }
+
+    /// Calculate the average rate per second of transferring this size in a duration, e.g. for showing the speed of a download. The result is rounded up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use byte_unit::{Byte, ByteRate};
+    ///
+    /// let size = Byte::from_u64(30_000_000);
+    ///
+    /// assert_eq!(
+    ///     ByteRate::per_second(Byte::from_u64(12_000_000)),
+    ///     size.rate_over(Duration::from_millis(2500))
+    /// );
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * Unlike dividing by a `Duration`, this method does not panic. If the duration is zero, or the result exceeds `Byte::MAX`, the result is saturated at `Byte::MAX` per second, unless this size is zero.
+    #[inline]
+    pub fn rate_over(self, duration: Duration) -> ByteRate {
+        match ByteRate::from_duration(self, duration) {
+            Some(rate) => rate,
+            None if self.as_u128() == 0 => ByteRate::per_second(self),
+            None => ByteRate::per_second(Byte::MAX),
+        }
+    }
+}
+
+impl Div<Duration> for Byte {
+    type Output = ByteRate;
+
+    /// See [`ByteRate::from_duration`](struct.ByteRate.html#method.from_duration).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the duration is zero or the result exceeds `Byte::MAX`.
+    #[inline]
+    fn div(self, rhs: Duration) -> ByteRate {
+        ByteRate::from_duration(self, rhs)
+            .expect("attempt to divide by a zero duration or overflow")
+    }
+}
+
+impl Mul<Duration> for ByteRate {
+    type Output = Byte;
+
+    /// See [`ByteRate::checked_mul_duration`](#method.checked_mul_duration).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result exceeds `Byte::MAX`.
+    #[inline]
+    fn mul(self, rhs: Duration) -> Byte {
+        self.checked_mul_duration(rhs).expect("attempt to multiply with overflow")
+    }
+}
+
+impl Mul<ByteRate> for Duration {
+    type Output = Byte;
+
+    /// See [`ByteRate::checked_mul_duration`](struct.ByteRate.html#method.checked_mul_duration).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result exceeds `Byte::MAX`.
+    #[inline]
+    fn mul(self, rhs: ByteRate) -> Byte {
+        rhs * self
+    }
+}
+
+/// The size of a `ByteRate` instance with an appropriate unit, created by [`ByteRate::get_appropriate_unit`](struct.ByteRate.html#method.get_appropriate_unit).
+#[derive(Debug, Clone, Copy)]
+pub struct AdjustedByteRate {
+    size:      AdjustedByte,
+    time_unit: TimeUnit,
+}
+
+/// Methods for getting values.
+impl AdjustedByteRate {
+    /// Retrieve the adjusted size per time unit.
+    #[inline]
+    pub const fn get_size(&self) -> AdjustedByte {
+        self.size
+    }
+
+    /// Retrieve the time unit.
+    #[inline]
+    pub const fn get_time_unit(&self) -> TimeUnit {
+        self.time_unit
+    }
+}
+
+impl Display for AdjustedByteRate {
+    /// Write the size in the same way as the `Display` implementation of `AdjustedByte`, followed by the time unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, ByteRate, UnitType};
+    ///
+    /// let rate = ByteRate::per_second(Byte::from_u64(10000));
+    ///
+    /// let adjusted_rate = rate.get_appropriate_unit(UnitType::Binary.into());
+    ///
+    /// assert_eq!("9.77 KiB/s", format!("{adjusted_rate:.2}"));
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.size, f)?;
+
+        f.write_fmt(format_args!("/{}", self.time_unit))
+    }
+}
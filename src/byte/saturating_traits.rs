@@ -0,0 +1,35 @@
+use core::num::Saturating;
+
+use super::Byte;
+use crate::ExceededBoundsError;
+
+impl TryFrom<Saturating<u128>> for Byte {
+    type Error = ExceededBoundsError;
+
+    #[inline]
+    fn try_from(value: Saturating<u128>) -> Result<Self, Self::Error> {
+        Byte::try_from(value.0)
+    }
+}
+
+impl From<Saturating<u64>> for Byte {
+    #[inline]
+    fn from(value: Saturating<u64>) -> Self {
+        Byte::from_u64(value.0)
+    }
+}
+
+impl From<Byte> for Saturating<u128> {
+    #[inline]
+    fn from(byte: Byte) -> Self {
+        Saturating(byte.as_u128())
+    }
+}
+
+impl From<Byte> for Saturating<u64> {
+    /// See [`Byte::as_u64`](#method.as_u64).
+    #[inline]
+    fn from(byte: Byte) -> Self {
+        Saturating(byte.as_u64())
+    }
+}
@@ -0,0 +1,23 @@
+use alloc::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use super::Byte;
+use crate::serde::SIZE_PATTERN;
+
+impl JsonSchema for Byte {
+    #[inline]
+    fn schema_name() -> Cow<'static, str> {
+        "Byte".into()
+    }
+
+    #[inline]
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": SIZE_PATTERN,
+            "description": "A size in bytes, written as a non-negative number optionally followed by a unit, such as \"1.5 GiB\" or \"500 MB\". A number without a unit is in bytes.",
+            "examples": ["1.5 GiB", "500 MB", "4096"]
+        })
+    }
+}
@@ -1,3 +1,4 @@
+use alloc::string::String;
 use core::{
     fmt::{self, Formatter},
     str::FromStr,
@@ -5,13 +6,17 @@ use core::{
 
 use serde::{
     self,
-    de::{Error as DeError, Unexpected, Visitor},
+    de::{Error as DeError, MapAccess, SeqAccess, Unexpected, Visitor},
+    ser::SerializeStruct,
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
-use super::Byte;
 #[cfg(feature = "u128")]
 use super::RONNABYTE;
+use super::{BoundedByte, Byte, Inventory};
+use crate::serde::{invalid_str, Canonical};
+
+const INVENTORY_FIELDS: &[&str] = &["objects", "bytes"];
 
 impl Serialize for Byte {
     #[inline]
@@ -19,7 +24,7 @@ impl Serialize for Byte {
     where
         S: Serializer, {
         if serializer.is_human_readable() {
-            serializer.serialize_str(format!("{self:#}").as_str())
+            serializer.collect_str(&Canonical(*self))
         } else {
             serializer.serialize_u128(self.as_u128())
         }
@@ -54,6 +59,29 @@ impl<'de> Deserialize<'de> for Byte {
                 }
             }
 
+            #[inline]
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: DeError, {
+                Ok(Byte::from_u64(v))
+            }
+
+            #[inline]
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+            where
+                E: DeError, {
+                Byte::try_from(v).map_err(|_| DeError::invalid_value(Unexpected::Signed(v), &self))
+            }
+
+            #[inline]
+            fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+            where
+                E: DeError, {
+                Byte::try_from(v).map_err(|_| {
+                    DeError::invalid_value(Unexpected::Other(format!("{v}").as_str()), &self)
+                })
+            }
+
             #[inline]
             fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
             where
@@ -67,7 +95,7 @@ impl<'de> Deserialize<'de> for Byte {
             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
             where
                 E: DeError, {
-                Byte::from_str(v).map_err(DeError::custom)
+                Byte::from_str(v).map_err(|error| invalid_str(v, &self, error))
             }
         }
 
@@ -78,3 +106,103 @@ impl<'de> Deserialize<'de> for Byte {
         }
     }
 }
+
+impl<const MAX: u128> Serialize for BoundedByte<MAX> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer, {
+        self.get_byte().serialize(serializer)
+    }
+}
+
+impl<'de, const MAX: u128> Deserialize<'de> for BoundedByte<MAX> {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>, {
+        let byte = Byte::deserialize(deserializer)?;
+
+        BoundedByte::new(byte).ok_or_else(|| {
+            DeError::custom(format_args!(
+                "{byte:#} exceeds the maximum of {:#}",
+                BoundedByte::<MAX>::MAX.get_byte()
+            ))
+        })
+    }
+}
+
+impl Serialize for Inventory {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer, {
+        let mut state = serializer.serialize_struct("Inventory", INVENTORY_FIELDS.len())?;
+
+        state.serialize_field("objects", &self.objects)?;
+        state.serialize_field("bytes", &self.bytes)?;
+
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Inventory {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>, {
+        struct MyVisitor;
+
+        impl<'de> Visitor<'de> for MyVisitor {
+            type Value = Inventory;
+
+            #[inline]
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("an object such as { \"objects\": 2, \"bytes\": \"4 KiB\" }")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>, {
+                let objects =
+                    seq.next_element()?.ok_or_else(|| DeError::invalid_length(0, &self))?;
+                let bytes = seq.next_element()?.ok_or_else(|| DeError::invalid_length(1, &self))?;
+
+                Ok(Inventory::new(objects, bytes))
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>, {
+                let mut objects = None;
+                let mut bytes = None;
+
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "objects" => {
+                            if objects.is_some() {
+                                return Err(DeError::duplicate_field("objects"));
+                            }
+
+                            objects = Some(map.next_value()?);
+                        },
+                        "bytes" => {
+                            if bytes.is_some() {
+                                return Err(DeError::duplicate_field("bytes"));
+                            }
+
+                            bytes = Some(map.next_value()?);
+                        },
+                        _ => return Err(DeError::unknown_field(key.as_str(), INVENTORY_FIELDS)),
+                    }
+                }
+
+                let objects = objects.ok_or_else(|| DeError::missing_field("objects"))?;
+                let bytes = bytes.ok_or_else(|| DeError::missing_field("bytes"))?;
+
+                Ok(Inventory::new(objects, bytes))
+            }
+        }
+
+        deserializer.deserialize_struct("Inventory", INVENTORY_FIELDS, MyVisitor)
+    }
+}
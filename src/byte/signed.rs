@@ -0,0 +1,306 @@
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    ops::Neg,
+    str::FromStr,
+};
+
+use super::Byte;
+use crate::{format::fmt_signed_with_unit, ExceededBoundsError, ParseError, UnitPreference};
+
+/// Representing a signed difference of sizes in bytes, e.g. the growth of a directory.
+///
+/// The absolute value is never greater than `Byte::MAX`, so every `Byte` instance can be converted into a `SignedByte` instance, and so can the difference of any two `Byte` instances.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{Byte, SignedByte};
+///
+/// let before = Byte::from_u64(1_500_000_000);
+/// let after = Byte::from_u64(1_150_000_000);
+///
+/// let delta = after.signed_sub(before);
+///
+/// assert_eq!(-350_000_000, delta.as_i128());
+/// assert_eq!("-350 MB", format!("{delta:#}"));
+/// assert_eq!("+350 MB", format!("{:#}", -delta));
+/// ```
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Default)]
+pub struct SignedByte(i128);
+
+/// Constants of `SignedByte`.
+impl SignedByte {
+    /// `Byte::MAX`.
+    pub const MAX: SignedByte = SignedByte(Byte::MAX.as_u128() as i128);
+    /// The negative of `Byte::MAX`.
+    pub const MIN: SignedByte = SignedByte(-(Byte::MAX.as_u128() as i128));
+    /// 0 byte.
+    pub const ZERO: SignedByte = SignedByte(0);
+}
+
+/// Associated functions for building `SignedByte` instances.
+impl SignedByte {
+    /// Create a new `SignedByte` instance from a size in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, SignedByte};
+    ///
+    /// assert_eq!(Some(SignedByte::ZERO), SignedByte::from_i128(0));
+    /// assert_eq!(None, SignedByte::from_i128(i128::MIN));
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If the absolute value of the input is greater than `Byte::MAX`, this function will return `None`.
+    #[inline]
+    pub const fn from_i128(size: i128) -> Option<Self> {
+        if size.unsigned_abs() <= Byte::MAX.as_u128() {
+            Some(Self(size))
+        } else {
+            None
+        }
+    }
+
+    /// Create a new `SignedByte` instance from a size in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::SignedByte;
+    ///
+    /// assert_eq!(-15, SignedByte::from_i64(-15).as_i128());
+    /// ```
+    #[inline]
+    pub const fn from_i64(size: i64) -> Self {
+        Self(size as i128)
+    }
+
+    /// Create a new `SignedByte` instance from a `Byte` instance and a sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, SignedByte};
+    ///
+    /// assert_eq!(
+    ///     SignedByte::from_i64(-15),
+    ///     SignedByte::from_byte(Byte::from_u64(15), true)
+    /// );
+    /// ```
+    #[inline]
+    pub const fn from_byte(byte: Byte, negative: bool) -> Self {
+        let size = byte.as_u128() as i128;
+
+        if negative {
+            Self(-size)
+        } else {
+            Self(size)
+        }
+    }
+}
+
+/// Methods for converting a `SignedByte` instance into a primitive integer or a `Byte` instance.
+impl SignedByte {
+    /// Retrieve the size in bytes.
+    #[inline]
+    pub const fn as_i128(self) -> i128 {
+        self.0
+    }
+
+    /// Retrieve the absolute value as a `Byte` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, SignedByte};
+    ///
+    /// assert_eq!(Byte::from_u64(15), SignedByte::from_i64(-15).abs());
+    /// ```
+    #[inline]
+    pub const fn abs(self) -> Byte {
+        match Byte::from_u128(self.0.unsigned_abs()) {
+            Some(byte) => byte,
+            None => unreachable!(),
+        }
+    }
+
+    /// Retrieve the sign, **-1**, **0** or **1**.
+    #[inline]
+    pub const fn signum(self) -> i8 {
+        self.0.signum() as i8
+    }
+
+    /// Whether the size is less than zero.
+    #[inline]
+    pub const fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    /// Whether the size is greater than zero.
+    #[inline]
+    pub const fn is_positive(self) -> bool {
+        self.0 > 0
+    }
+}
+
+/// Methods for arithmetic with the same semantics as primitive integers.
+impl SignedByte {
+    /// Add another `SignedByte` instance. If the absolute value of the result is greater than `Byte::MAX`, this function will return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::SignedByte;
+    ///
+    /// assert_eq!(
+    ///     Some(SignedByte::from_i64(-5)),
+    ///     SignedByte::from_i64(10).checked_add(SignedByte::from_i64(-15))
+    /// );
+    /// assert_eq!(None, SignedByte::MAX.checked_add(SignedByte::from_i64(1)));
+    /// ```
+    #[inline]
+    pub const fn checked_add(self, rhs: SignedByte) -> Option<SignedByte> {
+        // cannot overflow, the absolute values are less than 2^127 - 1
+        Self::from_i128(self.0 + rhs.0)
+    }
+
+    /// Subtract another `SignedByte` instance. If the absolute value of the result is greater than `Byte::MAX`, this function will return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::SignedByte;
+    ///
+    /// assert_eq!(
+    ///     Some(SignedByte::from_i64(25)),
+    ///     SignedByte::from_i64(10).checked_sub(SignedByte::from_i64(-15))
+    /// );
+    /// assert_eq!(None, SignedByte::MIN.checked_sub(SignedByte::from_i64(1)));
+    /// ```
+    #[inline]
+    pub const fn checked_sub(self, rhs: SignedByte) -> Option<SignedByte> {
+        Self::from_i128(self.0 - rhs.0)
+    }
+}
+
+/// Associated functions for parsing strings.
+impl SignedByte {
+    /// Create a new `SignedByte` instance from a string. The string may start with a sign, **"-"** (or **"−"**) or **"+"**, followed by a string which can be parsed by [`Byte::parse_str`](struct.Byte.html#method.parse_str).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::SignedByte;
+    ///
+    /// let delta = SignedByte::parse_str("-350 MB", false).unwrap(); // -350 * 10^6 B
+    ///
+    /// assert_eq!(-350_000_000, delta.as_i128());
+    /// ```
+    ///
+    /// ```
+    /// use byte_unit::SignedByte;
+    ///
+    /// let delta = SignedByte::parse_str("+2 KiB", false).unwrap(); // 2 * 2^10 B
+    ///
+    /// assert_eq!(2048, delta.as_i128());
+    /// ```
+    pub fn parse_str<S: AsRef<str>>(s: S, ignore_case: bool) -> Result<Self, ParseError> {
+        let s = s.as_ref().trim();
+
+        let (negative, s) = match s.strip_prefix(['-', '\u{2212}']) {
+            Some(s) => (true, s),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        Byte::parse_str(s, ignore_case).map(|byte| Self::from_byte(byte, negative))
+    }
+}
+
+impl Display for SignedByte {
+    /// Write the size with an explicit sign, **"+"** or **"-"**, unless it is zero. The flags work in the same way as the `Display` implementation of `Byte`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let sign = match self.0.cmp(&0) {
+            Ordering::Less => "-",
+            Ordering::Equal => "",
+            Ordering::Greater => "+",
+        };
+
+        if f.alternate() {
+            let precision = f.precision().unwrap_or(3);
+
+            let (value, unit) = self.abs().get_recoverable_unit_with_preference(
+                false,
+                precision,
+                UnitPreference::global(),
+            );
+
+            let value = value.normalize();
+
+            fmt_signed_with_unit(f, sign, format_args!("{value}"), Some(unit))
+        } else {
+            fmt_signed_with_unit(f, sign, format_args!("{}", self.0.unsigned_abs()), None)
+        }
+    }
+}
+
+impl FromStr for SignedByte {
+    type Err = ParseError;
+
+    /// `ignore_case` is set to `false`. See [`SignedByte::parse_str`](#method.parse_str).
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SignedByte::parse_str(s, false)
+    }
+}
+
+impl Neg for SignedByte {
+    type Output = SignedByte;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl From<Byte> for SignedByte {
+    #[inline]
+    fn from(value: Byte) -> Self {
+        Self::from_byte(value, false)
+    }
+}
+
+impl TryFrom<SignedByte> for Byte {
+    type Error = ExceededBoundsError;
+
+    #[inline]
+    fn try_from(value: SignedByte) -> Result<Self, Self::Error> {
+        if value.is_negative() {
+            Err(ExceededBoundsError)
+        } else {
+            Ok(value.abs())
+        }
+    }
+}
+
+/// Methods for finding the difference between `Byte` instances.
+impl Byte {
+    /// Subtract another `Byte` instance, allowing the result to be negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, SignedByte};
+    ///
+    /// assert_eq!(
+    ///     SignedByte::from_i64(-512),
+    ///     Byte::from_u64(512).signed_sub(Byte::from_u64(1024))
+    /// );
+    /// ```
+    #[inline]
+    pub const fn signed_sub(self, rhs: Byte) -> SignedByte {
+        SignedByte(self.as_u128() as i128 - rhs.as_u128() as i128)
+    }
+}
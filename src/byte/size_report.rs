@@ -0,0 +1,136 @@
+use core::fmt::{self, Display, Formatter};
+use std::collections::BTreeMap;
+
+use super::{Byte, Inventory};
+use crate::{Unit, UnitType};
+
+/// The number and the total size of objects grouped by the appropriate units of their sizes, e.g. how many files are in bytes, in KiB, in MiB and so on, for a "size distribution by magnitude" report in a storage audit.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{Byte, SizeByUnitReport, Unit, UnitType};
+///
+/// let report = SizeByUnitReport::from_sizes(
+///     [100, 2048, 3072, 5 << 20].map(Byte::from_u64),
+///     UnitType::Binary,
+/// );
+///
+/// assert_eq!(2, report.get(Unit::KiB).objects);
+/// assert_eq!(Byte::from_u64(5120), report.get(Unit::KiB).bytes);
+///
+/// assert_eq!(
+///     "Unit    Objects        Total
+///    B          1      100   B
+///  KiB          2      5.0 KiB
+///  MiB          1      5.0 MiB",
+///     report.to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SizeByUnitReport {
+    unit_type: UnitType,
+    buckets:   BTreeMap<Unit, Inventory>,
+}
+
+/// Associated functions for building `SizeByUnitReport` instances.
+impl SizeByUnitReport {
+    /// Create a new empty `SizeByUnitReport` instance. Each size is grouped by its appropriate unit of `unit_type`. See [`Byte::get_appropriate_unit`](./struct.Byte.html#method.get_appropriate_unit).
+    #[inline]
+    pub const fn new(unit_type: UnitType) -> Self {
+        Self {
+            unit_type,
+            buckets: BTreeMap::new(),
+        }
+    }
+
+    /// Create a new `SizeByUnitReport` instance from sizes.
+    #[inline]
+    pub fn from_sizes<I: IntoIterator<Item = Byte>>(sizes: I, unit_type: UnitType) -> Self {
+        let mut report = Self::new(unit_type);
+
+        report.extend(sizes);
+
+        report
+    }
+}
+
+/// Methods for recording sizes.
+impl SizeByUnitReport {
+    /// Add an object of the given size to the group of its appropriate unit. The number of objects and the total size of a group saturate at their maximums.
+    #[inline]
+    pub fn record(&mut self, size: Byte) {
+        let unit = size.get_appropriate_unit(self.unit_type).get_unit();
+
+        let inventory = self.buckets.entry(unit).or_default();
+
+        *inventory = Inventory::new(
+            inventory.objects.saturating_add(1),
+            inventory.bytes.saturating_add(size),
+        );
+    }
+}
+
+/// Methods for getting values.
+impl SizeByUnitReport {
+    /// Retrieve the unit type by which the sizes are grouped.
+    #[inline]
+    pub const fn unit_type(&self) -> UnitType {
+        self.unit_type
+    }
+
+    /// Retrieve the objects in the group of a unit. If there are none, the `Inventory` instance is empty.
+    #[inline]
+    pub fn get(&self, unit: Unit) -> Inventory {
+        self.buckets.get(&unit).copied().unwrap_or_default()
+    }
+
+    /// Iterate over the groups which have objects, from the smallest unit to the largest one.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (Unit, Inventory)> + '_ {
+        self.buckets.iter().map(|(unit, inventory)| (*unit, *inventory))
+    }
+
+    /// Calculate the number and the total size of all objects, saturating at their maximums.
+    #[inline]
+    pub fn total(&self) -> Inventory {
+        self.buckets.values().fold(Inventory::default(), |total, inventory| {
+            Inventory::new(
+                total.objects.saturating_add(inventory.objects),
+                total.bytes.saturating_add(inventory.bytes),
+            )
+        })
+    }
+}
+
+impl Extend<Byte> for SizeByUnitReport {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Byte>>(&mut self, iter: I) {
+        for size in iter {
+            self.record(size);
+        }
+    }
+}
+
+impl FromIterator<Byte> for SizeByUnitReport {
+    /// `unit_type` is set to `UnitType::Binary`.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Byte>>(iter: I) -> Self {
+        Self::from_sizes(iter, UnitType::Binary)
+    }
+}
+
+impl Display for SizeByUnitReport {
+    /// Writes a table of the groups which have objects, with a header line. The total size of each group is written with one digit after the decimal point, in its appropriate unit, aligned like [`AdjustedByte::format_aligned`](./struct.AdjustedByte.html#method.format_aligned).
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("{:>4} {:>10} {:>12}", "Unit", "Objects", "Total"))?;
+
+        for (unit, inventory) in self.iter() {
+            let total = inventory.bytes.get_appropriate_unit(self.unit_type);
+
+            f.write_fmt(format_args!("\n{unit:>4} {:>10} {total:>+12.1}", inventory.objects))?;
+        }
+
+        Ok(())
+    }
+}
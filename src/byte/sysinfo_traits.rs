@@ -0,0 +1,63 @@
+use sysinfo::{Disk, System};
+
+use super::{Byte, FsUsage};
+
+/// The usage of memory, generated from a [`sysinfo::System`](https://docs.rs/sysinfo/latest/sysinfo/struct.System.html) instance.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::MemoryUsage;
+/// use sysinfo::System;
+///
+/// let mut system = System::new();
+///
+/// system.refresh_memory();
+///
+/// let memory = MemoryUsage::from(&system);
+///
+/// assert!(memory.used <= memory.total);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MemoryUsage {
+    /// The size of RAM.
+    pub total:      Byte,
+    /// The used RAM.
+    pub used:       Byte,
+    /// The free RAM, which does not include the memory which can be reclaimed.
+    pub free:       Byte,
+    /// The RAM available for starting new applications.
+    pub available:  Byte,
+    /// The size of swap.
+    pub total_swap: Byte,
+    /// The used swap.
+    pub used_swap:  Byte,
+}
+
+impl From<&System> for MemoryUsage {
+    #[inline]
+    fn from(system: &System) -> Self {
+        MemoryUsage {
+            total:      Byte::from_u64(system.total_memory()),
+            used:       Byte::from_u64(system.used_memory()),
+            free:       Byte::from_u64(system.free_memory()),
+            available:  Byte::from_u64(system.available_memory()),
+            total_swap: Byte::from_u64(system.total_swap()),
+            used_swap:  Byte::from_u64(system.used_swap()),
+        }
+    }
+}
+
+impl From<&Disk> for FsUsage {
+    /// `sysinfo` does not distinguish the free space from the available space, so both `free` and `available` are set to the available space.
+    #[inline]
+    fn from(disk: &Disk) -> Self {
+        let available = Byte::from_u64(disk.available_space());
+
+        FsUsage {
+            total: Byte::from_u64(disk.total_space()),
+            free: available,
+            available,
+        }
+    }
+}
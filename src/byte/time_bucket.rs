@@ -0,0 +1,153 @@
+use core::time::Duration;
+
+use super::Byte;
+use crate::{rate::NANOS_PER_SEC, TimeUnit};
+
+/// Accumulate sizes into `N` fixed time buckets, e.g. the bytes transferred in each of the last 60 seconds for a "bytes per interval" chart.
+///
+/// No clock is read by this struct. The time of each record is given by the caller as a `Duration` since any fixed point, such as `Instant::duration_since` of the start of the program, or a hardware tick counter on embedded targets.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use byte_unit::{Byte, TimeBucketedCounter, TimeUnit};
+///
+/// let mut counter =
+///     TimeBucketedCounter::<3>::with_time_unit(TimeUnit::Second);
+///
+/// counter.record(Duration::from_millis(100), Byte::from_u64(10));
+/// counter.record(Duration::from_millis(900), Byte::from_u64(20));
+/// counter.record(Duration::from_millis(2500), Byte::from_u64(5));
+///
+/// assert_eq!(
+///     &[Byte::from_u64(30), Byte::from_u64(0), Byte::from_u64(5)],
+///     counter.history()
+/// );
+///
+/// // two buckets roll over
+/// counter.advance(Duration::from_secs(4));
+///
+/// assert_eq!(
+///     &[Byte::from_u64(5), Byte::from_u64(0), Byte::from_u64(0)],
+///     counter.history()
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct TimeBucketedCounter<const N: usize> {
+    interval: u128,
+    buckets:  [Byte; N],
+    /// The index of the time interval of the last bucket, i.e. the time divided by the interval.
+    current:  u128,
+}
+
+/// Associated functions for building `TimeBucketedCounter` instances.
+impl<const N: usize> TimeBucketedCounter<N> {
+    /// Create a new `TimeBucketedCounter` instance whose buckets last for `interval`. All buckets are empty, and the last one is the bucket of the time zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero or `N` is zero.
+    #[inline]
+    pub const fn new(interval: Duration) -> Self {
+        let interval = interval.as_nanos();
+
+        assert!(interval > 0, "the interval must not be zero");
+        assert!(N > 0, "there must be at least one bucket");
+
+        Self {
+            interval,
+            buckets: [Byte::MIN; N],
+            current: 0,
+        }
+    }
+
+    /// Create a new `TimeBucketedCounter` instance whose buckets last for one time unit, e.g. per second or per minute.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    #[inline]
+    pub const fn with_time_unit(time_unit: TimeUnit) -> Self {
+        Self::new(Duration::from_secs(time_unit.as_secs()))
+    }
+}
+
+/// Methods for accumulating sizes.
+impl<const N: usize> TimeBucketedCounter<N> {
+    /// Roll the buckets over to the time interval of `now`. The buckets which fall out of the window are dropped, and empty buckets are added for the new intervals. A time before the last bucket changes nothing.
+    pub fn advance(&mut self, now: Duration) {
+        let index = now.as_nanos() / self.interval;
+
+        if index <= self.current {
+            return;
+        }
+
+        let elapsed = index - self.current;
+
+        if elapsed >= N as u128 {
+            self.buckets = [Byte::MIN; N];
+        } else {
+            // smaller than N
+            let elapsed = elapsed as usize;
+
+            self.buckets.rotate_left(elapsed);
+            self.buckets[N - elapsed..].fill(Byte::MIN);
+        }
+
+        self.current = index;
+    }
+
+    /// Add a size to the bucket of `now`, rolling the buckets over if needed. A size recorded a bit late still goes to its bucket if that is in the window, or is dropped otherwise. Each bucket saturates at `Byte::MAX`.
+    pub fn record(&mut self, now: Duration, size: Byte) {
+        self.advance(now);
+
+        let index = now.as_nanos() / self.interval;
+
+        let age = self.current - index;
+
+        if age < N as u128 {
+            let bucket = &mut self.buckets[N - 1 - age as usize];
+
+            *bucket = bucket.saturating_add(size);
+        }
+    }
+
+    /// Empty all the buckets, keeping the current time interval.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.buckets = [Byte::MIN; N];
+    }
+}
+
+/// Methods for getting values.
+impl<const N: usize> TimeBucketedCounter<N> {
+    /// Retrieve the duration of a bucket.
+    #[inline]
+    pub const fn interval(&self) -> Duration {
+        // the interval is created from a `Duration`
+        Duration::new(
+            (self.interval / NANOS_PER_SEC) as u64,
+            (self.interval % NANOS_PER_SEC) as u32,
+        )
+    }
+
+    /// Retrieve the buckets from the oldest to the current one. Call [`TimeBucketedCounter::advance`](#method.advance) first so that the buckets which are empty because nothing has been recorded recently are included.
+    #[inline]
+    pub const fn history(&self) -> &[Byte] {
+        &self.buckets
+    }
+
+    /// Retrieve the size of the current bucket.
+    #[inline]
+    pub const fn current(&self) -> Byte {
+        self.buckets[N - 1]
+    }
+
+    /// Calculate the sum of all the buckets, saturating at `Byte::MAX`.
+    #[inline]
+    pub fn total(&self) -> Byte {
+        Byte::saturating_sum(self.buckets)
+    }
+}
@@ -0,0 +1,342 @@
+//! Value parsers for [clap](https://docs.rs/clap), so command-line arguments can be parsed into `Byte`, `Bit` and `Unit` instances, optionally within a range.
+//!
+//! `Byte`, `Bit` and `Unit` also implement [`ValueParserFactory`](https://docs.rs/clap/latest/clap/builder/trait.ValueParserFactory.html), so `clap::value_parser!` and the derive API pick the parsers in this module automatically.
+//!
+//! ```
+//! use byte_unit::{clap::byte_in_range, Byte};
+//! use clap::{Arg, Command};
+//!
+//! let command = Command::new("app").arg(
+//!     Arg::new("buffer")
+//!         .long("buffer")
+//!         .value_parser(byte_in_range("1 KiB".."1 GiB")),
+//! );
+//!
+//! let matches = command
+//!     .clone()
+//!     .try_get_matches_from(["app", "--buffer", "64 MiB"])
+//!     .unwrap();
+//!
+//! assert_eq!(
+//!     Some(&Byte::from_u64(64 << 20)),
+//!     matches.get_one::<Byte>("buffer")
+//! );
+//!
+//! assert!(command
+//!     .try_get_matches_from(["app", "--buffer", "2 GiB"])
+//!     .is_err());
+//! ```
+
+#[cfg(any(feature = "byte", feature = "bit"))]
+use core::ops::{Bound, RangeBounds};
+use std::{ffi::OsStr, format, string::ToString};
+
+use ::clap::{builder::TypedValueParser, error::ErrorKind, Arg, Command, Error};
+
+#[cfg(feature = "bit")]
+use crate::Bit;
+#[cfg(feature = "byte")]
+use crate::Byte;
+use crate::Unit;
+
+/// Parse a bound of a range. The bounds are written by the developer, so they are expected to be valid.
+#[cfg(any(feature = "byte", feature = "bit"))]
+fn parse_bound<T: Copy, E: core::fmt::Debug>(
+    bound: Bound<&&str>,
+    parse: impl Fn(&str) -> Result<T, E>,
+) -> Bound<T> {
+    let parse = |s: &str| parse(s).unwrap_or_else(|error| panic!("invalid bound {s:?}: {error:?}"));
+
+    match bound {
+        Bound::Included(s) => Bound::Included(parse(s)),
+        Bound::Excluded(s) => Bound::Excluded(parse(s)),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+/// Retrieve the value as `&str`, or create an error for the invalid UTF-8.
+fn value_to_str<'a>(cmd: &Command, value: &'a OsStr) -> Result<&'a str, Error> {
+    value.to_str().ok_or_else(|| Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))
+}
+
+/// Create an error which tells why the value of the argument is invalid.
+fn invalid_value(
+    cmd: &Command,
+    arg: Option<&Arg>,
+    value: &str,
+    reason: impl core::fmt::Display,
+) -> Error {
+    let arg = arg.map(ToString::to_string).unwrap_or_else(|| "...".to_string());
+
+    Error::raw(
+        ErrorKind::ValueValidation,
+        format!("invalid value '{value}' for '{arg}': {reason}\n"),
+    )
+    .with_cmd(cmd)
+}
+
+/// A parser for `Byte` values, which can be restricted to a range.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{clap::ByteValueParser, Byte};
+/// use clap::{Arg, Command};
+///
+/// let command = Command::new("app").arg(
+///     Arg::new("limit")
+///         .long("limit")
+///         .value_parser(ByteValueParser::new().ignore_case(true)),
+/// );
+///
+/// let matches =
+///     command.try_get_matches_from(["app", "--limit", "10 mb"]).unwrap();
+///
+/// assert_eq!(
+///     Some(&Byte::from_u64(10_000_000)),
+///     matches.get_one::<Byte>("limit")
+/// );
+/// ```
+#[cfg(feature = "byte")]
+#[derive(Debug, Copy, Clone)]
+pub struct ByteValueParser {
+    ignore_case: bool,
+    min:         Bound<Byte>,
+    max:         Bound<Byte>,
+}
+
+#[cfg(feature = "byte")]
+impl ByteValueParser {
+    /// Create a new `ByteValueParser` instance which accepts any size. Like the `FromStr` implementation of `Byte`, `ignore_case` is `false` by default.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            ignore_case: false, min: Bound::Unbounded, max: Bound::Unbounded
+        }
+    }
+
+    /// Set whether to ignore the case of **"B"** (byte). See [`Byte::parse_str`](../struct.Byte.html#method.parse_str).
+    #[inline]
+    pub const fn ignore_case(mut self, ignore_case: bool) -> Self {
+        self.ignore_case = ignore_case;
+
+        self
+    }
+
+    /// Restrict the size to a range.
+    #[inline]
+    pub fn range<R: RangeBounds<Byte>>(mut self, range: R) -> Self {
+        self.min = range.start_bound().cloned();
+        self.max = range.end_bound().cloned();
+
+        self
+    }
+}
+
+#[cfg(feature = "byte")]
+impl Default for ByteValueParser {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "byte")]
+impl TypedValueParser for ByteValueParser {
+    type Value = Byte;
+
+    fn parse_ref(&self, cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<Byte, Error> {
+        let value = value_to_str(cmd, value)?;
+
+        Byte::parse_str_in_range(value, self.ignore_case, (self.min, self.max))
+            .map_err(|error| invalid_value(cmd, arg, value, error))
+    }
+}
+
+/// Create a `ByteValueParser` instance which only accepts sizes in the range. The bounds are parsed with `ignore_case` set to `false`, the same as the values by default.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::clap::byte_in_range;
+/// use clap::{Arg, Command};
+///
+/// let command = Command::new("app")
+///     .arg(Arg::new("cache").value_parser(byte_in_range("1 MiB"..="1 GiB")));
+///
+/// let error = command.try_get_matches_from(["app", "512 KiB"]).unwrap_err();
+///
+/// assert!(error
+///     .to_string()
+///     .contains("512 KiB is less than the minimum of 1 MiB"));
+/// ```
+///
+/// # Panics
+///
+/// Panics if a bound cannot be parsed.
+#[cfg(feature = "byte")]
+pub fn byte_in_range<R: RangeBounds<&'static str>>(range: R) -> ByteValueParser {
+    let parse = |s: &str| Byte::parse_str(s, false);
+
+    ByteValueParser::new()
+        .range((parse_bound(range.start_bound(), parse), parse_bound(range.end_bound(), parse)))
+}
+
+/// A parser for `Bit` values, which can be restricted to a range.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{clap::BitValueParser, Bit};
+/// use clap::{Arg, Command};
+///
+/// let command = Command::new("app").arg(
+///     Arg::new("speed").long("speed").value_parser(BitValueParser::new()),
+/// );
+///
+/// let matches =
+///     command.try_get_matches_from(["app", "--speed", "100 Mb"]).unwrap();
+///
+/// assert_eq!(
+///     Some(&Bit::from_u64(100_000_000)),
+///     matches.get_one::<Bit>("speed")
+/// );
+/// ```
+#[cfg(feature = "bit")]
+#[derive(Debug, Copy, Clone)]
+pub struct BitValueParser {
+    min: Bound<Bit>,
+    max: Bound<Bit>,
+}
+
+#[cfg(feature = "bit")]
+impl BitValueParser {
+    /// Create a new `BitValueParser` instance which accepts any size.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            min: Bound::Unbounded, max: Bound::Unbounded
+        }
+    }
+
+    /// Restrict the size to a range.
+    #[inline]
+    pub fn range<R: RangeBounds<Bit>>(mut self, range: R) -> Self {
+        self.min = range.start_bound().cloned();
+        self.max = range.end_bound().cloned();
+
+        self
+    }
+}
+
+#[cfg(feature = "bit")]
+impl Default for BitValueParser {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "bit")]
+impl TypedValueParser for BitValueParser {
+    type Value = Bit;
+
+    fn parse_ref(&self, cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<Bit, Error> {
+        let value = value_to_str(cmd, value)?;
+
+        Bit::parse_str_in_range(value, (self.min, self.max))
+            .map_err(|error| invalid_value(cmd, arg, value, error))
+    }
+}
+
+/// Create a `BitValueParser` instance which only accepts sizes in the range.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::clap::bit_in_range;
+/// use clap::{Arg, Command};
+///
+/// let command = Command::new("app")
+///     .arg(Arg::new("speed").value_parser(bit_in_range("1 Mb"..)));
+///
+/// assert!(command.try_get_matches_from(["app", "100 Kb"]).is_err());
+/// ```
+///
+/// # Panics
+///
+/// Panics if a bound cannot be parsed.
+#[cfg(feature = "bit")]
+pub fn bit_in_range<R: RangeBounds<&'static str>>(range: R) -> BitValueParser {
+    let parse = |s: &str| Bit::parse_str(s);
+
+    BitValueParser::new()
+        .range((parse_bound(range.start_bound(), parse), parse_bound(range.end_bound(), parse)))
+}
+
+/// A parser for `Unit` values.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{clap::UnitValueParser, Unit};
+/// use clap::{Arg, Command};
+///
+/// let command = Command::new("app").arg(
+///     Arg::new("unit").long("unit").value_parser(UnitValueParser::new()),
+/// );
+///
+/// let matches =
+///     command.try_get_matches_from(["app", "--unit", "MiB"]).unwrap();
+///
+/// assert_eq!(Some(&Unit::MiB), matches.get_one::<Unit>("unit"));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct UnitValueParser {
+    ignore_case: bool,
+    prefer_byte: bool,
+}
+
+impl UnitValueParser {
+    /// Create a new `UnitValueParser` instance. Like the `FromStr` implementation of `Unit`, `ignore_case` is `false` and `prefer_byte` is `true` by default.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            ignore_case: false, prefer_byte: true
+        }
+    }
+
+    /// Set whether to ignore the case of **"B"** (byte). See [`Unit::parse_str`](../enum.Unit.html#method.parse_str).
+    #[inline]
+    pub const fn ignore_case(mut self, ignore_case: bool) -> Self {
+        self.ignore_case = ignore_case;
+
+        self
+    }
+
+    /// Set whether the base is **"B"** (byte) if it is not explicit. See [`Unit::parse_str`](../enum.Unit.html#method.parse_str).
+    #[inline]
+    pub const fn prefer_byte(mut self, prefer_byte: bool) -> Self {
+        self.prefer_byte = prefer_byte;
+
+        self
+    }
+}
+
+impl Default for UnitValueParser {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TypedValueParser for UnitValueParser {
+    type Value = Unit;
+
+    fn parse_ref(&self, cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<Unit, Error> {
+        let value = value_to_str(cmd, value)?;
+
+        Unit::parse_str(value, self.ignore_case, self.prefer_byte)
+            .map_err(|error| invalid_value(cmd, arg, value, error))
+    }
+}
@@ -0,0 +1,177 @@
+//! Helpers for fitting sizes into the ranges accepted by cloud providers.
+
+use crate::{common::div_ceil_u128, Byte, RoundingMode};
+
+/// A range of sizes accepted by a provider, where a valid size is a multiple of `step` between `min` and `max` (inclusive).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SizeConstraint {
+    /// The smallest accepted size.
+    pub min:  Byte,
+    /// The largest accepted size.
+    pub max:  Byte,
+    /// Every accepted size must be a multiple of this, which must not be zero.
+    pub step: Byte,
+}
+
+impl SizeConstraint {
+    /// Get the smallest valid size which is not less than `size`. Returns `None` if `size` is larger than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{cloud::EBS_GP3_VOLUME, Byte};
+    ///
+    /// assert_eq!(
+    ///     Some(Byte::from_u64(2 << 30)),
+    ///     EBS_GP3_VOLUME.allocate(Byte::from_u64(1500 << 20))
+    /// );
+    /// assert_eq!(
+    ///     Some(Byte::from_u64(1 << 30)),
+    ///     EBS_GP3_VOLUME.allocate(Byte::MIN)
+    /// );
+    /// assert_eq!(None, EBS_GP3_VOLUME.allocate(Byte::from_u64(17 << 40)));
+    /// ```
+    #[inline]
+    pub const fn allocate(self, size: Byte) -> Option<Byte> {
+        let size = if size.as_u128() < self.min.as_u128() { self.min } else { size };
+
+        match RoundingMode::Up.round_u128(size.as_u128(), self.step.as_u128()) {
+            Some(v) if v <= self.max.as_u128() => Byte::from_u128(v),
+            _ => None,
+        }
+    }
+
+    /// Round `size` to a multiple of `step` with the given rounding mode, and clamp the result into this range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{cloud::EBS_GP3_VOLUME, Byte, RoundingMode};
+    ///
+    /// assert_eq!(
+    ///     Byte::from_u64(1 << 30),
+    ///     EBS_GP3_VOLUME.clamp(Byte::from_u64(1500 << 20), RoundingMode::Down)
+    /// );
+    /// assert_eq!(
+    ///     Byte::from_u64(16 << 40),
+    ///     EBS_GP3_VOLUME.clamp(Byte::from_u64(17 << 40), RoundingMode::Nearest)
+    /// );
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * If `min` or `max` is not a multiple of `step`, it is used as is.
+    #[inline]
+    pub const fn clamp(self, size: Byte, rounding_mode: RoundingMode) -> Byte {
+        let v = match rounding_mode.round_u128(size.as_u128(), self.step.as_u128()) {
+            Some(v) => v,
+            None => return self.max,
+        };
+
+        if v < self.min.as_u128() {
+            self.min
+        } else if v > self.max.as_u128() {
+            self.max
+        } else {
+            match Byte::from_u128(v) {
+                Some(byte) => byte,
+                None => self.max,
+            }
+        }
+    }
+
+    /// Check whether `size` is accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{cloud::S3_MULTIPART_PART, Byte};
+    ///
+    /// assert!(S3_MULTIPART_PART.contains(Byte::from_u64(8 << 20)));
+    /// assert!(!S3_MULTIPART_PART.contains(Byte::from_u64(4 << 20)));
+    /// ```
+    #[inline]
+    pub const fn contains(self, size: Byte) -> bool {
+        let v = size.as_u128();
+
+        v >= self.min.as_u128() && v <= self.max.as_u128() && v % self.step.as_u128() == 0
+    }
+}
+
+/// The sizes of Amazon EBS `gp2` and `gp3` volumes: 1 GiB to 16 TiB, in whole GiB.
+pub const EBS_GP3_VOLUME: SizeConstraint =
+    SizeConstraint {
+        min: Byte::GIBIBYTE, max: Byte::from_u64(16 << 40), step: Byte::GIBIBYTE
+    };
+
+/// The sizes of Amazon EBS `io1` and `io2` volumes: 4 GiB to 16 TiB, in whole GiB.
+pub const EBS_IO2_VOLUME: SizeConstraint = SizeConstraint {
+    min:  Byte::from_u64(4 << 30),
+    max:  Byte::from_u64(16 << 40),
+    step: Byte::GIBIBYTE,
+};
+
+/// The sizes of Amazon EBS `st1` and `sc1` volumes: 125 GiB to 16 TiB, in whole GiB.
+pub const EBS_HDD_VOLUME: SizeConstraint = SizeConstraint {
+    min:  Byte::from_u64(125 << 30),
+    max:  Byte::from_u64(16 << 40),
+    step: Byte::GIBIBYTE,
+};
+
+/// The part sizes of an Amazon S3 multipart upload chosen by this module: 5 MiB to 5 GiB, in whole MiB. Only the last part of an upload may be smaller.
+pub const S3_MULTIPART_PART: SizeConstraint = SizeConstraint {
+    min:  Byte::from_u64(5 << 20),
+    max:  Byte::from_u64(5 << 30),
+    step: Byte::MEBIBYTE,
+};
+
+/// The maximum number of parts of an Amazon S3 multipart upload.
+pub const S3_MULTIPART_MAX_PARTS: u64 = 10_000;
+
+/// Get the size of the smallest `gp3` EBS volume that can hold `requested` bytes. Returns `None` if no volume is large enough.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{cloud::ebs_volume_size, Byte};
+///
+/// assert_eq!(
+///     Some(Byte::from_u64(1 << 30)),
+///     ebs_volume_size(Byte::from_u64(1))
+/// );
+/// assert_eq!(
+///     Some(Byte::from_u64(10 << 30)),
+///     ebs_volume_size(Byte::from_u64(10_000_000_000))
+/// );
+/// ```
+#[inline]
+pub const fn ebs_volume_size(requested: Byte) -> Option<Byte> {
+    EBS_GP3_VOLUME.allocate(requested)
+}
+
+/// Get the smallest part size for uploading an object of `object_size` bytes to Amazon S3 in at most [`S3_MULTIPART_MAX_PARTS`] parts. Returns `None` if the object is too large.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{cloud::s3_multipart_part_size, Byte};
+///
+/// assert_eq!(
+///     Some(Byte::from_u64(5 << 20)),
+///     s3_multipart_part_size(Byte::from_u64(1 << 30))
+/// );
+/// assert_eq!(
+///     Some(Byte::from_u64(105 << 20)),
+///     s3_multipart_part_size(Byte::from_u64(1 << 40))
+/// );
+/// assert_eq!(None, s3_multipart_part_size(Byte::from_u64(60 << 40)));
+/// ```
+#[inline]
+pub const fn s3_multipart_part_size(object_size: Byte) -> Option<Byte> {
+    let parts = S3_MULTIPART_MAX_PARTS as u128;
+
+    match Byte::from_u128(div_ceil_u128(object_size.as_u128(), parts)) {
+        Some(part_size) => S3_MULTIPART_PART.allocate(part_size),
+        None => None,
+    }
+}
@@ -0,0 +1,98 @@
+//! [`nom`](https://docs.rs/nom) parsers for embedding sizes in larger grammars.
+//!
+//! The parsers consume a value, optional spaces and an optional unit, and leave the rest of the input untouched.
+//!
+//! # Examples
+//!
+//! ```
+//! use byte_unit::{combinators::byte_parser, Byte};
+//! use nom::{
+//!     bytes::complete::tag, character::complete::alpha1,
+//!     sequence::separated_pair, IResult,
+//! };
+//!
+//! fn entry(input: &str) -> IResult<&str, (&str, Byte)> {
+//!     separated_pair(alpha1, tag("="), byte_parser(true))(input)
+//! }
+//!
+//! let (rest, (key, limit)) = entry("limit=1GiB;burst=2GiB").unwrap();
+//!
+//! assert_eq!("limit", key);
+//! assert_eq!(1073741824, limit.as_u64());
+//! assert_eq!(";burst=2GiB", rest);
+//! ```
+
+use nom::{
+    error::{ErrorKind, ParseError},
+    Err, IResult,
+};
+
+#[cfg(feature = "bit")]
+use crate::Bit;
+use crate::{common::scan_size, Byte};
+
+#[inline]
+fn scan<'a, E: ParseError<&'a str>>(input: &'a str) -> Result<(&'a str, &'a str), Err<E>> {
+    let (value_length, size_length) = scan_size(input.as_bytes());
+
+    if value_length == 0 {
+        return Err(Err::Error(E::from_error_kind(input, ErrorKind::Digit)));
+    }
+
+    Ok((&input[size_length..], &input[..size_length]))
+}
+
+/// Create a parser which recognizes a size and parses it by [`Byte::parse_str`](../struct.Byte.html#method.parse_str).
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::combinators::byte_parser;
+/// use nom::IResult;
+///
+/// let result: IResult<&str, _> = byte_parser(true)("15 MB, 20 MB");
+/// let (rest, byte) = result.unwrap();
+///
+/// assert_eq!(15000000, byte.as_u64());
+/// assert_eq!(", 20 MB", rest);
+/// ```
+#[inline]
+pub fn byte_parser<'a, E: ParseError<&'a str>>(
+    ignore_case: bool,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Byte, E> {
+    move |input: &'a str| {
+        let (rest, s) = scan(input)?;
+
+        match Byte::parse_str(s, ignore_case) {
+            Ok(byte) => Ok((rest, byte)),
+            Err(_) => Err(Err::Error(E::from_error_kind(input, ErrorKind::Verify))),
+        }
+    }
+}
+
+/// Create a parser which recognizes a size and parses it by [`Bit::parse_str`](../struct.Bit.html#method.parse_str).
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::combinators::bit_parser;
+/// use nom::IResult;
+///
+/// let result: IResult<&str, _> = bit_parser()("100 Mbit/s");
+/// let (rest, bit) = result.unwrap();
+///
+/// assert_eq!(100000000, bit.as_u64());
+/// assert_eq!("/s", rest);
+/// ```
+#[cfg(feature = "bit")]
+#[inline]
+pub fn bit_parser<'a, E: ParseError<&'a str>>() -> impl FnMut(&'a str) -> IResult<&'a str, Bit, E> {
+    move |input: &'a str| {
+        let (rest, s) = scan(input)?;
+
+        match Bit::parse_str(s) {
+            Ok(bit) => Ok((rest, bit)),
+            Err(_) => Err(Err::Error(E::from_error_kind(input, ErrorKind::Verify))),
+        }
+    }
+}
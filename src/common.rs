@@ -1,24 +1,416 @@
+#[cfg(any(feature = "byte", feature = "bit"))]
+use core::ops::{Bound, RangeBounds};
 use core::str::Bytes;
 
 #[cfg(any(feature = "byte", feature = "bit"))]
 use rust_decimal::prelude::*;
 
-/// # Safety
-/// Make sure the input is valid on your own.
-pub(crate) unsafe fn get_char_from_bytes(e: u8, mut bytes: Bytes) -> char {
-    let width = utf8_width::get_width_assume_valid(e);
+#[cfg(all(any(feature = "byte", feature = "bit"), feature = "std"))]
+use crate::ParseWarning;
+#[cfg(any(feature = "byte", feature = "bit"))]
+use crate::{RangeParseError, ValueParseError};
+
+/// Decode the character starting with the byte `e`, reading its continuation bytes from `bytes`.
+///
+/// Returns `char::REPLACEMENT_CHARACTER` if the bytes are not a valid UTF-8 sequence.
+pub(crate) fn get_char_from_bytes(e: u8, mut bytes: impl Iterator<Item = u8>) -> char {
+    let width = utf8_width::get_width(e);
+
+    if width == 0 {
+        return char::REPLACEMENT_CHARACTER;
+    }
 
     let mut char_bytes = [0; 4];
 
     char_bytes[0] = e;
 
-    if width > 1 {
-        for e in char_bytes[1..].iter_mut().take(width - 1) {
-            *e = bytes.next().unwrap();
+    for e in char_bytes[1..width].iter_mut() {
+        match bytes.next() {
+            Some(b) => *e = b,
+            None => return char::REPLACEMENT_CHARACTER,
+        }
+    }
+
+    match core::str::from_utf8(&char_bytes[..width]) {
+        Ok(s) => s.chars().next().unwrap_or(char::REPLACEMENT_CHARACTER),
+        Err(_) => char::REPLACEMENT_CHARACTER,
+    }
+}
+
+/// Check whether the byte `e` starts a space character (`' '`, U+00A0 no-break space or U+2009 thin space). If it does, the rest of the character is consumed from `bytes`.
+#[cfg(any(feature = "byte", feature = "bit"))]
+pub(crate) fn read_space<I: Iterator<Item = u8> + Clone>(e: u8, bytes: &mut I) -> bool {
+    let rest: &[u8] = match e {
+        b' ' => return true,
+        0xC2 => &[0xA0],
+        0xE2 => &[0x80, 0x89],
+        _ => return false,
+    };
+
+    let mut peek = bytes.clone();
+
+    if rest.iter().all(|e| peek.next() == Some(*e)) {
+        *bytes = peek;
+
+        true
+    } else {
+        false
+    }
+}
+
+/// Scan a size, a value optionally followed by spaces and a unit, at the beginning of `bytes`. The value follows the grammar of `parse_str`, including an exponent of scientific notation. Returns the length of the value and the length of the whole size. Spaces which are not followed by a unit are excluded.
+#[cfg(feature = "byte")]
+pub(crate) fn scan_size(bytes: &[u8]) -> (usize, usize) {
+    let length = bytes.len();
+
+    let mut i = 0;
+
+    while i < length && bytes[i].is_ascii_digit() {
+        i += 1;
+    }
+
+    if i > 0 && i + 1 < length && bytes[i] == b'.' && bytes[i + 1].is_ascii_digit() {
+        i += 1;
+
+        while i < length && bytes[i].is_ascii_digit() {
+            i += 1;
         }
     }
 
-    char::from_u32_unchecked(u32::from_ne_bytes(char_bytes))
+    // an exponent of scientific notation, such as the "e9" of "1.5e9", but not the "E" of "1EB"
+    if i > 0 && i + 1 < length && matches!(bytes[i], b'e' | b'E') {
+        let digits = if matches!(bytes[i + 1], b'+' | b'-') { i + 2 } else { i + 1 };
+
+        if digits < length && bytes[digits].is_ascii_digit() {
+            i = digits + 1;
+
+            while i < length && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+        }
+    }
+
+    let value_length = i;
+
+    if value_length == 0 {
+        return (0, 0);
+    }
+
+    while i < length {
+        let mut rest = bytes[i + 1..].iter().copied();
+
+        if !read_space(bytes[i], &mut rest) {
+            break;
+        }
+
+        i = length - rest.len();
+    }
+
+    let unit_start = i;
+
+    while i < length && bytes[i].is_ascii_alphabetic() {
+        i += 1;
+    }
+
+    if i == unit_start {
+        (value_length, value_length)
+    } else {
+        (value_length, i)
+    }
+}
+
+/// Parse a non-negative decimal number such as `"15"` or `"1.5"`.
+#[cfg(any(feature = "byte", feature = "bit"))]
+#[inline]
+pub(crate) fn parse_value(s: &str) -> Result<Decimal, ValueParseError> {
+    parse_value_with_decimal_separator(s, b'.')
+}
+
+/// Parse a non-negative decimal number whose fractional part is separated by `decimal_separator`, such as `"1,5"` with `b','`.
+#[cfg(any(feature = "byte", feature = "bit"))]
+#[inline]
+pub(crate) fn parse_value_with_decimal_separator(
+    s: &str,
+    decimal_separator: u8,
+) -> Result<Decimal, ValueParseError> {
+    parse_value_from_bytes(s.trim().bytes(), decimal_separator)
+}
+
+/// Parse a non-negative decimal number from the bytes of a trimmed string. See [`parse_value_with_decimal_separator`].
+#[cfg(any(feature = "byte", feature = "bit"))]
+pub(crate) fn parse_value_from_bytes<I: Iterator<Item = u8> + Clone>(
+    mut bytes: I,
+    decimal_separator: u8,
+) -> Result<Decimal, ValueParseError> {
+    let mut value = match bytes.next() {
+        Some(e) => match e {
+            b'0'..=b'9' => Decimal::from(e - b'0'),
+            _ => return Err(get_not_number_error(e, bytes)),
+        },
+        None => return Err(ValueParseError::NoValue),
+    };
+
+    let mut scale: Option<u32> = None;
+
+    while let Some(e) = bytes.next() {
+        match e {
+            b'0'..=b'9' => match scale.as_mut() {
+                Some(scale) => {
+                    *scale += 1;
+
+                    let mut d = Decimal::from(e - b'0');
+
+                    d.set_scale(*scale).map_err(|_| ValueParseError::NumberTooLong)?;
+
+                    value += d;
+                },
+                None => {
+                    value = value
+                        .checked_mul(Decimal::TEN)
+                        .ok_or(ValueParseError::NumberTooLong)?
+                        .checked_add(Decimal::from(e - b'0'))
+                        .ok_or(ValueParseError::NumberTooLong)?;
+                },
+            },
+            _ if e == decimal_separator && scale.is_none() => scale = Some(0),
+            b'e' | b'E' if scale != Some(0) => match read_exponent(&mut bytes) {
+                Some(exponent) if bytes.next().is_none() => {
+                    return scale_by_power_of_ten(value, exponent)
+                        .ok_or(ValueParseError::NumberTooLong);
+                },
+                _ => return Err(ValueParseError::NotNumber(e as char)),
+            },
+            _ => return Err(ValueParseError::NotNumber(get_char_from_bytes(e, bytes))),
+        }
+    }
+
+    if scale == Some(0) {
+        return Err(ValueParseError::NotNumber(decimal_separator as char));
+    }
+
+    Ok(value)
+}
+
+/// Read the exponent of scientific notation after `'e'` or `'E'`, such as `"9"`, `"+3"` or `"-2"`. Nothing is consumed if it is not an exponent, such as the `"B"` after the `"E"` of `"1EB"`.
+#[cfg(any(feature = "byte", feature = "bit"))]
+pub(crate) fn read_exponent<I: Iterator<Item = u8> + Clone>(bytes: &mut I) -> Option<i32> {
+    let mut peek = bytes.clone();
+
+    let (negative, first) = match peek.next()? {
+        b'+' => (false, peek.next()?),
+        b'-' => (true, peek.next()?),
+        e => (false, e),
+    };
+
+    if !first.is_ascii_digit() {
+        return None;
+    }
+
+    let mut exponent = i32::from(first - b'0');
+    let mut rest = peek.clone();
+
+    while let Some(e @ b'0'..=b'9') = rest.next() {
+        exponent = exponent.saturating_mul(10).saturating_add(i32::from(e - b'0'));
+        peek = rest.clone();
+    }
+
+    *bytes = peek;
+
+    Some(if negative { -exponent } else { exponent })
+}
+
+/// Multiply `value` by 10 to the power of `exponent`. A non-zero value which becomes too small to be represented is kept as the smallest positive `Decimal`, so that it is still rounded up to a whole byte or bit. Returns `None` if it overflows.
+#[cfg(any(feature = "byte", feature = "bit"))]
+pub(crate) fn scale_by_power_of_ten(value: Decimal, exponent: i32) -> Option<Decimal> {
+    if value.is_zero() {
+        return Some(value);
+    }
+
+    let mut scaled = value;
+
+    if exponent >= 0 {
+        // a non-zero value overflows in a few dozen iterations
+        for _ in 0..exponent {
+            scaled = scaled.checked_mul(Decimal::TEN)?;
+        }
+    } else {
+        for _ in 0..exponent.unsigned_abs() {
+            let next = scaled / Decimal::TEN;
+
+            if next.is_zero() {
+                return Some(Decimal::new(1, Decimal::MAX_SCALE));
+            }
+
+            scaled = next;
+        }
+    }
+
+    Some(scaled)
+}
+
+/// If the byte `e` read after a value starts an exponent of scientific notation, such as the `"e9"` of `"1.5e9 B"`, apply the exponent to `value` and read the byte after the exponent and spaces, which begins the unit. The exponent must follow the digits of the value directly.
+#[cfg(any(feature = "byte", feature = "bit"))]
+pub(crate) fn read_value_exponent(
+    s: &str,
+    value: Decimal,
+    e: Option<u8>,
+    bytes: &mut Bytes,
+) -> Result<(Decimal, Option<u8>), ValueParseError> {
+    // the index of `e`, which is after at least one digit
+    let index = s.len() - bytes.len() - 1;
+
+    if !matches!(e, Some(b'e' | b'E')) || !s.as_bytes()[index - 1].is_ascii_digit() {
+        return Ok((value, e));
+    }
+
+    let exponent = match read_exponent(bytes) {
+        Some(exponent) => exponent,
+        None => return Ok((value, e)),
+    };
+
+    let value = scale_by_power_of_ten(value, exponent).ok_or(ValueParseError::NumberTooLong)?;
+
+    loop {
+        match bytes.next() {
+            Some(e) if read_space(e, bytes) => (),
+            e => return Ok((value, e)),
+        }
+    }
+}
+
+/// Create the error for the first character of a value, which is not a digit. Both the hyphen-minus and the minus sign (U+2212) are reported as `NotNumber('-')`, so a negative value can be recognized with a single pattern.
+#[cfg(any(feature = "byte", feature = "bit"))]
+pub(crate) fn get_not_number_error(e: u8, bytes: impl Iterator<Item = u8>) -> ValueParseError {
+    match get_char_from_bytes(e, bytes) {
+        '-' | '\u{2212}' => ValueParseError::NotNumber('-'),
+        c => ValueParseError::NotNumber(c),
+    }
+}
+
+/// Parse `s` with `parse`, fixing minor mistakes if it fails: a trailing period, whitespaces inside the value or the unit, and a repeated unit. The warnings are only returned if the fixed string can be parsed.
+#[cfg(all(any(feature = "byte", feature = "bit"), feature = "std"))]
+pub(crate) fn parse_lenient<T, E>(
+    s: &str,
+    parse: impl Fn(&str) -> Result<T, E>,
+) -> (Option<T>, Vec<ParseWarning>) {
+    let mut s = s.trim();
+
+    if let Ok(v) = parse(s) {
+        return (Some(v), Vec::new());
+    }
+
+    let mut warnings = Vec::new();
+
+    if s.ends_with('.') {
+        s = s.trim_end_matches('.').trim_end();
+
+        warnings.push(ParseWarning::TrailingPeriodRemoved);
+    }
+
+    // the whitespaces between the value and the unit are a separator, not a mistake
+    let (value, unit) = s.split_at(
+        s.find(|c: char| !c.is_ascii_digit() && c != '.' && !c.is_whitespace()).unwrap_or(s.len()),
+    );
+    let value = value.trim_end();
+
+    let compact_value: String = value.split_whitespace().collect();
+    let unit_parts: Vec<&str> = unit.split_whitespace().collect();
+
+    let (unit, doubled) = match unit_parts.as_slice() {
+        [a, b] if a == b => (String::from(*a), true),
+        _ => (unit_parts.concat(), false),
+    };
+
+    if compact_value.len() != value.len() || (!doubled && unit_parts.len() > 1) {
+        warnings.push(ParseWarning::WhitespaceRemoved);
+    }
+
+    let join = |unit: &str| {
+        if unit.is_empty() {
+            compact_value.clone()
+        } else {
+            format!("{compact_value} {unit}")
+        }
+    };
+
+    let half = if doubled {
+        Some(unit.as_str())
+    } else {
+        if let Ok(v) = parse(&join(&unit)) {
+            return (Some(v), warnings);
+        }
+
+        let middle = unit.len() / 2;
+
+        if unit.len() % 2 == 0 && unit.is_char_boundary(middle) && unit[..middle] == unit[middle..]
+        {
+            Some(&unit[..middle])
+        } else {
+            None
+        }
+    };
+
+    if let Some(half) = half {
+        warnings.push(ParseWarning::DoubledUnitRemoved);
+
+        if let Ok(v) = parse(&join(half)) {
+            return (Some(v), warnings);
+        }
+    }
+
+    (None, Vec::new())
+}
+
+/// Check that `size` is in `range`, or tell which bound it is beyond.
+#[cfg(any(feature = "byte", feature = "bit"))]
+pub(crate) fn check_range<T: PartialOrd + Copy>(
+    size: T,
+    range: impl RangeBounds<T>,
+) -> Result<T, RangeParseError<T>> {
+    match range.start_bound() {
+        Bound::Included(&min) if size < min => {
+            return Err(RangeParseError::TooSmall {
+                size,
+                min,
+                inclusive: true,
+            });
+        },
+        Bound::Excluded(&min) if size <= min => {
+            return Err(RangeParseError::TooSmall {
+                size,
+                min,
+                inclusive: false,
+            });
+        },
+        _ => (),
+    }
+
+    match range.end_bound() {
+        Bound::Included(&max) if size > max => Err(RangeParseError::TooLarge {
+            size,
+            max,
+            inclusive: true,
+        }),
+        Bound::Excluded(&max) if size >= max => Err(RangeParseError::TooLarge {
+            size,
+            max,
+            inclusive: false,
+        }),
+        _ => Ok(size),
+    }
+}
+
+/// Divide `a` by `b`, rounding up. `u128::div_ceil` needs a newer compiler than the MSRV.
+#[cfg(any(feature = "byte", feature = "bit"))]
+#[inline]
+pub(crate) const fn div_ceil_u128(a: u128, b: u128) -> u128 {
+    let q = a / b;
+
+    if a % b == 0 {
+        q
+    } else {
+        q + 1
+    }
 }
 
 #[cfg(any(feature = "byte", feature = "bit"))]
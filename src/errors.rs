@@ -7,6 +7,9 @@ use std::error::Error;
 #[cfg(any(feature = "byte", feature = "bit"))]
 use rust_decimal::Decimal;
 
+#[cfg(feature = "byte")]
+use crate::Byte;
+
 #[cfg(any(feature = "byte", feature = "bit"))]
 /// The error type returned when it exceeds representation range.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -24,6 +27,31 @@ impl Display for ExceededBoundsError {
 #[cfg(feature = "std")]
 impl Error for ExceededBoundsError {}
 
+#[cfg(any(feature = "byte", feature = "bit"))]
+/// The error type returned when a size cannot be converted into a `NonZero*` integer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NonZeroError {
+    /// The size is zero.
+    Zero,
+    /// The size is too large for the integer type.
+    ExceededBounds,
+}
+
+#[cfg(any(feature = "byte", feature = "bit"))]
+impl Display for NonZeroError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Zero => f.write_str("the size is zero"),
+            Self::ExceededBounds => Display::fmt(&ExceededBoundsError, f),
+        }
+    }
+}
+
+#[cfg(any(feature = "byte", feature = "bit"))]
+#[cfg(feature = "std")]
+impl Error for NonZeroError {}
+
 #[cfg(any(feature = "byte", feature = "bit"))]
 /// The error type returned when parsing values.
 #[derive(Debug, Clone)]
@@ -61,6 +89,25 @@ pub struct UnitParseError {
     pub also_expect_no_character: bool,
 }
 
+impl UnitParseError {
+    /// Check whether the incorrect character is a micro sign (`'µ'`, U+00B5) or a Greek small letter mu (`'μ'`, U+03BC), which is usually meant to be the micro prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Unit;
+    ///
+    /// let error = Unit::parse_str("μB", true, true).unwrap_err();
+    ///
+    /// assert_eq!('μ', error.character);
+    /// assert!(error.is_micro_prefix());
+    /// ```
+    #[inline]
+    pub const fn is_micro_prefix(&self) -> bool {
+        matches!(self.character, '\u{b5}' | '\u{3bc}')
+    }
+}
+
 impl Display for UnitParseError {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
@@ -70,6 +117,13 @@ impl Display for UnitParseError {
             also_expect_no_character,
         } = self;
 
+        if self.is_micro_prefix() {
+            return f.write_fmt(format_args!(
+                "the micro prefix {character:?} is not supported (sizes smaller than a bit cannot \
+                 be represented)"
+            ));
+        }
+
         let expected_characters_length = expected_characters.len();
 
         f.write_fmt(format_args!("the character {character:?} is incorrect",))?;
@@ -152,3 +206,319 @@ impl Display for ParseError {
 #[cfg(any(feature = "byte", feature = "bit"))]
 #[cfg(feature = "std")]
 impl Error for ParseError {}
+
+#[cfg(any(feature = "byte", feature = "bit"))]
+/// The error type returned when parsing rates, such as `"12.5 MB/s"`.
+#[derive(Debug, Clone)]
+pub enum RateParseError {
+    /// The size before the time unit is invalid.
+    Size(ParseError),
+    /// There is neither a slash followed by a time unit nor a trailing **"ps"**.
+    NoTimeUnit,
+    /// The time unit after the slash is unknown.
+    UnknownTimeUnit,
+}
+
+#[cfg(any(feature = "byte", feature = "bit"))]
+impl From<ParseError> for RateParseError {
+    #[inline]
+    fn from(error: ParseError) -> Self {
+        Self::Size(error)
+    }
+}
+
+#[cfg(any(feature = "byte", feature = "bit"))]
+impl Display for RateParseError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Size(error) => Display::fmt(error, f),
+            Self::NoTimeUnit => f.write_str("a time unit (e.g. \"/s\" or \"ps\") is expected"),
+            Self::UnknownTimeUnit => f.write_str("the time unit is unknown"),
+        }
+    }
+}
+
+#[cfg(any(feature = "byte", feature = "bit"))]
+#[cfg(feature = "std")]
+impl Error for RateParseError {}
+
+#[cfg(any(feature = "byte", feature = "bit"))]
+/// The warning type returned when a string is parsed leniently.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ParseWarning {
+    /// Whitespaces inside the value or the unit were removed, e.g. `"1 5 M B"`.
+    WhitespaceRemoved,
+    /// A trailing period was removed, e.g. `"15 MB."`.
+    TrailingPeriodRemoved,
+    /// A repeated unit was removed, e.g. `"15 MB MB"`.
+    DoubledUnitRemoved,
+}
+
+#[cfg(any(feature = "byte", feature = "bit"))]
+impl Display for ParseWarning {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WhitespaceRemoved => f.write_str("stray whitespaces were removed"),
+            Self::TrailingPeriodRemoved => f.write_str("a trailing period was removed"),
+            Self::DoubledUnitRemoved => f.write_str("a repeated unit was removed"),
+        }
+    }
+}
+
+#[cfg(feature = "byte")]
+/// The error type returned when the bounds of content-defined chunks are invalid.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ChunkBoundsError {
+    /// The minimum size is zero.
+    ZeroMinimum,
+    /// The sizes are not in the order of minimum ≤ average ≤ maximum.
+    NotOrdered,
+    /// The average size is not a power of two.
+    AverageNotPowerOfTwo,
+    /// The maximum size does not fit in `usize`, so a chunk cannot be buffered in memory.
+    MaximumTooLarge,
+}
+
+#[cfg(feature = "byte")]
+impl Display for ChunkBoundsError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroMinimum => f.write_str("the minimum chunk size must not be zero"),
+            Self::NotOrdered => {
+                f.write_str("the chunk sizes must satisfy minimum <= average <= maximum")
+            },
+            Self::AverageNotPowerOfTwo => {
+                f.write_str("the average chunk size must be a power of two")
+            },
+            Self::MaximumTooLarge => f.write_str("the maximum chunk size is too large"),
+        }
+    }
+}
+
+#[cfg(feature = "byte")]
+#[cfg(feature = "std")]
+impl Error for ChunkBoundsError {}
+
+#[cfg(feature = "byte")]
+/// The error type returned when a transition of a `ByteBudget` cannot be made.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BudgetError {
+    /// The requested amount is larger than the available amount, so it cannot be reserved.
+    InsufficientAvailable { requested: Byte, available: Byte },
+    /// The requested amount is larger than the reserved amount, so it cannot be committed or cancelled.
+    InsufficientReserved { requested: Byte, reserved: Byte },
+    /// The requested amount is larger than the committed amount, so it cannot be released.
+    InsufficientCommitted { requested: Byte, committed: Byte },
+}
+
+#[cfg(feature = "byte")]
+impl Display for BudgetError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InsufficientAvailable {
+                requested,
+                available,
+            } => f.write_fmt(format_args!(
+                "cannot reserve {requested:#}, only {available:#} is available"
+            )),
+            Self::InsufficientReserved {
+                requested,
+                reserved,
+            } => f.write_fmt(format_args!(
+                "cannot take {requested:#} from the reservation, only {reserved:#} is reserved"
+            )),
+            Self::InsufficientCommitted {
+                requested,
+                committed,
+            } => f.write_fmt(format_args!(
+                "cannot release {requested:#}, only {committed:#} is committed"
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "byte")]
+#[cfg(feature = "std")]
+impl Error for BudgetError {}
+
+#[cfg(any(feature = "byte", feature = "bit"))]
+/// The error type returned by [`Byte::parse_str_in_range`](struct.Byte.html#method.parse_str_in_range) and [`Bit::parse_str_in_range`](struct.Bit.html#method.parse_str_in_range), where `T` is `Byte` or `Bit`.
+#[derive(Debug, Clone)]
+pub enum RangeParseError<T> {
+    /// The string cannot be parsed.
+    Parse(ParseError),
+    /// The size is less than the lower bound, or equal to it if the bound is exclusive.
+    TooSmall { size: T, min: T, inclusive: bool },
+    /// The size is greater than the upper bound, or equal to it if the bound is exclusive.
+    TooLarge { size: T, max: T, inclusive: bool },
+}
+
+#[cfg(any(feature = "byte", feature = "bit"))]
+impl<T> From<ParseError> for RangeParseError<T> {
+    #[inline]
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
+}
+
+#[cfg(any(feature = "byte", feature = "bit"))]
+impl<T: Display> Display for RangeParseError<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(error) => Display::fmt(error, f),
+            Self::TooSmall {
+                size,
+                min,
+                inclusive,
+            } => {
+                if *inclusive {
+                    f.write_fmt(format_args!("{size:#} is less than the minimum of {min:#}"))
+                } else {
+                    f.write_fmt(format_args!("{size:#} is not greater than {min:#}"))
+                }
+            },
+            Self::TooLarge {
+                size,
+                max,
+                inclusive,
+            } => {
+                if *inclusive {
+                    f.write_fmt(format_args!("{size:#} is greater than the maximum of {max:#}"))
+                } else {
+                    f.write_fmt(format_args!("{size:#} is not less than {max:#}"))
+                }
+            },
+        }
+    }
+}
+
+#[cfg(any(feature = "byte", feature = "bit"))]
+#[cfg(feature = "std")]
+impl<T: fmt::Debug + Display> Error for RangeParseError<T> {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+/// The error type returned by [`Byte::from_json_value`](struct.Byte.html#method.from_json_value).
+#[derive(Debug, Clone)]
+pub enum JsonValueError {
+    /// The value is a string which cannot be parsed.
+    Parse(ParseError),
+    /// The value is a number which is negative or too large.
+    ExceededBounds,
+    /// The value is neither a number nor a string. A description of its type, such as `"null"` or `"an array"`, is included.
+    InvalidType(&'static str),
+}
+
+#[cfg(feature = "json")]
+impl From<ParseError> for JsonValueError {
+    #[inline]
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
+}
+
+#[cfg(feature = "json")]
+impl Display for JsonValueError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(error) => Display::fmt(error, f),
+            Self::ExceededBounds => Display::fmt(&ExceededBoundsError, f),
+            Self::InvalidType(found) => {
+                f.write_fmt(format_args!("expected a number or a string, found {found}"))
+            },
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl Error for JsonValueError {}
+
+#[cfg(feature = "polars")]
+/// The error type returned by the `TryFrom<AnyValue>` implementation of `Byte`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AnyValueError {
+    /// The value is an integer which is negative or too large.
+    ExceededBounds,
+    /// The value is not an integer. A description of its type, such as `"null"` or `"a float"`, is included.
+    InvalidType(&'static str),
+}
+
+#[cfg(feature = "polars")]
+impl Display for AnyValueError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ExceededBounds => Display::fmt(&ExceededBoundsError, f),
+            Self::InvalidType(found) => {
+                f.write_fmt(format_args!("expected an integer, found {found}"))
+            },
+        }
+    }
+}
+
+#[cfg(feature = "polars")]
+impl Error for AnyValueError {}
+
+#[cfg(all(feature = "byte", feature = "bit"))]
+/// The error type returned when a number of bits cannot be converted into whole bytes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct NotWholeBytesError;
+
+#[cfg(all(feature = "byte", feature = "bit"))]
+impl Display for NotWholeBytesError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("the number of bits is not a multiple of 8")
+    }
+}
+
+#[cfg(all(feature = "byte", feature = "bit"))]
+#[cfg(feature = "std")]
+impl Error for NotWholeBytesError {}
+
+#[cfg(any(feature = "byte", feature = "bit"))]
+/// The error type returned when the global [`UnitPreference`](enum.UnitPreference.html) has already been set.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct UnitPreferenceAlreadySetError;
+
+#[cfg(any(feature = "byte", feature = "bit"))]
+impl Display for UnitPreferenceAlreadySetError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("the global unit preference has already been set")
+    }
+}
+
+#[cfg(any(feature = "byte", feature = "bit"))]
+#[cfg(feature = "std")]
+impl Error for UnitPreferenceAlreadySetError {}
+
+#[cfg(feature = "locale")]
+/// The error type returned when a language tag is malformed or its locale is not supported.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct UnsupportedLocaleError;
+
+#[cfg(feature = "locale")]
+impl Display for UnsupportedLocaleError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("the locale is not supported")
+    }
+}
+
+#[cfg(feature = "locale")]
+#[cfg(feature = "std")]
+impl Error for UnsupportedLocaleError {}
@@ -0,0 +1,84 @@
+//! Helpers for finding sizes in free text.
+
+use core::ops::Range;
+
+use crate::{common::scan_size, Byte};
+
+/// An iterator over the sizes found in a text, generated from the [`find_sizes`](./fn.find_sizes.html) function.
+#[derive(Debug, Clone)]
+pub struct FindSizes<'a> {
+    text:     &'a str,
+    position: usize,
+}
+
+impl<'a> Iterator for FindSizes<'a> {
+    type Item = (Range<usize>, Byte);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.text.as_bytes();
+        let length = bytes.len();
+
+        while self.position < length {
+            let start = self.position;
+
+            if !bytes[start].is_ascii_digit() || (start > 0 && is_word_byte(bytes[start - 1])) {
+                self.position += 1;
+
+                continue;
+            }
+
+            let (value_length, size_length) = scan_size(&bytes[start..]);
+
+            let end = start + size_length;
+
+            self.position = start + value_length;
+
+            if value_length == size_length || (end < length && is_word_byte(bytes[end])) {
+                continue;
+            }
+
+            if let Ok(byte) = Byte::parse_str(&self.text[start..end], false) {
+                self.position = end;
+
+                return Some((start..end, byte));
+            }
+        }
+
+        None
+    }
+}
+
+#[inline]
+fn is_word_byte(e: u8) -> bool {
+    e.is_ascii_alphanumeric() || e == b'_' || e == b'.'
+}
+
+/// Find sizes with explicit units in a text. Each item is the byte range of the size in the text and the parsed `Byte` instance. The sizes are parsed by [`Byte::parse_str`](../struct.Byte.html#method.parse_str) with `ignore_case` set to `false`.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{extract::find_sizes, Byte};
+///
+/// let text = "disk usage: used 3.2GiB of 100 GB, 12 files";
+///
+/// let sizes: Vec<_> = find_sizes(text).collect();
+///
+/// assert_eq!(2, sizes.len());
+/// assert_eq!("3.2GiB", &text[sizes[0].0.clone()]);
+/// assert_eq!(Byte::from_u64(3435973837), sizes[0].1);
+/// assert_eq!("100 GB", &text[sizes[1].0.clone()]);
+/// assert_eq!(Byte::from_u64(100000000000), sizes[1].1);
+/// ```
+///
+/// # Points to Note
+///
+/// * Numbers without units, such as `"12"` in the example above, are not treated as sizes.
+/// * Values in scientific notation, such as `"1.5e6 B"`, are found as well, since they are accepted by `parse_str`.
+#[inline]
+pub const fn find_sizes(text: &str) -> FindSizes<'_> {
+    FindSizes {
+        text,
+        position: 0,
+    }
+}
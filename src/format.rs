@@ -0,0 +1,465 @@
+use core::fmt::{self, Alignment, Formatter, Write};
+#[cfg(feature = "std")]
+use std::string::String;
+
+use rust_decimal::Decimal;
+
+use crate::{common::round_fractional_part_f64, Unit};
+#[cfg(feature = "locale")]
+use crate::{locale::LocalizedFormat, Locale};
+
+/// Choose how to write the unit after the value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum FormatStyle {
+    /// `"1.5 GiB"`.
+    #[default]
+    Standard,
+    /// `"1.5GiB"`, no spaces between the value and the unit.
+    Compact,
+    /// `"1.5 gibibytes"`, the full name of the unit.
+    Verbose,
+}
+
+/// Options for formatting a `Byte`, `Bit`, `AdjustedByte` or `AdjustedBit` instance.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{FormatOptions, FormatStyle};
+///
+/// let options = FormatOptions::new().style(FormatStyle::Compact).precision(2);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct FormatOptions {
+    pub(crate) style:               FormatStyle,
+    pub(crate) precision:           Option<usize>,
+    pub(crate) keep_trailing_zeros: bool,
+    #[cfg(feature = "locale")]
+    pub(crate) localized:           LocalizedFormat,
+}
+
+impl FormatOptions {
+    /// Create a new `FormatOptions` instance with the `FormatStyle::Standard` style and no fixed precision.
+    #[inline]
+    #[rustfmt::skip]
+    pub const fn new() -> Self {
+        Self {
+            style:               FormatStyle::Standard,
+            precision:           None,
+            keep_trailing_zeros: false,
+            #[cfg(feature = "locale")]
+            localized:           LocalizedFormat::new(),
+        }
+    }
+
+    /// Set the style of the unit.
+    #[inline]
+    pub const fn style(mut self, style: FormatStyle) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    /// Set the maximum number of digits after the decimal point.
+    #[inline]
+    pub const fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+
+        self
+    }
+
+    /// Set whether to keep the trailing zeros so that there are always exactly as many digits after the decimal point as the precision. The default value is `false`, which means the unnecessary trailing zeros are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, FormatOptions};
+    ///
+    /// let byte = Byte::from_u64(10240);
+    ///
+    /// assert_eq!(
+    ///     "10 KiB",
+    ///     byte.display_with(FormatOptions::new().precision(2)).to_string()
+    /// );
+    /// assert_eq!(
+    ///     "10.00 KiB",
+    ///     byte.display_with(
+    ///         FormatOptions::new().precision(2).keep_trailing_zeros(true)
+    ///     )
+    ///     .to_string()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn keep_trailing_zeros(mut self, keep_trailing_zeros: bool) -> Self {
+        self.keep_trailing_zeros = keep_trailing_zeros;
+
+        self
+    }
+
+    /// Set the unit symbols, the decimal separator and the thousands separator to the ones of a locale. The `FormatStyle::Verbose` style does not use the unit symbols.
+    ///
+    /// Without a locale, the decimal separator is `'.'` and there is no thousands separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, FormatOptions, Locale, Unit, UnitType};
+    ///
+    /// let adjusted_byte =
+    ///     Byte::from_u64(1_500_000_000).get_appropriate_unit(UnitType::Decimal);
+    ///
+    /// assert_eq!(
+    ///     "1,5 Go",
+    ///     adjusted_byte
+    ///         .display_with(FormatOptions::new().locale(Locale::Fr))
+    ///         .to_string()
+    /// );
+    /// assert_eq!(
+    ///     "1.5 吉字节",
+    ///     adjusted_byte
+    ///         .display_with(FormatOptions::new().locale(Locale::Zh))
+    ///         .to_string()
+    /// );
+    ///
+    /// let adjusted_byte =
+    ///     Byte::from_u64(1_234_567_890).get_adjusted_unit(Unit::MB);
+    ///
+    /// assert_eq!(
+    ///     "1.234,57 MB",
+    ///     adjusted_byte
+    ///         .display_with(FormatOptions::new().precision(2).locale(Locale::De))
+    ///         .to_string()
+    /// );
+    /// assert_eq!(
+    ///     "1,234.57 MB",
+    ///     adjusted_byte
+    ///         .display_with(FormatOptions::new().precision(2).locale(Locale::En))
+    ///         .to_string()
+    /// );
+    /// ```
+    #[cfg(feature = "locale")]
+    #[inline]
+    pub const fn locale(mut self, locale: Locale) -> Self {
+        self.localized.locale = locale;
+        self.localized.decimal_separator = locale.decimal_separator();
+        self.localized.thousands_separator = Some(locale.thousands_separator());
+
+        self
+    }
+
+    /// Set the character which separates the integer part and the fractional part of the value. The default value is `'.'`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, FormatOptions, UnitType};
+    ///
+    /// let adjusted_byte =
+    ///     Byte::from_u64(1536).get_appropriate_unit(UnitType::Binary);
+    ///
+    /// assert_eq!(
+    ///     "1,5 KiB",
+    ///     adjusted_byte
+    ///         .display_with(FormatOptions::new().decimal_separator(','))
+    ///         .to_string()
+    /// );
+    /// ```
+    #[cfg(feature = "locale")]
+    #[inline]
+    pub const fn decimal_separator(mut self, decimal_separator: char) -> Self {
+        self.localized.decimal_separator = decimal_separator;
+
+        self
+    }
+
+    /// Set the character which separates the groups of thousands of the value, or `None` not to separate them. The default value is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, FormatOptions, Unit};
+    ///
+    /// let adjusted_byte = Byte::from_u64(1_234_567).get_adjusted_unit(Unit::B);
+    ///
+    /// assert_eq!(
+    ///     "1'234'567 B",
+    ///     adjusted_byte
+    ///         .display_with(FormatOptions::new().thousands_separator(Some('\'')))
+    ///         .to_string()
+    /// );
+    /// ```
+    #[cfg(feature = "locale")]
+    #[inline]
+    pub const fn thousands_separator(mut self, thousands_separator: Option<char>) -> Self {
+        self.localized.thousands_separator = thousands_separator;
+
+        self
+    }
+
+    /// Set the character between the value and the unit for the `FormatStyle::Standard` and `FormatStyle::Verbose` styles. The default value is `' '`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, FormatOptions, UnitType};
+    ///
+    /// let adjusted_byte =
+    ///     Byte::from_u64(1536).get_appropriate_unit(UnitType::Binary);
+    ///
+    /// assert_eq!(
+    ///     "1.5\u{a0}KiB",
+    ///     adjusted_byte
+    ///         .display_with(FormatOptions::new().unit_separator('\u{a0}'))
+    ///         .to_string()
+    /// );
+    /// ```
+    #[cfg(feature = "locale")]
+    #[inline]
+    pub const fn unit_separator(mut self, unit_separator: char) -> Self {
+        self.localized.unit_separator = unit_separator;
+
+        self
+    }
+}
+
+struct CharCounter(usize);
+
+impl Write for CharCounter {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+
+        Ok(())
+    }
+}
+
+/// Write `args`, optionally followed by `unit`, honoring the width, the fill character, the alignment and the `0` flag of the formatter in the same way for all the `Display` implementations.
+///
+/// The width counts the whole output. Without a unit, the value is right-aligned by default, like integers. With a unit, the value is left-aligned by default, and the `+` flag (spaces to align the units) or the `-` flag (no spaces) decides the spaces between the value and the unit.
+#[inline]
+pub(crate) fn fmt_with_unit(
+    f: &mut Formatter<'_>,
+    args: fmt::Arguments<'_>,
+    unit: Option<Unit>,
+) -> fmt::Result {
+    fmt_signed_with_unit(f, "", args, unit)
+}
+
+/// The same as `fmt_with_unit`, but `sign` is written before `args`. With the `0` flag, the zeros are written between the sign and the value, like signed integers.
+pub(crate) fn fmt_signed_with_unit(
+    f: &mut Formatter<'_>,
+    sign: &str,
+    args: fmt::Arguments<'_>,
+    unit: Option<Unit>,
+) -> fmt::Result {
+    let (space_length, default_alignment) = match unit {
+        Some(unit) => {
+            let space_length = if f.sign_plus() {
+                4 - unit.as_str().len()
+            } else if f.sign_minus() {
+                0
+            } else {
+                1
+            };
+
+            (space_length, Alignment::Left)
+        },
+        None => (0, Alignment::Right),
+    };
+
+    if let Some(width) = f.width() {
+        let unit_length = unit.map(|unit| unit.as_str().len() + space_length).unwrap_or(0);
+
+        let mut counter = CharCounter(0);
+
+        counter.write_fmt(args)?;
+
+        let padding = width.saturating_sub(unit_length + sign.chars().count() + counter.0);
+
+        if f.sign_aware_zero_pad() {
+            f.write_str(sign)?;
+
+            for _ in 0..padding {
+                f.write_char('0')?;
+            }
+
+            f.write_fmt(args)?;
+        } else {
+            let (pre, post) = match f.align().unwrap_or(default_alignment) {
+                Alignment::Left => (0, padding),
+                Alignment::Right => (padding, 0),
+                Alignment::Center => (padding / 2, padding - padding / 2),
+            };
+
+            let fill = f.fill();
+
+            for _ in 0..pre {
+                f.write_char(fill)?;
+            }
+
+            f.write_str(sign)?;
+            f.write_fmt(args)?;
+
+            for _ in 0..post {
+                f.write_char(fill)?;
+            }
+        }
+    } else {
+        f.write_str(sign)?;
+        f.write_fmt(args)?;
+    }
+
+    if let Some(unit) = unit {
+        for _ in 0..space_length {
+            f.write_char(' ')?;
+        }
+
+        f.write_str(unit.as_str())?;
+    }
+
+    Ok(())
+}
+
+/// Write an adjusted value with `fmt_with_unit`. The value is rounded to the precision, and the unnecessary fractional part is removed if the `#` flag is set. Values in bits or bytes are never written with a fractional part.
+pub(crate) fn fmt_value(
+    f: &mut Formatter<'_>,
+    value: f64,
+    unit: Unit,
+    precision: Option<usize>,
+    with_unit: bool,
+) -> fmt::Result {
+    let (value, precision) = match precision {
+        Some(precision) if f.alternate() => (round_fractional_part_f64(value, precision), None),
+        Some(_) if matches!(unit, Unit::Bit | Unit::B) => (value, None),
+        _ => (value, precision),
+    };
+
+    let unit = if with_unit { Some(unit) } else { None };
+
+    match precision {
+        Some(precision) => fmt_with_unit(f, format_args!("{value:.precision$}"), unit),
+        None => fmt_with_unit(f, format_args!("{value}"), unit),
+    }
+}
+
+#[cfg(feature = "locale")]
+#[inline]
+fn write_number(
+    f: &mut Formatter<'_>,
+    args: fmt::Arguments<'_>,
+    options: &FormatOptions,
+) -> fmt::Result {
+    options.localized.write_number(f, args)
+}
+
+#[cfg(not(feature = "locale"))]
+#[inline]
+fn write_number(
+    f: &mut Formatter<'_>,
+    args: fmt::Arguments<'_>,
+    _options: &FormatOptions,
+) -> fmt::Result {
+    f.write_fmt(args)
+}
+
+pub(crate) fn fmt_value_with_unit(
+    f: &mut Formatter<'_>,
+    value: f64,
+    unit: Unit,
+    options: &FormatOptions,
+) -> fmt::Result {
+    match options.precision {
+        Some(precision) if !matches!(unit, Unit::Bit | Unit::B) => {
+            if options.keep_trailing_zeros {
+                write_number(f, format_args!("{value:.precision$}"), options)?
+            } else {
+                let value = round_fractional_part_f64(value, precision);
+
+                write_number(f, format_args!("{value}"), options)?
+            }
+        },
+        _ => write_number(f, format_args!("{value}"), options)?,
+    }
+
+    fmt_unit(f, unit, options, value != 1.0)
+}
+
+pub(crate) fn fmt_decimal_with_unit(
+    f: &mut Formatter<'_>,
+    value: Decimal,
+    unit: Unit,
+    options: &FormatOptions,
+) -> fmt::Result {
+    match options.precision {
+        Some(precision) if options.keep_trailing_zeros && !matches!(unit, Unit::Bit | Unit::B) => {
+            write_number(f, format_args!("{value:.precision$}"), options)?
+        },
+        _ => write_number(f, format_args!("{}", value.normalize()), options)?,
+    }
+
+    fmt_unit(f, unit, options, value != Decimal::ONE)
+}
+
+fn fmt_unit(
+    f: &mut Formatter<'_>,
+    unit: Unit,
+    options: &FormatOptions,
+    plural: bool,
+) -> fmt::Result {
+    #[cfg(feature = "locale")]
+    let (symbol, separator) =
+        (options.localized.locale.unit_symbol(unit), options.localized.unit_separator);
+
+    #[cfg(not(feature = "locale"))]
+    let (symbol, separator) = (unit.as_str(), ' ');
+
+    match options.style {
+        FormatStyle::Standard => f.write_fmt(format_args!("{separator}{symbol}")),
+        FormatStyle::Compact => f.write_str(symbol),
+        FormatStyle::Verbose => {
+            f.write_fmt(format_args!("{separator}{}", unit.as_full_name()))?;
+
+            if plural {
+                f.write_str("s")?;
+            }
+
+            Ok(())
+        },
+    }
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn format_fixed_width(mut value: f64, mut unit: Unit, width: usize) -> Option<String> {
+    loop {
+        let unit_length = unit.as_str().len();
+
+        if width < unit_length + 2 {
+            return None;
+        }
+
+        let value_width = width - unit_length - 1;
+
+        let max_precision = if matches!(unit, Unit::Bit | Unit::B) {
+            0
+        } else {
+            value_width.saturating_sub(2).min(16)
+        };
+
+        for precision in (0..=max_precision).rev() {
+            let value = format!("{value:.precision$}");
+
+            if value.len() <= value_width {
+                return Some(format!("{value:>value_width$} {unit}"));
+            }
+        }
+
+        let next_unit = Unit::get_multiples().iter().copied().find(|u| {
+            u.is_bit() == unit.is_bit()
+                && u.is_binary_multiples() == unit.is_binary_multiples()
+                && u.as_bits_u128() > unit.as_bits_u128()
+        })?;
+
+        value = value * unit.as_bits_u128() as f64 / next_unit.as_bits_u128() as f64;
+        unit = next_unit;
+    }
+}
@@ -57,7 +57,24 @@ assert_eq!(50840000, Byte::parse_str("50.84 MB", true).unwrap().as_u64());
 # }
 ```
 
-A `Byte` instance can be formatted to string precisely. For more detailed usage, please refer to the implementation documentation of `Display::fmt` for `Byte`.
+Digit group separators, such as in `"1,500,000 KB"`, can be accepted with `ParseOptions`.
+
+```rust
+# #[cfg(feature = "byte")]
+# {
+use byte_unit::{Byte, ParseOptions};
+
+let options = ParseOptions::new().underscores(true).thousands_separator(Some(','));
+
+assert_eq!(1500000000, Byte::parse_str_with_options("1,500,000 KB", &options).unwrap().as_u64());
+# }
+```
+
+The `mode` option decides what the decimal prefixes mean. With `ParseMode::Jedec`, `"1 KB"` is 1024 bytes, as in configuration files written for Windows. With `ParseMode::Flexible`, only a bare prefix such as `"1K"` is 1024 bytes, like `dd` and `java -Xmx`.
+
+The `composite` option accepts several values with units, such as `"1 GiB 512 MiB 10 KiB"` from legacy formats, and adds them up, like `"1h 30m"` for a duration.
+
+A `Byte` instance can be formatted to string precisely. For more detailed usage, please refer to the implementation documentation of `Display::fmt` for `Byte`. The `Binary`, `Octal`, `LowerHex` and `UpperHex` traits are forwarded to the inner integer, so `format!("{byte:#x}")` writes the raw size in hexadecimal, like an integer.
 
 ```rust
 # #[cfg(feature = "byte")]
@@ -110,6 +127,19 @@ assert_eq!((50840.into(), Unit::KB), byte.get_recoverable_unit(false, 0));
 # }
 ```
 
+The alternate form (`{:#}`) of the `Display` implementation writes a size with the unit found by `get_recoverable_unit`. The largest unit is chosen by default, so `10240` bytes is written as `"10 KiB"`. An application can set a global `UnitPreference` once to prefer binary units or decimal units instead.
+
+```rust
+# #[cfg(feature = "byte")]
+# {
+use byte_unit::{Byte, UnitPreference};
+
+UnitPreference::Decimal.set_global().unwrap();
+
+assert_eq!("10.24 KB", format!("{:#}", Byte::from_u64(10240)));
+# }
+```
+
 #### AdjustedByte
 
 The `AdjustedByte` struct can be used for roughly representing a size of bytes with a unit.
@@ -147,6 +177,25 @@ assert_eq!("1.43 MiB", format!("{adjusted_byte:.2}"));
 # }
 ```
 
+A unit is chosen as soon as the size reaches it. To switch to a larger unit a bit earlier, like `ls -h` writing `"0.98 GiB"` instead of `"1003 MiB"`, use the `get_appropriate_unit_with_options` method with an `AppropriateUnitOptions` instance.
+
+The value of an `AdjustedByte` instance is an `f64`, which loses precision above 2<sup>53</sup>. The `get_adjusted_unit_integer` and `get_appropriate_unit_integer` methods create an `IntegerAdjustedByte` instance instead, which is written exactly with integer arithmetic only, so it also suits `no_std` targets without floating-point hardware. The `get_adjusted_unit_fixed` and `get_appropriate_unit_fixed` methods create a `FixedAdjustedByte<PRECISION>` instance, which is rounded once to `PRECISION` fractional digits, always written with exactly that many digits, and compared exactly, for snapshot tests of user interfaces.
+
+```rust
+# #[cfg(feature = "byte")]
+# {
+use byte_unit::{Byte, UnitType};
+
+let byte = Byte::from_u64((1 << 53) + 1);
+
+let adjusted_byte = byte.get_appropriate_unit_integer(UnitType::Binary);
+
+assert_eq!("8.000000000000001 PiB", format!("{adjusted_byte:.15}"));
+# }
+```
+
+To convert a large array of sizes for a dataframe or a plotting buffer, the `bulk::to_unit_values` and `bulk::to_unit_decimals` functions write the values in one unit into a slice, computing the factor of the unit only once.
+
 ### Bit
 
 The `Bit` struct can be used for representing a size in bits.
@@ -169,6 +218,33 @@ assert_eq!("125.95 Kb", format!("{adjusted_bit:.2}"));
 # }
 ```
 
+### Rate
+
+The `ByteRate` and `BitRate` structs can be used for representing sizes per a time unit, such as `"12.5 MB/s"` or `"100 Mbps"`. Dividing a `Byte` instance by a `Duration` yields a rate, and multiplying a rate by a `Duration` yields a size. `RateUnitType::DecimalBitsOnly` reports rates in decimal bit units, as is usual for network links. An `OverheadProfile`, such as `OverheadProfile::ETHERNET_IPV4_TCP`, `OverheadProfile::VXLAN` or `OverheadProfile::QUIC`, converts an advertised line rate into the expected payload throughput with `apply_overhead`, and back with `required_line_rate`.
+
+```rust
+# #[cfg(feature = "byte")]
+# {
+use std::time::Duration;
+
+use byte_unit::{Byte, ByteRate, RateUnitType};
+
+let rate = ByteRate::parse_str("100 Mbps", false).unwrap();
+
+assert_eq!("12.5 MB/s", format!("{rate:#}"));
+assert_eq!("100 Mb/s", rate.get_appropriate_unit(RateUnitType::DecimalBitsOnly).to_string());
+
+assert_eq!(rate, Byte::from_u64(25_000_000) / Duration::from_secs(2));
+assert_eq!(Byte::from_u64(750_000_000), rate * Duration::from_secs(60));
+# }
+```
+
+Conversely, multiplying a `Byte` instance by a `Duration` yields a `ByteSeconds` instance, which accounts for storage over time like a cloud bill does, e.g. `"3.5 GiB·month"`.
+
+To measure the bytes flowing through a stream, wrap an `io::Read` or `io::Write` instance in a `CountingReader` or a `CountingWriter`. Each adapter counts with a `ByteCounter` by default, or with a shared `AtomicByteCounter` to sum up several streams across threads.
+
+For benchmark reports, the `bench::Throughput` struct pairs a size with a measured duration and is displayed as a bandwidth, like `"2.34 GiB/s"`.
+
 ## No Std
 
 Disable the default features to compile this crate without std.
@@ -184,6 +260,14 @@ features = ["byte"]
 
 Enable the `serde` feature to support the serde framework.
 
+The representation can be chosen per field with the modules in `byte_unit::serde`, such as `bytes_as_u64`, `bytes_as_string`, `bytes_as_mib_f64` and `value_and_unit`, and `#[serde(with = "...")]`. Maps keyed by `Byte`, `Bit` or `Unit` instances can be used with formats which only allow string keys, such as JSON, through the `as_map_key` module.
+
+In formats which are not human-readable, sizes are serialized as `u128` integers. For formats which do not support `u128`, a `Byte` field can be serialized as a `u64` integer with `#[serde(with = "byte_unit::serde::bytes_as_u64")]`. The exact serialized forms are documented, pinned by golden tests and kept stable across minor versions in the `byte_unit::serde::wire` module.
+
+`FormatOptions` and `ParseOptions` (as well as `FormatStyle` and `Locale`) are also serializable, so formatting and parsing preferences can be kept in configuration files. Missing fields take the default values when they are deserialized.
+
+Enable the `schemars` feature to derive JSON schemas with [schemars](https://crates.io/crates/schemars). `Byte` and `Bit` are described as strings with a `pattern` of the accepted syntax, and `Unit` as an `enum` of its symbols.
+
 ```toml
 [dependencies.byte-unit]
 version = "*"
@@ -199,6 +283,417 @@ Enable the `rocket` feature to support the Rocket framework.
 version = "*"
 features = ["rocket"]
 ```
+
+## Rayon Support
+
+Enable the `rayon` feature to parse and aggregate large amounts of sizes in parallel.
+
+```rust
+# #[cfg(all(feature = "byte", feature = "rayon"))]
+# {
+use byte_unit::Byte;
+
+let sizes = Byte::parse_many(&["1 KB", "2 KB", "3 KB"], true).unwrap();
+
+assert_eq!(6000, Byte::par_total(sizes).unwrap().as_u64());
+# }
+```
+
+```toml
+[dependencies.byte-unit]
+version = "*"
+features = ["rayon"]
+```
+
+## Parser Combinators
+
+Enable the `parser-combinator` feature to use sizes inside larger [`nom`](https://docs.rs/nom) grammars without slicing substrings first.
+
+```rust
+# #[cfg(feature = "parser-combinator")]
+# {
+use byte_unit::combinators::byte_parser;
+use nom::{bytes::complete::tag, sequence::preceded, IResult};
+
+let result: IResult<&str, _> = preceded(tag("limit="), byte_parser(true))("limit=1GiB;burst=2GiB");
+let (rest, limit) = result.unwrap();
+
+assert_eq!(1073741824, limit.as_u64());
+assert_eq!(";burst=2GiB", rest);
+# }
+```
+
+```toml
+[dependencies.byte-unit]
+version = "*"
+features = ["parser-combinator"]
+```
+
+## File System Usage
+
+Enable the `os` feature to read the usage of a file system on Unix-like systems.
+
+```rust
+# #[cfg(all(feature = "os", unix))]
+# {
+use byte_unit::FsUsage;
+
+let usage = FsUsage::from_path("/").unwrap();
+
+assert!(usage.used() <= usage.total);
+# }
+```
+
+```toml
+[dependencies.byte-unit]
+version = "*"
+features = ["os"]
+```
+
+## sysinfo Support
+
+Enable the `sysinfo` feature to convert the statistics of the [`sysinfo`](https://docs.rs/sysinfo) crate into `Byte` instances.
+
+```rust
+# #[cfg(feature = "sysinfo")]
+# {
+use byte_unit::{FsUsage, MemoryUsage};
+use sysinfo::{Disks, System};
+
+let memory = MemoryUsage::from(&System::new_all());
+
+for disk in Disks::new_with_refreshed_list().list() {
+    let usage = FsUsage::from(disk);
+
+    assert!(usage.available <= usage.total);
+}
+# }
+```
+
+```toml
+[dependencies.byte-unit]
+version = "*"
+features = ["sysinfo"]
+```
+
+## indicatif Support
+
+Enable the `indicatif` feature to format the byte placeholders of [`indicatif`](https://docs.rs/indicatif) progress bars, such as `{bytes}` and `{bytes_per_sec}`, in the same way as the rest of your application.
+
+```rust
+# #[cfg(feature = "indicatif")]
+# {
+use byte_unit::{ByteProgressStyle, UnitType};
+use indicatif::ProgressBar;
+
+let style = ByteProgressStyle::new()
+    .unit_type(UnitType::Decimal)
+    .template("{bytes}/{total_bytes} ({bytes_per_sec})")
+    .unwrap();
+
+let bar = ProgressBar::new(1_000_000).with_style(style);
+# }
+```
+
+```toml
+[dependencies.byte-unit]
+version = "*"
+features = ["indicatif"]
+```
+
+## num-traits Support
+
+Enable the `num-traits` feature to use `Byte` and `Bit` in generic algorithms parameterized over the traits of [`num-traits`](https://docs.rs/num-traits), such as `Zero`, `One`, `Bounded`, `CheckedAdd` and `SaturatingSub`. `One` is 1 byte (or 1 bit). Since `One` and `CheckedMul` need a `Byte * Byte` (or `Bit * Bit`) multiplication, it is also implemented with this feature, and gives the raw product of the two numbers of bytes (or bits), e.g. 2 KB * 3 KB is 6000000 bytes.
+
+```rust
+# #[cfg(feature = "num-traits")]
+# {
+use byte_unit::Byte;
+use num_traits::{CheckedAdd, Zero};
+
+fn total<T: Zero + CheckedAdd>(values: &[T]) -> Option<T> {
+    values.iter().try_fold(T::zero(), |sum, v| sum.checked_add(v))
+}
+
+assert_eq!(Some(Byte::from_u64(300)), total(&[Byte::from_u64(100), Byte::from_u64(200)]));
+# }
+```
+
+```toml
+[dependencies.byte-unit]
+version = "*"
+features = ["num-traits"]
+```
+
+## criterion Support
+
+Enable the `criterion` feature to convert `Byte` instances (and `bench::Throughput` instances) into [`criterion::Throughput`](https://docs.rs/criterion/latest/criterion/enum.Throughput.html), so the size processed by a benchmark can be computed with this crate.
+
+```rust
+# #[cfg(feature = "criterion")]
+# {
+use byte_unit::{Byte, Unit};
+use criterion::Criterion;
+
+let mut c = Criterion::default();
+let mut group = c.benchmark_group("checksum");
+
+let bytes = Byte::from_u64_with_unit(64, Unit::KiB).unwrap();
+
+group.throughput(bytes.into());
+group.finish();
+# }
+```
+
+```toml
+[dependencies.byte-unit]
+version = "*"
+features = ["criterion"]
+```
+
+## clap Support
+
+Enable the `clap` feature to parse command-line arguments into `Byte`, `Bit` and `Unit` instances with [clap](https://crates.io/crates/clap). They implement `ValueParserFactory`, and the `clap` module provides value parsers which only accept sizes in a range, e.g. `#[arg(long, value_parser = byte_unit::clap::byte_in_range("1KiB".."1GiB"))]`.
+
+```rust
+# #[cfg(feature = "clap")]
+# {
+use byte_unit::{clap::byte_in_range, Byte};
+use clap::{Arg, Command};
+
+let command = Command::new("app")
+    .arg(Arg::new("buffer").long("buffer").value_parser(byte_in_range("1KiB".."1GiB")));
+
+let matches = command.try_get_matches_from(["app", "--buffer", "64 MiB"]).unwrap();
+
+assert_eq!(Some(&Byte::from_u64(64 << 20)), matches.get_one::<Byte>("buffer"));
+# }
+```
+
+```toml
+[dependencies.byte-unit]
+version = "*"
+features = ["clap"]
+```
+
+## Localization
+
+Enable the `locale` feature to write and read sizes with the unit symbols and the separators of a locale, such as `"1,5 Go"` in French and `"1.234,57 MB"` in German. The separators can also be set individually.
+
+```rust
+# #[cfg(feature = "locale")]
+# {
+use byte_unit::{Byte, ByteParser, FormatOptions, Locale, UnitType};
+
+let locale = Locale::try_from_bcp47("fr-FR").unwrap();
+
+let adjusted_byte = Byte::from_u64(1_500_000_000).get_appropriate_unit(UnitType::Decimal);
+
+assert_eq!("1,5 Go", adjusted_byte.display_with(FormatOptions::new().locale(locale)).to_string());
+
+assert_eq!(Byte::from_u64(1_500_000_000), ByteParser::new().with_locale(locale).parse("1,5 Go").unwrap());
+# }
+```
+
+```toml
+[dependencies.byte-unit]
+version = "*"
+features = ["locale"]
+```
+
+## Saturating Support
+
+Enable the `saturating` feature to convert `Byte` and `Bit` instances to and from `core::num::Saturating<u64>` and `Saturating<u128>`. `Saturating` was stabilized in Rust 1.74, so this feature needs a newer compiler than the rest of this crate.
+
+```rust
+# #[cfg(feature = "saturating")]
+# {
+use std::num::Saturating;
+
+use byte_unit::Byte;
+
+assert_eq!(Saturating(1024u64), Saturating::from(Byte::from_u64(1024)));
+# }
+```
+
+```toml
+[dependencies.byte-unit]
+version = "*"
+features = ["saturating"]
+```
+
+## Strict Mode
+
+Enable the `strict` feature to deprecate the APIs which go through floating-point numbers, such as `Byte::from_f64` and `AdjustedByte::get_value`, so that the compiler warns about every use of them. The deprecation notes point to the `Decimal` equivalents.
+
+The APIs are deprecated instead of removed, so enabling the feature never breaks other crates which depend on this crate. The trait implementations for floating-point numbers, such as `TryFrom<f64>`, cannot be deprecated and are still available.
+
+```toml
+[dependencies.byte-unit]
+version = "*"
+features = ["strict"]
+```
+
+## Cloud Allocation
+
+Enable the `cloud` feature to fit sizes into the ranges accepted by cloud providers, such as the sizes of Amazon EBS volumes and the part sizes of Amazon S3 multipart uploads.
+
+```rust
+# #[cfg(feature = "cloud")]
+# {
+use byte_unit::{
+    cloud::{ebs_volume_size, s3_multipart_part_size},
+    Byte,
+};
+
+assert_eq!(Some(Byte::from_u64(10 << 30)), ebs_volume_size(Byte::from_u64(10_000_000_000)));
+assert_eq!(Some(Byte::from_u64(105 << 20)), s3_multipart_part_size(Byte::from_u64(1 << 40)));
+# }
+```
+
+```toml
+[dependencies.byte-unit]
+version = "*"
+features = ["cloud"]
+```
+
+## Network Buffers
+
+Enable the `net` feature to size network buffers from link rates. `net::bdp` calculates the bandwidth-delay product of a link, and `net::tcp_buffer_size` suggests a TCP buffer size clamped into sysctl-style bounds such as `net::LINUX_TCP_RMEM`.
+
+```rust
+# #[cfg(feature = "net")]
+# {
+use std::time::Duration;
+
+use byte_unit::{
+    net::{bdp, tcp_buffer_size, LINUX_TCP_RMEM},
+    BitRate, Byte,
+};
+
+let rate = BitRate::parse_str("100 Mbps").unwrap();
+
+assert_eq!(Byte::from_u64(1_250_000), bdp(rate, Duration::from_millis(100)));
+assert_eq!(Byte::from_u64(2_500_000), tcp_buffer_size(rate, Duration::from_millis(100), LINUX_TCP_RMEM));
+# }
+```
+
+```toml
+[dependencies.byte-unit]
+version = "*"
+features = ["net"]
+```
+
+## Test Utilities
+
+Enable the `test-util` feature to reuse the generators and the invariant checks of this crate in the tests of your own types which embed `Byte` instances. The `assert_bytes_eq!` macro compares two sizes and, on failure, prints both of them with units and as exact integers, along with their difference.
+
+```rust
+# #[cfg(feature = "test-util")]
+# {
+use byte_unit::test_util::{assert_roundtrip, representative_bytes};
+
+for byte in representative_bytes() {
+    assert_roundtrip(byte);
+}
+# }
+```
+
+```toml
+[dev-dependencies.byte-unit]
+version = "*"
+features = ["test-util"]
+```
+
+## Test Data Generation
+
+Enable the `testgen` feature to generate deterministic pseudo-random sequences of sizes, which follow a uniform, Zipf or log-normal distribution, for benchmarks and simulations.
+
+```rust
+# #[cfg(feature = "testgen")]
+# {
+use byte_unit::{testgen::SizeSequence, Byte};
+
+let sizes = SizeSequence::zipf(Byte::from_u64(4096), 1000, 1.1).seed(42).take(100).collect::<Vec<Byte>>();
+# }
+```
+
+```toml
+[dev-dependencies.byte-unit]
+version = "*"
+features = ["testgen"]
+```
+
+## Fuzzing and Property-Based Testing
+
+Enable the `arbitrary` feature to implement [`arbitrary::Arbitrary`](https://docs.rs/arbitrary) for fuzzing, or the `proptest` feature to implement [`proptest::arbitrary::Arbitrary`](https://docs.rs/proptest) for property-based testing. `Byte`, `Bit`, `Unit`, `AdjustedByte` and `AdjustedBit` are supported. The generated sizes cover the whole range, and boundary values such as `Byte::MAX` and exact multiples of units are generated frequently. With `proptest`, use `any::<Byte>()` as a strategy.
+
+```rust
+# #[cfg(feature = "arbitrary")]
+# {
+use arbitrary::{Arbitrary, Unstructured};
+use byte_unit::Byte;
+
+let mut u = Unstructured::new(&[0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0]);
+
+let byte = Byte::arbitrary(&mut u).unwrap();
+# }
+```
+
+```toml
+[dev-dependencies.byte-unit]
+version = "*"
+features = ["arbitrary"]
+```
+
+## Arrow and Polars Support
+
+Enable the `arrow` feature to convert slices of `Byte` instances to [Apache Arrow](https://docs.rs/arrow-array) `UInt64Array` or `Decimal128Array` arrays and back, e.g. for writing Parquet files. Enable the `polars` feature to convert `Byte` instances to and from [Polars](https://docs.rs/polars-core) `AnyValue` values. `arrow-array` and `polars-core` need Rust 1.85, so these features need a newer compiler than the rest of this crate.
+
+```rust
+# #[cfg(feature = "arrow")]
+# {
+use byte_unit::{
+    arrow::{from_uint64_array, to_uint64_array},
+    Byte,
+};
+
+let sizes = [512, 1024, 1536].map(Byte::from_u64);
+
+let array = to_uint64_array(&sizes).unwrap();
+
+assert_eq!(vec![Some(Byte::from_u64(1024))], from_uint64_array(&array.slice(1, 1)));
+# }
+```
+
+```toml
+[dependencies.byte-unit]
+version = "*"
+features = ["arrow", "polars"]
+```
+
+## JSON Values
+
+Enable the `json` feature to read sizes from dynamic [`serde_json::Value`](https://docs.rs/serde_json) values with `Byte::from_json_value` or `Byte::try_from`. Numbers are read as numbers of bytes, and strings are parsed like `Byte::parse_str` does.
+
+```rust
+# #[cfg(feature = "json")]
+# {
+use byte_unit::Byte;
+use serde_json::json;
+
+let config = json!({ "cache": "64 MiB", "buffer": 4096 });
+
+assert_eq!(Byte::from_u64(64 << 20), Byte::from_json_value(&config["cache"]).unwrap());
+assert_eq!(Byte::from_u64(4096), Byte::try_from(&config["buffer"]).unwrap());
+# }
+```
+
+```toml
+[dependencies.byte-unit]
+version = "*"
+features = ["json"]
+```
 */
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -210,17 +705,76 @@ extern crate alloc;
 #[cfg(feature = "rust_decimal")]
 pub extern crate rust_decimal;
 
+#[cfg(any(feature = "byte", feature = "bit"))]
+mod appropriate_unit_options;
+#[cfg(feature = "arrow")]
+#[clippy::msrv = "1.85"]
+pub mod arrow;
+#[cfg(feature = "byte")]
+pub mod audit;
+#[cfg(feature = "byte")]
+pub mod bench;
 #[cfg(feature = "bit")]
 mod bit;
 #[cfg(feature = "byte")]
+pub mod bulk;
+#[cfg(feature = "byte")]
 mod byte;
+#[cfg(feature = "clap")]
+pub mod clap;
+#[cfg(feature = "cloud")]
+pub mod cloud;
+#[cfg(feature = "parser-combinator")]
+pub mod combinators;
 mod common;
 mod errors;
+#[cfg(feature = "byte")]
+pub mod extract;
+#[cfg(any(feature = "byte", feature = "bit"))]
+mod format;
+#[cfg(feature = "locale")]
+mod locale;
+#[cfg(feature = "net")]
+pub mod net;
+#[cfg(any(feature = "byte", feature = "bit"))]
+mod parse_options;
+#[cfg(any(feature = "byte", feature = "bit"))]
+mod precision;
+#[cfg(any(feature = "byte", feature = "bit"))]
+mod rate;
+#[cfg(any(feature = "byte", feature = "bit"))]
+mod rounding_mode;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "byte")]
+pub mod sort;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+#[cfg(feature = "testgen")]
+pub mod testgen;
 mod unit;
+#[cfg(any(feature = "byte", feature = "bit"))]
+mod unit_preference;
 
+#[cfg(any(feature = "byte", feature = "bit"))]
+pub use appropriate_unit_options::*;
 #[cfg(feature = "bit")]
 pub use bit::*;
 #[cfg(feature = "byte")]
 pub use byte::*;
 pub use errors::*;
+#[cfg(any(feature = "byte", feature = "bit"))]
+pub use format::*;
+#[cfg(feature = "locale")]
+pub use locale::*;
+#[cfg(any(feature = "byte", feature = "bit"))]
+pub use parse_options::*;
+#[cfg(any(feature = "byte", feature = "bit"))]
+pub use precision::*;
+#[cfg(any(feature = "byte", feature = "bit"))]
+pub use rate::*;
+#[cfg(any(feature = "byte", feature = "bit"))]
+pub use rounding_mode::*;
 pub use unit::*;
+#[cfg(any(feature = "byte", feature = "bit"))]
+pub use unit_preference::*;
@@ -0,0 +1,443 @@
+use core::{
+    fmt::{self, Display, Formatter, Write},
+    str::FromStr,
+};
+#[cfg(feature = "std")]
+use std::string::String;
+
+use crate::{Unit, UnsupportedLocaleError};
+
+/// The locales whose unit symbols are built in. The symbols are derived from the [CLDR](https://cldr.unicode.org/) data of digital units.
+///
+/// # Points to Note
+///
+/// * `Locale::En` uses the symbols of this crate, e.g. `"KB"` and `"Kb"`, so that the output stays the same as without a locale.
+/// * CLDR only defines the decimal multiples. The binary multiples follow the same convention where it is common, e.g. `"Gio"` in French and `"ГиБ"` in Russian, and fall back to the symbols of this crate otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{Locale, Unit};
+///
+/// assert_eq!("Go", Locale::Fr.unit_symbol(Unit::GB));
+/// assert_eq!("ГБ", Locale::Ru.unit_symbol(Unit::GB));
+/// assert_eq!("吉字节", Locale::Zh.unit_symbol(Unit::GB));
+/// assert_eq!("GB", Locale::En.unit_symbol(Unit::GB));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    /// English.
+    #[default]
+    En,
+    /// German.
+    De,
+    /// French.
+    Fr,
+    /// Russian.
+    Ru,
+    /// Chinese.
+    Zh,
+}
+
+impl Locale {
+    /// Find the locale of a [BCP 47](https://www.rfc-editor.org/info/bcp47) language tag, such as `"fr"`, `"fr-CA"` or `"zh-Hans-CN"`. Only the primary language subtag decides the locale, and it is case-insensitive. `'_'` is also accepted as the separator of subtags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Locale;
+    ///
+    /// assert_eq!(Ok(Locale::Fr), Locale::try_from_bcp47("fr-CA"));
+    /// assert_eq!(Ok(Locale::Zh), Locale::try_from_bcp47("zh_Hans_CN"));
+    /// assert_eq!(Ok(Locale::De), Locale::try_from_bcp47("DE"));
+    /// assert!(Locale::try_from_bcp47("ja-JP").is_err());
+    /// assert!(Locale::try_from_bcp47("fr--CA").is_err());
+    /// ```
+    pub fn try_from_bcp47(tag: &str) -> Result<Self, UnsupportedLocaleError> {
+        let mut subtags = tag.split(['-', '_']);
+
+        let language = subtags.next().unwrap_or("");
+
+        let well_formed = subtags.all(|subtag| {
+            (1..=8).contains(&subtag.len()) && subtag.bytes().all(|e| e.is_ascii_alphanumeric())
+        });
+
+        if !well_formed {
+            return Err(UnsupportedLocaleError);
+        }
+
+        let locale = if language.eq_ignore_ascii_case("en") {
+            Self::En
+        } else if language.eq_ignore_ascii_case("de") {
+            Self::De
+        } else if language.eq_ignore_ascii_case("fr") {
+            Self::Fr
+        } else if language.eq_ignore_ascii_case("ru") {
+            Self::Ru
+        } else if language.eq_ignore_ascii_case("zh") {
+            Self::Zh
+        } else {
+            return Err(UnsupportedLocaleError);
+        };
+
+        Ok(locale)
+    }
+
+    /// Retrieve the primary language subtag of this locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Locale;
+    ///
+    /// assert_eq!("fr", Locale::Fr.as_str());
+    /// ```
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::De => "de",
+            Self::Fr => "fr",
+            Self::Ru => "ru",
+            Self::Zh => "zh",
+        }
+    }
+
+    /// Retrieve the character which separates the integer part and the fractional part of a number in this locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Locale;
+    ///
+    /// assert_eq!('.', Locale::En.decimal_separator());
+    /// assert_eq!(',', Locale::Fr.decimal_separator());
+    /// ```
+    #[inline]
+    pub const fn decimal_separator(self) -> char {
+        match self {
+            Self::En | Self::Zh => '.',
+            Self::De | Self::Fr | Self::Ru => ',',
+        }
+    }
+
+    /// Retrieve the character which separates the groups of thousands of a number in this locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Locale;
+    ///
+    /// assert_eq!(',', Locale::En.thousands_separator());
+    /// assert_eq!('.', Locale::De.thousands_separator());
+    /// assert_eq!('\u{202f}', Locale::Fr.thousands_separator());
+    /// ```
+    #[inline]
+    pub const fn thousands_separator(self) -> char {
+        match self {
+            Self::En | Self::Zh => ',',
+            Self::De => '.',
+            Self::Fr => '\u{202f}',
+            Self::Ru => '\u{a0}',
+        }
+    }
+
+    /// Retrieve the symbol of a unit in this locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Locale, Unit};
+    ///
+    /// assert_eq!("kB", Locale::De.unit_symbol(Unit::KB));
+    /// assert_eq!("Mio", Locale::Fr.unit_symbol(Unit::MiB));
+    /// assert_eq!("Мбит", Locale::Ru.unit_symbol(Unit::Mbit));
+    /// assert_eq!("KiB", Locale::Zh.unit_symbol(Unit::KiB));
+    /// ```
+    #[inline]
+    pub const fn unit_symbol(self, unit: Unit) -> &'static str {
+        match self {
+            Self::En => unit.as_str(),
+            Self::De => de_unit_symbol(unit),
+            Self::Fr => fr_unit_symbol(unit),
+            Self::Ru => ru_unit_symbol(unit),
+            Self::Zh => zh_unit_symbol(unit),
+        }
+    }
+}
+
+impl Display for Locale {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Locale {
+    type Err = UnsupportedLocaleError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_bcp47(s)
+    }
+}
+
+impl TryFrom<&str> for Locale {
+    type Error = UnsupportedLocaleError;
+
+    /// See [`FromStr`](#impl-FromStr-for-Locale).
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<String> for Locale {
+    type Error = UnsupportedLocaleError;
+
+    /// See [`FromStr`](#impl-FromStr-for-Locale).
+    #[inline]
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+const fn de_unit_symbol(unit: Unit) -> &'static str {
+    match unit {
+        Unit::Bit => "bit",
+        Unit::Kbit => "kbit",
+        Unit::Kibit => "Kibit",
+        Unit::KB => "kB",
+        Unit::Mbit => "Mbit",
+        Unit::Mibit => "Mibit",
+        Unit::Gbit => "Gbit",
+        Unit::Gibit => "Gibit",
+        Unit::Tbit => "Tbit",
+        Unit::Tibit => "Tibit",
+        Unit::Pbit => "Pbit",
+        Unit::Pibit => "Pibit",
+        Unit::Ebit => "Ebit",
+        Unit::Eibit => "Eibit",
+        #[cfg(feature = "u128")]
+        Unit::Zbit => "Zbit",
+        #[cfg(feature = "u128")]
+        Unit::Zibit => "Zibit",
+        #[cfg(feature = "u128")]
+        Unit::Ybit => "Ybit",
+        #[cfg(feature = "u128")]
+        Unit::Yibit => "Yibit",
+        _ => unit.as_str(),
+    }
+}
+
+const fn fr_unit_symbol(unit: Unit) -> &'static str {
+    match unit {
+        Unit::B => "o",
+        Unit::KB => "ko",
+        Unit::KiB => "Kio",
+        Unit::MB => "Mo",
+        Unit::MiB => "Mio",
+        Unit::GB => "Go",
+        Unit::GiB => "Gio",
+        Unit::TB => "To",
+        Unit::TiB => "Tio",
+        Unit::PB => "Po",
+        Unit::PiB => "Pio",
+        Unit::EB => "Eo",
+        Unit::EiB => "Eio",
+        #[cfg(feature = "u128")]
+        Unit::ZB => "Zo",
+        #[cfg(feature = "u128")]
+        Unit::ZiB => "Zio",
+        #[cfg(feature = "u128")]
+        Unit::YB => "Yo",
+        #[cfg(feature = "u128")]
+        Unit::YiB => "Yio",
+        _ => de_unit_symbol(unit),
+    }
+}
+
+const fn ru_unit_symbol(unit: Unit) -> &'static str {
+    match unit {
+        Unit::Bit => "бит",
+        Unit::B => "Б",
+        Unit::Kbit => "кбит",
+        Unit::Kibit => "Кибит",
+        Unit::KB => "кБ",
+        Unit::KiB => "КиБ",
+        Unit::Mbit => "Мбит",
+        Unit::Mibit => "Мибит",
+        Unit::MB => "МБ",
+        Unit::MiB => "МиБ",
+        Unit::Gbit => "Гбит",
+        Unit::Gibit => "Гибит",
+        Unit::GB => "ГБ",
+        Unit::GiB => "ГиБ",
+        Unit::Tbit => "Тбит",
+        Unit::Tibit => "Тибит",
+        Unit::TB => "ТБ",
+        Unit::TiB => "ТиБ",
+        Unit::Pbit => "Пбит",
+        Unit::Pibit => "Пибит",
+        Unit::PB => "ПБ",
+        Unit::PiB => "ПиБ",
+        Unit::Ebit => "Эбит",
+        Unit::Eibit => "Эибит",
+        Unit::EB => "ЭБ",
+        Unit::EiB => "ЭиБ",
+        #[cfg(feature = "u128")]
+        Unit::Zbit => "Збит",
+        #[cfg(feature = "u128")]
+        Unit::Zibit => "Зибит",
+        #[cfg(feature = "u128")]
+        Unit::ZB => "ЗБ",
+        #[cfg(feature = "u128")]
+        Unit::ZiB => "ЗиБ",
+        #[cfg(feature = "u128")]
+        Unit::Ybit => "Йбит",
+        #[cfg(feature = "u128")]
+        Unit::Yibit => "Йибит",
+        #[cfg(feature = "u128")]
+        Unit::YB => "ЙБ",
+        #[cfg(feature = "u128")]
+        Unit::YiB => "ЙиБ",
+    }
+}
+
+const fn zh_unit_symbol(unit: Unit) -> &'static str {
+    match unit {
+        Unit::Bit => "比特",
+        Unit::B => "字节",
+        Unit::Kbit => "千比特",
+        Unit::KB => "千字节",
+        Unit::Mbit => "兆比特",
+        Unit::MB => "兆字节",
+        Unit::Gbit => "吉比特",
+        Unit::GB => "吉字节",
+        Unit::Tbit => "太比特",
+        Unit::TB => "太字节",
+        Unit::Pbit => "拍比特",
+        Unit::PB => "拍字节",
+        Unit::Ebit => "艾比特",
+        Unit::EB => "艾字节",
+        #[cfg(feature = "u128")]
+        Unit::Zbit => "泽比特",
+        #[cfg(feature = "u128")]
+        Unit::ZB => "泽字节",
+        #[cfg(feature = "u128")]
+        Unit::Ybit => "尧比特",
+        #[cfg(feature = "u128")]
+        Unit::YB => "尧字节",
+        _ => unit.as_str(),
+    }
+}
+
+/// The locale-specific parts of `FormatOptions`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct LocalizedFormat {
+    pub(crate) locale:              Locale,
+    pub(crate) decimal_separator:   char,
+    pub(crate) thousands_separator: Option<char>,
+    pub(crate) unit_separator:      char,
+}
+
+impl Default for LocalizedFormat {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocalizedFormat {
+    /// The format without a locale, which is the same as the format of the `Display` implementations.
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        Self {
+            locale:              Locale::En,
+            decimal_separator:   '.',
+            thousands_separator: None,
+            unit_separator:      ' ',
+        }
+    }
+
+    /// Write a number formatted with `'.'` as the decimal separator and without thousands separators, replacing the separators with the ones of this format.
+    pub(crate) fn write_number(
+        &self,
+        f: &mut Formatter<'_>,
+        args: fmt::Arguments<'_>,
+    ) -> fmt::Result {
+        if self.decimal_separator == '.' && self.thousands_separator.is_none() {
+            return f.write_fmt(args);
+        }
+
+        let mut counter = IntegerDigitCounter {
+            digits: 0, in_fraction: false
+        };
+
+        counter.write_fmt(args)?;
+
+        LocalizedNumberWriter {
+            f,
+            remaining_integer_digits: counter.digits,
+            format: self,
+        }
+        .write_fmt(args)
+    }
+}
+
+struct IntegerDigitCounter {
+    digits:      usize,
+    in_fraction: bool,
+}
+
+impl Write for IntegerDigitCounter {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match c {
+                '.' => self.in_fraction = true,
+                '0'..='9' if !self.in_fraction => self.digits += 1,
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+struct LocalizedNumberWriter<'a, 'b, 'c> {
+    f:                        &'a mut Formatter<'b>,
+    remaining_integer_digits: usize,
+    format:                   &'c LocalizedFormat,
+}
+
+impl Write for LocalizedNumberWriter<'_, '_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match c {
+                '.' => {
+                    self.remaining_integer_digits = 0;
+
+                    self.f.write_char(self.format.decimal_separator)?;
+                },
+                '0'..='9' if self.remaining_integer_digits > 0 => {
+                    self.f.write_char(c)?;
+
+                    self.remaining_integer_digits -= 1;
+
+                    if let Some(thousands_separator) = self.format.thousands_separator {
+                        if self.remaining_integer_digits > 0
+                            && self.remaining_integer_digits % 3 == 0
+                        {
+                            self.f.write_char(thousands_separator)?;
+                        }
+                    }
+                },
+                _ => self.f.write_char(c)?,
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+//! Helpers for sizing network buffers from link rates, such as the bandwidth-delay product of a TCP connection.
+
+use core::time::Duration;
+
+use crate::{Bit, BitRate, Byte};
+
+/// The bounds of a TCP buffer size, in the style of the `net.ipv4.tcp_rmem` and `net.ipv4.tcp_wmem` sysctls of Linux, which are written as `"min default max"`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TcpBufferBounds {
+    /// The smallest buffer size.
+    pub min:     Byte,
+    /// The buffer size used when nothing is known about the connection.
+    pub default: Byte,
+    /// The largest buffer size.
+    pub max:     Byte,
+}
+
+impl TcpBufferBounds {
+    /// Clamp `size` into `min..=max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{net::LINUX_TCP_RMEM, Byte};
+    ///
+    /// assert_eq!(LINUX_TCP_RMEM.min, LINUX_TCP_RMEM.clamp(Byte::from_u64(100)));
+    /// assert_eq!(
+    ///     LINUX_TCP_RMEM.max,
+    ///     LINUX_TCP_RMEM.clamp(Byte::from_u64(1 << 30))
+    /// );
+    /// ```
+    #[inline]
+    pub const fn clamp(self, size: Byte) -> Byte {
+        let v = size.as_u128();
+
+        if v < self.min.as_u128() {
+            self.min
+        } else if v > self.max.as_u128() {
+            self.max
+        } else {
+            size
+        }
+    }
+}
+
+/// The default bounds of the receive buffers of Linux: 4 KiB, 128 KiB and 6 MiB.
+pub const LINUX_TCP_RMEM: TcpBufferBounds = TcpBufferBounds {
+    min:     Byte::from_u64(4096),
+    default: Byte::from_u64(131_072),
+    max:     Byte::from_u64(6_291_456),
+};
+
+/// The default bounds of the send buffers of Linux: 4 KiB, 16 KiB and 4 MiB.
+pub const LINUX_TCP_WMEM: TcpBufferBounds = TcpBufferBounds {
+    min:     Byte::from_u64(4096),
+    default: Byte::from_u64(16_384),
+    max:     Byte::from_u64(4_194_304),
+};
+
+/// Calculate the bandwidth-delay product, which is the amount of data in flight on a link of the given rate and round-trip time. The result is rounded up to a whole byte, and saturates at the largest size a `Bit` instance can hold.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use byte_unit::{net::bdp, Bit, BitRate, Byte};
+///
+/// let rate = BitRate::per_second(Bit::from_u64(100_000_000)); // 100 Mbps
+///
+/// assert_eq!(
+///     Byte::from_u64(1_250_000),
+///     bdp(rate, Duration::from_millis(100))
+/// );
+/// ```
+#[inline]
+pub fn bdp(rate: BitRate, rtt: Duration) -> Byte {
+    rate.checked_mul_duration(rtt).unwrap_or(Bit::MAX).as_byte_ceil()
+}
+
+/// Suggest the size of a TCP buffer for a link of the given rate and round-trip time, clamped into `bounds`.
+///
+/// The suggestion is twice the bandwidth-delay product, because the kernel keeps part of a socket buffer for its own bookkeeping, so a buffer of exactly one BDP cannot keep the link full.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use byte_unit::{
+///     net::{tcp_buffer_size, LINUX_TCP_RMEM},
+///     Bit, BitRate, Byte,
+/// };
+///
+/// let rate = BitRate::per_second(Bit::from_u64(100_000_000)); // 100 Mbps
+///
+/// assert_eq!(
+///     Byte::from_u64(2_500_000),
+///     tcp_buffer_size(rate, Duration::from_millis(100), LINUX_TCP_RMEM)
+/// );
+/// assert_eq!(
+///     LINUX_TCP_RMEM.max,
+///     tcp_buffer_size(rate, Duration::from_secs(1), LINUX_TCP_RMEM)
+/// );
+/// ```
+#[inline]
+pub fn tcp_buffer_size(rate: BitRate, rtt: Duration, bounds: TcpBufferBounds) -> Byte {
+    bounds.clamp(bdp(rate, rtt).saturating_mul(2))
+}
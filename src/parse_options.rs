@@ -0,0 +1,325 @@
+use rust_decimal::prelude::*;
+
+use crate::{common::parse_value_from_bytes, Unit, UnitBase, ValueParseError};
+
+/// Choose how the decimal prefixes, such as **"K"** in `"1 KB"`, are interpreted when parsing strings with options.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "byte")]
+/// # {
+/// use byte_unit::{Byte, ParseMode, ParseOptions};
+///
+/// let options = ParseOptions::new().mode(ParseMode::Jedec);
+///
+/// assert_eq!(
+///     Byte::from_u64(1024),
+///     Byte::parse_str_with_options("1 KB", &options).unwrap()
+/// );
+///
+/// let options = ParseOptions::new().mode(ParseMode::Flexible);
+///
+/// assert_eq!(
+///     Byte::from_u64(1024),
+///     Byte::parse_str_with_options("1K", &options).unwrap()
+/// );
+/// assert_eq!(
+///     Byte::from_u64(1000),
+///     Byte::parse_str_with_options("1 KB", &options).unwrap()
+/// );
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum ParseMode {
+    /// Decimal prefixes are multiples of 1000 and binary prefixes are multiples of 1024, e.g. `"1 KB"` and `"1K"` are 1000 bytes, and `"1 KiB"` is 1024 bytes. This is the same as `parse_str`.
+    #[default]
+    Iec,
+    /// Decimal prefixes are multiples of 1024 as well, like Windows and memory specifications, e.g. `"1 KB"`, `"1K"` and `"1 KiB"` are all 1024 bytes.
+    Jedec,
+    /// A prefix without a unit after it is a multiple of 1024, like `dd`, `sort -S` and `java -Xmx`, e.g. `"1K"` and `"1 KiB"` are 1024 bytes, while `"1 KB"` is 1000 bytes.
+    Flexible,
+}
+
+impl ParseMode {
+    /// Adjust a unit parsed from `unit_str` to this mode.
+    pub(crate) fn apply(self, unit: Unit, unit_str: &str) -> Unit {
+        let binary = match self {
+            Self::Iec => false,
+            Self::Jedec => true,
+            // the full names of units and the units themselves always contain a 'b'
+            Self::Flexible => {
+                let unit_str = unit_str.trim();
+
+                !unit_str.is_empty() && !unit_str.contains(['b', 'B'])
+            },
+        };
+
+        if !binary {
+            return unit;
+        }
+
+        match unit.to_parts() {
+            (kind, UnitBase::Decimal, exponent) if exponent > 0 => {
+                Unit::from_parts(kind, UnitBase::Binary, exponent).unwrap_or(unit)
+            },
+            _ => unit,
+        }
+    }
+}
+
+/// Options for parsing strings with [`Byte::parse_str_with_options`](struct.Byte.html#method.parse_str_with_options) or [`Bit::parse_str_with_options`](struct.Bit.html#method.parse_str_with_options).
+///
+/// The default options parse strings in the same way as `parse_str` without ignoring case. Digit group separators can be enabled for values copied from logs or configuration files, such as `"1_500_000"` or `"1,500,000 KB"`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "byte")]
+/// # {
+/// use byte_unit::{Byte, ParseOptions};
+///
+/// let options =
+///     ParseOptions::new().underscores(true).thousands_separator(Some(','));
+///
+/// assert_eq!(
+///     Byte::from_u64(1_500_000),
+///     Byte::parse_str_with_options("1_500_000", &options).unwrap()
+/// );
+/// assert_eq!(
+///     Byte::from_u64(1_500_000_000),
+///     Byte::parse_str_with_options("1,500,000 KB", &options).unwrap()
+/// );
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ParseOptions {
+    pub(crate) mode:                ParseMode,
+    pub(crate) ignore_case:         bool,
+    pub(crate) underscores:         bool,
+    pub(crate) thousands_separator: Option<char>,
+    pub(crate) composite:           bool,
+}
+
+impl Default for ParseOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParseOptions {
+    /// Create a new `ParseOptions` instance which accepts no digit group separators and does not ignore case.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            mode:                ParseMode::Iec,
+            ignore_case:         false,
+            underscores:         false,
+            thousands_separator: None,
+            composite:           false,
+        }
+    }
+
+    /// Set how the decimal prefixes are interpreted. The default value is `ParseMode::Iec`.
+    #[inline]
+    pub const fn mode(mut self, mode: ParseMode) -> Self {
+        self.mode = mode;
+
+        self
+    }
+
+    /// Set whether to ignore the case of **"B"** (byte). See [`Byte::parse_str`](struct.Byte.html#method.parse_str). It has no effect on parsing `Bit` instances.
+    #[inline]
+    pub const fn ignore_case(mut self, ignore_case: bool) -> Self {
+        self.ignore_case = ignore_case;
+
+        self
+    }
+
+    /// Set whether to accept underscores between digits, e.g. `"1_500_000"` or `"0.000_5"`, like Rust literals. The default value is `false`.
+    #[inline]
+    pub const fn underscores(mut self, underscores: bool) -> Self {
+        self.underscores = underscores;
+
+        self
+    }
+
+    /// Set the character which separates the groups of three digits in the integer part, e.g. `Some(',')` for `"1,500,000"` or `Some(' ')` for `"1 500 000"`. The default value is `None`.
+    ///
+    /// The separator is only accepted between digits, and the groups must be complete, so `"1,5 KB"` is rejected instead of being treated as `"15 KB"`.
+    ///
+    /// # Points to Note
+    ///
+    /// * The decimal point is always `'.'`, so `Some('.')` is ignored.
+    #[inline]
+    pub const fn thousands_separator(mut self, thousands_separator: Option<char>) -> Self {
+        self.thousands_separator = match thousands_separator {
+            Some('.') => None,
+            _ => thousands_separator,
+        };
+
+        self
+    }
+
+    /// Set whether to accept a composite string of several values with units separated by spaces, such as `"1 GiB 512 MiB 10 KiB"`, whose sizes are added up, like `"1h 30m"` for a duration. The default value is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "byte")]
+    /// # {
+    /// use byte_unit::{Byte, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().composite(true);
+    ///
+    /// assert_eq!(
+    ///     Byte::from_u64((1 << 30) + (512 << 20) + (10 << 10)),
+    ///     Byte::parse_str_with_options("1 GiB 512 MiB 10 KiB", &options).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     Byte::from_u64(1_500_000_100),
+    ///     Byte::parse_str_with_options("1GB 500MB 100", &options).unwrap()
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * Every value except the last one must be followed by a unit, so `"1 2 MiB"` is still rejected.
+    /// * Each size is rounded up to a whole byte (or bit) before they are added up. If the sum is too large, a `ValueParseError::ExceededBounds` error is returned.
+    #[inline]
+    pub const fn composite(mut self, composite: bool) -> Self {
+        self.composite = composite;
+
+        self
+    }
+
+    #[inline]
+    fn is_separator(&self, c: char) -> bool {
+        (self.underscores && c == '_') || self.thousands_separator == Some(c)
+    }
+
+    /// Split a trimmed string into the value and the rest, which should be the unit. The value follows the grammar of `parse_str`, including an exponent of scientific notation, with digit group separators.
+    pub(crate) fn split_value<'a>(&self, s: &'a str) -> (&'a str, &'a str) {
+        let mut chars = s.char_indices().peekable();
+        let mut previous_is_digit = false;
+
+        while let Some((i, c)) = chars.next() {
+            let accepted = match c {
+                '0'..='9' | '.' => true,
+                // an exponent must follow a digit directly, so the "E" of "1EB" is still a unit
+                'e' | 'E' if previous_is_digit => {
+                    let exponent = s[i + 1..].strip_prefix(['+', '-']).unwrap_or(&s[i + 1..]);
+
+                    if exponent.starts_with(|c: char| c.is_ascii_digit()) {
+                        chars.next_if(|(_, c)| matches!(c, '+' | '-'));
+
+                        true
+                    } else {
+                        false
+                    }
+                },
+                _ if self.is_separator(c) => {
+                    matches!(chars.peek(), Some((_, next)) if next.is_ascii_digit())
+                },
+                _ => false,
+            };
+
+            if !accepted {
+                return s.split_at(i);
+            }
+
+            previous_is_digit = c.is_ascii_digit();
+        }
+
+        (s, "")
+    }
+
+    /// Split a trimmed string into the first value with its unit, and the rest, which starts with the next value. If there is no unit between two values, the string is not split.
+    pub(crate) fn split_component<'a>(&self, s: &'a str) -> (&'a str, &'a str) {
+        let (value, rest) = self.split_value(s);
+
+        match rest.find(|c: char| c.is_ascii_digit()) {
+            Some(i) if !rest[..i].trim().is_empty() => {
+                let (component, rest) = s.split_at(value.len() + i);
+
+                (component.trim_end(), rest)
+            },
+            _ => (s, ""),
+        }
+    }
+
+    /// Parse a value split by `split_value`. `s` is the whole string, which explains why there is no value. The digit group separators are checked and removed, and the rest is parsed in the same way as `parse_str`.
+    pub(crate) fn parse_value(&self, value: &str, s: &str) -> Result<Decimal, ValueParseError> {
+        if value.is_empty() {
+            return Err(match s.chars().next() {
+                Some('-' | '\u{2212}') => ValueParseError::NotNumber('-'),
+                Some(c) => ValueParseError::NotNumber(c),
+                None => ValueParseError::NoValue,
+            });
+        }
+
+        self.check_separators(value)?;
+
+        // `split_value` only accepts ASCII characters other than the separators
+        parse_value_from_bytes(
+            value.chars().filter(|&c| !self.is_separator(c)).map(|c| c as u8),
+            b'.',
+        )
+    }
+
+    /// Check that the digit group separators in a value split by `split_value` are between digits, and that the thousands separators are only in the integer part and separate complete groups.
+    fn check_separators(&self, value: &str) -> Result<(), ValueParseError> {
+        // the number of digits after the last thousands separator, and whether there is one
+        let mut group_length = 0usize;
+        let mut grouped = false;
+
+        let mut integer_part = true;
+        let mut previous_is_digit = false;
+
+        for c in value.chars() {
+            match c {
+                '0'..='9' => {
+                    if integer_part {
+                        group_length += 1;
+                    }
+
+                    previous_is_digit = true;
+
+                    continue;
+                },
+                '_' if self.underscores && previous_is_digit => (),
+                _ if self.thousands_separator == Some(c) => {
+                    if !integer_part
+                        || !previous_is_digit
+                        || (grouped && group_length != 3)
+                        || group_length > 3
+                    {
+                        return Err(ValueParseError::NotNumber(c));
+                    }
+
+                    grouped = true;
+                    group_length = 0;
+                },
+                _ if self.is_separator(c) => return Err(ValueParseError::NotNumber(c)),
+                _ => {
+                    if integer_part && grouped && group_length != 3 {
+                        return Err(ValueParseError::NotNumber(c));
+                    }
+
+                    integer_part = false;
+                },
+            }
+
+            previous_is_digit = false;
+        }
+
+        match self.thousands_separator {
+            Some(separator) if integer_part && grouped && group_length != 3 => {
+                Err(ValueParseError::NotNumber(separator))
+            },
+            _ => Ok(()),
+        }
+    }
+}
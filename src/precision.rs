@@ -0,0 +1,41 @@
+/// Choose how many digits after the decimal point a `Decimal` value can keep.
+///
+/// `Decimal` values can have at most [`Precision::MAX_DIGITS`] digits after the decimal point, so a larger number of digits is clamped to it.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::Precision;
+///
+/// assert_eq!(3, Precision::Digits(3).digits());
+/// assert_eq!(28, Precision::Digits(100).digits());
+/// assert_eq!(28, Precision::Max.digits());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Precision {
+    /// As many digits as possible, which is [`Precision::MAX_DIGITS`].
+    #[default]
+    Max,
+    /// At most the given number of digits.
+    Digits(usize),
+}
+
+impl Precision {
+    /// The maximum number of digits after the decimal point of a `Decimal` value.
+    pub const MAX_DIGITS: usize = 28;
+
+    /// Get the number of digits, clamped to [`Precision::MAX_DIGITS`].
+    #[inline]
+    pub const fn digits(self) -> usize {
+        match self {
+            Self::Max => Self::MAX_DIGITS,
+            Self::Digits(digits) => {
+                if digits > Self::MAX_DIGITS {
+                    Self::MAX_DIGITS
+                } else {
+                    digits
+                }
+            },
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use core::fmt::{self, Display, Formatter};
+
+use crate::{common::div_ceil_u128, UnitType};
+
+/// The time denominator of a rate, such as [`ByteRate`](struct.ByteRate.html) or [`BitRate`](struct.BitRate.html).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum TimeUnit {
+    /// 1 second, **"s"**.
+    #[default]
+    Second,
+    /// 60 seconds, **"min"**.
+    Minute,
+    /// 3600 seconds, **"h"**.
+    Hour,
+    /// 86400 seconds, **"d"**.
+    Day,
+}
+
+impl TimeUnit {
+    /// Retrieve the number of seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::TimeUnit;
+    ///
+    /// assert_eq!(3600, TimeUnit::Hour.as_secs());
+    /// ```
+    #[inline]
+    pub const fn as_secs(self) -> u64 {
+        match self {
+            Self::Second => 1,
+            Self::Minute => 60,
+            Self::Hour => 3600,
+            Self::Day => 86400,
+        }
+    }
+
+    /// Retrieve the symbol.
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Second => "s",
+            Self::Minute => "min",
+            Self::Hour => "h",
+            Self::Day => "d",
+        }
+    }
+
+    /// Parse a time unit, case-insensitively. The symbols and the full names in English are accepted, e.g. **"s"**, **"sec"**, **"second"**, **"min"**, **"h"**, **"hr"**, **"hour"**, **"d"** and **"day"**, as well as their plurals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::TimeUnit;
+    ///
+    /// assert_eq!(Some(TimeUnit::Second), TimeUnit::parse_str("sec"));
+    /// assert_eq!(Some(TimeUnit::Hour), TimeUnit::parse_str("Hours"));
+    /// assert_eq!(None, TimeUnit::parse_str("week"));
+    /// ```
+    pub fn parse_str<S: AsRef<str>>(s: S) -> Option<Self> {
+        const NAMES: [(TimeUnit, &[&str]); 4] = [
+            (TimeUnit::Second, &["s", "sec", "secs", "second", "seconds"]),
+            (TimeUnit::Minute, &["min", "mins", "minute", "minutes"]),
+            (TimeUnit::Hour, &["h", "hr", "hrs", "hour", "hours"]),
+            (TimeUnit::Day, &["d", "day", "days"]),
+        ];
+
+        let s = s.as_ref().trim();
+
+        NAMES
+            .iter()
+            .find(|(_, names)| names.iter().any(|name| name.eq_ignore_ascii_case(s)))
+            .map(|(time_unit, _)| *time_unit)
+    }
+}
+
+impl Display for TimeUnit {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Choose how to find an appropriate unit for a rate.
+///
+/// It extends [`UnitType`] with `DecimalBitsOnly`, which is common in telecommunications and network monitoring (e.g. SNMP interface speeds), where rates are always reported in decimal bit units.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum RateUnitType {
+    /// Units in powers of 2, like `UnitType::Binary`.
+    Binary,
+    /// Units in powers of 10, like `UnitType::Decimal`.
+    #[default]
+    Decimal,
+    /// Units in powers of 2 and 10, like `UnitType::Both`.
+    Both,
+    /// Decimal bit units only, e.g. `Kbit`, `Mbit` and `Gbit`, even if the size is in bytes. Binary units like `Kibit` are never chosen.
+    DecimalBitsOnly,
+}
+
+impl RateUnitType {
+    /// The corresponding `UnitType`, which is `UnitType::Decimal` for `DecimalBitsOnly`.
+    #[inline]
+    pub const fn unit_type(self) -> UnitType {
+        match self {
+            Self::Binary => UnitType::Binary,
+            Self::Decimal | Self::DecimalBitsOnly => UnitType::Decimal,
+            Self::Both => UnitType::Both,
+        }
+    }
+}
+
+impl From<UnitType> for RateUnitType {
+    #[inline]
+    fn from(unit_type: UnitType) -> Self {
+        match unit_type {
+            UnitType::Binary => Self::Binary,
+            UnitType::Decimal => Self::Decimal,
+            UnitType::Both => Self::Both,
+        }
+    }
+}
+
+/// The protocol overhead of a link, expressed as the ratio of the payload carried by a packet to the bytes it occupies on the wire. It converts an advertised line rate into the expected payload throughput (goodput), and back. See [`BitRate::apply_overhead`](struct.BitRate.html#method.apply_overhead) and [`ByteRate::apply_overhead`](struct.ByteRate.html#method.apply_overhead).
+///
+/// The built-in profiles assume full-sized packets on an Ethernet link with a 1500-byte MTU, where every frame occupies 1538 bytes on the wire, including the preamble, the frame check sequence and the inter-frame gap.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct OverheadProfile {
+    payload: u32,
+    wire:    u32,
+}
+
+impl OverheadProfile {
+    /// TCP over IPv4 over Ethernet, without TCP options: 1460 bytes of payload per 1538 bytes on the wire.
+    pub const ETHERNET_IPV4_TCP: Self = Self::new(1460, 1538);
+    /// QUIC over IPv4 and Ethernet, with 1-RTT packets carrying an 8-byte connection ID, a 4-byte packet number, a 16-byte AEAD tag and an 8-byte STREAM frame header: 1435 bytes of payload per 1538 bytes on the wire.
+    pub const QUIC: Self = Self::new(1435, 1538);
+    /// TCP over IPv4 inside a VXLAN tunnel over IPv4 and Ethernet, whose encapsulation takes 50 bytes of the outer MTU: 1410 bytes of payload per 1538 bytes on the wire.
+    pub const VXLAN: Self = Self::new(1410, 1538);
+
+    /// Create a new `OverheadProfile` instance in which `wire` bytes on the wire carry `payload` bytes of payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::OverheadProfile;
+    ///
+    /// // TCP over IPv6 over Ethernet
+    /// let profile = OverheadProfile::new(1440, 1538);
+    ///
+    /// assert_eq!(1440.0 / 1538.0, profile.efficiency());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `wire` is zero or `payload` is larger than `wire`.
+    #[inline]
+    pub const fn new(payload: u32, wire: u32) -> Self {
+        assert!(wire > 0, "the size on the wire must not be zero");
+        assert!(payload <= wire, "the payload must not be larger than the size on the wire");
+
+        Self {
+            payload,
+            wire,
+        }
+    }
+
+    /// Retrieve the payload carried by `get_wire_size` bytes on the wire.
+    #[inline]
+    pub const fn get_payload_size(self) -> u32 {
+        self.payload
+    }
+
+    /// Retrieve the bytes on the wire which carry `get_payload_size` bytes of payload.
+    #[inline]
+    pub const fn get_wire_size(self) -> u32 {
+        self.wire
+    }
+
+    /// Retrieve the ratio of the payload to the bytes on the wire, between `0.0` and `1.0`.
+    #[inline]
+    pub fn efficiency(self) -> f64 {
+        self.payload as f64 / self.wire as f64
+    }
+
+    /// Scale a line rate down to the payload it carries, rounded down.
+    #[inline]
+    pub(crate) const fn goodput_u128(self, line_rate: u128) -> u128 {
+        let (payload, wire) = (self.payload as u128, self.wire as u128);
+
+        line_rate / wire * payload + line_rate % wire * payload / wire
+    }
+
+    /// Scale a payload rate up to the line rate needed to carry it, rounded up. Returns `None` on overflow.
+    #[inline]
+    pub(crate) const fn line_rate_u128(self, goodput: u128) -> Option<u128> {
+        let (payload, wire) = (self.payload as u128, self.wire as u128);
+
+        if payload == 0 {
+            return if goodput == 0 { Some(0) } else { None };
+        }
+
+        match (goodput / payload).checked_mul(wire) {
+            Some(v) => v.checked_add(div_ceil_u128(goodput % payload * wire, payload)),
+            None => None,
+        }
+    }
+}
+
+/// The number of nanoseconds in a second.
+pub(crate) const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Split a rate string into the size and the time unit. `"12.5 MB/s"` is split at the slash, and `"100 Mbps"` is split before the trailing **"ps"** (**"PS"** is also accepted if `ignore_case` is `true`).
+pub(crate) fn split_rate(s: &str, ignore_case: bool) -> Option<(&str, &str)> {
+    let s = s.trim();
+
+    if let Some((size, time_unit)) = s.rsplit_once('/') {
+        return Some((size, time_unit));
+    }
+
+    let size = match s.strip_suffix("ps") {
+        Some(size) => size,
+        None if ignore_case => s.strip_suffix("PS")?,
+        None => return None,
+    };
+
+    Some((size, "s"))
+}
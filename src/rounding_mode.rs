@@ -0,0 +1,37 @@
+/// Choose how to round a size to a multiple of a unit.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+    /// Round towards zero.
+    Down,
+    /// Round away from zero.
+    Up,
+    /// Round to the nearest multiple, and round half away from zero.
+    #[default]
+    Nearest,
+}
+
+impl RoundingMode {
+    /// Round `value` to a multiple of `step`, which must not be zero. Returns `None` on overflow.
+    #[inline]
+    pub(crate) const fn round_u128(self, value: u128, step: u128) -> Option<u128> {
+        let remainder = value % step;
+
+        if remainder == 0 {
+            return Some(value);
+        }
+
+        let down = value - remainder;
+
+        let up = match self {
+            Self::Down => false,
+            Self::Up => true,
+            Self::Nearest => remainder >= step - remainder,
+        };
+
+        if up {
+            down.checked_add(step)
+        } else {
+            Some(down)
+        }
+    }
+}
@@ -0,0 +1,196 @@
+//! Serialize and deserialize a map keyed by `Byte`, `Bit` or `Unit` instances, whose keys are written as canonical strings such as `"1 KiB"`, regardless of whether the format is human-readable.
+//!
+//! Formats such as JSON only allow strings as the keys of maps. With this module, a `HashMap<Byte, V>` or a `BTreeMap<Byte, V>` can be used directly, without converting the keys to strings and back. The keys of `Byte` and `Bit` instances are the alternate form (`{:#}`) of their `Display` implementations with `UnitPreference::Largest`, regardless of the global preference, so each size has exactly one key. When deserializing, every string accepted by `FromStr` is allowed, so `"1024"` and `"1 KiB"` are the same key.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::collections::BTreeMap;
+//!
+//! use byte_unit::Byte;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Pricing {
+//!     #[serde(with = "byte_unit::serde::as_map_key")]
+//!     tiers: BTreeMap<Byte, f64>,
+//! }
+//!
+//! let pricing = Pricing {
+//!     tiers: BTreeMap::from([
+//!         (Byte::from_u64(1024), 0.5),
+//!         (Byte::from_u64(1_000_000_000), 0.25),
+//!     ]),
+//! };
+//!
+//! assert_eq!(
+//!     r#"{"tiers":{"1 KiB":0.5,"1 GB":0.25}}"#,
+//!     serde_json::to_string(&pricing).unwrap()
+//! );
+//! assert_eq!(
+//!     pricing,
+//!     serde_json::from_str(r#"{"tiers":{"1024":0.5,"1GB":0.25}}"#).unwrap()
+//! );
+//! ```
+//!
+//! # Points to Note
+//!
+//! * The map type must implement `IntoIterator`, `Default` and `Extend`, like `HashMap` and `BTreeMap`. If a key appears twice, the later entry wins, as `Extend` does.
+
+use core::{
+    fmt::{self, Display, Formatter},
+    marker::PhantomData,
+    str::FromStr,
+};
+
+use serde::{
+    de::{Error as DeError, MapAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::invalid_str;
+#[cfg(any(feature = "byte", feature = "bit"))]
+use super::Canonical;
+#[cfg(feature = "bit")]
+use crate::Bit;
+#[cfg(feature = "byte")]
+use crate::Byte;
+use crate::Unit;
+
+/// Types which can be used as the keys of maps with this module.
+pub trait MapKey: FromStr {
+    /// Write the canonical string of this key.
+    fn fmt_key(&self, f: &mut Formatter<'_>) -> fmt::Result;
+}
+
+#[cfg(feature = "byte")]
+impl MapKey for Byte {
+    #[inline]
+    fn fmt_key(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&Canonical(*self), f)
+    }
+}
+
+#[cfg(feature = "bit")]
+impl MapKey for Bit {
+    #[inline]
+    fn fmt_key(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&Canonical(*self), f)
+    }
+}
+
+impl MapKey for Unit {
+    #[inline]
+    fn fmt_key(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A key to be serialized as a string.
+struct SerializeKey<'a, K: MapKey>(&'a K);
+
+impl<K: MapKey> Display for SerializeKey<'_, K> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt_key(f)
+    }
+}
+
+impl<K: MapKey> Serialize for SerializeKey<'_, K> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer, {
+        serializer.collect_str(self)
+    }
+}
+
+/// A key to be deserialized from a string.
+struct DeserializeKey<K>(K);
+
+impl<'de, K: MapKey> Deserialize<'de> for DeserializeKey<K>
+where
+    K::Err: Display,
+{
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>, {
+        struct MyVisitor<K>(PhantomData<K>);
+
+        impl<'de, K: MapKey> Visitor<'de> for MyVisitor<K>
+        where
+            K::Err: Display,
+        {
+            type Value = DeserializeKey<K>;
+
+            #[inline]
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("a string such as \"123\", \"123KiB\" or \"MiB\"")
+            }
+
+            #[inline]
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: DeError, {
+                K::from_str(v).map(DeserializeKey).map_err(|error| invalid_str(v, &self, error))
+            }
+        }
+
+        deserializer.deserialize_str(MyVisitor(PhantomData))
+    }
+}
+
+/// Serialize a map whose keys are written as strings.
+#[inline]
+pub fn serialize<'a, M, K, V, S>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+where
+    &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+    K: MapKey + 'a,
+    V: Serialize + 'a,
+    S: Serializer, {
+    serializer.collect_map(map.into_iter().map(|(key, value)| (SerializeKey(key), value)))
+}
+
+/// Deserialize a map whose keys are strings.
+#[inline]
+pub fn deserialize<'de, M, K, V, D>(deserializer: D) -> Result<M, D::Error>
+where
+    M: IntoIterator<Item = (K, V)> + Default + Extend<(K, V)>,
+    K: MapKey,
+    K::Err: Display,
+    V: Deserialize<'de>,
+    D: Deserializer<'de>, {
+    struct MyVisitor<M, K, V>(PhantomData<(M, K, V)>);
+
+    impl<'de, M, K, V> Visitor<'de> for MyVisitor<M, K, V>
+    where
+        M: IntoIterator<Item = (K, V)> + Default + Extend<(K, V)>,
+        K: MapKey,
+        K::Err: Display,
+        V: Deserialize<'de>,
+    {
+        type Value = M;
+
+        #[inline]
+        fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            f.write_str("a map whose keys are strings such as \"123\", \"123KiB\" or \"MiB\"")
+        }
+
+        fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>, {
+            let mut map = M::default();
+
+            while let Some((DeserializeKey(key), value)) =
+                access.next_entry::<DeserializeKey<K>, V>()?
+            {
+                map.extend(core::iter::once((key, value)));
+            }
+
+            Ok(map)
+        }
+    }
+
+    deserializer.deserialize_map(MyVisitor(PhantomData))
+}
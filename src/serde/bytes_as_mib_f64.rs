@@ -0,0 +1,95 @@
+//! Serialize and deserialize a `Byte` as a floating-point number of mebibytes (MiB), regardless of whether the format is human-readable.
+//!
+//! When deserializing, the size is rounded up to a whole byte, and an integer is accepted as well.
+//!
+//! # Examples
+//!
+//! ```
+//! use byte_unit::Byte;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Quota {
+//!     #[serde(with = "byte_unit::serde::bytes_as_mib_f64")]
+//!     size: Byte,
+//! }
+//!
+//! let quota = Quota {
+//!     size: Byte::from_u64(1572864)
+//! };
+//!
+//! assert_eq!(r#"{"size":1.5}"#, serde_json::to_string(&quota).unwrap());
+//! assert_eq!(quota, serde_json::from_str(r#"{"size":1.5}"#).unwrap());
+//! ```
+//!
+//! # Points to Note
+//!
+//! * A `f64` has only 53 bits of precision, so very large sizes may not be serialized exactly.
+
+use core::fmt::{self, Formatter};
+
+use rust_decimal::prelude::*;
+use serde::{
+    de::{Error as DeError, Unexpected, Visitor},
+    Deserializer, Serializer,
+};
+
+use crate::{Byte, Unit};
+
+/// Serialize a `Byte` as a `f64` of mebibytes.
+#[inline]
+pub fn serialize<S>(byte: &Byte, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer, {
+    serializer.serialize_f64(byte.as_u128() as f64 / Unit::MiB.as_bytes_u128() as f64)
+}
+
+/// Deserialize a `Byte` from a `f64` of mebibytes.
+#[inline]
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Byte, D::Error>
+where
+    D: Deserializer<'de>, {
+    struct MyVisitor;
+
+    impl<'de> Visitor<'de> for MyVisitor {
+        type Value = Byte;
+
+        #[inline]
+        fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            f.write_str("a non-negative number of mebibytes")
+        }
+
+        #[inline]
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: DeError, {
+            Byte::from_u64_with_unit(v, Unit::MiB)
+                .ok_or_else(|| DeError::invalid_value(Unexpected::Unsigned(v), &self))
+        }
+
+        #[inline]
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: DeError, {
+            match u64::try_from(v) {
+                Ok(v) => self.visit_u64(v),
+                Err(_) => Err(DeError::invalid_value(Unexpected::Signed(v), &self)),
+            }
+        }
+
+        #[inline]
+        fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+        where
+            E: DeError, {
+            if v < 0.0 {
+                return Err(DeError::invalid_value(Unexpected::Float(v), &self));
+            }
+
+            Decimal::from_f64(v)
+                .and_then(|value| Byte::from_decimal_with_unit(value, Unit::MiB))
+                .ok_or_else(|| DeError::invalid_value(Unexpected::Float(v), &self))
+        }
+    }
+
+    deserializer.deserialize_f64(MyVisitor)
+}
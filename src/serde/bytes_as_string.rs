@@ -0,0 +1,70 @@
+//! Serialize and deserialize a `Byte` as a string such as `"1.5 GiB"`, regardless of whether the format is human-readable.
+//!
+//! The string is the same as the alternate form (`{:#}`) of the `Display` implementation of `Byte` with `UnitPreference::Largest`, regardless of the global preference. When deserializing, the string is parsed by [`Byte::parse_str`](../../struct.Byte.html#method.parse_str) with `ignore_case` set to `false`.
+//!
+//! # Examples
+//!
+//! ```
+//! use byte_unit::Byte;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Quota {
+//!     #[serde(with = "byte_unit::serde::bytes_as_string")]
+//!     size: Byte,
+//! }
+//!
+//! let quota = Quota {
+//!     size: Byte::from_u64(1610612736)
+//! };
+//!
+//! assert_eq!(r#"{"size":"1.5 GiB"}"#, serde_json::to_string(&quota).unwrap());
+//! assert_eq!(quota, serde_json::from_str(r#"{"size":"1.5 GiB"}"#).unwrap());
+//! ```
+
+use core::{
+    fmt::{self, Formatter},
+    str::FromStr,
+};
+
+use serde::{
+    de::{Error as DeError, Visitor},
+    Deserializer, Serializer,
+};
+
+use super::{invalid_str, Canonical};
+use crate::Byte;
+
+/// Serialize a `Byte` as a string.
+#[inline]
+pub fn serialize<S>(byte: &Byte, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer, {
+    serializer.collect_str(&Canonical(*byte))
+}
+
+/// Deserialize a `Byte` from a string.
+#[inline]
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Byte, D::Error>
+where
+    D: Deserializer<'de>, {
+    struct MyVisitor;
+
+    impl<'de> Visitor<'de> for MyVisitor {
+        type Value = Byte;
+
+        #[inline]
+        fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            f.write_str("a string such as \"123\", \"123KiB\" or \"50.84 MB\"")
+        }
+
+        #[inline]
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: DeError, {
+            Byte::from_str(v).map_err(|error| invalid_str(v, &self, error))
+        }
+    }
+
+    deserializer.deserialize_str(MyVisitor)
+}
@@ -0,0 +1,84 @@
+//! Serialize and deserialize a `Byte` as an unsigned 64-bit integer of its size in bytes, regardless of whether the format is human-readable.
+//!
+//! # Examples
+//!
+//! ```
+//! use byte_unit::Byte;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Quota {
+//!     #[serde(with = "byte_unit::serde::bytes_as_u64")]
+//!     size: Byte,
+//! }
+//!
+//! let quota = Quota {
+//!     size: Byte::from_u64(1610612736)
+//! };
+//!
+//! assert_eq!(
+//!     r#"{"size":1610612736}"#,
+//!     serde_json::to_string(&quota).unwrap()
+//! );
+//! assert_eq!(quota, serde_json::from_str(r#"{"size":1610612736}"#).unwrap());
+//! ```
+//!
+//! # Points to Note
+//!
+//! * If the `u128` feature is enabled, serializing a size larger than **2<sup>64</sup> - 1** bytes fails.
+
+use core::fmt::{self, Formatter};
+
+use serde::{
+    de::{Error as DeError, Unexpected, Visitor},
+    ser::Error as SerError,
+    Deserializer, Serializer,
+};
+
+use crate::Byte;
+
+/// Serialize a `Byte` as a `u64`.
+#[inline]
+pub fn serialize<S>(byte: &Byte, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer, {
+    match u64::try_from(byte.as_u128()) {
+        Ok(value) => serializer.serialize_u64(value),
+        Err(_) => Err(SerError::custom(format_args!("{} bytes do not fit in u64", byte.as_u128()))),
+    }
+}
+
+/// Deserialize a `Byte` from a `u64`.
+#[inline]
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Byte, D::Error>
+where
+    D: Deserializer<'de>, {
+    struct MyVisitor;
+
+    impl<'de> Visitor<'de> for MyVisitor {
+        type Value = Byte;
+
+        #[inline]
+        fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            f.write_str("a non-negative integer of bytes")
+        }
+
+        #[inline]
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: DeError, {
+            Ok(Byte::from_u64(v))
+        }
+
+        #[inline]
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: DeError, {
+            u64::try_from(v)
+                .map(Byte::from_u64)
+                .map_err(|_| E::invalid_value(Unexpected::Signed(v), &self))
+        }
+    }
+
+    deserializer.deserialize_u64(MyVisitor)
+}
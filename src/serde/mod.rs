@@ -0,0 +1,83 @@
+//! Modules for customizing the serde representations, to be used with `#[serde(with = "...")]`.
+
+pub mod as_map_key;
+#[cfg(feature = "byte")]
+pub mod bytes_as_mib_f64;
+#[cfg(feature = "byte")]
+pub mod bytes_as_string;
+#[cfg(feature = "byte")]
+pub mod bytes_as_u64;
+#[cfg(any(feature = "byte", feature = "bit"))]
+mod options;
+pub mod unit_as_factor;
+#[cfg(feature = "byte")]
+pub mod value_and_unit;
+#[cfg(feature = "byte")]
+pub mod wire;
+
+use core::fmt::{self, Display, Formatter};
+
+use serde::de::{Error as DeError, Expected, Unexpected};
+
+#[cfg(any(feature = "byte", feature = "bit"))]
+use crate::format::fmt_with_unit;
+#[cfg(feature = "bit")]
+use crate::Bit;
+#[cfg(feature = "byte")]
+use crate::Byte;
+
+/// The regular expression of the strings accepted by the `Deserialize` implementations of `Byte` and `Bit`, for their JSON schemas.
+#[cfg(all(feature = "schemars", any(feature = "byte", feature = "bit")))]
+pub(crate) const SIZE_PATTERN: &str = r"^\s*[0-9]+(\.[0-9]+)?([eE][+-]?[0-9]+)?\s*[A-Za-z]*\s*$";
+
+/// A `Byte` or `Bit` instance written in the fixed form of the [wire contract](wire/index.html), which is the alternate form (`{:#}`) of its `Display` implementation with `UnitPreference::Largest`, regardless of the global preference.
+#[cfg(any(feature = "byte", feature = "bit"))]
+pub(crate) struct Canonical<T>(pub(crate) T);
+
+#[cfg(feature = "byte")]
+impl Display for Canonical<Byte> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (value, unit) = self.0.get_recoverable_unit(false, 3);
+
+        fmt_with_unit(f, format_args!("{}", value.normalize()), Some(unit))
+    }
+}
+
+#[cfg(feature = "bit")]
+impl Display for Canonical<Bit> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (value, unit) = self.0.get_recoverable_unit(false, 3);
+
+        fmt_with_unit(f, format_args!("{}", value.normalize()), Some(unit))
+    }
+}
+
+/// What a visitor expects, followed by the reason why the input was rejected.
+struct ExpectedWithReason<'a, R: Display> {
+    expected: &'a dyn Expected,
+    reason:   R,
+}
+
+impl<R: Display> Expected for ExpectedWithReason<'_, R> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.expected.fmt(f)?;
+
+        f.write_fmt(format_args!(" ({})", self.reason))
+    }
+}
+
+/// Create an `invalid_value` error which includes the offending string and the reason why it cannot be parsed, so that the message is still useful when it is swallowed by an untagged enum.
+#[inline]
+pub(crate) fn invalid_str<E: DeError, R: Display>(
+    v: &str,
+    expected: &dyn Expected,
+    reason: R,
+) -> E {
+    E::invalid_value(Unexpected::Str(v), &ExpectedWithReason {
+        expected,
+        reason,
+    })
+}
@@ -0,0 +1,371 @@
+use alloc::string::String;
+use core::fmt::{self, Formatter};
+
+use serde::{
+    de::{Error as DeError, MapAccess, SeqAccess, Unexpected, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+#[cfg(feature = "locale")]
+use super::invalid_str;
+#[cfg(feature = "locale")]
+use crate::Locale;
+use crate::{FormatOptions, FormatStyle, ParseMode, ParseOptions};
+
+#[cfg(feature = "locale")]
+const FORMAT_OPTIONS_FIELDS: &[&str] = &[
+    "style",
+    "precision",
+    "keep_trailing_zeros",
+    "locale",
+    "decimal_separator",
+    "thousands_separator",
+    "unit_separator",
+];
+#[cfg(not(feature = "locale"))]
+const FORMAT_OPTIONS_FIELDS: &[&str] = &["style", "precision", "keep_trailing_zeros"];
+
+const PARSE_OPTIONS_FIELDS: &[&str] =
+    &["ignore_case", "underscores", "thousands_separator", "mode", "composite"];
+
+impl FormatStyle {
+    #[inline]
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Standard => "standard",
+            Self::Compact => "compact",
+            Self::Verbose => "verbose",
+        }
+    }
+}
+
+impl Serialize for FormatStyle {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer, {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for FormatStyle {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>, {
+        struct MyVisitor;
+
+        impl<'de> Visitor<'de> for MyVisitor {
+            type Value = FormatStyle;
+
+            #[inline]
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("\"standard\", \"compact\" or \"verbose\"")
+            }
+
+            #[inline]
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: DeError, {
+                [FormatStyle::Standard, FormatStyle::Compact, FormatStyle::Verbose]
+                    .into_iter()
+                    .find(|style| style.as_str().eq_ignore_ascii_case(v))
+                    .ok_or_else(|| DeError::invalid_value(Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_str(MyVisitor)
+    }
+}
+
+impl ParseMode {
+    #[inline]
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Iec => "iec",
+            Self::Jedec => "jedec",
+            Self::Flexible => "flexible",
+        }
+    }
+}
+
+impl Serialize for ParseMode {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer, {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ParseMode {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>, {
+        struct MyVisitor;
+
+        impl<'de> Visitor<'de> for MyVisitor {
+            type Value = ParseMode;
+
+            #[inline]
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("\"iec\", \"jedec\" or \"flexible\"")
+            }
+
+            #[inline]
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: DeError, {
+                [ParseMode::Iec, ParseMode::Jedec, ParseMode::Flexible]
+                    .into_iter()
+                    .find(|mode| mode.as_str().eq_ignore_ascii_case(v))
+                    .ok_or_else(|| DeError::invalid_value(Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_str(MyVisitor)
+    }
+}
+
+#[cfg(feature = "locale")]
+impl Serialize for Locale {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer, {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "locale")]
+impl<'de> Deserialize<'de> for Locale {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>, {
+        struct MyVisitor;
+
+        impl<'de> Visitor<'de> for MyVisitor {
+            type Value = Locale;
+
+            #[inline]
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("a language tag such as \"en\" or \"fr-CA\"")
+            }
+
+            #[inline]
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: DeError, {
+                Locale::try_from_bcp47(v).map_err(|error| invalid_str(v, &self, error))
+            }
+        }
+
+        deserializer.deserialize_str(MyVisitor)
+    }
+}
+
+impl Serialize for FormatOptions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer, {
+        let mut state =
+            serializer.serialize_struct("FormatOptions", FORMAT_OPTIONS_FIELDS.len())?;
+
+        state.serialize_field("style", &self.style)?;
+        state.serialize_field("precision", &self.precision)?;
+        state.serialize_field("keep_trailing_zeros", &self.keep_trailing_zeros)?;
+
+        #[cfg(feature = "locale")]
+        {
+            state.serialize_field("locale", &self.localized.locale)?;
+            state.serialize_field("decimal_separator", &self.localized.decimal_separator)?;
+            state.serialize_field("thousands_separator", &self.localized.thousands_separator)?;
+            state.serialize_field("unit_separator", &self.localized.unit_separator)?;
+        }
+
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for FormatOptions {
+    /// Missing fields take the default values. If `locale` is set, the separators which are not set explicitly are the ones of the locale, like [`FormatOptions::locale`](struct.FormatOptions.html#method.locale).
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>, {
+        struct MyVisitor;
+
+        impl<'de> Visitor<'de> for MyVisitor {
+            type Value = FormatOptions;
+
+            #[inline]
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("an object such as { \"style\": \"compact\", \"precision\": 2 }")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>, {
+                let mut options = FormatOptions::new();
+
+                options.style =
+                    seq.next_element()?.ok_or_else(|| DeError::invalid_length(0, &self))?;
+                options.precision =
+                    seq.next_element()?.ok_or_else(|| DeError::invalid_length(1, &self))?;
+                options.keep_trailing_zeros =
+                    seq.next_element()?.ok_or_else(|| DeError::invalid_length(2, &self))?;
+
+                #[cfg(feature = "locale")]
+                let options = options
+                    .locale(seq.next_element()?.ok_or_else(|| DeError::invalid_length(3, &self))?)
+                    .decimal_separator(
+                        seq.next_element()?.ok_or_else(|| DeError::invalid_length(4, &self))?,
+                    )
+                    .thousands_separator(
+                        seq.next_element()?.ok_or_else(|| DeError::invalid_length(5, &self))?,
+                    )
+                    .unit_separator(
+                        seq.next_element()?.ok_or_else(|| DeError::invalid_length(6, &self))?,
+                    );
+
+                Ok(options)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>, {
+                let mut options = FormatOptions::new();
+
+                #[cfg(feature = "locale")]
+                let (
+                    mut locale,
+                    mut decimal_separator,
+                    mut thousands_separator,
+                    mut unit_separator,
+                ) = (None, None, None, None);
+
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "style" => options.style = map.next_value()?,
+                        "precision" => options.precision = map.next_value()?,
+                        "keep_trailing_zeros" => options.keep_trailing_zeros = map.next_value()?,
+                        #[cfg(feature = "locale")]
+                        "locale" => locale = Some(map.next_value()?),
+                        #[cfg(feature = "locale")]
+                        "decimal_separator" => decimal_separator = Some(map.next_value()?),
+                        #[cfg(feature = "locale")]
+                        "thousands_separator" => thousands_separator = Some(map.next_value()?),
+                        #[cfg(feature = "locale")]
+                        "unit_separator" => unit_separator = Some(map.next_value()?),
+                        _ => {
+                            return Err(DeError::unknown_field(key.as_str(), FORMAT_OPTIONS_FIELDS))
+                        },
+                    }
+                }
+
+                #[cfg(feature = "locale")]
+                {
+                    if let Some(locale) = locale {
+                        options = options.locale(locale);
+                    }
+
+                    if let Some(decimal_separator) = decimal_separator {
+                        options = options.decimal_separator(decimal_separator);
+                    }
+
+                    if let Some(thousands_separator) = thousands_separator {
+                        options = options.thousands_separator(thousands_separator);
+                    }
+
+                    if let Some(unit_separator) = unit_separator {
+                        options = options.unit_separator(unit_separator);
+                    }
+                }
+
+                Ok(options)
+            }
+        }
+
+        deserializer.deserialize_struct("FormatOptions", FORMAT_OPTIONS_FIELDS, MyVisitor)
+    }
+}
+
+impl Serialize for ParseOptions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer, {
+        let mut state = serializer.serialize_struct("ParseOptions", PARSE_OPTIONS_FIELDS.len())?;
+
+        state.serialize_field("ignore_case", &self.ignore_case)?;
+        state.serialize_field("underscores", &self.underscores)?;
+        state.serialize_field("thousands_separator", &self.thousands_separator)?;
+        state.serialize_field("mode", &self.mode)?;
+        state.serialize_field("composite", &self.composite)?;
+
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ParseOptions {
+    /// Missing fields take the default values.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>, {
+        struct MyVisitor;
+
+        impl<'de> Visitor<'de> for MyVisitor {
+            type Value = ParseOptions;
+
+            #[inline]
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("an object such as { \"ignore_case\": true, \"underscores\": true }")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>, {
+                let ignore_case =
+                    seq.next_element()?.ok_or_else(|| DeError::invalid_length(0, &self))?;
+                let underscores =
+                    seq.next_element()?.ok_or_else(|| DeError::invalid_length(1, &self))?;
+                let thousands_separator =
+                    seq.next_element()?.ok_or_else(|| DeError::invalid_length(2, &self))?;
+                let mode = seq.next_element()?.ok_or_else(|| DeError::invalid_length(3, &self))?;
+                let composite =
+                    seq.next_element()?.ok_or_else(|| DeError::invalid_length(4, &self))?;
+
+                Ok(ParseOptions::new()
+                    .ignore_case(ignore_case)
+                    .underscores(underscores)
+                    .thousands_separator(thousands_separator)
+                    .mode(mode)
+                    .composite(composite))
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>, {
+                let mut options = ParseOptions::new();
+
+                while let Some(key) = map.next_key::<String>()? {
+                    options = match key.as_str() {
+                        "ignore_case" => options.ignore_case(map.next_value()?),
+                        "underscores" => options.underscores(map.next_value()?),
+                        "thousands_separator" => options.thousands_separator(map.next_value()?),
+                        "mode" => options.mode(map.next_value()?),
+                        "composite" => options.composite(map.next_value()?),
+                        _ => {
+                            return Err(DeError::unknown_field(key.as_str(), PARSE_OPTIONS_FIELDS))
+                        },
+                    };
+                }
+
+                Ok(options)
+            }
+        }
+
+        deserializer.deserialize_struct("ParseOptions", PARSE_OPTIONS_FIELDS, MyVisitor)
+    }
+}
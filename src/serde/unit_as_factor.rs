@@ -0,0 +1,126 @@
+//! Serialize a `Unit` as its size in bytes, and deserialize a `Unit` from either its size in bytes or a string.
+//!
+//! This is useful when interoperating with APIs that encode block-size units numerically (e.g. `1024` for `KiB`).
+//!
+//! # Examples
+//!
+//! ```
+//! use byte_unit::Unit;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct BlockSize {
+//!     #[serde(with = "byte_unit::serde::unit_as_factor")]
+//!     unit: Unit,
+//! }
+//!
+//! assert_eq!(
+//!     r#"{"unit":1024}"#,
+//!     serde_json::to_string(&BlockSize {
+//!         unit: Unit::KiB
+//!     })
+//!     .unwrap()
+//! );
+//!
+//! assert_eq!(
+//!     BlockSize {
+//!         unit: Unit::KB
+//!     },
+//!     serde_json::from_str(r#"{"unit":1000}"#).unwrap()
+//! );
+//! assert_eq!(
+//!     BlockSize {
+//!         unit: Unit::MiB
+//!     },
+//!     serde_json::from_str(r#"{"unit":"MiB"}"#).unwrap()
+//! );
+//! ```
+//!
+//! # Points to Note
+//!
+//! * Bit units, including the multiples such as `Kbit`, cannot be serialized, because a number is always deserialized as a byte unit.
+//! * Deserialization relies on `Deserializer::deserialize_any`, so it only works with self-describing formats.
+
+use core::{fmt, fmt::Formatter, str::FromStr};
+
+use serde::{
+    de::{Error as DeError, Unexpected, Visitor},
+    ser::Error as SerError,
+    Deserializer, Serializer,
+};
+
+use super::invalid_str;
+use crate::Unit;
+
+/// Serialize a `Unit` as its size in bytes.
+#[inline]
+pub fn serialize<S>(unit: &Unit, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer, {
+    if unit.is_bit() {
+        return Err(S::Error::custom("a bit unit cannot be represented as a size in bytes"));
+    }
+
+    let factor = unit.as_bits_u128() >> 3;
+
+    match u64::try_from(factor) {
+        Ok(factor) => serializer.serialize_u64(factor),
+        Err(_) => serializer.serialize_u128(factor),
+    }
+}
+
+/// Deserialize a `Unit` from its size in bytes or from a string.
+#[inline]
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Unit, D::Error>
+where
+    D: Deserializer<'de>, {
+    struct MyVisitor;
+
+    impl<'de> Visitor<'de> for MyVisitor {
+        type Value = Unit;
+
+        #[inline]
+        fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            f.write_str(
+                "a size in bytes such as 1000 or 1024, or a string such as \"KB\" or \"KiB\"",
+            )
+        }
+
+        #[inline]
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: DeError, {
+            Unit::from_bytes_factor(v as u128)
+                .ok_or_else(|| DeError::invalid_value(Unexpected::Unsigned(v), &self))
+        }
+
+        #[inline]
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: DeError, {
+            if v < 0 {
+                Err(DeError::invalid_value(Unexpected::Signed(v), &self))
+            } else {
+                self.visit_u64(v as u64)
+            }
+        }
+
+        #[inline]
+        fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+        where
+            E: DeError, {
+            Unit::from_bytes_factor(v).ok_or_else(|| {
+                DeError::invalid_value(Unexpected::Other("an unsupported size"), &self)
+            })
+        }
+
+        #[inline]
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: DeError, {
+            Unit::from_str(v).map_err(|error| invalid_str(v, &self, error))
+        }
+    }
+
+    deserializer.deserialize_any(MyVisitor)
+}
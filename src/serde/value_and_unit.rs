@@ -0,0 +1,265 @@
+//! Serialize and deserialize a `Byte` as an object with two fields, such as `{ "value": 1.5, "unit": "GiB" }`.
+//!
+//! The value and the unit are chosen in the same way as the alternate form (`{:#}`) of the `Display` implementation of `Byte`. The value is serialized as an integer if it has no fractional part. If the value cannot be represented by a `f64` precisely, the size in bytes is serialized with the `B` unit instead. When deserializing, the value can be an integer, a floating-point number or a string, and the unit is parsed by [`Unit::parse_str`](../../enum.Unit.html#method.parse_str) with `ignore_case` set to `true`.
+//!
+//! # Examples
+//!
+//! ```
+//! use byte_unit::Byte;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Quota {
+//!     #[serde(with = "byte_unit::serde::value_and_unit")]
+//!     size: Byte,
+//! }
+//!
+//! let quota = Quota {
+//!     size: Byte::from_u64(1610612736)
+//! };
+//!
+//! assert_eq!(
+//!     r#"{"size":{"value":1.5,"unit":"GiB"}}"#,
+//!     serde_json::to_string(&quota).unwrap()
+//! );
+//!
+//! assert_eq!(
+//!     quota,
+//!     serde_json::from_str(r#"{"size":{"value":1.5,"unit":"GiB"}}"#).unwrap()
+//! );
+//! assert_eq!(
+//!     quota,
+//!     serde_json::from_str(r#"{"size":{"value":"1.5","unit":"gib"}}"#)
+//!         .unwrap()
+//! );
+//! ```
+//!
+//! # Points to Note
+//!
+//! * Deserialization relies on `Deserializer::deserialize_any` for the value, so it only works with self-describing formats.
+//! * If the `schemars` feature is enabled, use [`ValueAndUnit`](./struct.ValueAndUnit.html) with `#[schemars(with = "byte_unit::serde::value_and_unit::ValueAndUnit")]` to describe the object form in JSON schemas.
+
+use alloc::string::String;
+use core::fmt::{self, Formatter};
+
+use rust_decimal::prelude::*;
+use serde::{
+    de::{Error as DeError, MapAccess, Unexpected, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serializer,
+};
+
+use super::invalid_str;
+use crate::{common::parse_value, Byte, Unit};
+
+/// A type for describing the object form in JSON schemas. See the [module-level documentation](./index.html).
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "schemars")]
+/// # {
+/// use byte_unit::Byte;
+/// use schemars::JsonSchema;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize, JsonSchema)]
+/// struct Quota {
+///     #[serde(with = "byte_unit::serde::value_and_unit")]
+///     #[schemars(with = "byte_unit::serde::value_and_unit::ValueAndUnit")]
+///     size: Byte,
+/// }
+///
+/// let schema = schemars::schema_for!(Quota);
+///
+/// assert!(schema.as_value().to_string().contains("\"unit\""));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValueAndUnit;
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ValueAndUnit {
+    #[inline]
+    fn schema_name() -> alloc::borrow::Cow<'static, str> {
+        "ValueAndUnit".into()
+    }
+
+    #[inline]
+    fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "object",
+            "properties": {
+                "value": {
+                    "type": "number",
+                    "minimum": 0
+                },
+                "unit": {
+                    "type": "string",
+                    "examples": ["B", "KB", "KiB", "MB", "MiB", "Mb"]
+                }
+            },
+            "required": ["value", "unit"]
+        })
+    }
+}
+
+/// Serialize a `Byte` as an object with the `value` and `unit` fields.
+pub fn serialize<S>(byte: &Byte, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer, {
+    let (value, unit) = byte.get_recoverable_unit(false, 3);
+    let value = value.normalize();
+
+    let mut state = serializer.serialize_struct("Byte", 2)?;
+
+    match value.to_f64() {
+        Some(f) if value.scale() > 0 && Decimal::from_f64(f) == Some(value) => {
+            state.serialize_field("value", &f)?;
+            state.serialize_field("unit", &unit)?;
+        },
+        _ => {
+            // integers, and fractions which cannot be represented by `f64` precisely
+            let (value, unit) = if value.scale() == 0 {
+                (value.to_u128().unwrap(), unit)
+            } else {
+                (byte.as_u128(), Unit::B)
+            };
+
+            match u64::try_from(value) {
+                Ok(value) => state.serialize_field("value", &value)?,
+                Err(_) => state.serialize_field("value", &value)?,
+            }
+
+            state.serialize_field("unit", &unit)?;
+        },
+    }
+
+    state.end()
+}
+
+struct Value(Decimal);
+
+impl<'de> Deserialize<'de> for Value {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>, {
+        struct MyVisitor;
+
+        impl<'de> Visitor<'de> for MyVisitor {
+            type Value = Value;
+
+            #[inline]
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("a non-negative number or a string such as \"1.5\"")
+            }
+
+            #[inline]
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: DeError, {
+                Ok(Value(Decimal::from(v)))
+            }
+
+            #[inline]
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+            where
+                E: DeError, {
+                if v < 0 {
+                    Err(DeError::invalid_value(Unexpected::Signed(v), &self))
+                } else {
+                    Ok(Value(Decimal::from(v)))
+                }
+            }
+
+            #[inline]
+            fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+            where
+                E: DeError, {
+                Decimal::from_u128(v).map(Value).ok_or_else(|| {
+                    DeError::invalid_value(Unexpected::Other(format!("{v}").as_str()), &self)
+                })
+            }
+
+            #[inline]
+            fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+            where
+                E: DeError, {
+                if v < 0.0 {
+                    return Err(DeError::invalid_value(Unexpected::Float(v), &self));
+                }
+
+                Decimal::from_f64(v)
+                    .map(Value)
+                    .ok_or_else(|| DeError::invalid_value(Unexpected::Float(v), &self))
+            }
+
+            #[inline]
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: DeError, {
+                parse_value(v).map(Value).map_err(|error| invalid_str(v, &self, error))
+            }
+        }
+
+        deserializer.deserialize_any(MyVisitor)
+    }
+}
+
+/// Deserialize a `Byte` from an object with the `value` and `unit` fields.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Byte, D::Error>
+where
+    D: Deserializer<'de>, {
+    struct MyVisitor;
+
+    impl<'de> Visitor<'de> for MyVisitor {
+        type Value = Byte;
+
+        #[inline]
+        fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            f.write_str("an object such as { \"value\": 1.5, \"unit\": \"GiB\" }")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>, {
+            let mut value: Option<Decimal> = None;
+            let mut unit: Option<Unit> = None;
+
+            while let Some(key) = map.next_key::<String>()? {
+                match key.as_str() {
+                    "value" => {
+                        if value.is_some() {
+                            return Err(DeError::duplicate_field("value"));
+                        }
+
+                        value = Some(map.next_value::<Value>()?.0);
+                    },
+                    "unit" => {
+                        if unit.is_some() {
+                            return Err(DeError::duplicate_field("unit"));
+                        }
+
+                        let s = map.next_value::<String>()?;
+
+                        unit =
+                            Some(Unit::parse_str(&s, true, true).map_err(|error| {
+                                invalid_str(&s, &"a unit such as \"GiB\"", error)
+                            })?);
+                    },
+                    _ => return Err(DeError::unknown_field(key.as_str(), &["value", "unit"])),
+                }
+            }
+
+            let value = value.ok_or_else(|| DeError::missing_field("value"))?;
+            let unit = unit.ok_or_else(|| DeError::missing_field("unit"))?;
+
+            Byte::from_decimal_with_unit(value, unit).ok_or_else(|| {
+                DeError::invalid_value(Unexpected::Other(format!("{value}").as_str()), &self)
+            })
+        }
+    }
+
+    deserializer.deserialize_struct("Byte", &["value", "unit"], MyVisitor)
+}
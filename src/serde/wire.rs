@@ -0,0 +1,101 @@
+//! The exact serialized forms of `Byte`, `Bit` and `Unit` instances, which are kept stable across minor versions.
+//!
+//! Sizes are often embedded in persisted snapshots, caches and messages, which may be read by a different version of this crate than the one which wrote them. The forms below are a contract: they only change in a new major version, and every form written by an older `5.x` version can be read by a newer one. The examples in this module are golden tests, so any change to the bytes produced breaks the build.
+//!
+//! # Human-Readable Formats
+//!
+//! In human-readable formats, such as JSON, TOML and YAML, a `Byte` or `Bit` instance is written as a string in the alternate form (`{:#}`) of its `Display` implementation with `UnitPreference::Largest`, which is the value followed by a space and the largest unit with which the size can be written exactly in at most three decimal places. Sizes smaller than 1 KB (or 1 Kb) are written in bytes (or bits).
+//!
+//! ```
+//! use byte_unit::Byte;
+//!
+//! assert_eq!(r#""0 B""#, serde_json::to_string(&Byte::from_u64(0)).unwrap());
+//! assert_eq!(
+//!     r#""100 B""#,
+//!     serde_json::to_string(&Byte::from_u64(100)).unwrap()
+//! );
+//! assert_eq!(
+//!     r#""1 KiB""#,
+//!     serde_json::to_string(&Byte::from_u64(1024)).unwrap()
+//! );
+//! assert_eq!(
+//!     r#""1.025 KB""#,
+//!     serde_json::to_string(&Byte::from_u64(1025)).unwrap()
+//! );
+//! assert_eq!(
+//!     r#""1.5 KiB""#,
+//!     serde_json::to_string(&Byte::from_u64(1536)).unwrap()
+//! );
+//! assert_eq!(
+//!     r#""1 GB""#,
+//!     serde_json::to_string(&Byte::from_u64(1_000_000_000)).unwrap()
+//! );
+//! ```
+//!
+//! ```
+//! # #[cfg(feature = "bit")]
+//! # {
+//! use byte_unit::Bit;
+//!
+//! assert_eq!(
+//!     r#""1 Kib""#,
+//!     serde_json::to_string(&Bit::from_u64(1024)).unwrap()
+//! );
+//! assert_eq!(
+//!     r#""100 Mb""#,
+//!     serde_json::to_string(&Bit::from_u64(100_000_000)).unwrap()
+//! );
+//! # }
+//! ```
+//!
+//! When reading, every string accepted by `FromStr` is allowed, so `"1024"`, `"1 KiB"` and `"1KiB"` are the same size.
+//!
+//! ```
+//! use byte_unit::Byte;
+//!
+//! for json in [r#""1024""#, r#""1 KiB""#, r#""1KiB""#] {
+//!     assert_eq!(
+//!         Byte::from_u64(1024),
+//!         serde_json::from_str::<Byte>(json).unwrap()
+//!     );
+//! }
+//! ```
+//!
+//! # Compact Formats
+//!
+//! In formats which are not human-readable, such as postcard and bincode, a `Byte` or `Bit` instance is written as an integer with `serialize_u128`. Formats with variable-length integers, like postcard, produce the same bytes as for a `u64` integer for sizes up to `u64::MAX`.
+//!
+//! ```
+//! use byte_unit::Byte;
+//!
+//! assert_eq!(vec![0x00], postcard::to_allocvec(&Byte::from_u64(0)).unwrap());
+//! assert_eq!(
+//!     vec![0x80, 0x08],
+//!     postcard::to_allocvec(&Byte::from_u64(1024)).unwrap()
+//! );
+//! assert_eq!(
+//!     vec![0x80, 0x94, 0xEB, 0xDC, 0x03],
+//!     postcard::to_allocvec(&Byte::from_u64(1_000_000_000)).unwrap()
+//! );
+//! ```
+//!
+//! Formats with fixed-width integers, like bincode, write 16 bytes. To write a `Byte` field as a `u64` integer instead, for example for a format which does not support `u128`, use `#[serde(with = "byte_unit::serde::bytes_as_u64")]`. See [`bytes_as_u64`](../bytes_as_u64/index.html).
+//!
+//! # Units
+//!
+//! A `Unit` instance is written as its symbol, [`Unit::as_str`](../../enum.Unit.html#method.as_str), in every format.
+//!
+//! ```
+//! use byte_unit::Unit;
+//!
+//! assert_eq!(r#""KiB""#, serde_json::to_string(&Unit::KiB).unwrap());
+//! assert_eq!(
+//!     vec![3, b'K', b'i', b'B'],
+//!     postcard::to_allocvec(&Unit::KiB).unwrap()
+//! );
+//! ```
+//!
+//! # Points to Note
+//!
+//! * The unit written in a human-readable format does not follow the global [`UnitPreference`](../../enum.UnitPreference.html), which only affects the `Display` implementations, so `10240` bytes is always written as `"10 KiB"`.
+//! * To pin the string further, for example to always write plain integers, use one of the sibling modules, such as [`bytes_as_u64`](../bytes_as_u64/index.html) or [`bytes_as_string`](../bytes_as_string/index.html).
@@ -0,0 +1,54 @@
+//! Helpers for sorting human-readable sizes.
+
+use core::{cmp::Ordering, str::FromStr};
+
+use crate::{Byte, ParseError};
+
+/// Compare two strings by the sizes they represent. The strings are parsed by [`Byte::from_str`](../struct.Byte.html#method.from_str).
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use byte_unit::sort::cmp_size_str;
+///
+/// assert_eq!(Ordering::Less, cmp_size_str("10 KB", "9 MB").unwrap());
+/// assert_eq!(Ordering::Equal, cmp_size_str("1 KiB", "1024").unwrap());
+/// assert!(cmp_size_str("10 KB", "nine").is_err());
+/// ```
+#[inline]
+pub fn cmp_size_str<A: AsRef<str>, B: AsRef<str>>(a: A, b: B) -> Result<Ordering, ParseError> {
+    let a = Byte::from_str(a.as_ref())?;
+    let b = Byte::from_str(b.as_ref())?;
+
+    Ok(a.cmp(&b))
+}
+
+/// Sort strings in ascending order by the sizes they represent. The strings are parsed by [`Byte::from_str`](../struct.Byte.html#method.from_str). The sort is stable.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::sort::sort_size_strings;
+///
+/// let mut sizes = vec!["9 MB", "10 KB", "1 GiB", "512"];
+///
+/// sort_size_strings(&mut sizes).unwrap();
+///
+/// assert_eq!(vec!["512", "10 KB", "9 MB", "1 GiB"], sizes);
+/// ```
+///
+/// # Points to Note
+///
+/// * If any string cannot be parsed, this function will return the error and the slice will be left unchanged.
+#[cfg(feature = "std")]
+pub fn sort_size_strings<S: AsRef<str>>(strings: &mut [S]) -> Result<(), ParseError> {
+    for s in strings.iter() {
+        Byte::from_str(s.as_ref())?;
+    }
+
+    strings.sort_by_cached_key(|s| Byte::from_str(s.as_ref()).unwrap_or(Byte::MIN));
+
+    Ok(())
+}
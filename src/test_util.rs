@@ -0,0 +1,221 @@
+//! Generators and invariant checks for testing code which stores sizes as `Byte` instances, e.g. in configuration types.
+//!
+//! ```
+//! use byte_unit::test_util::{assert_roundtrip, representative_bytes};
+//!
+//! for byte in representative_bytes() {
+//!     assert_roundtrip(byte);
+//! }
+//! ```
+
+use core::{fmt, str::FromStr};
+use std::vec::Vec;
+
+use crate::{Byte, Unit, UnitType};
+
+/// Get all the units, from the smallest to the largest. The units of the `u128` feature are included only if the feature is enabled.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{test_util::all_units, Unit};
+///
+/// let units = all_units().collect::<Vec<_>>();
+///
+/// assert_eq!(Some(&Unit::Bit), units.first());
+/// assert!(units.contains(&Unit::KiB));
+/// ```
+#[inline]
+pub fn all_units() -> impl Iterator<Item = Unit> {
+    Unit::get_all()
+}
+
+/// Get `Byte` instances which are likely to break the assumptions about parsing and formatting: zero, the bounds, and the sizes around every unit and its half.
+///
+/// The returned sizes are sorted in ascending order and have no duplicates.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{test_util::representative_bytes, Byte};
+///
+/// let bytes = representative_bytes();
+///
+/// assert_eq!(Some(&Byte::MIN), bytes.first());
+/// assert_eq!(Some(&Byte::MAX), bytes.last());
+/// assert!(bytes.contains(&Byte::from_u64(1023)));
+/// ```
+pub fn representative_bytes() -> Vec<Byte> {
+    let max = Byte::MAX.as_u128();
+
+    let mut sizes: Vec<u128> = vec![0, 1, 2, 7, 8, 9, max - 1, max];
+
+    for unit in all_units() {
+        if matches!(unit, Unit::Bit) {
+            continue;
+        }
+
+        let factor = unit.as_bytes_u128();
+
+        for size in [factor - 1, factor, factor + 1, factor + factor / 2, factor * 1023] {
+            if size <= max {
+                sizes.push(size);
+            }
+        }
+    }
+
+    sizes.sort_unstable();
+    sizes.dedup();
+
+    sizes.into_iter().filter_map(Byte::from_u128).collect()
+}
+
+/// Assert that `byte` survives all the lossless conversions of this crate.
+///
+/// The following invariants are checked:
+///
+/// * The plain string (`"{byte}"`) is parsed back to the same size.
+/// * The string with a unit (`"{byte:#}"`) is parsed back to the same size.
+/// * The value and the unit obtained from [`Byte::get_exact_unit`](../struct.Byte.html#method.get_exact_unit) are converted back to the same size.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{test_util::assert_roundtrip, Byte};
+///
+/// assert_roundtrip(Byte::from_u64(1536));
+/// ```
+///
+/// # Panics
+///
+/// Panics with a message including `byte` if any invariant is violated.
+#[track_caller]
+pub fn assert_roundtrip(byte: Byte) {
+    let s = format!("{byte}");
+
+    assert_eq!(
+        Some(byte),
+        Byte::from_str(&s).ok(),
+        "the plain string {s:?} of {byte:?} is not recoverable"
+    );
+
+    let s = format!("{byte:#}");
+
+    assert_eq!(
+        Some(byte),
+        Byte::from_str(&s).ok(),
+        "the string {s:?} of {byte:?} is not recoverable"
+    );
+
+    for allow_in_bits in [false, true] {
+        let (value, unit) = byte.get_exact_unit(allow_in_bits);
+
+        assert_eq!(
+            Some(byte),
+            Byte::from_u128_with_unit(value, unit),
+            "the exact unit ({value}, {unit}) of {byte:?} is not recoverable"
+        );
+    }
+}
+
+/// Assert that `unit` is parsed back from its symbol.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::test_util::{all_units, assert_unit_roundtrip};
+///
+/// for unit in all_units() {
+///     assert_unit_roundtrip(unit);
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics with a message including `unit` if it is not recovered.
+#[track_caller]
+pub fn assert_unit_roundtrip(unit: Unit) {
+    assert_eq!(
+        Some(unit),
+        Unit::parse_str(unit.as_str(), false, true).ok(),
+        "the symbol of {unit:?} is not recoverable"
+    );
+}
+
+/// Assert that two `Byte` instances are equal, like `assert_eq!`. On failure, both sizes are printed with units and as exact integers, followed by their difference, so that a confusion of units is easy to spot.
+///
+/// ```text
+/// assertion `left == right` failed
+///   left: 1 GiB (1073741824)
+///  right: 1 GB (1000000000)
+///   diff: 70.3 MiB (73741824)
+/// ```
+///
+/// Like `assert_eq!`, a custom message with format arguments can be given after the two sizes.
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{assert_bytes_eq, Byte, Unit};
+///
+/// let byte = Byte::from_u64_with_unit(1, Unit::MiB).unwrap();
+///
+/// assert_bytes_eq!(Byte::from_u64(1048576), byte);
+/// assert_bytes_eq!(
+///     Byte::from_u64(1048576),
+///     byte,
+///     "the size of {}",
+///     "a block"
+/// );
+/// ```
+///
+/// ```should_panic
+/// use byte_unit::{assert_bytes_eq, Byte, Unit};
+///
+/// assert_bytes_eq!(
+///     Byte::from_u64_with_unit(1, Unit::GiB).unwrap(),
+///     Byte::from_u64_with_unit(1, Unit::GB).unwrap()
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_bytes_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if *left != *right {
+                    $crate::test_util::assert_bytes_eq_failed(*left, *right, None);
+                }
+            },
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if *left != *right {
+                    $crate::test_util::assert_bytes_eq_failed(
+                        *left,
+                        *right,
+                        Some(format_args!($($arg)+)),
+                    );
+                }
+            },
+        }
+    };
+}
+
+/// Panic with the message of [`assert_bytes_eq!`](../macro.assert_bytes_eq.html).
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_bytes_eq_failed(left: Byte, right: Byte, args: Option<fmt::Arguments<'_>>) -> ! {
+    let diff = left.abs_diff(right);
+
+    let message = format!(
+        "  left: {left:#} ({left})\n right: {right:#} ({right})\n  diff: {:#.1} ({diff})",
+        diff.get_appropriate_unit(UnitType::Binary)
+    );
+
+    match args {
+        Some(args) => panic!("assertion `left == right` failed: {args}\n{message}"),
+        None => panic!("assertion `left == right` failed\n{message}"),
+    }
+}
@@ -0,0 +1,321 @@
+//! Deterministic pseudo-random sequences of sizes for benchmarks and simulations, e.g. of object storage workloads.
+//!
+//! The sequences are generated by SplitMix64 and depend only on the distribution and the seed, so they are reproducible for a given platform. The log-normal and Zipf distributions use floating-point functions such as `ln` and `exp`, whose results may differ slightly between platforms, so only the uniform distribution gives the same sizes everywhere.
+//!
+//! ```
+//! use byte_unit::{testgen::SizeSequence, Byte};
+//!
+//! let sizes = SizeSequence::log_normal(Byte::from_u64(64 * 1024), 1.5)
+//!     .seed(42)
+//!     .take(1000)
+//!     .collect::<Vec<Byte>>();
+//!
+//! assert_eq!(1000, sizes.len());
+//! ```
+
+use crate::Byte;
+
+/// The distribution of the sizes generated by a [`SizeSequence`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SizeDistribution {
+    /// Uniformly distributed sizes from `min` to `max`, inclusive.
+    Uniform { min: Byte, max: Byte },
+    /// Multiples of `step` from `1 × step` to `n × step`, where `k × step` is drawn with a probability proportional to <code>1 / k<sup>exponent</sup></code>, so small sizes are common and large sizes are rare.
+    Zipf { step: Byte, n: u64, exponent: f64 },
+    /// Sizes whose logarithm is normally distributed, with the median `median` and the standard deviation `sigma` of the logarithm.
+    LogNormal { median: Byte, sigma: f64 },
+}
+
+/// An infinite, deterministic sequence of pseudo-random `Byte` instances following a [`SizeDistribution`].
+///
+/// # Examples
+///
+/// ```
+/// use byte_unit::{testgen::SizeSequence, Byte};
+///
+/// let a =
+///     SizeSequence::uniform(Byte::from_u64(1), Byte::from_u64(1000)).seed(7);
+/// let b =
+///     SizeSequence::uniform(Byte::from_u64(1), Byte::from_u64(1000)).seed(7);
+///
+/// assert!(a.take(100).eq(b.take(100)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SizeSequence {
+    distribution: SizeDistribution,
+    state:        u64,
+    zipf:         Option<ZipfSampler>,
+}
+
+/// Associated functions for building `SizeSequence` instances.
+impl SizeSequence {
+    /// Create a new `SizeSequence` instance with the seed **0**.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters are invalid: `min` is larger than `max`, `step` or `n` is zero, `n × step` exceeds `Byte::MAX`, `median` is zero, or `exponent` or `sigma` is negative or not finite.
+    pub fn new(distribution: SizeDistribution) -> Self {
+        let zipf = match distribution {
+            SizeDistribution::Uniform {
+                min,
+                max,
+            } => {
+                assert!(min <= max, "`min` must not be larger than `max`");
+
+                None
+            },
+            SizeDistribution::Zipf {
+                step,
+                n,
+                exponent,
+            } => {
+                assert!(step.as_u128() > 0 && n > 0, "`step` and `n` must not be zero");
+                assert!(step.checked_mul(n).is_some(), "`n × step` must not exceed `Byte::MAX`");
+                assert!(
+                    exponent.is_finite() && exponent >= 0.0,
+                    "`exponent` must be finite and non-negative"
+                );
+
+                Some(ZipfSampler::new(n, exponent))
+            },
+            SizeDistribution::LogNormal {
+                median,
+                sigma,
+            } => {
+                assert!(median.as_u128() > 0, "`median` must not be zero");
+                assert!(
+                    sigma.is_finite() && sigma >= 0.0,
+                    "`sigma` must be finite and non-negative"
+                );
+
+                None
+            },
+        };
+
+        Self {
+            distribution,
+            state: 0,
+            zipf,
+        }
+    }
+
+    /// Create a new `SizeSequence` instance with `SizeDistribution::Uniform`. See [`SizeSequence::new`](#method.new).
+    #[inline]
+    pub fn uniform(min: Byte, max: Byte) -> Self {
+        Self::new(SizeDistribution::Uniform {
+            min,
+            max,
+        })
+    }
+
+    /// Create a new `SizeSequence` instance with `SizeDistribution::Zipf`. See [`SizeSequence::new`](#method.new).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{testgen::SizeSequence, Byte};
+    ///
+    /// let step = Byte::from_u64(4096);
+    ///
+    /// let small = SizeSequence::zipf(step, 1000, 1.2)
+    ///     .take(1000)
+    ///     .filter(|size| *size == step)
+    ///     .count();
+    ///
+    /// assert!(small > 100);
+    /// ```
+    #[inline]
+    pub fn zipf(step: Byte, n: u64, exponent: f64) -> Self {
+        Self::new(SizeDistribution::Zipf {
+            step,
+            n,
+            exponent,
+        })
+    }
+
+    /// Create a new `SizeSequence` instance with `SizeDistribution::LogNormal`. See [`SizeSequence::new`](#method.new).
+    #[inline]
+    pub fn log_normal(median: Byte, sigma: f64) -> Self {
+        Self::new(SizeDistribution::LogNormal {
+            median,
+            sigma,
+        })
+    }
+
+    /// Set the seed. Sequences with the same distribution and the same seed are identical.
+    #[inline]
+    pub const fn seed(mut self, seed: u64) -> Self {
+        self.state = seed;
+
+        self
+    }
+}
+
+/// Methods for generating sizes.
+impl SizeSequence {
+    /// Get the distribution.
+    #[inline]
+    pub const fn distribution(&self) -> SizeDistribution {
+        self.distribution
+    }
+
+    /// SplitMix64.
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+        z ^ (z >> 31)
+    }
+
+    /// A number in `[0, 1)`.
+    #[inline]
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// A number in `[0, span)` without modulo bias.
+    fn next_u128_below(&mut self, span: u128) -> u128 {
+        let zone = u128::MAX - u128::MAX % span;
+
+        loop {
+            let v = ((self.next_u64() as u128) << 64) | self.next_u64() as u128;
+
+            if v < zone {
+                return v % span;
+            }
+        }
+    }
+}
+
+impl Iterator for SizeSequence {
+    type Item = Byte;
+
+    fn next(&mut self) -> Option<Byte> {
+        let size = match self.distribution {
+            SizeDistribution::Uniform {
+                min,
+                max,
+            } => {
+                let (min, max) = (min.as_u128(), max.as_u128());
+
+                min + self.next_u128_below(max - min + 1)
+            },
+            SizeDistribution::Zipf {
+                step, ..
+            } => {
+                let zipf = self.zipf.unwrap();
+
+                let k = zipf.sample(|| self.next_f64());
+
+                step.as_u128() * k as u128
+            },
+            SizeDistribution::LogNormal {
+                median,
+                sigma,
+            } => {
+                // Box-Muller
+                let u1 = 1.0 - self.next_f64();
+                let u2 = self.next_f64();
+
+                let z = (-2.0 * u1.ln()).sqrt() * (2.0 * core::f64::consts::PI * u2).cos();
+
+                let size = (median.as_u128() as f64).ln() + sigma * z;
+
+                // saturated by the cast and `Byte::MAX`
+                ((size.exp() + 0.5) as u128).min(Byte::MAX.as_u128())
+            },
+        };
+
+        Byte::from_u128(size)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// The rejection-inversion method of W. Hörmann and G. Derflinger for sampling the Zipf distribution in constant time.
+#[derive(Debug, Copy, Clone)]
+struct ZipfSampler {
+    n:             u64,
+    exponent:      f64,
+    h_integral_x1: f64,
+    h_integral_n:  f64,
+    s:             f64,
+}
+
+impl ZipfSampler {
+    fn new(n: u64, exponent: f64) -> Self {
+        let mut sampler = Self {
+            n,
+            exponent,
+            h_integral_x1: 0.0,
+            h_integral_n: 0.0,
+            s: 0.0,
+        };
+
+        sampler.h_integral_x1 = sampler.h_integral(1.5) - 1.0;
+        sampler.h_integral_n = sampler.h_integral(n as f64 + 0.5);
+        sampler.s = 2.0 - sampler.h_integral_inverse(sampler.h_integral(2.5) - sampler.h(2.0));
+
+        sampler
+    }
+
+    fn sample(&self, mut next_f64: impl FnMut() -> f64) -> u64 {
+        loop {
+            let u = self.h_integral_n + next_f64() * (self.h_integral_x1 - self.h_integral_n);
+            let x = self.h_integral_inverse(u);
+
+            let k = ((x + 0.5) as u64).clamp(1, self.n);
+
+            if k as f64 - x <= self.s || u >= self.h_integral(k as f64 + 0.5) - self.h(k as f64) {
+                return k;
+            }
+        }
+    }
+
+    #[inline]
+    fn h(&self, x: f64) -> f64 {
+        (-self.exponent * x.ln()).exp()
+    }
+
+    #[inline]
+    fn h_integral(&self, x: f64) -> f64 {
+        let log_x = x.ln();
+
+        helper2((1.0 - self.exponent) * log_x) * log_x
+    }
+
+    #[inline]
+    fn h_integral_inverse(&self, x: f64) -> f64 {
+        let t = (x * (1.0 - self.exponent)).max(-1.0);
+
+        (helper1(t) * x).exp()
+    }
+}
+
+/// `ln(1 + x) / x`, which is accurate for small `x`.
+#[inline]
+fn helper1(x: f64) -> f64 {
+    if x.abs() > 1e-8 {
+        x.ln_1p() / x
+    } else {
+        1.0 - x * (0.5 - x * (1.0 / 3.0 - 0.25 * x))
+    }
+}
+
+/// `(exp(x) - 1) / x`, which is accurate for small `x`.
+#[inline]
+fn helper2(x: f64) -> f64 {
+    if x.abs() > 1e-8 {
+        x.exp_m1() / x
+    } else {
+        1.0 + x * 0.5 * (1.0 + x * (1.0 / 3.0) * (1.0 + 0.25 * x))
+    }
+}
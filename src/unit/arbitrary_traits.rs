@@ -0,0 +1,18 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use super::Unit;
+
+impl<'a> Arbitrary<'a> for Unit {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let index = u.choose_index(Unit::get_multiples().len() + 2)?;
+
+        // the index is in the range
+        Ok(Unit::get_all().nth(index).unwrap())
+    }
+
+    #[inline]
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, Some(4))
+    }
+}
@@ -1,4 +1,6 @@
 use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::string::String;
 
 use super::Unit;
 use crate::UnitParseError;
@@ -13,6 +15,27 @@ impl FromStr for Unit {
     }
 }
 
+impl TryFrom<&str> for Unit {
+    type Error = UnitParseError;
+
+    /// See [`FromStr`](#impl-FromStr-for-Unit).
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<String> for Unit {
+    type Error = UnitParseError;
+
+    /// See [`FromStr`](#impl-FromStr-for-Unit).
+    #[inline]
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
 impl From<Unit> for u128 {
     /// See [`Unit::as_bits_u128`](#method.as_bits_u128).
     #[inline]
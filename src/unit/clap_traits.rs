@@ -0,0 +1,14 @@
+use clap::builder::ValueParserFactory;
+
+use super::Unit;
+use crate::clap::UnitValueParser;
+
+impl ValueParserFactory for Unit {
+    type Parser = UnitValueParser;
+
+    /// `ignore_case` is set to `false`; `prefer_byte` is set to `true`. See [`UnitValueParser`](clap/struct.UnitValueParser.html).
+    #[inline]
+    fn value_parser() -> Self::Parser {
+        UnitValueParser::new()
+    }
+}
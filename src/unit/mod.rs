@@ -1,7 +1,16 @@
+#[cfg(all(feature = "arbitrary", any(feature = "byte", feature = "bit")))]
+mod arbitrary_traits;
 mod built_in_trait;
+#[cfg(feature = "clap")]
+mod clap_traits;
 pub(crate) mod parse;
+mod parts;
+#[cfg(all(feature = "proptest", any(feature = "byte", feature = "bit")))]
+mod proptest_traits;
 #[cfg(feature = "rocket")]
 mod rocket_traits;
+#[cfg(all(feature = "schemars", any(feature = "byte", feature = "bit")))]
+mod schemars_traits;
 #[cfg(feature = "serde")]
 mod serde_traits;
 #[cfg(any(feature = "byte", feature = "bit"))]
@@ -9,11 +18,12 @@ mod unit_type;
 
 use core::fmt::{self, Display, Formatter};
 
+pub use parts::*;
 #[cfg(any(feature = "byte", feature = "bit"))]
 pub use unit_type::*;
 
-/// The unit of bits/bytes.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The unit of bits/bytes. Units are ordered by their sizes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Unit {
     /// 8 Bit = 1 byte.
     Bit,
@@ -187,6 +197,68 @@ impl Unit {
     }
 }
 
+/// Associated functions for converting a primitive integer into a `Unit` instance.
+impl Unit {
+    /// Find the byte unit whose size in bytes is exactly `factor`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Unit;
+    ///
+    /// assert_eq!(Some(Unit::B), Unit::from_bytes_factor(1));
+    /// assert_eq!(Some(Unit::KB), Unit::from_bytes_factor(1000));
+    /// assert_eq!(Some(Unit::KiB), Unit::from_bytes_factor(1024));
+    /// assert_eq!(None, Unit::from_bytes_factor(4096));
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * Bit units are never returned.
+    pub const fn from_bytes_factor(factor: u128) -> Option<Self> {
+        let bits = match factor.checked_mul(8) {
+            Some(bits) => bits,
+            None => return None,
+        };
+
+        let units = [
+            Self::B,
+            Self::KB,
+            Self::KiB,
+            Self::MB,
+            Self::MiB,
+            Self::GB,
+            Self::GiB,
+            Self::TB,
+            Self::TiB,
+            Self::PB,
+            Self::PiB,
+            Self::EB,
+            Self::EiB,
+            #[cfg(feature = "u128")]
+            Self::ZB,
+            #[cfg(feature = "u128")]
+            Self::ZiB,
+            #[cfg(feature = "u128")]
+            Self::YB,
+            #[cfg(feature = "u128")]
+            Self::YiB,
+        ];
+
+        let mut i = 0;
+
+        while i < units.len() {
+            if units[i].as_bits_u128() == bits {
+                return Some(units[i]);
+            }
+
+            i += 1;
+        }
+
+        None
+    }
+}
+
 /// Methods for converting a `Unit` instance into a string.
 impl Unit {
     /// Retrieve the string represented by this `Unit` instance.
@@ -249,6 +321,67 @@ impl Unit {
             Self::YiB => "YiB",
         }
     }
+
+    /// Retrieve the full name of this `Unit` instance in singular form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::Unit;
+    ///
+    /// assert_eq!("byte", Unit::B.as_full_name());
+    /// assert_eq!("kilobyte", Unit::KB.as_full_name());
+    /// assert_eq!("mebibyte", Unit::MiB.as_full_name());
+    /// assert_eq!("gigabit", Unit::Gbit.as_full_name());
+    /// assert_eq!("tebibit", Unit::Tibit.as_full_name());
+    /// ```
+    #[inline]
+    pub const fn as_full_name(self) -> &'static str {
+        match self {
+            Self::Bit => "bit",
+            Self::B => "byte",
+            Self::Kbit => "kilobit",
+            Self::Kibit => "kibibit",
+            Self::KB => "kilobyte",
+            Self::KiB => "kibibyte",
+            Self::Mbit => "megabit",
+            Self::Mibit => "mebibit",
+            Self::MB => "megabyte",
+            Self::MiB => "mebibyte",
+            Self::Gbit => "gigabit",
+            Self::Gibit => "gibibit",
+            Self::GB => "gigabyte",
+            Self::GiB => "gibibyte",
+            Self::Tbit => "terabit",
+            Self::Tibit => "tebibit",
+            Self::TB => "terabyte",
+            Self::TiB => "tebibyte",
+            Self::Pbit => "petabit",
+            Self::Pibit => "pebibit",
+            Self::PB => "petabyte",
+            Self::PiB => "pebibyte",
+            Self::Ebit => "exabit",
+            Self::Eibit => "exbibit",
+            Self::EB => "exabyte",
+            Self::EiB => "exbibyte",
+            #[cfg(feature = "u128")]
+            Self::Zbit => "zettabit",
+            #[cfg(feature = "u128")]
+            Self::Zibit => "zebibit",
+            #[cfg(feature = "u128")]
+            Self::ZB => "zettabyte",
+            #[cfg(feature = "u128")]
+            Self::ZiB => "zebibyte",
+            #[cfg(feature = "u128")]
+            Self::Ybit => "yottabit",
+            #[cfg(feature = "u128")]
+            Self::Yibit => "yobibit",
+            #[cfg(feature = "u128")]
+            Self::YB => "yottabyte",
+            #[cfg(feature = "u128")]
+            Self::YiB => "yobibyte",
+        }
+    }
 }
 
 /// Methods for categorizing variants.
@@ -367,6 +500,14 @@ impl Unit {
 }
 
 impl Unit {
+    /// Get all the units, from the smallest to the largest.
+    #[cfg(any(feature = "byte", feature = "bit"))]
+    #[allow(dead_code)]
+    #[inline]
+    pub(crate) fn get_all() -> impl Iterator<Item = Unit> {
+        [Unit::Bit, Unit::B].into_iter().chain(Unit::get_multiples().iter().copied())
+    }
+
     #[cfg(any(feature = "byte", feature = "bit"))]
     #[allow(dead_code)]
     #[inline]
@@ -442,7 +583,7 @@ impl Unit {
         ]
     }
 
-    #[cfg(feature = "bit")]
+    #[cfg(any(feature = "byte", feature = "bit"))]
     #[allow(dead_code)]
     #[inline]
     pub(crate) const fn get_multiples_bits() -> &'static [Self] {
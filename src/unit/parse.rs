@@ -1,6 +1,6 @@
 use core::str::Bytes;
 
-use super::Unit;
+use super::{Unit, UnitBase, UnitKind};
 use crate::{common::get_char_from_bytes, UnitParseError};
 
 /// Associated functions for parsing strings.
@@ -10,6 +10,8 @@ impl Unit {
     ///
     /// You can ignore the case of **"B"** (byte), which means **b** will still be treated as bytes instead of bits.
     ///
+    /// The full name of a unit, such as `"megabyte"`, `"gibibytes"` or `"Kilobits"`, is also accepted. It is always case-insensitive, and the trailing **"s"** is optional.
+    ///
     /// If the input string is empty, it will return `B` if `prefer_byte` is true; otherwise, it will return `b`. Similarly, if the string is not empty but it does not explicitly contains `"B"`, `"b"`, or `"bit"`, it will imply the base is `"B"` if `prefer_byte` is true; otherwise, imply the base is `"b"`.
     ///
     /// # Examples
@@ -23,6 +25,11 @@ impl Unit {
     /// # use byte_unit::Unit;
     /// let unit = Unit::parse_str("Kib", false, true).unwrap(); // Kibit
     /// ```
+    ///
+    /// ```
+    /// # use byte_unit::Unit;
+    /// let unit = Unit::parse_str("gibibytes", false, true).unwrap(); // GiB
+    /// ```
     pub fn parse_str<S: AsRef<str>>(
         s: S,
         ignore_case: bool,
@@ -42,6 +49,12 @@ pub(crate) fn read_xib(
     ignore_case: bool,
     prefer_byte: bool,
 ) -> Result<Unit, UnitParseError> {
+    if let Some(e) = e {
+        if let Some(unit) = read_full_name(e, bytes.clone()) {
+            return Ok(unit);
+        }
+    }
+
     match e {
         Some(e) => match e.to_ascii_uppercase() {
             b'B' => {
@@ -179,7 +192,7 @@ pub(crate) fn read_xib(
                 #[cfg(feature = "u128")]
                 {
                     Err(UnitParseError {
-                        character:                unsafe { get_char_from_bytes(e, bytes) },
+                        character:                get_char_from_bytes(e, bytes),
                         expected_characters:      &['B', 'K', 'M', 'G', 'T', 'P', 'E', 'Z', 'Y'],
                         also_expect_no_character: true,
                     })
@@ -187,7 +200,7 @@ pub(crate) fn read_xib(
                 #[cfg(not(feature = "u128"))]
                 {
                     Err(UnitParseError {
-                        character:                unsafe { get_char_from_bytes(e, bytes) },
+                        character:                get_char_from_bytes(e, bytes),
                         expected_characters:      &['B', 'K', 'M', 'G', 'T', 'P', 'E'],
                         also_expect_no_character: true,
                     })
@@ -197,6 +210,38 @@ pub(crate) fn read_xib(
         None => Ok(if prefer_byte { Unit::B } else { Unit::Bit }),
     }
 }
+
+/// Match the full name of a unit, case-insensitively and with an optional trailing `'s'`.
+fn read_full_name(e: u8, bytes: Bytes) -> Option<Unit> {
+    let matches = |name: &str| {
+        let mut input = core::iter::once(e).chain(bytes.clone());
+        let mut name = name.bytes();
+
+        loop {
+            match (input.next(), name.next()) {
+                (Some(a), Some(b)) if a.eq_ignore_ascii_case(&b) => (),
+                (None, None) => return true,
+                (Some(a), None) => return a.eq_ignore_ascii_case(&b's') && input.next().is_none(),
+                _ => return false,
+            }
+        }
+    };
+
+    for kind in [UnitKind::Bit, UnitKind::Byte] {
+        for base in [UnitBase::Decimal, UnitBase::Binary] {
+            for exponent in 0..=8 {
+                match Unit::from_parts(kind, base, exponent) {
+                    Some(unit) if matches(unit.as_full_name()) => return Some(unit),
+                    Some(_) => (),
+                    None => break,
+                }
+            }
+        }
+    }
+
+    None
+}
+
 fn read_ib(
     mut bytes: Bytes,
     ignore_case: bool,
@@ -227,7 +272,7 @@ fn read_ib(
                     };
 
                     Err(UnitParseError {
-                        character: unsafe { get_char_from_bytes(e, bytes) },
+                        character: get_char_from_bytes(e, bytes),
                         expected_characters,
                         also_expect_no_character: true,
                     })
@@ -247,16 +292,14 @@ fn read_b(mut bytes: Bytes, byte: bool) -> Result<bool, UnitParseError> {
                         Some(e) => match e.to_ascii_lowercase() {
                             b's' => match bytes.next() {
                                 Some(e) => Err(UnitParseError {
-                                    character:                unsafe {
-                                        get_char_from_bytes(e, bytes)
-                                    },
+                                    character:                get_char_from_bytes(e, bytes),
                                     expected_characters:      &[],
                                     also_expect_no_character: true,
                                 }),
                                 None => Ok(false),
                             },
                             _ => Err(UnitParseError {
-                                character:                unsafe { get_char_from_bytes(e, bytes) },
+                                character:                get_char_from_bytes(e, bytes),
                                 expected_characters:      &['s'],
                                 also_expect_no_character: true,
                             }),
@@ -264,7 +307,7 @@ fn read_b(mut bytes: Bytes, byte: bool) -> Result<bool, UnitParseError> {
                         None => Ok(false),
                     },
                     _ => Err(UnitParseError {
-                        character:                unsafe { get_char_from_bytes(e, bytes) },
+                        character:                get_char_from_bytes(e, bytes),
                         expected_characters:      &['t'],
                         also_expect_no_character: false,
                     }),
@@ -276,7 +319,7 @@ fn read_b(mut bytes: Bytes, byte: bool) -> Result<bool, UnitParseError> {
                 }),
             },
             _ => Err(UnitParseError {
-                character:                unsafe { get_char_from_bytes(e, bytes) },
+                character:                get_char_from_bytes(e, bytes),
                 expected_characters:      &['i'],
                 also_expect_no_character: true,
             }),
@@ -284,3 +327,95 @@ fn read_b(mut bytes: Bytes, byte: bool) -> Result<bool, UnitParseError> {
         None => Ok(byte),
     }
 }
+
+/// Split a string into an integer value and a unit symbol, e.g. `"250 MiB"`, in const contexts. The symbol must be the same as the one of [`Unit::as_str`], and a missing symbol is `default_unit`.
+#[cfg(any(feature = "byte", feature = "bit"))]
+pub(crate) const fn parse_integer_and_unit_const(
+    s: &str,
+    default_unit: Unit,
+) -> Option<(u128, Unit)> {
+    let mut bytes = s.as_bytes();
+
+    // `trim_ascii` is not available for the MSRV
+    while let [b' ', rest @ ..] = bytes {
+        bytes = rest;
+    }
+
+    while let [rest @ .., b' '] = bytes {
+        bytes = rest;
+    }
+
+    let mut value = 0u128;
+    let mut digits = 0;
+
+    while let [e @ b'0'..=b'9', rest @ ..] = bytes {
+        value = match value.checked_mul(10) {
+            Some(v) => match v.checked_add((*e - b'0') as u128) {
+                Some(v) => v,
+                None => return None,
+            },
+            None => return None,
+        };
+
+        digits += 1;
+        bytes = rest;
+    }
+
+    if digits == 0 {
+        return None;
+    }
+
+    while let [b' ', rest @ ..] = bytes {
+        bytes = rest;
+    }
+
+    if bytes.is_empty() {
+        return Some((value, default_unit));
+    }
+
+    let kinds = [UnitKind::Bit, UnitKind::Byte];
+    let bases = [UnitBase::Decimal, UnitBase::Binary];
+
+    let mut i = 0;
+
+    while i < kinds.len() {
+        let mut j = 0;
+
+        while j < bases.len() {
+            let mut exponent = 0;
+
+            while let Some(unit) = Unit::from_parts(kinds[i], bases[j], exponent) {
+                if bytes_eq(unit.as_str().as_bytes(), bytes) {
+                    return Some((value, unit));
+                }
+
+                exponent += 1;
+            }
+
+            j += 1;
+        }
+
+        i += 1;
+    }
+
+    None
+}
+
+#[cfg(any(feature = "byte", feature = "bit"))]
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    true
+}
@@ -0,0 +1,131 @@
+use super::Unit;
+
+/// Whether a unit counts bits or bytes. See [`Unit::to_parts`](enum.Unit.html#method.to_parts).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum UnitKind {
+    /// b, Kb, Kib, ..., etc.
+    Bit,
+    /// B, KB, KiB, ..., etc.
+    Byte,
+}
+
+/// The base of the multiples of a unit. See [`Unit::to_parts`](enum.Unit.html#method.to_parts).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum UnitBase {
+    /// Multiples of 10<sup>3</sup>, e.g. KB and MB.
+    Decimal,
+    /// Multiples of 2<sup>10</sup>, e.g. KiB and MiB.
+    Binary,
+}
+
+/// Methods and associated functions for converting between a `Unit` instance and its parts.
+impl Unit {
+    /// Split this unit into its kind, its base and the exponent of the base. The size of the unit is <code>base<sup>exponent</sup></code> bits or bytes.
+    ///
+    /// The parts are suitable for encoding a unit compactly, e.g. in a binary protocol. Unlike the order of the variants, they do not change when new units are added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Unit, UnitBase, UnitKind};
+    ///
+    /// assert_eq!((UnitKind::Byte, UnitBase::Binary, 3), Unit::GiB.to_parts());
+    /// assert_eq!((UnitKind::Bit, UnitBase::Decimal, 2), Unit::Mbit.to_parts());
+    /// assert_eq!((UnitKind::Byte, UnitBase::Decimal, 0), Unit::B.to_parts());
+    /// ```
+    ///
+    /// # Points to Note
+    ///
+    /// * The base of `Unit::Bit` and `Unit::B` is always `UnitBase::Decimal`, because the exponent is **0**.
+    #[inline]
+    pub const fn to_parts(self) -> (UnitKind, UnitBase, u8) {
+        let kind = if self.is_bit() { UnitKind::Bit } else { UnitKind::Byte };
+
+        let exponent = match self {
+            Self::Bit | Self::B => return (kind, UnitBase::Decimal, 0),
+            Self::Kbit | Self::Kibit | Self::KB | Self::KiB => 1,
+            Self::Mbit | Self::Mibit | Self::MB | Self::MiB => 2,
+            Self::Gbit | Self::Gibit | Self::GB | Self::GiB => 3,
+            Self::Tbit | Self::Tibit | Self::TB | Self::TiB => 4,
+            Self::Pbit | Self::Pibit | Self::PB | Self::PiB => 5,
+            Self::Ebit | Self::Eibit | Self::EB | Self::EiB => 6,
+            #[cfg(feature = "u128")]
+            Self::Zbit | Self::Zibit | Self::ZB | Self::ZiB => 7,
+            #[cfg(feature = "u128")]
+            Self::Ybit | Self::Yibit | Self::YB | Self::YiB => 8,
+        };
+
+        let base = if self.is_binary_multiples() { UnitBase::Binary } else { UnitBase::Decimal };
+
+        (kind, base, exponent)
+    }
+
+    /// Create a unit from its kind, its base and the exponent of the base. See [`Unit::to_parts`](#method.to_parts).
+    ///
+    /// If the exponent is **0**, the base is ignored. If there is no such unit, e.g. the exponent is too large, this function will return `None`, so a decoder can reject the units added in later versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Unit, UnitBase, UnitKind};
+    ///
+    /// assert_eq!(
+    ///     Some(Unit::GiB),
+    ///     Unit::from_parts(UnitKind::Byte, UnitBase::Binary, 3)
+    /// );
+    /// assert_eq!(
+    ///     Some(Unit::Bit),
+    ///     Unit::from_parts(UnitKind::Bit, UnitBase::Binary, 0)
+    /// );
+    /// assert_eq!(None, Unit::from_parts(UnitKind::Byte, UnitBase::Decimal, 10));
+    /// ```
+    pub const fn from_parts(kind: UnitKind, base: UnitBase, exponent: u8) -> Option<Self> {
+        let unit = match (kind, base, exponent) {
+            (UnitKind::Bit, _, 0) => Self::Bit,
+            (UnitKind::Byte, _, 0) => Self::B,
+            (UnitKind::Bit, UnitBase::Decimal, 1) => Self::Kbit,
+            (UnitKind::Bit, UnitBase::Binary, 1) => Self::Kibit,
+            (UnitKind::Byte, UnitBase::Decimal, 1) => Self::KB,
+            (UnitKind::Byte, UnitBase::Binary, 1) => Self::KiB,
+            (UnitKind::Bit, UnitBase::Decimal, 2) => Self::Mbit,
+            (UnitKind::Bit, UnitBase::Binary, 2) => Self::Mibit,
+            (UnitKind::Byte, UnitBase::Decimal, 2) => Self::MB,
+            (UnitKind::Byte, UnitBase::Binary, 2) => Self::MiB,
+            (UnitKind::Bit, UnitBase::Decimal, 3) => Self::Gbit,
+            (UnitKind::Bit, UnitBase::Binary, 3) => Self::Gibit,
+            (UnitKind::Byte, UnitBase::Decimal, 3) => Self::GB,
+            (UnitKind::Byte, UnitBase::Binary, 3) => Self::GiB,
+            (UnitKind::Bit, UnitBase::Decimal, 4) => Self::Tbit,
+            (UnitKind::Bit, UnitBase::Binary, 4) => Self::Tibit,
+            (UnitKind::Byte, UnitBase::Decimal, 4) => Self::TB,
+            (UnitKind::Byte, UnitBase::Binary, 4) => Self::TiB,
+            (UnitKind::Bit, UnitBase::Decimal, 5) => Self::Pbit,
+            (UnitKind::Bit, UnitBase::Binary, 5) => Self::Pibit,
+            (UnitKind::Byte, UnitBase::Decimal, 5) => Self::PB,
+            (UnitKind::Byte, UnitBase::Binary, 5) => Self::PiB,
+            (UnitKind::Bit, UnitBase::Decimal, 6) => Self::Ebit,
+            (UnitKind::Bit, UnitBase::Binary, 6) => Self::Eibit,
+            (UnitKind::Byte, UnitBase::Decimal, 6) => Self::EB,
+            (UnitKind::Byte, UnitBase::Binary, 6) => Self::EiB,
+            #[cfg(feature = "u128")]
+            (UnitKind::Bit, UnitBase::Decimal, 7) => Self::Zbit,
+            #[cfg(feature = "u128")]
+            (UnitKind::Bit, UnitBase::Binary, 7) => Self::Zibit,
+            #[cfg(feature = "u128")]
+            (UnitKind::Byte, UnitBase::Decimal, 7) => Self::ZB,
+            #[cfg(feature = "u128")]
+            (UnitKind::Byte, UnitBase::Binary, 7) => Self::ZiB,
+            #[cfg(feature = "u128")]
+            (UnitKind::Bit, UnitBase::Decimal, 8) => Self::Ybit,
+            #[cfg(feature = "u128")]
+            (UnitKind::Bit, UnitBase::Binary, 8) => Self::Yibit,
+            #[cfg(feature = "u128")]
+            (UnitKind::Byte, UnitBase::Decimal, 8) => Self::YB,
+            #[cfg(feature = "u128")]
+            (UnitKind::Byte, UnitBase::Binary, 8) => Self::YiB,
+            _ => return None,
+        };
+
+        Some(unit)
+    }
+}
@@ -0,0 +1,18 @@
+use std::vec::Vec;
+
+use proptest::{
+    arbitrary::Arbitrary,
+    sample::{select, Select},
+};
+
+use super::Unit;
+
+impl Arbitrary for Unit {
+    type Parameters = ();
+    type Strategy = Select<Unit>;
+
+    #[inline]
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        select(Unit::get_all().collect::<Vec<_>>())
+    }
+}
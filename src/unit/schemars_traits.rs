@@ -0,0 +1,26 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use super::Unit;
+
+impl JsonSchema for Unit {
+    #[inline]
+    fn schema_name() -> Cow<'static, str> {
+        "Unit".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        let units = [Unit::Bit, Unit::B]
+            .iter()
+            .chain(Unit::get_multiples())
+            .map(|unit| unit.as_str())
+            .collect::<Vec<_>>();
+
+        json_schema!({
+            "type": "string",
+            "enum": units,
+            "description": "A unit of bits or bytes, such as \"B\", \"KB\", \"KiB\" or \"Mbit\"."
+        })
+    }
+}
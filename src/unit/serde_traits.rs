@@ -7,6 +7,7 @@ use serde::{
 };
 
 use super::Unit;
+use crate::serde::invalid_str;
 
 impl Serialize for Unit {
     #[inline]
@@ -36,7 +37,7 @@ impl<'de> Deserialize<'de> for Unit {
             fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
             where
                 E: DeError, {
-                Unit::from_str(v).map_err(DeError::custom)
+                Unit::from_str(v).map_err(|error| invalid_str(v, &self, error))
             }
         }
 
@@ -1,3 +1,5 @@
+use crate::Unit;
+
 /// Choose how to find an appropriate unit based on a base of 2 or 10.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum UnitType {
@@ -8,3 +10,20 @@ pub enum UnitType {
     /// Use both binary and decimal, choose the closest one.
     Both,
 }
+
+impl UnitType {
+    /// Find the largest unit of this type among `units`, which are sorted from the smallest one and alternate between decimal and binary multiples (e.g. `KB`, `KiB`, `MB`, `MiB`), for which `reached` returns `true`.
+    pub(crate) fn find_largest_unit(
+        self,
+        units: &[Unit],
+        mut reached: impl FnMut(Unit) -> bool,
+    ) -> Option<Unit> {
+        let (skip, step) = match self {
+            UnitType::Binary => (0, 2),
+            UnitType::Decimal => (1, 2),
+            UnitType::Both => (0, 1),
+        };
+
+        units.iter().rev().skip(skip).step_by(step).copied().find(|&unit| reached(unit))
+    }
+}
@@ -0,0 +1,89 @@
+use core::sync::atomic::{AtomicU8, Ordering};
+
+use crate::{Unit, UnitPreferenceAlreadySetError};
+
+const UNSET: u8 = u8::MAX;
+
+static GLOBAL: AtomicU8 = AtomicU8::new(UNSET);
+
+/// Choose which unit is preferred when a size can be recovered precisely with both a binary unit and a decimal unit, e.g. `10240` bytes, which is `"10 KiB"` or `"10.24 KB"`.
+///
+/// The alternate form (`{:#}`) of the `Display` implementations and the `display` methods without a fixed unit follow the global preference, which can be set once by the application with [`UnitPreference::set_global`](#method.set_global). The serialized forms, including the keys written by [`serde::as_map_key`](serde/as_map_key/index.html), always use `UnitPreference::Largest`, as described in [`serde::wire`](serde/wire/index.html).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum UnitPreference {
+    /// The largest unit is chosen, regardless of whether it is binary or decimal. For example, `"10 KiB"` for `10240` bytes, and `"2.048 MB"` for `2048000` bytes.
+    #[default]
+    Largest,
+    /// The largest binary unit is chosen if there is one, e.g. `"2000 KiB"` for `2048000` bytes.
+    Binary,
+    /// The largest decimal unit is chosen if there is one, e.g. `"10.24 KB"` for `10240` bytes.
+    Decimal,
+}
+
+#[cfg(target_has_atomic = "8")]
+#[inline]
+fn set_global_once(value: u8) -> bool {
+    GLOBAL.compare_exchange(UNSET, value, Ordering::Relaxed, Ordering::Relaxed).is_ok()
+}
+
+// only loads and stores are available, so the check and the store are not one atomic operation
+#[cfg(not(target_has_atomic = "8"))]
+#[inline]
+fn set_global_once(value: u8) -> bool {
+    if GLOBAL.load(Ordering::Relaxed) != UNSET {
+        return false;
+    }
+
+    GLOBAL.store(value, Ordering::Relaxed);
+
+    true
+}
+
+impl UnitPreference {
+    /// Set the global preference. It can only be set once, usually at the start of the application, so that sizes are written consistently everywhere.
+    ///
+    /// On targets without atomic compare-and-swap operations, two threads calling this method at the same time for the first time may both succeed, and the later one wins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byte_unit::{Byte, UnitPreference};
+    ///
+    /// let byte = Byte::from_u64(10240);
+    ///
+    /// assert_eq!("10 KiB", format!("{byte:#}"));
+    ///
+    /// UnitPreference::Decimal.set_global().unwrap();
+    ///
+    /// assert_eq!("10.24 KB", format!("{byte:#}"));
+    /// assert!(UnitPreference::Binary.set_global().is_err());
+    /// ```
+    #[inline]
+    pub fn set_global(self) -> Result<(), UnitPreferenceAlreadySetError> {
+        if set_global_once(self as u8) {
+            Ok(())
+        } else {
+            Err(UnitPreferenceAlreadySetError)
+        }
+    }
+
+    /// Retrieve the global preference, which is `UnitPreference::Largest` if it has not been set.
+    #[inline]
+    pub fn global() -> Self {
+        match GLOBAL.load(Ordering::Relaxed) {
+            1 => Self::Binary,
+            2 => Self::Decimal,
+            _ => Self::Largest,
+        }
+    }
+
+    /// Check whether `unit` is one of the preferred units. Every unit is preferred by `UnitPreference::Largest`.
+    #[inline]
+    pub(crate) const fn is_preferred(self, unit: Unit) -> bool {
+        match self {
+            Self::Largest => true,
+            Self::Binary => unit.is_binary_multiples(),
+            Self::Decimal => !unit.is_binary_multiples(),
+        }
+    }
+}
@@ -24,6 +24,9 @@ fn parse_str() {
         (Ok(Bit::from_u64(1)), "1 b"),
         (Ok(Bit::from_u64(1)), "1  b"),
         (Ok(Bit::from_u64(1)), "1  b"),
+        (Ok(Bit::from_u64(1)), "1.0\u{a0}b"),
+        (Ok(Bit::from_u64(1)), "1\u{2009}b"),
+        (Err(()), "\u{2212}1"),
         (Ok(Bit::from_u64(2)), "1.1"),
         (Ok(Bit::from_u64(2)), "1.1b"),
         (Ok(Bit::from_u64(0)), "0kb"),
@@ -155,3 +158,687 @@ fn tests() {
         assert_eq!(bit, serde_json::from_str::<Bit>(case.0).unwrap(), "{i}");
     }
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_integers() {
+    use serde::{
+        de::{value, IntoDeserializer},
+        Deserialize,
+    };
+
+    let expected = Bit::from_u64(1024);
+
+    let encoded = bincode::serialize(&expected).unwrap();
+
+    assert_eq!(16, encoded.len());
+
+    assert_eq!(expected, bincode::deserialize::<Bit>(&encoded).unwrap());
+
+    fn deserialize<'de, D: IntoDeserializer<'de, value::Error>>(v: D) -> Option<Bit> {
+        Bit::deserialize(v.into_deserializer()).ok()
+    }
+
+    assert_eq!(Some(expected), deserialize(1024u16));
+    assert_eq!(Some(expected), deserialize(1024u32));
+    assert_eq!(Some(expected), deserialize(1024u64));
+    assert_eq!(Some(expected), deserialize(1024u128));
+    assert_eq!(Some(expected), deserialize(1024i32));
+    assert_eq!(Some(expected), deserialize(1024i64));
+    assert_eq!(Some(expected), deserialize(1024i128));
+    assert_eq!(None, deserialize(-1i64));
+    assert_eq!(None, deserialize(-1i128));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn parse_many() {
+    let strings = ["0", "1 Kb", "2Kib", "1.5 Mb", "3 B"];
+
+    assert_eq!(
+        vec![
+            Bit::from_u64(0),
+            Bit::from_u64(1000),
+            Bit::from_u64(2048),
+            Bit::from_u64(1500000),
+            Bit::from_u64(24),
+        ],
+        Bit::parse_many(&strings).unwrap()
+    );
+
+    let strings = ["1 Kb", "-1", "2 Kib", "c"];
+
+    assert_eq!(1, Bit::parse_many(&strings).unwrap_err().0);
+
+    assert!(Bit::parse_many::<&str>(&[]).unwrap().is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn parse_str_lenient() {
+    use byte_unit::ParseWarning::*;
+
+    let cases = [
+        ("15 Mb", Some(15000000), vec![]),
+        ("15 Mb.", Some(15000000), vec![TrailingPeriodRemoved]),
+        ("1 5 M b", Some(15000000), vec![WhitespaceRemoved]),
+        ("15 Mb Mb", Some(15000000), vec![DoubledUnitRemoved]),
+        ("fifteen Mb", None, vec![]),
+    ];
+
+    for (i, (s, expected, warnings)) in cases.into_iter().enumerate() {
+        let (result, result_warnings) = Bit::parse_str_lenient(s);
+
+        assert_eq!(expected, result.map(|b| b.as_u64()), "{i}");
+        assert_eq!(warnings, result_warnings, "{i}");
+    }
+}
+
+#[test]
+fn adjusted_unit_exact() {
+    let bit = Bit::from_u64(15000000);
+
+    for unit in [Unit::Bit, Unit::B, Unit::Kbit, Unit::KiB, Unit::Mbit, Unit::MB] {
+        let (value, unit) = bit.get_adjusted_unit_exact(unit);
+
+        assert_eq!(value.to_f64().unwrap(), bit.get_adjusted_unit(unit).get_value(), "{unit}");
+    }
+}
+
+#[test]
+fn wrapper_types() {
+    use std::num::{NonZeroU128, NonZeroU64, NonZeroU8, Wrapping};
+
+    use byte_unit::NonZeroError;
+
+    assert_eq!(Bit::from_u64(5), Bit::from(NonZeroU8::new(5).unwrap()));
+    assert_eq!(Bit::from_u64(5), Bit::from(NonZeroU64::new(5).unwrap()));
+    assert_eq!(Bit::from_u64(5), Bit::try_from(NonZeroU128::new(5).unwrap()).unwrap());
+    assert_eq!(Bit::from_u64(5), Bit::from(Wrapping(5u64)));
+
+    assert_eq!(NonZeroU64::new(5), NonZeroU64::try_from(Bit::from_u64(5)).ok());
+    assert_eq!(Err(NonZeroError::Zero), NonZeroU64::try_from(Bit::MIN));
+    assert_eq!(Err(NonZeroError::Zero), NonZeroU128::try_from(Bit::MIN));
+    #[cfg(feature = "u128")]
+    assert_eq!(Err(NonZeroError::ExceededBounds), NonZeroU64::try_from(Bit::MAX));
+    assert_eq!(Wrapping(5u128), Wrapping::from(Bit::from_u64(5)));
+}
+
+#[cfg(feature = "saturating")]
+#[test]
+fn saturating_wrapper() {
+    use std::num::Saturating;
+
+    assert_eq!(Bit::from_u64(5), Bit::from(Saturating(5u64)));
+    assert!(Bit::try_from(Saturating(u128::MAX)).is_err());
+    assert_eq!(Saturating(u64::MAX), Saturating::<u64>::from(Bit::MAX));
+}
+
+#[cfg(feature = "byte")]
+#[test]
+fn cross_appropriate_unit() {
+    use byte_unit::Byte;
+
+    let cases = [
+        (Unit::B, 4, "0.5 B", UnitType::Decimal),
+        (Unit::KB, 12_000, "1.5 KB", UnitType::Decimal),
+        (Unit::KiB, 12_288, "1.5 KiB", UnitType::Binary),
+    ];
+
+    for (i, (unit, n, s, unit_type)) in cases.iter().enumerate() {
+        let adjusted_byte = Bit::from_u64(*n).get_appropriate_byte_unit(*unit_type);
+
+        assert_eq!(*unit, adjusted_byte.get_unit(), "{i}");
+        assert_eq!(*s, adjusted_byte.to_string(), "{i}");
+    }
+
+    let cases = [
+        (Unit::Bit, 0, "0 b", UnitType::Decimal),
+        (Unit::Bit, 100, "800 b", UnitType::Decimal),
+        (Unit::Kbit, 125, "1 Kb", UnitType::Decimal),
+        (Unit::Kibit, 128, "1 Kib", UnitType::Binary),
+    ];
+
+    for (i, (unit, n, s, unit_type)) in cases.iter().enumerate() {
+        let adjusted_bit = Byte::from_u64(*n).get_appropriate_bit_unit(*unit_type);
+
+        assert_eq!(*unit, adjusted_bit.get_unit(), "{i}");
+        assert_eq!(*s, adjusted_bit.to_string(), "{i}");
+    }
+}
+
+#[test]
+fn display_flags() {
+    let bit = Bit::from_u64(1536);
+    let adjusted_bit = bit.get_adjusted_unit(Unit::Kibit);
+
+    let cases = [
+        ("0000001536", format!("{bit:010}")),
+        ("***1536***", format!("{bit:*^10}")),
+        ("+1536", format!("{bit:+}")),
+        ("0001.5 Kib", format!("{bit:#010}")),
+        ("*1.5** Kib", format!("{bit:*^#10}")),
+        ("001.50 Kib", format!("{adjusted_bit:010.2}")),
+        ("*1.5** Kib", format!("{adjusted_bit:*^10}")),
+    ];
+
+    for (i, (expected, actual)) in cases.iter().enumerate() {
+        assert_eq!(*expected, actual, "{i}");
+    }
+}
+
+#[test]
+fn operators() {
+    let mut bit = Bit::from_u64(1024) + Bit::from_u64(512);
+
+    bit -= Bit::from_u64(512);
+    bit *= 3;
+    bit /= 2;
+
+    assert_eq!(1536, bit.as_u64());
+    assert_eq!(Bit::from_u64(4096), 4 * Bit::from_u64(1024));
+    assert_eq!(Bit::MAX, Bit::MAX.saturating_mul(2));
+    assert_eq!(Bit::MAX, Bit::MIN.wrapping_sub(Bit::from_u64(1)));
+
+    let sizes = [Bit::from_u64(1024), Bit::from_u64(512)];
+
+    assert_eq!(Bit::from_u64(1536), sizes.iter().sum::<Bit>());
+    assert_eq!(Some(Bit::from_u64(1536)), Bit::checked_sum(sizes));
+    assert_eq!(Bit::MAX, Bit::saturating_sum([Bit::MAX, Bit::from_u64(1)]));
+}
+
+#[cfg(feature = "byte")]
+#[test]
+fn byte_conversion() {
+    use byte_unit::{Byte, ExceededBoundsError, NotWholeBytesError};
+
+    let cases =
+        [(0, 0, 0, Ok(0)), (1, 0, 1, Err(())), (8, 1, 1, Ok(1)), (12_001, 1500, 1501, Err(()))];
+
+    for (i, (bits, floor, ceil, exact)) in cases.iter().enumerate() {
+        let bit = Bit::from_u64(*bits);
+
+        assert_eq!(Byte::from_u64(*floor), bit.as_byte_floor(), "{i}");
+        assert_eq!(Byte::from_u64(*ceil), bit.as_byte_ceil(), "{i}");
+        assert_eq!(
+            exact.map(Byte::from_u64).map_err(|_| NotWholeBytesError),
+            Byte::try_from(bit),
+            "{i}"
+        );
+    }
+
+    assert_eq!(Bit::from_u128(Bit::MAX.as_u128() / 8 * 8), Bit::MAX.as_byte_floor().as_bit());
+
+    assert_eq!(Some(Bit::from_u64(8000)), Byte::from_u64(1000).as_bit());
+    assert_eq!(Ok(Bit::from_u64(8000)), Bit::try_from(Byte::from_u64(1000)));
+    assert_eq!(None, Byte::MAX.as_bit());
+    assert_eq!(Err(ExceededBoundsError), Bit::try_from(Byte::MAX));
+}
+
+#[cfg(feature = "locale")]
+#[test]
+fn display_locale() {
+    use byte_unit::{FormatOptions, Locale};
+
+    let adjusted_bit = Bit::from_u64(1_500_000).get_appropriate_unit(UnitType::Decimal);
+
+    assert_eq!(
+        "1,5 Мбит",
+        adjusted_bit.display_with(FormatOptions::new().locale(Locale::Ru)).to_string()
+    );
+    assert_eq!(
+        "1.500 kbit",
+        Bit::from_u64(1_500_000)
+            .get_adjusted_unit(Unit::Kbit)
+            .display_with(FormatOptions::new().locale(Locale::De))
+            .to_string()
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn try_from_str() {
+    use byte_unit::AdjustedBit;
+
+    assert_eq!(Ok(Bit::from_u64(1536)), Bit::try_from("1.5 Kib").map_err(|_| ()));
+    assert_eq!(Ok(Bit::from_u64(1536)), Bit::try_from(String::from("1.5 Kib")).map_err(|_| ()));
+    assert!(Bit::try_from("1.5 KiX").is_err());
+    assert_eq!(
+        Ok(Bit::from_u64(1536).get_appropriate_unit(UnitType::Both)),
+        AdjustedBit::try_from("1.5 Kib").map_err(|_| ())
+    );
+}
+
+#[test]
+fn signed() {
+    use byte_unit::SignedBit;
+
+    let cases = [
+        (Some(-350_000_000), "-350 Mb", "-350000000", "-350 Mb"),
+        (Some(1536), "+1.5 Kib", "+1536", "+1.5 Kib"),
+        (Some(0), "-0", "0", "0 b"),
+        (None, "--1", "", ""),
+    ];
+
+    for (expected, input, display, alternate) in cases {
+        let result = SignedBit::parse_str(input).ok();
+
+        assert_eq!(expected, result.map(SignedBit::as_i128), "{input}");
+
+        if let Some(delta) = result {
+            assert_eq!(display, delta.to_string(), "{input}");
+            assert_eq!(alternate, format!("{delta:#}"), "{input}");
+        }
+    }
+
+    let delta = Bit::from_u64(512).signed_sub(Bit::from_u64(1024));
+
+    assert_eq!(-512, delta.as_i128());
+    assert_eq!(Bit::from_u64(512), delta.abs());
+    assert!(Bit::try_from(delta).is_err());
+    assert_eq!(Ok(Bit::from_u64(512)), Bit::try_from(-delta));
+    assert_eq!(SignedBit::MIN, -SignedBit::from(Bit::MAX));
+}
+
+#[test]
+fn parse_str_with_options() {
+    use byte_unit::ParseOptions;
+
+    let options = ParseOptions::new().underscores(true).thousands_separator(Some(','));
+
+    assert_eq!(
+        Ok(Bit::from_u64(1_500_000_000)),
+        Bit::parse_str_with_options("1,500,000 Kb", &options).map_err(|_| ())
+    );
+    assert_eq!(
+        Ok(Bit::from_u64(8_000)),
+        Bit::parse_str_with_options("1_000 B", &options).map_err(|_| ())
+    );
+    assert_eq!(
+        Ok(Bit::from_u64(1_500_000)),
+        Bit::parse_str_with_options("1.5e3 Kb", &options).map_err(|_| ())
+    );
+    assert!(Bit::parse_str_with_options("1,5 Kb", &options).is_err());
+}
+
+#[test]
+fn parse_mode() {
+    use byte_unit::{ParseMode, ParseOptions};
+
+    let jedec = ParseOptions::new().mode(ParseMode::Jedec);
+    let flexible = ParseOptions::new().mode(ParseMode::Flexible);
+
+    assert_eq!(Bit::from_u64(1024), Bit::parse_str_with_options("1 Kb", &jedec).unwrap());
+    assert_eq!(Bit::from_u64(8192), Bit::parse_str_with_options("1 KB", &jedec).unwrap());
+    assert_eq!(Bit::from_u64(1000), Bit::parse_str_with_options("1 Kb", &flexible).unwrap());
+    assert_eq!(Bit::from_u64(1024), Bit::parse_str_with_options("1 K", &flexible).unwrap());
+    assert_eq!(
+        Bit::from_u64(1000),
+        Bit::parse_str_with_options("1 K", &ParseOptions::new()).unwrap()
+    );
+}
+
+#[test]
+fn parse_str_const() {
+    const LINK_SPEED: Bit = Bit::parse_str_const("100 Mb");
+    const BUFFER: Bit = Bit::parse_str_const("2 KiB");
+
+    assert_eq!(100_000_000, LINK_SPEED.as_u64());
+    assert_eq!(16_384, BUFFER.as_u64());
+    assert_eq!(512, Bit::parse_str_const("512").as_u64());
+    assert!(std::panic::catch_unwind(|| Bit::parse_str_const("1.5 Mb")).is_err());
+}
+
+#[test]
+fn lerp() {
+    let a = Bit::from_u64(1000);
+    let b = Bit::from_u64(2000);
+
+    assert_eq!(Bit::from_u64(1250), a.lerp(b, 0.25));
+    assert_eq!(Bit::from_u64(1750), b.lerp(a, 0.25));
+    assert_eq!(a, a.lerp(b, f64::NAN));
+    assert_eq!(None, a.checked_lerp(b, 1.5));
+    assert_eq!(Bit::from_u64(1500), a.smooth_towards(b, 0.5));
+}
+
+#[test]
+fn rate() {
+    use std::time::Duration;
+
+    use byte_unit::{BitRate, RateUnitType, TimeUnit};
+
+    let cases = [
+        (Some((100_000_000, TimeUnit::Second)), "100 Mbps"),
+        (Some((100_000_000, TimeUnit::Second)), "12.5 MB/s"),
+        (Some((1024, TimeUnit::Minute)), "1 Kib/min"),
+        (None, "100 MBPS"),
+        (None, "100 Mb"),
+        (None, "100 Mb/fortnight"),
+    ];
+
+    for (expected, input) in cases {
+        let result = BitRate::parse_str(input).ok();
+
+        assert_eq!(
+            expected,
+            result.map(|rate| (rate.get_size().as_u64(), rate.get_time_unit())),
+            "{input}"
+        );
+    }
+
+    let rate: BitRate = "100 Mbps".parse().unwrap();
+
+    assert_eq!("100000000/s", rate.to_string());
+    assert_eq!("100 Mb/s", format!("{rate:#}"));
+    assert_eq!("95.37 Mib/s", format!("{:.2}", rate.get_appropriate_unit(RateUnitType::Binary)));
+    assert_eq!("100 Mb/s", rate.get_appropriate_unit(RateUnitType::DecimalBitsOnly).to_string());
+
+    assert_eq!(
+        BitRate::new(Bit::from_u64(120), TimeUnit::Minute),
+        BitRate::per_second(Bit::from_u64(2))
+    );
+    assert_eq!(rate, Bit::from_u64(50_000_000) / Duration::from_millis(500));
+    assert_eq!(Bit::from_u64(6_000_000_000), rate * Duration::from_secs(60));
+    assert_eq!(None, BitRate::per_second(Bit::MAX).checked_mul_duration(Duration::from_secs(2)));
+}
+
+#[test]
+fn from_u128_unchecked() {
+    const SIZE: Bit = Bit::from_u128_unchecked(15_000_000);
+
+    assert_eq!(Bit::from_u64(15_000_000), SIZE);
+    assert_eq!(Bit::MAX, Bit::new_clamped(Bit::MAX.as_u128() + 1));
+    assert_eq!(Bit::from_u64(15), Bit::new_clamped(15));
+}
+
+#[test]
+fn display_builder() {
+    let bit = Bit::from_u64(1_500_000);
+
+    assert_eq!("1.5 Mb", bit.display().to_string());
+    assert_eq!("1500Kb", bit.display().unit(Unit::Kbit).no_space().to_string());
+    assert_eq!("1.43 Mib", bit.display().unit(Unit::Mibit).precision(2).to_string());
+    assert_eq!("187.5 KB", bit.display().unit(Unit::KB).to_string());
+}
+
+#[cfg(feature = "clap")]
+#[test]
+fn clap_value_parser() {
+    use byte_unit::clap::bit_in_range;
+    use clap::{error::ErrorKind, value_parser, Arg, Command};
+
+    let command = Command::new("app")
+        .arg(Arg::new("size").long("size").value_parser(value_parser!(Bit)))
+        .arg(Arg::new("speed").long("speed").value_parser(bit_in_range("1 Mb"..="10 Gb")));
+
+    let matches = command
+        .clone()
+        .try_get_matches_from(["app", "--size", "2 Kib", "--speed", "1 Gb"])
+        .unwrap();
+
+    assert_eq!(Some(&Bit::from_u64(2048)), matches.get_one::<Bit>("size"));
+    assert_eq!(Some(&Bit::from_u64(1_000_000_000)), matches.get_one::<Bit>("speed"));
+
+    let cases = [
+        (["app", "--speed", "100 Kb"], "100 Kb is less than the minimum of 1 Mb"),
+        (["app", "--speed", "11 Gb"], "11 Gb is greater than the maximum of 10 Gb"),
+    ];
+
+    for (args, reason) in cases {
+        let error = command.clone().try_get_matches_from(args).unwrap_err();
+
+        assert_eq!(ErrorKind::ValueValidation, error.kind());
+        assert!(error.to_string().contains(reason), "{error}");
+    }
+}
+
+#[test]
+fn parse_str_in_range() {
+    use byte_unit::RangeParseError;
+
+    let range = Bit::from_u64(1000)..Bit::from_u64(1_000_000);
+
+    assert_eq!(Bit::from_u64(1000), Bit::parse_str_in_range("1 Kb", range.clone()).unwrap());
+    assert!(matches!(
+        Bit::parse_str_in_range("999 b", range.clone()),
+        Err(RangeParseError::TooSmall {
+            inclusive: true,
+            ..
+        })
+    ));
+    assert!(matches!(
+        Bit::parse_str_in_range("1 Mb", range.clone()),
+        Err(RangeParseError::TooLarge {
+            inclusive: false,
+            ..
+        })
+    ));
+    assert!(matches!(Bit::parse_str_in_range("1 XB", range), Err(RangeParseError::Parse(_))));
+}
+
+#[test]
+fn parse_str_scientific_notation() {
+    let cases = [
+        (1_500_000_000, "1.5e9"),
+        (2000 * 1024, "2E3 Kib"),
+        (100_000_000, "1e2 Mbit"),
+        (1, "5e-1 b"),
+    ];
+
+    for (expected, s) in cases {
+        assert_eq!(expected, Bit::parse_str(s).unwrap().as_u64(), "{s}");
+    }
+
+    assert!(Bit::parse_str("1e+").is_err());
+}
+
+#[test]
+fn const_min_max_clamp() {
+    const SMALL: Bit = Bit::from_u64(100);
+    const LARGE: Bit = Bit::from_u64(200);
+
+    const MIN: Bit = SMALL.min(LARGE);
+    const MAX: Bit = SMALL.max(LARGE);
+
+    assert_eq!((SMALL, LARGE), (MIN, MAX));
+    assert_eq!(SMALL, Bit::MIN.clamp(SMALL, LARGE));
+    assert_eq!(LARGE, Bit::MAX.clamp(SMALL, LARGE));
+    assert_eq!(Bit::from_u64(150), Bit::from_u64(150).clamp(SMALL, LARGE));
+}
+
+#[cfg(feature = "proptest")]
+#[test]
+fn proptest_arbitrary() {
+    use byte_unit::AdjustedBit;
+    use proptest::{
+        arbitrary::any,
+        strategy::{Strategy, ValueTree},
+        test_runner::TestRunner,
+    };
+
+    let mut runner = TestRunner::deterministic();
+
+    let bits = (0..1000)
+        .map(|_| any::<Bit>().new_tree(&mut runner).unwrap().current())
+        .collect::<Vec<_>>();
+
+    assert!(bits.contains(&Bit::MAX));
+    assert!(bits.contains(&Bit::MIN));
+
+    any::<AdjustedBit>().new_tree(&mut runner).unwrap().current();
+}
+
+#[test]
+fn adjusted_parse_str_keep_unit() {
+    use byte_unit::AdjustedBit;
+
+    let cases = [
+        ("2048 Kibit", Unit::Kibit, "2048 Kib"),
+        ("1.5 Gbit", Unit::Gbit, "1.5 Gb"),
+        ("1000000", Unit::Bit, "1000000 b"),
+        ("2 MiB", Unit::MiB, "2 MiB"),
+    ];
+
+    for (i, (s, unit, expected)) in cases.iter().enumerate() {
+        let adjusted_bit = AdjustedBit::parse_str_keep_unit(s).unwrap();
+
+        assert_eq!(*unit, adjusted_bit.get_unit(), "{i}");
+        assert_eq!(*expected, adjusted_bit.to_string(), "{i}");
+        assert_eq!(Bit::parse_str(s).unwrap(), adjusted_bit.get_bit(), "{i}");
+    }
+
+    assert!(AdjustedBit::parse_str_keep_unit("1 XB").is_err());
+}
+
+#[test]
+fn appropriate_unit_with_options() {
+    use byte_unit::AppropriateUnitOptions;
+
+    let options = AppropriateUnitOptions::new(UnitType::Binary).max_value(1000);
+
+    assert_eq!(
+        Unit::Kibit,
+        Bit::from_u64(1000).get_appropriate_unit_with_options(&options).get_unit()
+    );
+    assert_eq!(
+        Unit::Bit,
+        Bit::from_u64(999).get_appropriate_unit_with_options(&options).get_unit()
+    );
+
+    // sizes below 1 Kbit stay in bits instead of becoming fractional bytes
+    for unit_type in [UnitType::Binary, UnitType::Decimal, UnitType::Both] {
+        let adjusted_bit = Bit::from_u64(500).get_appropriate_unit(unit_type);
+
+        assert_eq!(Unit::Bit, adjusted_bit.get_unit(), "{unit_type:?}");
+        assert_eq!("500 b", adjusted_bit.to_string(), "{unit_type:?}");
+    }
+
+    for n in [0, 1, 1000, 1 << 40, u64::MAX] {
+        let bit = Bit::from_u64(n);
+
+        for unit_type in [UnitType::Binary, UnitType::Decimal, UnitType::Both] {
+            assert_eq!(
+                bit.get_appropriate_unit(unit_type).get_unit(),
+                bit.get_appropriate_unit_with_options(&AppropriateUnitOptions::new(unit_type))
+                    .get_unit(),
+                "{n}"
+            );
+        }
+    }
+}
+
+#[cfg(feature = "net")]
+#[test]
+fn net_buffer_sizing() {
+    use std::time::Duration;
+
+    use byte_unit::{
+        net::{bdp, tcp_buffer_size, LINUX_TCP_RMEM, LINUX_TCP_WMEM},
+        BitRate, Byte,
+    };
+
+    let rate = BitRate::per_second(Bit::from_u64(1_000_000_000)); // 1 Gbps
+
+    let cases = [
+        (0, Duration::ZERO),
+        (1, Duration::from_nanos(1)),
+        (125_000, Duration::from_millis(1)),
+        (12_500_000, Duration::from_millis(100)),
+    ];
+
+    for (i, (expected, rtt)) in cases.iter().enumerate() {
+        assert_eq!(*expected, bdp(rate, *rtt).as_u64(), "{i}");
+    }
+
+    assert_eq!(Bit::MAX.as_byte_ceil(), bdp(BitRate::per_second(Bit::MAX), Duration::from_secs(2)));
+
+    assert_eq!(LINUX_TCP_RMEM.min, tcp_buffer_size(rate, Duration::ZERO, LINUX_TCP_RMEM));
+    assert_eq!(
+        Byte::from_u64(250_000),
+        tcp_buffer_size(rate, Duration::from_millis(1), LINUX_TCP_RMEM)
+    );
+    assert_eq!(
+        LINUX_TCP_WMEM.max,
+        tcp_buffer_size(rate, Duration::from_millis(100), LINUX_TCP_WMEM)
+    );
+}
+
+#[test]
+fn rate_overhead() {
+    use byte_unit::{BitRate, OverheadProfile, TimeUnit};
+
+    let line_rate = BitRate::per_second(Bit::from_u64(1_538_000));
+
+    let cases = [
+        (1_460_000, OverheadProfile::ETHERNET_IPV4_TCP),
+        (1_410_000, OverheadProfile::VXLAN),
+        (1_435_000, OverheadProfile::QUIC),
+        (1_538_000, OverheadProfile::new(1, 1)),
+        (0, OverheadProfile::new(0, 1)),
+    ];
+
+    for (i, (expected, profile)) in cases.iter().enumerate() {
+        let goodput = line_rate.apply_overhead(*profile);
+
+        assert_eq!(*expected, goodput.get_size().as_u64(), "{i}");
+
+        if profile.get_payload_size() > 0 {
+            assert_eq!(Some(line_rate), goodput.required_line_rate(*profile), "{i}");
+        }
+    }
+
+    let rate = BitRate::new(Bit::from_u64(3), TimeUnit::Minute);
+
+    assert_eq!(Bit::from_u64(1), rate.apply_overhead(OverheadProfile::new(1, 2)).get_size());
+    assert_eq!(
+        Some(BitRate::new(Bit::from_u64(6), TimeUnit::Minute)),
+        rate.required_line_rate(OverheadProfile::new(1, 2))
+    );
+    assert_eq!(
+        Some(BitRate::per_second(Bit::MIN)),
+        BitRate::per_second(Bit::MIN).required_line_rate(OverheadProfile::new(0, 1))
+    );
+    assert_eq!(None, rate.required_line_rate(OverheadProfile::new(0, 1)));
+    assert_eq!(None, BitRate::per_second(Bit::MAX).required_line_rate(OverheadProfile::VXLAN));
+}
+
+#[test]
+fn parse_composite() {
+    use byte_unit::ParseOptions;
+
+    let options = ParseOptions::new().composite(true);
+
+    assert_eq!(
+        Bit::from_u64(1_000_512),
+        Bit::parse_str_with_options("1 Mb 512 b", &options).unwrap()
+    );
+    assert_eq!(
+        Bit::from_u64((1 << 20) + 8 * (1 << 10)),
+        Bit::parse_str_with_options("1 Mib 1 KiB", &options).unwrap()
+    );
+    assert!(Bit::parse_str_with_options("1 Mb 512", &ParseOptions::new()).is_err());
+}
+
+#[test]
+fn integer_radix_formatting() {
+    let bit = Bit::from_u64(0xBEEF);
+
+    assert_eq!("0xbeef", format!("{bit:#x}"));
+    assert_eq!("BEEF", format!("{bit:X}"));
+    assert_eq!("137357", format!("{bit:o}"));
+    assert_eq!("1011111011101111", format!("{bit:b}"));
+}
+
+#[test]
+fn alignment() {
+    let octet = Bit::from_u64(8);
+
+    assert_eq!(Some(Bit::from_u64(16)), Bit::from_u64(9).align_up(octet));
+    assert_eq!(Some(Bit::from_u64(8)), Bit::from_u64(15).align_down(octet));
+    assert!(Bit::from_u64(24).is_aligned_to(octet));
+    assert!(!Bit::from_u64(24).is_aligned_to(Bit::MIN));
+    assert_eq!(None, Bit::from_u64(24).align_up(Bit::MIN));
+    assert_eq!(None, Bit::MAX.align_up(Bit::from_u64(7)));
+    assert_eq!(Some(Bit::from_u64(32)), Bit::from_u64(17).next_power_of_two());
+    assert_eq!(None, Bit::MAX.next_power_of_two());
+}
@@ -17,6 +17,10 @@ fn parse_str() {
         (Ok(Byte::from_u64(1)), "1", false),
         (Err(()), "-1", false),
         (Ok(Byte::from_u64(1)), "1b", false),
+        (Ok(Byte::from_u64(5_000_000)), "5 megabytes", false),
+        (Ok(Byte::from_u64(3 << 30)), "3 Gibibytes", false),
+        (Ok(Byte::from_u64(1500)), "12 kilobits", false),
+        (Ok(Byte::from_u64(1)), "1 byte", false),
         (Ok(Byte::from_u64(1)), "1b", true),
         (Err(()), "1i", false),
         (Err(()), "1c", false),
@@ -24,6 +28,10 @@ fn parse_str() {
         (Ok(Byte::from_u64(1)), "1 b", true),
         (Ok(Byte::from_u64(1)), "1  b", true),
         (Ok(Byte::from_u64(1)), "1  b", true),
+        (Ok(Byte::from_u64(1)), "1\u{a0}b", true),
+        (Ok(Byte::from_u64(1)), "1\u{2009}b", true),
+        (Err(()), "1\u{2002}b", true),
+        (Err(()), "\u{2212}1", false),
         (Ok(Byte::from_u64(2)), "1.1", false),
         (Ok(Byte::from_u64(1)), "1.1b", false),
         (Ok(Byte::from_u64(2)), "1.1b", true),
@@ -156,3 +164,2795 @@ fn tests() {
         assert_eq!(byte, serde_json::from_str::<Byte>(case.0).unwrap(), "{i}");
     }
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_integers() {
+    use serde::{
+        de::{value, IntoDeserializer},
+        Deserialize,
+    };
+
+    let expected = Byte::from_u64(1024);
+
+    let encoded = bincode::serialize(&expected).unwrap();
+
+    assert_eq!(16, encoded.len());
+
+    assert_eq!(expected, bincode::deserialize::<Byte>(&encoded).unwrap());
+
+    fn deserialize<'de, D: IntoDeserializer<'de, value::Error>>(v: D) -> Option<Byte> {
+        Byte::deserialize(v.into_deserializer()).ok()
+    }
+
+    assert_eq!(Some(expected), deserialize(1024u16));
+    assert_eq!(Some(expected), deserialize(1024u32));
+    assert_eq!(Some(expected), deserialize(1024u64));
+    assert_eq!(Some(expected), deserialize(1024u128));
+    assert_eq!(Some(expected), deserialize(1024i32));
+    assert_eq!(Some(expected), deserialize(1024i64));
+    assert_eq!(Some(expected), deserialize(1024i128));
+    assert_eq!(None, deserialize(-1i64));
+    assert_eq!(None, deserialize(-1i128));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn parse_many() {
+    let strings = ["0", "1 KB", "2KiB", "1.5 MB", "3 b"];
+
+    assert_eq!(
+        vec![
+            Byte::from_u64(0),
+            Byte::from_u64(1000),
+            Byte::from_u64(2048),
+            Byte::from_u64(1500000),
+            Byte::from_u64(3),
+        ],
+        Byte::parse_many(&strings, true).unwrap()
+    );
+
+    let strings = ["1 KB", "-1", "2 KiB", "c"];
+
+    assert_eq!(1, Byte::parse_many(&strings, true).unwrap_err().0);
+
+    assert!(Byte::parse_many::<&str>(&[], true).unwrap().is_empty());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_total() {
+    let sizes: Vec<Byte> = (0..10000u64).map(Byte::from_u64).collect();
+
+    assert_eq!(49995000, Byte::par_total(sizes.clone()).unwrap().as_u64());
+
+    let stats = Byte::par_stats(sizes).unwrap();
+
+    assert_eq!(10000, stats.count);
+    assert_eq!(0, stats.min.as_u64());
+    assert_eq!(9999, stats.max.as_u64());
+
+    assert_eq!(Some(Byte::MIN), Byte::par_total(Vec::new()));
+    assert_eq!(None, Byte::par_stats(Vec::new()));
+
+    assert_eq!(None, Byte::par_total(vec![Byte::MAX, Byte::from_u64(1)]));
+    assert_eq!(None, Byte::par_stats(vec![Byte::MAX, Byte::from_u64(1)]));
+}
+
+#[test]
+fn adjusted_unit_exact() {
+    let byte = Byte::from_u64(15000000);
+
+    for unit in [Unit::Bit, Unit::B, Unit::Kbit, Unit::KiB, Unit::Mbit, Unit::MB] {
+        let (value, unit) = byte.get_adjusted_unit_exact(unit);
+
+        assert_eq!(value.to_f64().unwrap(), byte.get_adjusted_unit(unit).get_value(), "{unit}");
+    }
+}
+
+#[test]
+fn wrapper_types() {
+    use std::num::{NonZeroU128, NonZeroU64, NonZeroU8, Wrapping};
+
+    use byte_unit::NonZeroError;
+
+    assert_eq!(Byte::from_u64(5), Byte::from(NonZeroU8::new(5).unwrap()));
+    assert_eq!(Byte::from_u64(5), Byte::from(NonZeroU64::new(5).unwrap()));
+    assert_eq!(Byte::from_u64(5), Byte::try_from(NonZeroU128::new(5).unwrap()).unwrap());
+    assert_eq!(Byte::from_u64(5), Byte::from(Wrapping(5u64)));
+
+    assert_eq!(NonZeroU64::new(5), NonZeroU64::try_from(Byte::from_u64(5)).ok());
+    assert_eq!(Err(NonZeroError::Zero), NonZeroU64::try_from(Byte::MIN));
+    assert_eq!(Err(NonZeroError::Zero), NonZeroU128::try_from(Byte::MIN));
+    #[cfg(feature = "u128")]
+    assert_eq!(Err(NonZeroError::ExceededBounds), NonZeroU64::try_from(Byte::MAX));
+    assert_eq!(Wrapping(5u128), Wrapping::from(Byte::from_u64(5)));
+}
+
+#[cfg(feature = "saturating")]
+#[test]
+fn saturating_wrapper() {
+    use std::num::Saturating;
+
+    assert_eq!(Byte::from_u64(5), Byte::from(Saturating(5u64)));
+    assert!(Byte::try_from(Saturating(u128::MAX)).is_err());
+    assert_eq!(Saturating(u64::MAX), Saturating::<u64>::from(Byte::MAX));
+}
+
+#[test]
+fn find_sizes() {
+    use byte_unit::extract::find_sizes;
+
+    let cases = [
+        ("", vec![]),
+        ("12 files", vec![]),
+        ("10MB", vec![("10MB", 10000000)]),
+        ("v2 MB", vec![]),
+        ("1.5 KiB and 2KB/s", vec![("1.5 KiB", 1536), ("2KB", 2000)]),
+        ("x1.5 KB, 3 KBX", vec![]),
+        ("wrote 512 B\tto μdisk 1KB", vec![("512 B", 512), ("1KB", 1000)]),
+        ("rss=1.5e6B heap 2E3 KiB", vec![("1.5e6B", 1500000), ("2E3 KiB", 2048000)]),
+        ("1EB free", vec![("1EB", 1000000000000000000)]),
+    ];
+
+    for (i, (text, expected)) in cases.iter().enumerate() {
+        let result: Vec<(&str, u64)> =
+            find_sizes(text).map(|(range, byte)| (&text[range], byte.as_u64())).collect();
+
+        assert_eq!(expected, &result, "{i}");
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn parse_str_lenient() {
+    use byte_unit::ParseWarning::*;
+
+    let cases = [
+        ("15 MB", Some(15000000), vec![]),
+        ("15 MB.", Some(15000000), vec![TrailingPeriodRemoved]),
+        ("1 5 M B", Some(15000000), vec![WhitespaceRemoved]),
+        ("15MBMB", Some(15000000), vec![DoubledUnitRemoved]),
+        ("1 5 MB MB.", Some(15000000), vec![
+            TrailingPeriodRemoved,
+            WhitespaceRemoved,
+            DoubledUnitRemoved,
+        ]),
+        ("15 MB MB", Some(15000000), vec![DoubledUnitRemoved]),
+        ("1 5 M B M B", Some(15000000), vec![WhitespaceRemoved, DoubledUnitRemoved]),
+        ("fifteen MB", None, vec![]),
+        ("15 XB.", None, vec![]),
+    ];
+
+    for (i, (s, expected, warnings)) in cases.into_iter().enumerate() {
+        let (result, result_warnings) = Byte::parse_str_lenient(s, true);
+
+        assert_eq!(expected, result.map(|b| b.as_u64()), "{i}");
+        assert_eq!(warnings, result_warnings, "{i}");
+    }
+}
+
+#[test]
+fn parse_str_negative() {
+    use byte_unit::{ParseError, ValueParseError};
+
+    for s in ["-1 KB", "\u{2212}1 KB"] {
+        assert!(
+            matches!(
+                Byte::parse_str(s, true),
+                Err(ParseError::Value(ValueParseError::NotNumber('-')))
+            ),
+            "{s}"
+        );
+    }
+}
+
+#[test]
+fn appropriate_unit_allowing_bits() {
+    let cases = [
+        (Unit::B, 100, UnitType::Decimal),
+        (Unit::Kbit, 125, UnitType::Decimal),
+        (Unit::KB, 1000, UnitType::Decimal),
+        (Unit::Mbit, 375000, UnitType::Decimal),
+        (Unit::MB, 3000000, UnitType::Decimal),
+        (Unit::B, 127, UnitType::Binary),
+        (Unit::Kibit, 128, UnitType::Binary),
+        (Unit::Mibit, 131072, UnitType::Binary),
+        (Unit::Kbit, 127, UnitType::Both),
+        (Unit::Kibit, 128, UnitType::Both),
+        (Unit::KiB, 1024, UnitType::Both),
+    ];
+
+    for (i, (unit, n, unit_type)) in cases.iter().enumerate() {
+        let adjusted_byte = Byte::from_u64(*n).get_appropriate_unit_allowing_bits(*unit_type);
+
+        assert_eq!(*unit, adjusted_byte.get_unit(), "{i}");
+    }
+}
+
+#[cfg(feature = "parser-combinator")]
+#[test]
+fn byte_parser() {
+    use byte_unit::combinators::byte_parser;
+    use nom::IResult;
+
+    let cases = [
+        (Some((1, "")), "1"),
+        (Some((1000, "")), "1KB"),
+        (Some((1000, " ")), "1 KB "),
+        (Some((1, " ;")), "1 ;"),
+        (Some((1500, "/s")), "1.5 KB/s"),
+        (Some((1, ".")), "1."),
+        (Some((1500000, "/s")), "1.5e3 KB/s"),
+        (Some((2048000, "")), "2E3 KiB"),
+        (Some((1000000000000000000, "")), "1EB"),
+        (None, "KB"),
+        (None, "1 XB"),
+    ];
+
+    for (i, (expected, s)) in cases.iter().enumerate() {
+        let result: IResult<&str, Byte> = byte_parser(true)(s);
+
+        assert_eq!(*expected, result.ok().map(|(rest, byte)| (byte.as_u64(), rest)), "{i}");
+    }
+}
+
+#[test]
+fn from_statvfs_blocks() {
+    use byte_unit::FsUsage;
+
+    assert_eq!(Some(Byte::from_u64(0)), Byte::from_statvfs_blocks(0, 4096));
+    assert_eq!(Some(Byte::from_u64(1 << 32)), Byte::from_statvfs_blocks(1 << 20, 4096));
+
+    #[cfg(feature = "u128")]
+    assert_eq!(Byte::from_u128(u64::MAX as u128 * 2), Byte::from_statvfs_blocks(u64::MAX, 2));
+    #[cfg(not(feature = "u128"))]
+    assert_eq!(None, Byte::from_statvfs_blocks(u64::MAX, 2));
+
+    let usage = FsUsage::from_statvfs_blocks(10, 4, 2, 512).unwrap();
+
+    assert_eq!(Byte::from_u64(3072), usage.used());
+    assert_eq!(
+        Byte::MIN,
+        FsUsage {
+            free: Byte::MAX,
+            ..usage
+        }
+        .used()
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn format_fixed_width() {
+    let cases = [
+        (Some("512 B"), 512, Unit::B, 5),
+        (Some("  512 B"), 512, Unit::B, 7),
+        (Some("1 KiB"), 1000, Unit::B, 5),
+        (Some("0.5 KiB"), 512, Unit::KiB, 7),
+        (Some("9.77 KiB"), 10000, Unit::KiB, 8),
+        (Some("10 KiB"), 10000, Unit::KiB, 6),
+        (None, 10000, Unit::KiB, 4),
+        (Some("10 MB"), 9999999, Unit::KB, 5),
+    ];
+
+    for (i, (expected, n, unit, width)) in cases.iter().enumerate() {
+        let result = Byte::from_u64(*n).get_adjusted_unit(*unit).format_fixed_width(*width);
+
+        assert_eq!(*expected, result.as_deref(), "{i}");
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn format_aligned() {
+    let cases = [
+        ("  1.50 GiB", 1610612736, Unit::GiB, 10, 2),
+        ("1536.00 MiB", 1610612736, Unit::MiB, 10, 2),
+        ("   512   B", 512, Unit::B, 10, 2),
+        ("   1.5  KB", 1500, Unit::KB, 10, 1),
+        ("2 KiB", 2048, Unit::KiB, 0, 0),
+    ];
+
+    for (i, (expected, n, unit, width, precision)) in cases.iter().enumerate() {
+        let result = Byte::from_u64(*n).get_adjusted_unit(*unit).format_aligned(*width, *precision);
+
+        assert_eq!(*expected, result, "{i}");
+    }
+}
+
+#[test]
+fn from_value_and_unit_str() {
+    let cases = [
+        (Some(1610612736), "1.5", "GiB"),
+        (Some(1024), " 1 ", " KiB "),
+        (Some(10), "10", ""),
+        (Some(2), "1.1", "B"),
+        (None, "", "KB"),
+        (None, "1.", "KB"),
+        (None, "1.2.3", "KB"),
+        (None, "-1", "KB"),
+        (None, "1 5", "KB"),
+        (None, "1", "XB"),
+        (None, "1", "1KB"),
+    ];
+
+    for (i, (expected, value, unit)) in cases.iter().enumerate() {
+        let result = Byte::from_value_and_unit_str(value, unit, true);
+
+        assert_eq!(*expected, result.ok().map(|b| b.as_u64()), "{i}");
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn value_and_unit() {
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Wrapper(#[serde(with = "byte_unit::serde::value_and_unit")] Byte);
+
+    let cases = [
+        (r#"{"value":0,"unit":"B"}"#, 0),
+        (r#"{"value":1.023,"unit":"KB"}"#, 1023),
+        (r#"{"value":1001,"unit":"KiB"}"#, 1025024),
+        (r#"{"value":1,"unit":"KiB"}"#, 1024),
+        (r#"{"value":1.5,"unit":"GiB"}"#, 1610612736),
+        (r#"{"value":18446744073709551615,"unit":"B"}"#, u64::MAX),
+    ];
+
+    for (i, (s, n)) in cases.iter().enumerate() {
+        assert_eq!(*s, serde_json::to_string(&Wrapper(Byte::from_u64(*n))).unwrap(), "{i}");
+        assert_eq!(Wrapper(Byte::from_u64(*n)), serde_json::from_str(s).unwrap(), "{i}");
+    }
+
+    assert!(serde_json::from_str::<Wrapper>(r#"{"value":-1,"unit":"KB"}"#).is_err());
+    assert!(serde_json::from_str::<Wrapper>(r#"{"value":1}"#).is_err());
+    assert!(serde_json::from_str::<Wrapper>(r#"{"value":1,"unit":"XB"}"#).is_err());
+    assert!(serde_json::from_str::<Wrapper>(r#"{"value":1,"unit":"B","x":1}"#).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_with_modules() {
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Wrapper {
+        #[serde(with = "byte_unit::serde::bytes_as_u64")]
+        integer: Byte,
+        #[serde(with = "byte_unit::serde::bytes_as_string")]
+        string:  Byte,
+        #[serde(with = "byte_unit::serde::bytes_as_mib_f64")]
+        mib:     Byte,
+    }
+
+    let cases = [
+        (r#"{"integer":0,"string":"0 B","mib":0.0}"#, 0),
+        (r#"{"integer":1536,"string":"1.5 KiB","mib":0.00146484375}"#, 1536),
+        (r#"{"integer":1610612736,"string":"1.5 GiB","mib":1536.0}"#, 1610612736),
+    ];
+
+    for (s, n) in cases {
+        let byte = Byte::from_u64(n);
+        let wrapper = Wrapper {
+            integer: byte, string: byte, mib: byte
+        };
+
+        assert_eq!(s, serde_json::to_string(&wrapper).unwrap());
+        assert_eq!(wrapper, serde_json::from_str(s).unwrap());
+    }
+
+    let wrapper: Wrapper =
+        serde_json::from_str(r#"{"integer":1,"string":"1 MB","mib":2}"#).unwrap();
+
+    assert_eq!(Byte::from_u64(1_000_000), wrapper.string);
+    assert_eq!(Byte::from_u64(2_097_152), wrapper.mib);
+
+    assert!(serde_json::from_str::<Wrapper>(r#"{"integer":"1","string":"1","mib":1}"#).is_err());
+    assert!(serde_json::from_str::<Wrapper>(r#"{"integer":1,"string":1,"mib":1}"#).is_err());
+    assert!(serde_json::from_str::<Wrapper>(r#"{"integer":1,"string":"1","mib":-1}"#).is_err());
+    assert!(serde_json::from_str::<Wrapper>(r#"{"integer":-1,"string":"1","mib":1}"#).is_err());
+
+    // formats such as TOML produce signed integers
+    type I64Deserializer = serde::de::value::I64Deserializer<serde::de::value::Error>;
+
+    assert_eq!(
+        Byte::from_u64(1536),
+        byte_unit::serde::bytes_as_u64::deserialize(I64Deserializer::new(1536)).unwrap()
+    );
+    assert_eq!(
+        "invalid value: integer `-1`, expected a non-negative integer of bytes",
+        byte_unit::serde::bytes_as_u64::deserialize(I64Deserializer::new(-1))
+            .unwrap_err()
+            .to_string()
+    );
+}
+
+#[test]
+fn parse_content_length() {
+    let cases = [
+        (Some(0), "0"),
+        (Some(42), "042"),
+        (Some(42), " \t42 "),
+        (Some(u64::MAX), "18446744073709551615"),
+        (None, ""),
+        (None, " "),
+        (None, "-1"),
+        (None, "+1"),
+        (None, "1.0"),
+        (None, "1e3"),
+        (None, "1 KB"),
+        (None, "4 2"),
+        (None, "42,42"),
+        (None, "42, 42"),
+        (None, "\u{a0}42"),
+    ];
+
+    for (i, (expected, s)) in cases.iter().enumerate() {
+        assert_eq!(*expected, Byte::parse_content_length(s).ok().map(|b| b.as_u64()), "{i}");
+    }
+
+    #[cfg(not(feature = "u128"))]
+    assert!(Byte::parse_content_length("18446744073709551616").is_err());
+}
+
+#[test]
+fn quota_str() {
+    let cases = [
+        (Some(0), "0"),
+        (Some(512), "0.5K"),
+        (Some(1024), "1k"),
+        (Some(1024), "1K"),
+        (Some(1572864), "1.5M"),
+        (Some(536870912000), "500G"),
+        (Some(1649267441664), "1.5T"),
+        (None, ""),
+        (None, "G"),
+        (None, " 1G"),
+        (None, "1G "),
+        (None, "1 G"),
+        (None, "1GB"),
+        (None, "1Gi"),
+        (None, "1B"),
+        (None, "1X"),
+        (None, "-1G"),
+    ];
+
+    for (i, (expected, s)) in cases.iter().enumerate() {
+        assert_eq!(*expected, Byte::parse_quota_str(s).ok().map(|b| b.as_u64()), "{i}");
+    }
+
+    #[cfg(feature = "std")]
+    for s in ["0", "1000", "1K", "1023K", "1M", "500G", "1536G", "15E"] {
+        assert_eq!(s, Byte::parse_quota_str(s).unwrap().to_quota_string());
+    }
+}
+
+#[test]
+fn round_to_unit() {
+    use byte_unit::RoundingMode;
+
+    let cases = [
+        (Some(0), 0, Unit::KiB, RoundingMode::Up),
+        (Some(1024), 1, Unit::KiB, RoundingMode::Up),
+        (Some(0), 1023, Unit::KiB, RoundingMode::Down),
+        (Some(0), 511, Unit::KiB, RoundingMode::Nearest),
+        (Some(1024), 512, Unit::KiB, RoundingMode::Nearest),
+        (Some(2048), 2048, Unit::KiB, RoundingMode::Up),
+        (Some(1000), 1001, Unit::KB, RoundingMode::Nearest),
+        (Some(250), 130, Unit::Kbit, RoundingMode::Up),
+        (Some(7), 7, Unit::Bit, RoundingMode::Up),
+        (None, u64::MAX, Unit::KiB, RoundingMode::Up),
+        (Some(u64::MAX - 1023), u64::MAX, Unit::KiB, RoundingMode::Down),
+    ];
+
+    for (i, (expected, n, unit, rounding_mode)) in cases.iter().enumerate() {
+        let result = Byte::from_u64(*n).round_to_unit(*unit, *rounding_mode);
+
+        #[cfg(feature = "u128")]
+        if expected.is_none() {
+            assert_eq!(Some(u64::MAX as u128 + 1), result.map(|b| b.as_u128()), "{i}");
+
+            continue;
+        }
+
+        assert_eq!(*expected, result.map(|b| b.as_u64()), "{i}");
+    }
+}
+
+#[cfg(feature = "cloud")]
+#[test]
+fn cloud_allocation() {
+    use byte_unit::cloud::{ebs_volume_size, s3_multipart_part_size, EBS_HDD_VOLUME};
+
+    let cases = [
+        (Some(1 << 30), 0),
+        (Some(1 << 30), 1 << 30),
+        (Some(2 << 30), (1 << 30) + 1),
+        (Some(16 << 40), 16 << 40),
+        (None, (16 << 40) + 1),
+    ];
+
+    for (i, (expected, n)) in cases.iter().enumerate() {
+        assert_eq!(*expected, ebs_volume_size(Byte::from_u64(*n)).map(|b| b.as_u64()), "{i}");
+    }
+
+    let cases = [
+        (Some(5 << 20), 0),
+        (Some(5 << 20), 50_000 << 20),
+        (Some(6 << 20), (50_000 << 20) + 1),
+        (Some(5 << 30), 10_000 * (5 << 30)),
+        (None, 10_000 * (5 << 30) + 1),
+    ];
+
+    for (i, (expected, n)) in cases.iter().enumerate() {
+        assert_eq!(
+            *expected,
+            s3_multipart_part_size(Byte::from_u64(*n)).map(|b| b.as_u64()),
+            "{i}"
+        );
+    }
+
+    assert_eq!(Some(Byte::from_u64(125 << 30)), EBS_HDD_VOLUME.allocate(Byte::GIBIBYTE));
+}
+
+#[test]
+fn display_flags() {
+    let byte = Byte::from_u64(1536);
+    let adjusted_byte = byte.get_adjusted_unit(Unit::KiB);
+
+    let cases = [
+        ("      1536", format!("{byte:10}")),
+        ("1536      ", format!("{byte:<10}")),
+        ("***1536***", format!("{byte:*^10}")),
+        ("0000001536", format!("{byte:010}")),
+        ("+1536", format!("{byte:+}")),
+        ("1.5    KiB", format!("{byte:#10}")),
+        ("   1.5 KiB", format!("{byte:>#10}")),
+        ("*1.5** KiB", format!("{byte:*^#10}")),
+        ("0001.5 KiB", format!("{byte:#010}")),
+        ("001.5KiB", format!("{byte:-#08}")),
+        ("1.5    KiB", format!("{adjusted_byte:10}")),
+        ("   1.5 KiB", format!("{adjusted_byte:>10}")),
+        ("*1.5** KiB", format!("{adjusted_byte:*^10}")),
+        ("001.50 KiB", format!("{adjusted_byte:010.2}")),
+        ("0001.5 KiB", format!("{adjusted_byte:#010.2}")),
+        ("001.5KiB", format!("{adjusted_byte:-08}")),
+    ];
+
+    for (i, (expected, actual)) in cases.iter().enumerate() {
+        assert_eq!(*expected, actual, "{i}");
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_error_message() {
+    let error = serde_json::from_str::<Byte>("\"12 XB\"").unwrap_err().to_string();
+
+    assert!(
+        error.starts_with("invalid value: string \"12 XB\", expected a string such as"),
+        "{error}"
+    );
+    assert!(error.contains("the character 'X' is incorrect"), "{error}");
+
+    let error = serde_json::from_str::<Unit>("\"XB\"").unwrap_err().to_string();
+
+    assert!(error.starts_with("invalid value: string \"XB\""), "{error}");
+}
+
+#[test]
+fn parse_ascii_u64() {
+    let cases = [
+        (None, ""),
+        (Some(0), "0"),
+        (Some(123), "00123"),
+        (Some(u64::MAX), "18446744073709551615"),
+        (None, "18446744073709551616"),
+        (None, "+1"),
+        (None, "1.5"),
+        (None, " 1"),
+        (None, "1K"),
+    ];
+
+    for (i, (expected, s)) in cases.iter().enumerate() {
+        assert_eq!(*expected, Byte::parse_ascii_u64(s.as_bytes()).map(|b| b.as_u64()), "{i}");
+    }
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_util() {
+    use byte_unit::test_util::{
+        all_units, assert_roundtrip, assert_unit_roundtrip, representative_bytes,
+    };
+
+    for unit in all_units() {
+        assert_unit_roundtrip(unit);
+    }
+
+    let bytes = representative_bytes();
+
+    assert!(bytes.windows(2).all(|w| w[0] < w[1]));
+
+    for byte in bytes {
+        assert_roundtrip(byte);
+    }
+}
+
+#[cfg(feature = "testgen")]
+#[test]
+fn testgen() {
+    use byte_unit::testgen::SizeSequence;
+
+    let min = Byte::from_u64(100);
+    let max = Byte::from_u64(200);
+
+    let sizes = SizeSequence::uniform(min, max).seed(1).take(10_000).collect::<Vec<_>>();
+
+    assert!(sizes.iter().all(|size| (min..=max).contains(size)));
+    assert!(sizes.contains(&min) && sizes.contains(&max));
+    assert_eq!(sizes, SizeSequence::uniform(min, max).seed(1).take(10_000).collect::<Vec<_>>());
+    assert_ne!(sizes, SizeSequence::uniform(min, max).seed(2).take(10_000).collect::<Vec<_>>());
+
+    assert!(SizeSequence::uniform(Byte::MIN, Byte::MAX).take(100).all(|size| size <= Byte::MAX));
+    assert!(SizeSequence::uniform(max, max).take(100).all(|size| size == max));
+
+    let step = Byte::from_u64(4096);
+
+    let mut counts = [0usize; 11];
+
+    for size in SizeSequence::zipf(step, 10, 1.0).seed(3).take(100_000) {
+        assert_eq!(0, size.as_u64() % 4096);
+
+        counts[(size.as_u64() / 4096) as usize] += 1;
+    }
+
+    // P(1) / P(2) = 2 with the exponent 1
+    assert_eq!(0, counts[0]);
+    assert!(counts[1..].windows(2).all(|w| w[0] > w[1]));
+    assert!((1.9..2.1).contains(&(counts[1] as f64 / counts[2] as f64)));
+
+    let median = Byte::from_u64(65536);
+
+    let mut sizes = SizeSequence::log_normal(median, 1.0).seed(4).take(10_001).collect::<Vec<_>>();
+
+    sizes.sort_unstable();
+
+    assert!((60_000..70_000).contains(&sizes[5000].as_u64()));
+    assert!(SizeSequence::log_normal(median, 0.0).take(100).all(|size| size == median));
+    assert!(SizeSequence::log_normal(Byte::MAX, 100.0).take(100).all(|size| size <= Byte::MAX));
+
+    assert!(std::panic::catch_unwind(|| SizeSequence::uniform(max, min)).is_err());
+    assert!(std::panic::catch_unwind(|| SizeSequence::zipf(step, 0, 1.0)).is_err());
+    assert!(std::panic::catch_unwind(|| SizeSequence::zipf(step, 10, f64::NAN)).is_err());
+    assert!(std::panic::catch_unwind(|| SizeSequence::log_normal(median, -1.0)).is_err());
+}
+
+#[test]
+fn bench_throughput() {
+    use std::time::Duration;
+
+    use byte_unit::{bench::Throughput, ByteRate};
+
+    let cases = [
+        ("2.34 GiB/s", "2.3 GiB/s", "2.34 GiB/s", 5 << 30, 2135),
+        ("4.00 MiB/s", "4.0 MiB/s", "4 MiB/s", 1 << 20, 250),
+        ("1000.00 B/s", "1000.0 B/s", "1000 B/s", 1000, 1000),
+        ("1.50 B/s", "1.5 B/s", "1.5 B/s", 3, 2000),
+        ("0.33 B/s", "0.3 B/s", "0.33 B/s", 1, 3000),
+        ("0.05 B/s", "0.1 B/s", "0.05 B/s", 1, 20_000),
+        ("0.00 B/s", "0.0 B/s", "0 B/s", 0, 1),
+        ("inf B/s", "inf B/s", "inf B/s", 1, 0),
+    ];
+
+    for (display, precision_1, alternate, bytes, millis) in cases {
+        let throughput = Throughput::new(Byte::from_u64(bytes), Duration::from_millis(millis));
+
+        assert_eq!(display, throughput.to_string(), "{bytes} {millis}");
+        assert_eq!(precision_1, format!("{throughput:.1}"), "{bytes} {millis}");
+        assert_eq!(alternate, format!("{throughput:#}"), "{bytes} {millis}");
+    }
+
+    let throughput = Throughput::new(Byte::from_u64(3000), Duration::from_millis(1500));
+
+    assert_eq!(2000.0, throughput.bytes_per_second());
+    assert_eq!(Some(ByteRate::per_second(Byte::from_u64(2000))), throughput.rate());
+    assert_eq!(3000, throughput.criterion_bytes());
+    assert_eq!(None, Throughput::new(Byte::from_u64(1), Duration::ZERO).rate());
+}
+
+#[test]
+fn audit_interpret() {
+    use byte_unit::audit::interpret;
+
+    let cases = [
+        ((Some(10_000_000), Some(10 << 20), None), "10MB"),
+        ((Some(10_000_000), Some(10 << 20), Some(10_000_000)), "10M"),
+        ((Some(10 << 20), Some(10 << 20), Some(10 << 20)), "10Mi"),
+        ((Some(10 << 20), Some(10 << 20), None), "10 MiB"),
+        ((Some(1500), Some(1536), Some(1500)), "1.5k"),
+        ((Some(10_000), Some(10_240), None), "10 Kb"),
+        ((Some(512), Some(512), Some(512)), "512"),
+        ((None, None, None), "lots"),
+    ];
+
+    for (i, ((si, jedec, kubernetes), s)) in cases.iter().enumerate() {
+        let interpretations = interpret(s);
+
+        assert_eq!(*si, interpretations.si.map(|b| b.as_u64()), "{i}");
+        assert_eq!(*jedec, interpretations.jedec.map(|b| b.as_u64()), "{i}");
+        assert_eq!(*kubernetes, interpretations.kubernetes.map(|b| b.as_u64()), "{i}");
+    }
+}
+
+#[test]
+fn adjusted_unit_rounded() {
+    use byte_unit::Precision;
+
+    let cases = [
+        (("1.519", false), 1555, Unit::KiB, Precision::Digits(3)),
+        (("1.5185546875", true), 1555, Unit::KiB, Precision::Max),
+        (("1.5185546875", true), 1555, Unit::KiB, Precision::Digits(100)),
+        (("2", false), 1555, Unit::KiB, Precision::Digits(0)),
+        (("1.5", true), 1536, Unit::KiB, Precision::Digits(1)),
+        (("1555", true), 1555, Unit::B, Precision::Digits(0)),
+        (("12440", true), 1555, Unit::Bit, Precision::Digits(0)),
+        (("0.01", false), 1, Unit::Kbit, Precision::Digits(2)),
+    ];
+
+    for (i, ((value, exact), n, unit, precision)) in cases.iter().enumerate() {
+        let (v, u, e) = Byte::from_u64(*n).get_adjusted_unit_rounded(*unit, *precision);
+
+        assert_eq!(*value, v.to_string(), "{i}");
+        assert_eq!(*unit, u, "{i}");
+        assert_eq!(*exact, e, "{i}");
+    }
+}
+
+#[test]
+fn within() {
+    let cases = [
+        (true, 0, 0, 0),
+        (true, 1000, 1000, 0),
+        (false, 1000, 1001, 0),
+        (true, 1000, 1010, 10),
+        (true, 1010, 1000, 10),
+        (false, 1011, 1000, 10),
+        (true, 0, u64::MAX, u64::MAX),
+    ];
+
+    for (i, (expected, a, b, tolerance)) in cases.iter().enumerate() {
+        let a = Byte::from_u64(*a);
+        let b = Byte::from_u64(*b);
+
+        assert_eq!(*expected, a.within(b, Byte::from_u64(*tolerance)), "{i}");
+        assert_eq!(a.abs_diff(b), b.abs_diff(a), "{i}");
+    }
+
+    assert_eq!(1.0, Byte::from_u64(0).relative_diff(Byte::from_u64(1)));
+    assert_eq!(0.5, Byte::from_u64(512).relative_diff(Byte::from_u64(1024)));
+}
+
+#[test]
+fn cdc_chunk_bounds() {
+    use byte_unit::ChunkBoundsError;
+
+    let cases = [
+        (Ok(12), 2048, 4096, 65536),
+        (Ok(0), 1, 1, 1),
+        (Err(ChunkBoundsError::ZeroMinimum), 0, 4096, 65536),
+        (Err(ChunkBoundsError::NotOrdered), 8192, 4096, 65536),
+        (Err(ChunkBoundsError::NotOrdered), 2048, 4096, 4095),
+        (Err(ChunkBoundsError::AverageNotPowerOfTwo), 2048, 4000, 65536),
+    ];
+
+    for (i, (expected, min, avg, max)) in cases.iter().enumerate() {
+        let bounds = Byte::cdc_chunk_bounds(
+            Byte::from_u64(*min),
+            Byte::from_u64(*avg),
+            Byte::from_u64(*max),
+        );
+
+        assert_eq!(*expected, bounds.map(|b| b.mask_bits()), "{i}");
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn allocate_weighted() {
+    let cases: [(&[u64], u64, &[u32]); 7] = [
+        (&[], 1000, &[]),
+        (&[0, 0], 1000, &[0, 0]),
+        (&[1000], 1000, &[7]),
+        (&[0, 1000], 1000, &[0, 1]),
+        (&[334, 333, 333], 1000, &[1, 1, 1]),
+        (&[1, 1, 0], 2, &[1, 1, 1]),
+        (&[3, 7], 10, &[1, 2]),
+    ];
+
+    for (i, (expected, total, weights)) in cases.iter().enumerate() {
+        let shares = Byte::from_u64(*total).allocate_weighted(weights);
+
+        assert_eq!(*expected, shares.iter().map(|b| b.as_u64()).collect::<Vec<_>>(), "{i}");
+    }
+
+    let shares = Byte::MAX.allocate_weighted(&[u32::MAX, 1, u32::MAX]);
+
+    assert_eq!(Byte::MAX.as_u128(), shares.iter().map(|b| b.as_u128()).sum::<u128>());
+}
+
+#[test]
+fn operators() {
+    let mut byte = Byte::from_u64(1024) + Byte::from_u64(512);
+
+    assert_eq!(1536, byte.as_u64());
+
+    byte -= Byte::from_u64(512);
+    byte *= 3;
+    byte /= 2;
+
+    assert_eq!(1536, byte.as_u64());
+    assert_eq!(Byte::from_u64(4096), 4 * Byte::from_u64(1024));
+    assert_eq!(Byte::from_u64(341), Byte::from_u64(1024) / 3);
+
+    assert_eq!(None, Byte::MAX.checked_add(Byte::from_u64(1)));
+    assert_eq!(None, Byte::MAX.checked_mul(2));
+    assert_eq!(Byte::MAX, Byte::MAX.saturating_add(Byte::MAX));
+    assert_eq!(Byte::MIN, Byte::MIN.saturating_sub(Byte::MAX));
+    assert_eq!(Byte::from_u64(0), Byte::MAX.wrapping_add(Byte::from_u64(1)));
+    assert_eq!(Byte::from_u64(9), Byte::MAX.wrapping_add(Byte::from_u64(10)));
+    assert_eq!(Byte::MAX, Byte::MIN.wrapping_sub(Byte::from_u64(1)));
+    assert_eq!(None, Byte::MAX.add(Byte::from_u64(1)));
+    assert_eq!(None, Byte::MAX.multiply(2));
+}
+
+#[test]
+#[should_panic(expected = "attempt to add with overflow")]
+fn operators_overflow() {
+    let _ = Byte::MAX + Byte::from_u64(1);
+}
+
+#[test]
+fn sum() {
+    let sizes = [Byte::from_u64(1024), Byte::from_u64(512), Byte::from_u64(0)];
+
+    assert_eq!(Byte::from_u64(1536), sizes.iter().sum::<Byte>());
+    assert_eq!(Byte::from_u64(1536), sizes.into_iter().sum::<Byte>());
+    assert_eq!(Byte::MIN, [].iter().sum::<Byte>());
+
+    assert_eq!(Some(Byte::from_u64(1536)), Byte::checked_sum(sizes));
+    assert_eq!(None, Byte::checked_sum([Byte::MAX, Byte::from_u64(1)]));
+    assert_eq!(Byte::from_u64(1536), Byte::saturating_sum(sizes));
+    assert_eq!(Byte::MAX, Byte::saturating_sum([Byte::MAX, Byte::from_u64(1), Byte::MAX]));
+}
+
+#[test]
+#[should_panic(expected = "attempt to add with overflow")]
+fn sum_overflow() {
+    let _ = [Byte::MAX, Byte::from_u64(1)].iter().sum::<Byte>();
+}
+
+#[test]
+fn byte_budget() {
+    use byte_unit::{BudgetError, ByteBudget};
+
+    let mut budget = ByteBudget::new(Byte::from_u64(100));
+
+    assert_eq!(
+        Err(BudgetError::InsufficientAvailable {
+            requested: Byte::from_u64(101),
+            available: Byte::from_u64(100),
+        }),
+        budget.reserve(Byte::from_u64(101))
+    );
+
+    budget.reserve(Byte::from_u64(60)).unwrap();
+    budget.reserve(Byte::from_u64(40)).unwrap();
+
+    assert_eq!(Byte::MIN, budget.available());
+
+    assert_eq!(
+        Err(BudgetError::InsufficientReserved {
+            requested: Byte::from_u64(101),
+            reserved:  Byte::from_u64(100),
+        }),
+        budget.commit(Byte::from_u64(101))
+    );
+
+    budget.commit(Byte::from_u64(70)).unwrap();
+
+    assert_eq!(
+        Err(BudgetError::InsufficientCommitted {
+            requested: Byte::from_u64(71),
+            committed: Byte::from_u64(70),
+        }),
+        budget.release(Byte::from_u64(71))
+    );
+
+    budget.cancel(Byte::from_u64(30)).unwrap();
+    budget.release(Byte::from_u64(20)).unwrap();
+
+    assert_eq!(Byte::from_u64(0), budget.reserved());
+    assert_eq!(Byte::from_u64(50), budget.committed());
+    assert_eq!(Byte::from_u64(50), budget.available());
+    assert_eq!(Byte::from_u64(100), budget.capacity());
+}
+
+#[cfg(feature = "locale")]
+#[test]
+fn display_locale() {
+    use byte_unit::{FormatOptions, FormatStyle, Locale};
+
+    let cases = [
+        ("1.5 GB", "en-US", 1_500_000_000, UnitType::Decimal, FormatStyle::Standard),
+        ("1,5 kB", "de-DE", 1500, UnitType::Decimal, FormatStyle::Standard),
+        ("1,5 Go", "fr", 1_500_000_000, UnitType::Decimal, FormatStyle::Standard),
+        ("1,5Gio", "fr-CA", 1_610_612_736, UnitType::Binary, FormatStyle::Compact),
+        ("1,5 ГБ", "ru_RU", 1_500_000_000, UnitType::Decimal, FormatStyle::Standard),
+        ("10 Б", "ru", 10, UnitType::Decimal, FormatStyle::Standard),
+        ("1.5 吉字节", "zh-Hans-CN", 1_500_000_000, UnitType::Decimal, FormatStyle::Standard),
+        ("1,5 gigabytes", "fr", 1_500_000_000, UnitType::Decimal, FormatStyle::Verbose),
+    ];
+
+    for (i, (expected, tag, n, unit_type, style)) in cases.iter().enumerate() {
+        let locale = Locale::try_from_bcp47(tag).unwrap();
+        let options = FormatOptions::new().locale(locale).style(*style);
+
+        assert_eq!(
+            *expected,
+            Byte::from_u64(*n).get_appropriate_unit(*unit_type).display_with(options).to_string(),
+            "{i}"
+        );
+    }
+
+    assert_eq!(
+        "3 Mo",
+        Byte::from_u64(3_000_000).display_with(FormatOptions::new().locale(Locale::Fr)).to_string()
+    );
+
+    let adjusted_byte = Byte::from_u64(1_234_567_890).get_adjusted_unit(Unit::KB);
+
+    let cases = [
+        ("1234567,89 kB", FormatOptions::new().locale(Locale::De).thousands_separator(None)),
+        ("1.234.567,89 kB", FormatOptions::new().locale(Locale::De)),
+        ("1\u{202f}234\u{202f}567,89 ko", FormatOptions::new().locale(Locale::Fr)),
+        (
+            "1\u{a0}234\u{a0}567,89\u{a0}кБ",
+            FormatOptions::new().locale(Locale::Ru).unit_separator('\u{a0}'),
+        ),
+        (
+            "1_234_567,89 KB",
+            FormatOptions::new().thousands_separator(Some('_')).decimal_separator(','),
+        ),
+        (
+            "1,234,567.890 KB",
+            FormatOptions::new().locale(Locale::En).precision(3).keep_trailing_zeros(true),
+        ),
+    ];
+
+    for (i, (expected, options)) in cases.iter().enumerate() {
+        assert_eq!(*expected, adjusted_byte.display_with(*options).to_string(), "{i}");
+    }
+
+    assert_eq!(
+        "1.023 o",
+        Byte::from_u64(1023)
+            .get_adjusted_unit(Unit::B)
+            .display_with(
+                FormatOptions::new()
+                    .locale(Locale::De)
+                    .locale(Locale::Fr)
+                    .thousands_separator(Some('.'))
+            )
+            .to_string()
+    );
+
+    for tag in ["", "ja", "fr-", "fr--CA", "fr-toolongsubtag", "fr-C@"] {
+        assert!(Locale::try_from_bcp47(tag).is_err(), "{tag}");
+    }
+}
+
+#[cfg(feature = "locale")]
+#[test]
+fn parse_locale() {
+    use byte_unit::{ByteParser, FormatOptions, Locale};
+
+    let cases = [
+        (Some(1_500_000_000), Locale::Fr, false, "1,5 Go"),
+        (Some(1_500_000_000), Locale::Fr, false, "1,5\u{202f}Go"),
+        (Some(1_500_000_000), Locale::Fr, false, "1,5GB"),
+        (Some(1_610_612_736), Locale::Fr, false, "1,5 Gio"),
+        (Some(10), Locale::Fr, false, "10 o"),
+        (Some(10), Locale::Fr, false, "10"),
+        (None, Locale::Fr, false, "1.5 Go"),
+        (None, Locale::Fr, false, "1,5,0 Go"),
+        (None, Locale::Fr, false, "1, Go"),
+        (None, Locale::Fr, false, "-1 Go"),
+        (Some(1500), Locale::De, false, "1,5 kB"),
+        (Some(1000), Locale::De, false, "8 kbit"),
+        (Some(2_500_000), Locale::Ru, false, "2,5 МБ"),
+        (None, Locale::Ru, false, "2,5 Мб"),
+        (Some(2_500_000), Locale::Ru, true, "2,5 Мб"),
+        (Some(1_500_000_000), Locale::Zh, false, "1.5吉字节"),
+        (Some(1500), Locale::Zh, false, "1.5 KB"),
+        (Some(1500), Locale::En, false, "1.5 KB"),
+    ];
+
+    for (i, (expected, locale, ignore_case, s)) in cases.iter().enumerate() {
+        let parser = ByteParser::new().with_locale(*locale).ignore_case(*ignore_case);
+
+        assert_eq!(expected.map(Byte::from_u64), parser.parse(s).ok(), "{i}");
+    }
+
+    for locale in [Locale::En, Locale::De, Locale::Fr, Locale::Ru, Locale::Zh] {
+        let parser = ByteParser::new().with_locale(locale);
+
+        for n in [0, 1, 1500, 1_610_612_736, 2_684_354_560] {
+            for unit_type in [UnitType::Binary, UnitType::Decimal] {
+                let s = Byte::from_u64(n)
+                    .get_appropriate_unit(unit_type)
+                    .display_with(FormatOptions::new().locale(locale))
+                    .to_string();
+
+                assert_eq!(Ok(Byte::from_u64(n)), parser.parse(&s).map_err(|_| s), "{locale}");
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn try_from_str() {
+    use byte_unit::AdjustedByte;
+
+    fn parse<T: for<'a> TryFrom<&'a str> + TryFrom<String>>(s: &str) -> (Option<T>, Option<T>) {
+        (T::try_from(s).ok(), T::try_from(s.to_string()).ok())
+    }
+
+    let expected = Some(Byte::from_u64(1536));
+
+    assert_eq!((expected, expected), parse::<Byte>("1.5 KiB"));
+    assert_eq!((None, None), parse::<Byte>("1.5 KiX"));
+
+    let expected = Some(Byte::from_u64(1536).get_appropriate_unit(UnitType::Both));
+
+    assert_eq!((expected, expected), parse::<AdjustedByte>("1.5 KiB"));
+    assert_eq!((Some(Unit::KiB), Some(Unit::KiB)), parse::<Unit>("KiB"));
+    assert_eq!((None, None), parse::<Unit>("KiX"));
+}
+
+#[test]
+fn signed() {
+    use byte_unit::SignedByte;
+
+    let cases = [
+        (Some(-350_000_000), "-350 MB", "-350000000", "-350 MB"),
+        (Some(-350_000_000), "−350MB", "-350000000", "-350 MB"),
+        (Some(1536), "+1.5 KiB", "+1536", "+1.5 KiB"),
+        (Some(1536), "1.5 KiB", "+1536", "+1.5 KiB"),
+        (Some(0), "-0", "0", "0 B"),
+        (None, "--1", "", ""),
+        (None, "+-1", "", ""),
+        (None, "- ", "", ""),
+    ];
+
+    for (expected, input, display, alternate) in cases {
+        let result = SignedByte::parse_str(input, false).ok();
+
+        assert_eq!(expected, result.map(SignedByte::as_i128), "{input}");
+
+        if let Some(delta) = result {
+            assert_eq!(display, delta.to_string(), "{input}");
+            assert_eq!(alternate, format!("{delta:#}"), "{input}");
+        }
+    }
+
+    let delta = SignedByte::from_i64(-42);
+
+    assert_eq!("-0000042", format!("{delta:08}"));
+    assert_eq!("   -42", format!("{delta:>6}"));
+    assert_eq!("-42  B", format!("{delta:#6}"));
+    assert_eq!(Byte::from_u64(42), delta.abs());
+    assert_eq!(-1, delta.signum());
+    assert_eq!(1, (-delta).signum());
+    assert_eq!(0, SignedByte::ZERO.signum());
+    assert!(Byte::try_from(delta).is_err());
+    assert_eq!(Ok(Byte::from_u64(42)), Byte::try_from(-delta));
+
+    assert_eq!(SignedByte::MAX, SignedByte::from(Byte::MAX));
+    assert_eq!(SignedByte::MIN, Byte::MIN.signed_sub(Byte::MAX));
+    assert_eq!(Byte::MAX, SignedByte::MIN.abs());
+    assert_eq!(None, SignedByte::from_i128(SignedByte::MAX.as_i128() + 1));
+}
+
+#[test]
+fn repr() {
+    use std::{borrow::Borrow, collections::HashMap};
+
+    use byte_unit::ByteRepr;
+
+    fn double<T: AsRef<ByteRepr>>(value: T) -> ByteRepr {
+        value.as_ref() * 2
+    }
+
+    let byte = Byte::from_u64(1536);
+
+    assert_eq!(1536, byte.get());
+    assert_eq!(3072, double(byte));
+    assert_eq!(&1536, Borrow::<ByteRepr>::borrow(&byte));
+
+    let mut map = HashMap::new();
+
+    map.insert(byte, "1.5 KiB");
+
+    assert_eq!(Some(&"1.5 KiB"), map.get(&1536));
+}
+
+#[test]
+fn parse_str_with_options() {
+    use byte_unit::ParseOptions;
+
+    let options = ParseOptions::new().underscores(true).thousands_separator(Some(','));
+
+    let cases = [
+        (Ok(1_500_000), "1_500_000", options),
+        (Ok(1_500_000_000), "1,500,000 KB", options),
+        (Ok(1_500_000_000), "1,500,000KB", options),
+        (Ok(1_500_500), "1,500.5 KB", options),
+        (Ok(1_000), "1_0_0_0", options),
+        (Ok(500), "0.000_5 MB", options),
+        (Ok(1_500_000), "1 500 000", ParseOptions::new().thousands_separator(Some(' '))),
+        (Ok(1_500_000_000), "1 500 000 KB", ParseOptions::new().thousands_separator(Some(' '))),
+        (Ok(1_500), "1.5 kb", options.ignore_case(true)),
+        (Ok(1_500_000_000), "1.5e9", options),
+        (Ok(2_048_000), "2E3 KiB", options),
+        (Ok(1_500_000_000), "1,500e3 KB", options),
+        (Ok(1_500), "1.5\u{a0}KB", options),
+        (Ok(1_000_000_000_000_000_000), "1EB", options),
+        (Err(()), "1,5 KB", options),
+        (Err(()), "1,50e3 KB", options),
+        (Err(()), "1e3,000", options),
+        (Err(()), "1,5000 KB", options),
+        (Err(()), "1000,000", options),
+        (Err(()), "1,000,00", options),
+        (Err(()), "1.500,000", options),
+        (Err(()), "1__000", options),
+        (Err(()), "_1000", options),
+        (Err(()), "1000_", options),
+        (Err(()), "1_000", ParseOptions::new()),
+        (Err(()), "1,000", ParseOptions::new()),
+        (Err(()), "-1,000", options),
+        (Err(()), "", options),
+    ];
+
+    for (expected, input, options) in cases {
+        assert_eq!(
+            expected,
+            Byte::parse_str_with_options(input, &options).map(|byte| byte.as_u64()).map_err(|_| ()),
+            "{input}"
+        );
+    }
+
+    assert!(matches!(
+        Byte::parse_str_with_options("-1", &options),
+        Err(byte_unit::ParseError::Value(byte_unit::ValueParseError::NotNumber('-')))
+    ));
+}
+
+#[test]
+fn parse_mode() {
+    use byte_unit::{ParseMode, ParseOptions};
+
+    let iec = ParseOptions::new();
+    let jedec = ParseOptions::new().mode(ParseMode::Jedec);
+    let flexible = ParseOptions::new().mode(ParseMode::Flexible);
+
+    let cases = [
+        ("1 KB", 1000, 1024, 1000),
+        ("1K", 1000, 1024, 1024),
+        ("1 k", 1000, 1024, 1024),
+        ("1 KiB", 1024, 1024, 1024),
+        ("1Ki", 1024, 1024, 1024),
+        ("1.5 MB", 1_500_000, 1_572_864, 1_500_000),
+        ("2G", 2_000_000_000, 2 << 30, 2 << 30),
+        ("1 megabyte", 1_000_000, 1 << 20, 1_000_000),
+        ("8 Kb", 1000, 1024, 1000),
+        ("100", 100, 100, 100),
+        ("100 B", 100, 100, 100),
+    ];
+
+    for (input, expected_iec, expected_jedec, expected_flexible) in cases {
+        for (expected, options) in
+            [(expected_iec, iec), (expected_jedec, jedec), (expected_flexible, flexible)]
+        {
+            assert_eq!(
+                expected,
+                Byte::parse_str_with_options(input, &options).unwrap().as_u64(),
+                "{input} {options:?}"
+            );
+        }
+    }
+
+    assert_eq!(ParseMode::Iec, ParseMode::default());
+    assert!(Byte::parse_str_with_options("1 KX", &jedec).is_err());
+}
+
+#[cfg(feature = "num-traits")]
+#[test]
+fn num_traits() {
+    use num_traits::{
+        Bounded, CheckedAdd, CheckedMul, FromPrimitive, One, SaturatingAdd, SaturatingSub,
+        ToPrimitive, Zero,
+    };
+
+    fn moving_average<T: Zero + CheckedAdd + ToPrimitive + FromPrimitive>(values: &[T]) -> T {
+        let sum = values.iter().try_fold(T::zero(), |sum, v| sum.checked_add(v)).unwrap();
+
+        T::from_u128(sum.to_u128().unwrap() / values.len() as u128).unwrap()
+    }
+
+    let values = [Byte::from_u64(100), Byte::from_u64(200), Byte::from_u64(600)];
+
+    assert_eq!(Byte::from_u64(300), moving_average(&values));
+
+    assert!(Byte::zero().is_zero());
+    assert_eq!(Byte::from_u64(1), Byte::one());
+    assert_eq!(Byte::MAX, Byte::max_value());
+    assert_eq!(Byte::MIN, Byte::min_value());
+    assert_eq!(None, CheckedAdd::checked_add(&Byte::MAX, &Byte::one()));
+    assert_eq!(
+        Some(Byte::from_u64(6)),
+        CheckedMul::checked_mul(&Byte::from_u64(2), &Byte::from_u64(3))
+    );
+    assert_eq!(None, CheckedMul::checked_mul(&Byte::MAX, &Byte::from_u64(2)));
+    assert_eq!(Byte::from_u64(6_000_000), Byte::from_u64(2000) * Byte::from_u64(3000));
+    assert_eq!(Byte::MAX, SaturatingAdd::saturating_add(&Byte::MAX, &Byte::one()));
+    assert_eq!(Byte::MIN, SaturatingSub::saturating_sub(&Byte::MIN, &Byte::one()));
+    assert_eq!(None, <Byte as FromPrimitive>::from_i64(-1));
+    assert_eq!(Some(1536), Byte::from_u64(1536).to_i64());
+}
+
+#[test]
+fn parse_str_const() {
+    const CASES: [(Byte, u64); 6] = [
+        (Byte::parse_str_const("0"), 0),
+        (Byte::parse_str_const("1024"), 1024),
+        (Byte::parse_str_const("250 MiB"), 262_144_000),
+        (Byte::parse_str_const(" 15KB "), 15_000),
+        (Byte::parse_str_const("16 Mb"), 2_000_000),
+        (Byte::parse_str_const("2   GB"), 2_000_000_000),
+    ];
+
+    for (byte, expected) in CASES {
+        assert_eq!(expected, byte.as_u64());
+    }
+
+    for s in ["", "MB", "1.5 MB", "1 mb", "1 megabyte", "-1", "1 XB", "99999999999 EB"] {
+        assert!(std::panic::catch_unwind(|| Byte::parse_str_const(s)).is_err(), "{s}");
+    }
+}
+
+#[test]
+fn lerp() {
+    let a = Byte::from_u64(1000);
+    let b = Byte::from_u64(2000);
+
+    let cases = [
+        (1000, 0.0),
+        (1001, 0.0005),
+        (1333, 0.333),
+        (1500, 0.5),
+        (2000, 1.0),
+        (1000, -1.0),
+        (2000, 2.0),
+        (1000, f64::NAN),
+        (2000, f64::INFINITY),
+        (1000, f64::NEG_INFINITY),
+    ];
+
+    for (expected, t) in cases {
+        assert_eq!(Byte::from_u64(expected), a.lerp(b, t), "{t}");
+        assert_eq!(Byte::from_u64(3000 - expected), b.lerp(a, t), "{t}");
+        assert_eq!((0.0..=1.0).contains(&t), a.checked_lerp(b, t).is_some(), "{t}");
+    }
+
+    assert_eq!(Byte::MAX, Byte::MIN.lerp(Byte::MAX, 1.0));
+    assert_eq!(Byte::MIN, Byte::MAX.lerp(Byte::MIN, 1.0));
+    assert!(Byte::MIN.lerp(Byte::MAX, 0.999_999) <= Byte::MAX);
+
+    let mut v = Byte::from_u64(0);
+
+    for _ in 0..32 {
+        v = v.smooth_towards(b, 0.3);
+    }
+
+    assert_eq!(b, v);
+
+    for _ in 0..32 {
+        v = v.smooth_towards(a, 0.3);
+    }
+
+    assert_eq!(a, v);
+    assert_eq!(a, a.smooth_towards(b, 0.0));
+    assert_eq!(a, a.smooth_towards(b, f64::NAN));
+    assert_eq!(Byte::from_u64(1001), a.smooth_towards(b, 0.000_1));
+}
+
+#[test]
+fn rate() {
+    use std::time::Duration;
+
+    use byte_unit::{ByteRate, RateParseError, RateUnitType, TimeUnit};
+
+    let cases = [
+        (Some((12_500_000, TimeUnit::Second)), "12.5 MB/s", false),
+        (Some((12_500_000, TimeUnit::Second)), "100 Mbps", false),
+        (Some((100_000_000, TimeUnit::Second)), "100 Mbps", true),
+        (Some((100_000_000, TimeUnit::Second)), "100 MBPS", true),
+        (Some((1024, TimeUnit::Minute)), "1 KiB / min", false),
+        (Some((3, TimeUnit::Hour)), "3/hours", false),
+        (Some((5, TimeUnit::Day)), "5 B/Day", false),
+        (None, "100 MBPS", false),
+        (None, "12.5 MB", false),
+        (None, "12.5 MB/week", false),
+        (None, "-12.5 MB/s", false),
+    ];
+
+    for (expected, input, ignore_case) in cases {
+        let result = ByteRate::parse_str(input, ignore_case).ok();
+
+        assert_eq!(
+            expected,
+            result.map(|rate| (rate.get_size().as_u64(), rate.get_time_unit())),
+            "{input}"
+        );
+    }
+
+    assert!(matches!(ByteRate::parse_str("12.5 MB", false), Err(RateParseError::NoTimeUnit)));
+    assert!(matches!(
+        ByteRate::parse_str("12.5 MB/wk", false),
+        Err(RateParseError::UnknownTimeUnit)
+    ));
+    assert!(matches!(ByteRate::parse_str("12.5 QB/s", false), Err(RateParseError::Size(_))));
+
+    let rate: ByteRate = "1.5 KiB/min".parse().unwrap();
+
+    assert_eq!("1536/min", rate.to_string());
+    assert_eq!("1.5 KiB/min", format!("{rate:#}"));
+    assert_eq!("1.5KiB/min", format!("{rate:-#}"));
+    assert_eq!("1.54 KB/min", format!("{:.2}", rate.get_appropriate_unit(RateUnitType::Decimal)));
+    assert_eq!("1.5 KiB/min", rate.get_appropriate_unit(RateUnitType::Binary).to_string());
+    assert_eq!(
+        "12.288 Kb/min",
+        rate.get_appropriate_unit(RateUnitType::DecimalBitsOnly).to_string()
+    );
+    assert_eq!(
+        "800 b/s",
+        ByteRate::per_second(Byte::from_u64(100))
+            .get_appropriate_unit(RateUnitType::DecimalBitsOnly)
+            .to_string()
+    );
+    assert_eq!(
+        "1.048576 Mb/s",
+        ByteRate::per_second(Byte::from_u64(131_072))
+            .get_appropriate_unit(RateUnitType::DecimalBitsOnly)
+            .to_string()
+    );
+
+    assert_eq!(
+        ByteRate::new(Byte::from_u64(60), TimeUnit::Minute),
+        ByteRate::per_second(Byte::from_u64(1))
+    );
+    assert!(
+        ByteRate::new(Byte::from_u64(3601), TimeUnit::Hour)
+            > ByteRate::per_second(Byte::from_u64(1))
+    );
+    assert_eq!(0.025, ByteRate::new(Byte::from_u64(90), TimeUnit::Hour).as_f64_per_second());
+
+    assert_eq!(
+        ByteRate::per_second(Byte::from_u64(4)),
+        Byte::from_u64(10) / Duration::from_secs(3)
+    );
+    assert_eq!(
+        ByteRate::per_second(Byte::from_u64(2000)),
+        Byte::from_u64(2) / Duration::from_millis(1)
+    );
+    assert_eq!(None, ByteRate::from_duration(Byte::from_u64(1), Duration::ZERO));
+    assert_eq!(None, ByteRate::from_duration(Byte::MAX, Duration::from_nanos(1)));
+
+    let rate = ByteRate::new(Byte::from_u64(7200), TimeUnit::Hour);
+
+    assert_eq!(Byte::from_u64(20), rate * Duration::from_secs(10));
+    assert_eq!(Byte::from_u64(20), Duration::from_secs(10) * rate);
+    assert_eq!(Byte::from_u64(1), rate * Duration::from_nanos(1));
+    assert_eq!(Byte::from_u64(0), rate * Duration::ZERO);
+    assert_eq!(None, ByteRate::per_second(Byte::MAX).checked_mul_duration(Duration::from_secs(2)));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn rate_over() {
+    use std::time::Duration;
+
+    use byte_unit::ByteRate;
+
+    let cases = [
+        (12_000_000, 30_000_000, Duration::from_millis(2500)),
+        (4, 10, Duration::from_secs(3)),
+        (0, 0, Duration::from_secs(3)),
+        (0, 0, Duration::ZERO),
+    ];
+
+    for (expected, size, duration) in cases {
+        assert_eq!(
+            ByteRate::per_second(Byte::from_u64(expected)),
+            Byte::from_u64(size).rate_over(duration),
+            "{size} {duration:?}"
+        );
+    }
+
+    assert_eq!(ByteRate::per_second(Byte::MAX), Byte::from_u64(1).rate_over(Duration::ZERO));
+    assert_eq!(ByteRate::per_second(Byte::MAX), Byte::MAX.rate_over(Duration::from_nanos(1)));
+}
+
+#[cfg(all(feature = "std", feature = "bit"))]
+#[test]
+fn time_to_transfer() {
+    use std::time::Duration;
+
+    use byte_unit::{Bit, BitRate, TimeUnit};
+
+    let cases = [
+        (Duration::from_secs(120), "1.5 GB", "100 Mbps"),
+        (Duration::from_secs(1), "1 B", "8 b/s"),
+        (Duration::from_nanos(125_000_000), "1 B", "64 b/s"),
+        (Duration::from_nanos(333_333_334), "1 B", "24 b/s"),
+        (Duration::from_secs(3600), "450 B", "3600 b/h"),
+        (Duration::from_secs(86400 * 8), "1 KB", "1 Kb/d"),
+        (Duration::ZERO, "0 B", "1 b/s"),
+        (Duration::MAX, "1 B", "0 b/s"),
+    ];
+
+    for (expected, size, rate) in cases {
+        let size = Byte::parse_str(size, false).unwrap();
+        let rate = BitRate::parse_str(rate).unwrap();
+
+        assert_eq!(expected, size.time_to_transfer(rate), "{size} {rate}");
+    }
+
+    assert_eq!(
+        Duration::MAX,
+        Byte::MAX.time_to_transfer(BitRate::new(Bit::from_u64(1), TimeUnit::Day))
+    );
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by a zero duration or overflow")]
+fn rate_divide_by_zero() {
+    let _ = Byte::from_u64(1) / std::time::Duration::ZERO;
+}
+
+#[cfg(feature = "criterion")]
+#[test]
+fn criterion_throughput() {
+    use std::time::Duration;
+
+    use byte_unit::bench;
+    use criterion::Throughput;
+
+    assert!(matches!(Byte::from_u64(1500).into(), Throughput::Bytes(1500)));
+    assert!(matches!(Byte::MAX.into(), Throughput::Bytes(u64::MAX)));
+    assert!(matches!(
+        bench::Throughput::new(Byte::from_u64(4096), Duration::from_millis(3)).into(),
+        Throughput::Bytes(4096)
+    ));
+}
+
+#[test]
+fn from_u128_unchecked() {
+    const SIZE: Byte = Byte::from_u128_unchecked(15_000_000);
+
+    assert_eq!(Byte::from_u64(15_000_000), SIZE);
+    assert_eq!(Byte::MAX, Byte::from_u128_unchecked(Byte::MAX.as_u128()));
+
+    assert_eq!(Byte::from_u64(0), Byte::new_clamped(0));
+    assert_eq!(Byte::MAX, Byte::new_clamped(Byte::MAX.as_u128()));
+    assert_eq!(Byte::MAX, Byte::new_clamped(Byte::MAX.as_u128() + 1));
+    assert_eq!(Byte::MAX, Byte::new_clamped(u128::MAX));
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "the size is too large")]
+fn from_u128_unchecked_too_large() {
+    let _ = Byte::from_u128_unchecked(u128::MAX);
+}
+
+#[test]
+fn display_builder() {
+    use byte_unit::FormatStyle;
+
+    let byte = Byte::from_u64(1_500_000);
+
+    let cases = [
+        ("1.5 MB", byte.display()),
+        ("1.5MB", byte.display().no_space()),
+        ("1.5 megabytes", byte.display().style(FormatStyle::Verbose)),
+        ("1500 KB", byte.display().unit(Unit::KB)),
+        ("1.430511474609375 MiB", byte.display().unit(Unit::MiB)),
+        ("1.43MiB", byte.display().unit(Unit::MiB).precision(2).no_space()),
+        ("1.4 MiB", byte.display().unit(Unit::MiB).precision(1)),
+        ("1500.00 KB", byte.display().unit(Unit::KB).precision(2).keep_trailing_zeros(true)),
+        ("0.0015 GB", byte.display().unit(Unit::GB)),
+        ("0 GB", byte.display().unit(Unit::GB).precision(2)),
+        ("12000000 b", byte.display().unit(Unit::Bit)),
+        ("1500000 B", byte.display().unit(Unit::B).precision(2)),
+        ("1464.844 KiB", Byte::from_u64(1_500_000).display().precision(3).unit(Unit::KiB)),
+    ];
+
+    for (expected, display) in cases {
+        assert_eq!(expected, display.to_string());
+    }
+}
+
+#[test]
+fn hugepages() {
+    use byte_unit::RoundingMode;
+
+    let cases = [
+        (Some(0), 0, Byte::HUGE_PAGE_2MIB),
+        (Some(1), 1, Byte::HUGE_PAGE_2MIB),
+        (Some(1), 2 << 20, Byte::HUGE_PAGE_2MIB),
+        (Some(2), (2 << 20) + 1, Byte::HUGE_PAGE_2MIB),
+        (Some(4352), 17 << 29, Byte::HUGE_PAGE_2MIB),
+        (Some(9), 17 << 29, Byte::HUGE_PAGE_1GIB),
+        (None, 1, Byte::MIN),
+    ];
+
+    for (expected, size, page) in cases {
+        assert_eq!(expected, Byte::from_u64(size).count_hugepages(page), "{size} {page}");
+    }
+
+    let size = Byte::from_u64(3 << 20);
+
+    let cases = [
+        (Some(4 << 20), RoundingMode::Up),
+        (Some(2 << 20), RoundingMode::Down),
+        (Some(4 << 20), RoundingMode::Nearest),
+    ];
+
+    for (expected, rounding_mode) in cases {
+        assert_eq!(
+            expected.map(Byte::from_u64),
+            size.round_to_hugepages(Byte::HUGE_PAGE_2MIB, rounding_mode),
+            "{rounding_mode:?}"
+        );
+    }
+
+    assert_eq!(
+        Some(Byte::from_u64(0)),
+        Byte::from_u64(1).round_to_hugepages(Byte::HUGE_PAGE_1GIB, RoundingMode::Nearest)
+    );
+}
+
+#[test]
+fn block_padding() {
+    let cases = [(0, 0), (1, 1), (512, 1), (513, 2), (10240, 20), (10241, 21)];
+
+    for (size, blocks) in cases {
+        let size = Byte::from_u64(size);
+
+        assert_eq!(blocks, size.tar_blocks(), "{size}");
+        assert_eq!(Some(Byte::from_u64(blocks as u64 * 512)), size.tar_padded_size(), "{size}");
+        assert_eq!(size.tar_padded_size(), size.padded_to(Byte::TAR_BLOCK), "{size}");
+    }
+
+    assert_eq!(Some(Byte::from_u64(12)), Byte::from_u64(10).padded_to(Byte::from_u64(3)));
+    assert_eq!(None, Byte::MAX.tar_padded_size());
+    assert_eq!(Byte::MAX.as_u128().div_ceil(512), Byte::MAX.tar_blocks());
+}
+
+#[test]
+fn parse_str_in_range() {
+    use std::ops::Bound;
+
+    use byte_unit::RangeParseError;
+
+    let min = Byte::KIBIBYTE;
+    let max = Byte::from_u64(10 << 30);
+
+    let cases = [
+        (Ok(1024), "1 KiB"),
+        (Ok(10 << 30), "10 GiB"),
+        (Err("512 B is less than the minimum of 1 KiB"), "512 B"),
+        (Err("10.5 GiB is greater than the maximum of 10 GiB"), "10.5 GiB"),
+    ];
+
+    for (expected, input) in cases {
+        let result = Byte::parse_str_in_range(input, false, min..=max);
+
+        assert_eq!(
+            expected.map_err(String::from),
+            result.map(|byte| byte.as_u64()).map_err(|error| error.to_string()),
+            "{input}"
+        );
+    }
+
+    assert!(matches!(
+        Byte::parse_str_in_range("1 KiB", false, (Bound::Excluded(min), Bound::Unbounded)),
+        Err(RangeParseError::TooSmall {
+            inclusive: false,
+            ..
+        })
+    ));
+    assert!(matches!(
+        Byte::parse_str_in_range("10 GiB", false, min..max),
+        Err(RangeParseError::TooLarge {
+            inclusive: false,
+            ..
+        })
+    ));
+    assert!(matches!(
+        Byte::parse_str_in_range("-1 KiB", false, ..),
+        Err(RangeParseError::Parse(_))
+    ));
+    assert_eq!(Byte::from_u64(1), Byte::parse_str_in_range("1", false, ..).unwrap());
+
+    let error = Byte::parse_str_in_range("10 GiB", false, min..max).unwrap_err();
+
+    assert_eq!("10 GiB is not less than 10 GiB", error.to_string());
+
+    #[cfg(feature = "std")]
+    {
+        use std::error::Error;
+
+        assert!(error.source().is_none());
+
+        let error = Byte::parse_str_in_range("1 XB", false, ..).unwrap_err();
+
+        assert_eq!(error.to_string(), error.source().unwrap().to_string());
+    }
+}
+
+#[test]
+fn describe_in() {
+    let cases = [
+        ("12 blocks of 4 KiB (+3 KiB)", 12 * 4096 + 3072, 4096),
+        ("12 blocks of 4 KiB", 12 * 4096, 4096),
+        ("1 block of 512 B (+1 B)", 513, 512),
+        ("0 blocks of 1 MiB (+1 KB)", 1000, 1 << 20),
+        ("0 blocks of 4 KB", 0, 4000),
+        ("3 blocks of 1.5 KB (+500 B)", 5000, 1500),
+    ];
+
+    for (expected, size, block) in cases {
+        assert_eq!(expected, Byte::from_u64(size).describe_in(Byte::from_u64(block)));
+    }
+}
+
+#[test]
+#[should_panic(expected = "the block size must not be zero")]
+fn describe_in_zero() {
+    let _ = Byte::from_u64(1).describe_in(Byte::MIN);
+}
+
+#[cfg(feature = "clap")]
+#[test]
+fn clap_value_parser() {
+    use byte_unit::{clap::byte_in_range, Unit};
+    use clap::{error::ErrorKind, value_parser, Arg, Command};
+
+    let command = Command::new("app")
+        .arg(Arg::new("size").long("size").value_parser(value_parser!(Byte)))
+        .arg(Arg::new("buffer").long("buffer").value_parser(byte_in_range("1KiB".."1GiB")))
+        .arg(Arg::new("unit").long("unit").value_parser(value_parser!(Unit)))
+        .arg(Arg::new("floor").long("floor").value_parser(byte_in_range("1 Kb"..)));
+
+    let matches = command
+        .clone()
+        .try_get_matches_from(["app", "--size", "1.5 MB", "--buffer", "1 KiB", "--unit", "KiB"])
+        .unwrap();
+
+    // the bounds are parsed like the values, so "Kb" is kilobits
+    let matches_floor = command.clone().try_get_matches_from(["app", "--floor", "125 B"]).unwrap();
+
+    assert_eq!(Some(&Byte::from_u64(125)), matches_floor.get_one::<Byte>("floor"));
+
+    assert_eq!(Some(&Byte::from_u64(1_500_000)), matches.get_one::<Byte>("size"));
+    assert_eq!(Some(&Byte::from_u64(1024)), matches.get_one::<Byte>("buffer"));
+    assert_eq!(Some(&Unit::KiB), matches.get_one::<Unit>("unit"));
+
+    let cases = [
+        (["app", "--buffer", "1 GiB"], "1 GiB is not less than 1 GiB"),
+        (["app", "--buffer", "1000 B"], "1 KB is less than the minimum of 1 KiB"),
+        (["app", "--size", "1.5 XB"], "the character 'X' is incorrect"),
+        (["app", "--unit", "kg"], "the character 'g' is incorrect"),
+    ];
+
+    for (args, reason) in cases {
+        let error = command.clone().try_get_matches_from(args).unwrap_err();
+
+        assert_eq!(ErrorKind::ValueValidation, error.kind());
+        assert!(error.to_string().contains(reason), "{error}");
+    }
+}
+
+#[cfg(feature = "clap")]
+#[test]
+#[should_panic(expected = "invalid bound \"1 XB\"")]
+fn clap_invalid_bound() {
+    let _ = byte_unit::clap::byte_in_range("1 XB"..);
+}
+
+#[test]
+fn k8s_quantity() {
+    use byte_unit::K8sQuantityFormat::{self, *};
+
+    let cases: [(&str, u64, K8sQuantityFormat, &str); 14] = [
+        ("512Mi", 512 << 20, BinarySI, "512Mi"),
+        ("1024Mi", 1 << 30, BinarySI, "1Gi"),
+        ("1000Ki", 1_024_000, BinarySI, "1000Ki"),
+        ("0.5Gi", 512 << 20, BinarySI, "512Mi"),
+        ("1Ki", 1024, BinarySI, "1Ki"),
+        ("100", 100, DecimalSI, "100"),
+        ("1500000", 1_500_000, DecimalSI, "1500k"),
+        ("+2G", 2_000_000_000, DecimalSI, "2G"),
+        ("1.5k", 1500, DecimalSI, "1500"),
+        (".5", 1, DecimalSI, "1"),
+        ("0", 0, DecimalSI, "0"),
+        ("1e6", 1_000_000, DecimalExponent, "1e6"),
+        ("15E5", 1_500_000, DecimalExponent, "1500e3"),
+        ("1500e-3", 2, DecimalExponent, "2"),
+    ];
+
+    for (s, size, format, canonical) in cases {
+        let (byte, parsed_format) = Byte::parse_k8s_quantity_with_format(s).unwrap();
+
+        assert_eq!(size, byte.as_u64(), "{s}");
+        assert_eq!(format, parsed_format, "{s}");
+        assert_eq!(canonical, byte.to_k8s_quantity(format), "{s}");
+        assert_eq!(byte, Byte::parse_k8s_quantity(canonical).unwrap(), "{s}");
+    }
+
+    assert_eq!("1k", Byte::from_u64(1000).to_k8s_quantity(BinarySI));
+    assert_eq!("1E", Byte::from_u64(1_000_000_000_000_000_000).to_k8s_quantity(DecimalSI));
+    assert_eq!(Byte::from_u64(1 << 60), Byte::parse_k8s_quantity("1Ei").unwrap());
+
+    let cases = [
+        ("500m", "the character 'm' is incorrect"),
+        ("512MiB", "the character 'B' is incorrect (no character is expected)"),
+        ("1K", "the character 'K' is incorrect ('k' is expected)"),
+        ("1 Gi", "the character ' ' is incorrect"),
+        ("-1Gi", "the character '-' is not a number"),
+        ("Gi", "no value can be found"),
+        ("1.2.3", "the character '.' is not a number"),
+        ("1e", "the character 'e' is incorrect"),
+        ("1e3x", "the character 'x' is not a number"),
+        ("1e30", "exceeds the valid range"),
+    ];
+
+    for (s, message) in cases {
+        let error = Byte::parse_k8s_quantity(s).unwrap_err();
+
+        assert!(error.to_string().contains(message), "{s}: {error}");
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_options() {
+    use byte_unit::{FormatOptions, FormatStyle, ParseMode, ParseOptions};
+
+    let options = FormatOptions::new().style(FormatStyle::Compact).precision(2);
+
+    let json = serde_json::to_string(&options).unwrap();
+
+    #[cfg(not(feature = "locale"))]
+    assert_eq!(r#"{"style":"compact","precision":2,"keep_trailing_zeros":false}"#, json);
+
+    assert_eq!(options, serde_json::from_str::<FormatOptions>(&json).unwrap());
+    assert_eq!(options, serde_json::from_str(r#"{"precision":2,"style":"Compact"}"#).unwrap());
+    assert_eq!(FormatOptions::new(), serde_json::from_str("{}").unwrap());
+    assert!(serde_json::from_str::<FormatOptions>(r#"{"style":"tiny"}"#).is_err());
+    assert!(serde_json::from_str::<FormatOptions>(r#"{"width":8}"#).is_err());
+
+    let byte = Byte::from_u64(1_500_000);
+
+    let options: FormatOptions = serde_json::from_str(r#"{"style":"verbose"}"#).unwrap();
+
+    assert_eq!("1.5 megabytes", byte.display_with(options).to_string());
+
+    let options = ParseOptions::new().ignore_case(true).thousands_separator(Some(','));
+
+    let json = serde_json::to_string(&options).unwrap();
+
+    assert_eq!(
+        r#"{"ignore_case":true,"underscores":false,"thousands_separator":",","mode":"iec","composite":false}"#,
+        json
+    );
+    assert_eq!(options, serde_json::from_str::<ParseOptions>(&json).unwrap());
+    assert_eq!(
+        ParseOptions::new(),
+        serde_json::from_str(r#"{"thousands_separator":"."}"#).unwrap()
+    );
+
+    let options: ParseOptions = serde_json::from_str(r#"{"underscores":true}"#).unwrap();
+
+    assert_eq!(
+        Byte::from_u64(1_500_000),
+        Byte::parse_str_with_options("1_500_000", &options).unwrap()
+    );
+
+    let options: ParseOptions = serde_json::from_str(r#"{"mode":"jedec"}"#).unwrap();
+
+    assert_eq!(Byte::from_u64(1024), Byte::parse_str_with_options("1 KB", &options).unwrap());
+    assert!(serde_json::from_str::<ParseOptions>(r#"{"mode":"si"}"#).is_err());
+
+    // formats which are not self-describing, like bincode, read the fields as a sequence
+    let format_options = FormatOptions::new().style(FormatStyle::Verbose).precision(1);
+    let encoded = bincode::serialize(&format_options).unwrap();
+
+    assert_eq!(format_options, bincode::deserialize::<FormatOptions>(&encoded).unwrap());
+
+    let parse_options = ParseOptions::new()
+        .underscores(true)
+        .thousands_separator(Some(' '))
+        .mode(ParseMode::Flexible)
+        .composite(true);
+    let encoded = bincode::serialize(&parse_options).unwrap();
+
+    assert_eq!(parse_options, bincode::deserialize::<ParseOptions>(&encoded).unwrap());
+}
+
+#[cfg(all(feature = "serde", feature = "locale"))]
+#[test]
+fn serde_options_locale() {
+    use byte_unit::{FormatOptions, Locale};
+
+    let options: FormatOptions =
+        serde_json::from_str(r#"{"locale":"fr-FR","unit_separator":"_"}"#).unwrap();
+
+    assert_eq!(FormatOptions::new().locale(Locale::Fr).unit_separator('_'), options);
+    assert_eq!(options, serde_json::from_str(&serde_json::to_string(&options).unwrap()).unwrap());
+    assert!(serde_json::from_str::<FormatOptions>(r#"{"locale":"ja"}"#).is_err());
+}
+
+#[test]
+fn parse_str_scientific_notation() {
+    let cases = [
+        (1_500_000_000, "1.5e9"),
+        (1_500_000_000, "1.5E9 B"),
+        (2000 * 1024, "2E3 KiB"),
+        (2000 * 1024, "2e+3KiB"),
+        (1500, "1.5e-3 MB"),
+        (1, "1e-3"),
+        (1, "1e-40"),
+        (0, "0e99"),
+        (1 << 60, "1EiB"),
+        (1_000_000_000_000_000_000, "1EB"),
+    ];
+
+    for (expected, s) in cases {
+        assert_eq!(expected, Byte::parse_str(s, false).unwrap().as_u64(), "{s}");
+    }
+
+    assert_eq!(1_000_000, Byte::from_value_and_unit_str("1e3", "KB", false).unwrap().as_u64());
+
+    for s in ["1e+", "1 e9", "1.e9", "1e9e9", "1e40"] {
+        assert!(Byte::parse_str(s, false).is_err(), "{s}");
+    }
+}
+
+#[test]
+fn recoverable_unit_with_preference() {
+    use byte_unit::UnitPreference::{self, *};
+
+    let cases: [(u64, UnitPreference, &str, Unit); 8] = [
+        (10240, Largest, "10", Unit::KiB),
+        (10240, Binary, "10", Unit::KiB),
+        (10240, Decimal, "10.24", Unit::KB),
+        (2_048_000, Largest, "2.048", Unit::MB),
+        (2_048_000, Binary, "2000", Unit::KiB),
+        (2_048_000, Decimal, "2.048", Unit::MB),
+        (1_000_001, Binary, "1000.001", Unit::KB),
+        (100, Decimal, "100", Unit::B),
+    ];
+
+    for (size, preference, value, unit) in cases {
+        let (v, u) =
+            Byte::from_u64(size).get_recoverable_unit_with_preference(false, 3, preference);
+
+        assert_eq!((value, unit), (v.normalize().to_string().as_str(), u), "{size} {preference:?}");
+    }
+}
+
+#[test]
+fn inventory() {
+    use byte_unit::Inventory;
+
+    let cases: [(u64, u64, &str); 9] = [
+        (0, 0, "0 objects, 0 B"),
+        (1, 100, "1 object, 100 B"),
+        (999, 1536, "999 objects, 1.5 KiB"),
+        (1000, 1 << 30, "1K objects, 1 GiB"),
+        (1_234_567, 3_738_339_534_438, "1.2M objects, 3.4 TiB"),
+        (999_949, 1, "999.9K objects, 1 B"),
+        (999_950, 1, "1M objects, 1 B"),
+        (2_500_000_000, 1, "2.5B objects, 1 B"),
+        (u64::MAX, 1, "18446.7Q objects, 1 B"),
+    ];
+
+    for (objects, bytes, expected) in cases {
+        assert_eq!(expected, Inventory::new(objects, Byte::from_u64(bytes)).to_string());
+    }
+
+    let inventory = Inventory::new(3, Byte::from_u64(10));
+
+    assert_eq!(Some(Byte::from_u64(3)), inventory.mean_object_size());
+    assert_eq!(None, Inventory::default().mean_object_size());
+    assert_eq!(Some(Inventory::new(4, Byte::from_u64(12))), inventory.add(Byte::from_u64(2)));
+    assert_eq!(None, Inventory::new(u64::MAX, Byte::from_u64(0)).merge(inventory));
+    assert_eq!(None, inventory.add(Byte::MAX));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_inventory() {
+    use byte_unit::Inventory;
+
+    let inventory = Inventory::new(2, Byte::from_u64(4096));
+
+    let json = serde_json::to_string(&inventory).unwrap();
+
+    assert_eq!(r#"{"objects":2,"bytes":"4 KiB"}"#, json);
+    assert_eq!(inventory, serde_json::from_str::<Inventory>(&json).unwrap());
+    assert_eq!(inventory, serde_json::from_str(r#"{"bytes":"4096","objects":2}"#).unwrap());
+    assert!(serde_json::from_str::<Inventory>(r#"{"objects":2}"#).is_err());
+    assert!(serde_json::from_str::<Inventory>(r#"{"objects":2,"bytes":1,"files":2}"#).is_err());
+}
+
+#[test]
+fn appropriate_unit_integer() {
+    let cases: [(u64, UnitType, Option<usize>, bool, &str); 11] = [
+        (0, UnitType::Both, None, false, "0 B"),
+        (1023, UnitType::Binary, Some(2), false, "1023 B"),
+        (1536, UnitType::Binary, None, false, "1.5 KiB"),
+        (1536, UnitType::Binary, Some(3), false, "1.500 KiB"),
+        (1536, UnitType::Binary, Some(3), true, "1.5 KiB"),
+        (10000, UnitType::Binary, Some(0), false, "10 KiB"),
+        (1_999_999, UnitType::Decimal, Some(2), false, "2.00 MB"),
+        (1_999_999, UnitType::Decimal, Some(2), true, "2 MB"),
+        (1_994_999, UnitType::Decimal, Some(2), false, "1.99 MB"),
+        (1_995_000, UnitType::Decimal, Some(2), false, "2.00 MB"),
+        (u64::MAX, UnitType::Binary, Some(20), false, "15.99999999999999999913 EiB"),
+    ];
+
+    for (size, unit_type, precision, alternate, expected) in cases {
+        let adjusted_byte = Byte::from_u64(size).get_appropriate_unit_integer(unit_type);
+
+        let s = match (precision, alternate) {
+            (Some(precision), false) => format!("{adjusted_byte:.precision$}"),
+            (Some(precision), true) => format!("{adjusted_byte:#.precision$}"),
+            (None, _) => format!("{adjusted_byte}"),
+        };
+
+        assert_eq!(expected, s, "{size}");
+        assert_eq!(
+            Byte::from_u64(size).get_appropriate_unit(unit_type).get_unit(),
+            adjusted_byte.get_unit()
+        );
+    }
+
+    let adjusted_byte = Byte::from_u64(u64::MAX).get_adjusted_unit_integer(Unit::Kbit);
+
+    assert_eq!(147_573_952_589_676_412, adjusted_byte.get_integer_part());
+    assert_eq!("147573952589676412.92 Kb", adjusted_byte.to_string());
+}
+
+#[test]
+fn bounded_byte() {
+    use byte_unit::BoundedByte;
+
+    type ChunkSize = BoundedByte<{ u32::MAX as u128 }>;
+
+    assert_eq!(Byte::from_u64(u32::MAX as u64), ChunkSize::MAX);
+    assert_eq!(Some(Byte::from_u64(1 << 20)), ChunkSize::from_u64(1 << 20).map(Byte::from));
+    assert_eq!(None, ChunkSize::from_u64(1 << 32));
+    assert_eq!(None, ChunkSize::from_u128(u128::MAX));
+    assert_eq!(ChunkSize::MAX, ChunkSize::new_saturating(Byte::MAX));
+    assert!(ChunkSize::try_from(Byte::from_u64(1 << 32)).is_err());
+
+    let chunk_size = ChunkSize::from_u64(u32::MAX as u64 - 1).unwrap();
+
+    assert_eq!(Some(ChunkSize::MAX), chunk_size.add(Byte::from_u64(1)));
+    assert_eq!(None, chunk_size.add(Byte::from_u64(2)));
+    assert_eq!(Some(ChunkSize::MIN), chunk_size.subtract(*chunk_size));
+    assert_eq!(None, ChunkSize::MIN.subtract(Byte::from_u64(1)));
+    assert_eq!("4 GiB", format!("{:#.0}", ChunkSize::MAX.get_appropriate_unit(UnitType::Binary)));
+
+    assert_eq!(Byte::MAX, BoundedByte::<{ u128::MAX }>::MAX);
+    assert!(BoundedByte::<{ u128::MAX }>::new(Byte::MAX).is_some());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_bounded_byte() {
+    use byte_unit::BoundedByte;
+
+    type Limit = BoundedByte<{ 1 << 20 }>;
+
+    let limit = Limit::from_u64(1 << 19).unwrap();
+
+    assert_eq!(r#""512 KiB""#, serde_json::to_string(&limit).unwrap());
+    assert_eq!(limit, serde_json::from_str::<Limit>(r#""512 KiB""#).unwrap());
+
+    let error = serde_json::from_str::<Limit>(r#""2 MiB""#).unwrap_err();
+
+    assert!(error.to_string().contains("2 MiB exceeds the maximum of 1 MiB"), "{error}");
+}
+
+#[test]
+fn byte_seconds() {
+    use std::time::Duration;
+
+    use byte_unit::{ByteSeconds, UsagePeriod};
+
+    const HOUR: Duration = Duration::from_secs(3600);
+
+    let usage = Byte::from_u64(10) * Duration::from_millis(1050);
+
+    assert_eq!(11, usage.as_byte_seconds());
+    assert_eq!(usage, Duration::from_millis(1050) * Byte::from_u64(10));
+    assert_eq!(None, ByteSeconds::from_byte_seconds(u128::MAX).prorate(HOUR * 2, HOUR));
+
+    let usage = Byte::from_u64(1 << 30) * (HOUR * 730);
+
+    let cases = [
+        (UsagePeriod::Second, "2.506256103515625 PiB·s"),
+        (UsagePeriod::Hour, "730 GiB·h"),
+        (UsagePeriod::Day, "30.416666666666668 GiB·d"),
+        (UsagePeriod::Month, "1 GiB·month"),
+    ];
+
+    for (period, expected) in cases {
+        assert_eq!(expected, usage.display_per(period).to_string());
+    }
+
+    assert_eq!("30.42 GiB·d", format!("{:.2}", usage.display_per(UsagePeriod::Day)));
+    assert_eq!(Some(Byte::from_u64(1 << 30)), usage.size_per(UsagePeriod::Month));
+    assert_eq!(Some(Byte::from_u64(1 << 29) * (HOUR * 730)), usage.prorate(HOUR * 365, HOUR * 730));
+    assert_eq!(None, usage.prorate(HOUR, Duration::ZERO));
+    assert_eq!(None, ByteSeconds::from_byte_seconds(u128::MAX).add(usage));
+}
+
+#[test]
+fn time_bucketed_counter() {
+    use std::time::Duration;
+
+    use byte_unit::{TimeBucketedCounter, TimeUnit};
+
+    let mut counter = TimeBucketedCounter::<4>::with_time_unit(TimeUnit::Minute);
+
+    assert_eq!(Duration::from_secs(60), counter.interval());
+    assert_eq!(&[Byte::MIN; 4], counter.history());
+
+    let b = Byte::from_u64;
+
+    counter.record(Duration::from_secs(30), b(1));
+    counter.record(Duration::from_secs(90), b(2));
+    counter.record(Duration::from_secs(150), b(4));
+    counter.record(Duration::from_secs(100), b(8));
+
+    assert_eq!(&[b(0), b(1), b(10), b(4)], counter.history());
+    assert_eq!(b(4), counter.current());
+    assert_eq!(b(15), counter.total());
+
+    // too old for the window
+    counter.record(Duration::from_secs(240), Byte::MAX);
+    counter.record(Duration::from_secs(0), b(16));
+
+    assert_eq!(&[b(10), b(4), b(0), Byte::MAX], counter.history());
+    assert_eq!(Byte::MAX, counter.total());
+
+    // going back in time changes nothing
+    counter.advance(Duration::from_secs(60));
+
+    assert_eq!(&[b(10), b(4), b(0), Byte::MAX], counter.history());
+
+    counter.advance(Duration::from_secs(3600));
+
+    assert_eq!(&[Byte::MIN; 4], counter.history());
+
+    counter.record(Duration::from_secs(3600), b(3));
+    counter.clear();
+
+    assert_eq!(Byte::MIN, counter.total());
+}
+
+#[test]
+fn const_min_max_clamp() {
+    const SMALL: Byte = Byte::from_u64(100);
+    const LARGE: Byte = Byte::from_u64(200);
+
+    const MIN: Byte = SMALL.min(LARGE);
+    const MAX: Byte = SMALL.max(LARGE);
+
+    assert_eq!((SMALL, LARGE), (MIN, MAX));
+    assert_eq!(SMALL, Byte::MIN.clamp(SMALL, LARGE));
+    assert_eq!(LARGE, Byte::MAX.clamp(SMALL, LARGE));
+    assert_eq!(Byte::from_u64(150), Byte::from_u64(150).clamp(SMALL, LARGE));
+    assert_eq!(SMALL, SMALL.clamp(SMALL, SMALL));
+    assert!(std::panic::catch_unwind(|| SMALL.clamp(LARGE, SMALL)).is_err());
+}
+
+#[cfg(feature = "schemars")]
+#[test]
+fn json_schema() {
+    let schema = schemars::schema_for!(Byte);
+
+    assert_eq!(Some("string"), schema.get("type").and_then(|v| v.as_str()));
+
+    let pattern = schema.get("pattern").and_then(|v| v.as_str()).unwrap();
+
+    assert!(pattern.starts_with('^') && pattern.ends_with('$'));
+    assert!(schema.get("examples").is_some());
+
+    let schema = schemars::schema_for!(Unit);
+
+    let units = schema.get("enum").and_then(|v| v.as_array()).unwrap();
+
+    assert!(units.iter().any(|unit| unit == "KiB"));
+    assert!(units.iter().any(|unit| unit == "B"));
+    assert!(units.iter().all(|unit| unit.as_str().unwrap().parse::<Unit>().is_ok()));
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn assert_bytes_eq() {
+    use byte_unit::assert_bytes_eq;
+
+    assert_bytes_eq!(Byte::from_u64(1 << 30), Byte::GIBIBYTE);
+
+    let message =
+        |result: std::thread::Result<()>| *result.unwrap_err().downcast::<String>().unwrap();
+
+    let result = std::panic::catch_unwind(|| {
+        assert_bytes_eq!(Byte::GIBIBYTE, Byte::from_u64(1_000_000_000));
+    });
+
+    assert_eq!(
+        "assertion `left == right` failed\n  left: 1 GiB (1073741824)\n right: 1 GB \
+         (1000000000)\n  diff: 70.3 MiB (73741824)",
+        message(result)
+    );
+
+    let result = std::panic::catch_unwind(|| {
+        assert_bytes_eq!(Byte::from_u64(1), Byte::from_u64(0), "block {}", 7);
+    });
+
+    assert_eq!(
+        "assertion `left == right` failed: block 7\n  left: 1 B (1)\n right: 0 B (0)\n  diff: 1 B \
+         (1)",
+        message(result)
+    );
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn arbitrary() {
+    use arbitrary::{Arbitrary, Unstructured};
+    use byte_unit::AdjustedByte;
+
+    let data =
+        (0..4096u32).map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8).collect::<Vec<_>>();
+
+    let mut u = Unstructured::new(&data);
+
+    let mut bytes = Vec::new();
+
+    while let Ok(byte) = Byte::arbitrary(&mut u) {
+        if u.is_empty() {
+            break;
+        }
+
+        bytes.push(byte);
+    }
+
+    assert!(bytes.len() > 100);
+    assert!(bytes.contains(&Byte::MIN));
+    assert!(bytes.iter().any(|byte| byte.as_u128() > 1 << 40));
+
+    let adjusted_byte = AdjustedByte::arbitrary(&mut Unstructured::new(&data)).unwrap();
+
+    assert!(adjusted_byte.get_byte() <= Byte::MAX);
+}
+
+#[cfg(feature = "proptest")]
+#[test]
+fn proptest_arbitrary() {
+    use byte_unit::AdjustedByte;
+    use proptest::{
+        arbitrary::any,
+        strategy::{Strategy, ValueTree},
+        test_runner::TestRunner,
+    };
+
+    let mut runner = TestRunner::deterministic();
+
+    let bytes = (0..1000)
+        .map(|_| any::<Byte>().new_tree(&mut runner).unwrap().current())
+        .collect::<Vec<_>>();
+
+    assert!(bytes.contains(&Byte::MAX));
+    assert!(bytes.contains(&Byte::MIN));
+    assert!(bytes.iter().any(|byte| byte.get_exact_unit(false).1 == Unit::MiB));
+
+    let units = (0..1000)
+        .map(|_| any::<Unit>().new_tree(&mut runner).unwrap().current())
+        .collect::<Vec<_>>();
+
+    assert!(units.contains(&Unit::Bit) && units.contains(&Unit::EiB));
+
+    any::<AdjustedByte>().new_tree(&mut runner).unwrap().current();
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_as_map_key() {
+    use std::collections::{BTreeMap, HashMap};
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Wrapper {
+        #[serde(with = "byte_unit::serde::as_map_key")]
+        sizes: BTreeMap<Byte, u32>,
+        #[serde(with = "byte_unit::serde::as_map_key")]
+        units: HashMap<Unit, u32>,
+    }
+
+    let wrapper = Wrapper {
+        sizes: BTreeMap::from([(Byte::from_u64(0), 1), (Byte::from_u64(1536), 2)]),
+        units: HashMap::from([(Unit::MiB, 3)]),
+    };
+
+    let s = r#"{"sizes":{"0 B":1,"1.5 KiB":2},"units":{"MiB":3}}"#;
+
+    assert_eq!(s, serde_json::to_string(&wrapper).unwrap());
+    assert_eq!(wrapper, serde_json::from_str(s).unwrap());
+    assert_eq!(
+        wrapper,
+        serde_json::from_str(r#"{"sizes":{"0":1,"1536":2},"units":{"MiB":3}}"#).unwrap()
+    );
+
+    // the same size written twice
+    let wrapper: Wrapper =
+        serde_json::from_str(r#"{"sizes":{"1024":1,"1 KiB":2},"units":{}}"#).unwrap();
+
+    assert_eq!(BTreeMap::from([(Byte::from_u64(1024), 2)]), wrapper.sizes);
+
+    // keys are strings even in formats which are not human-readable
+    let wrapper = Wrapper {
+        sizes: BTreeMap::from([(Byte::MAX, 1)]), units: HashMap::new()
+    };
+
+    assert_eq!(wrapper, bincode::deserialize(&bincode::serialize(&wrapper).unwrap()).unwrap());
+
+    assert!(serde_json::from_str::<Wrapper>(r#"{"sizes":{"1 XB":1},"units":{}}"#).is_err());
+    assert!(serde_json::from_str::<Wrapper>(r#"{"sizes":{},"units":{"1 KB":1}}"#).is_err());
+}
+
+#[test]
+fn adjusted_parse_str_keep_unit() {
+    use byte_unit::AdjustedByte;
+
+    let cases = [
+        ("2048 KiB", false, Unit::KiB, "2048 KiB"),
+        ("1.5 GB", false, Unit::GB, "1.5 GB"),
+        ("1000000", false, Unit::B, "1000000 B"),
+        ("0 MiB", false, Unit::MiB, "0 MiB"),
+        ("80 Kb", false, Unit::Kbit, "80 Kb"),
+        ("80 Kb", true, Unit::KB, "80 KB"),
+    ];
+
+    for (i, (s, ignore_case, unit, expected)) in cases.iter().enumerate() {
+        let adjusted_byte = AdjustedByte::parse_str_keep_unit(s, *ignore_case).unwrap();
+
+        assert_eq!(*unit, adjusted_byte.get_unit(), "{i}");
+        assert_eq!(*expected, adjusted_byte.to_string(), "{i}");
+        assert_eq!(Byte::parse_str(s, *ignore_case).unwrap(), adjusted_byte.get_byte(), "{i}");
+    }
+
+    assert!(AdjustedByte::parse_str_keep_unit("1 XB", false).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn size_by_unit_report() {
+    use byte_unit::{Inventory, SizeByUnitReport};
+
+    let mut report: SizeByUnitReport =
+        [0, 1023, 1024, 1536, 1 << 30].into_iter().map(Byte::from_u64).collect();
+
+    assert_eq!(UnitType::Binary, report.unit_type());
+    assert_eq!(Inventory::new(2, Byte::from_u64(1023)), report.get(Unit::B));
+    assert_eq!(Inventory::new(2, Byte::from_u64(2560)), report.get(Unit::KiB));
+    assert_eq!(Inventory::default(), report.get(Unit::MiB));
+    assert_eq!(Inventory::new(1, Byte::GIBIBYTE), report.get(Unit::GiB));
+    assert_eq!(Inventory::new(5, Byte::from_u64(1073745407)), report.total());
+    assert_eq!(
+        vec![Unit::B, Unit::KiB, Unit::GiB],
+        report.iter().map(|(unit, _)| unit).collect::<Vec<_>>()
+    );
+
+    report.extend([Byte::MAX, Byte::MAX]);
+
+    assert_eq!(Byte::MAX, report.total().bytes);
+
+    let report = SizeByUnitReport::from_sizes([Byte::from_u64(1500)], UnitType::Decimal);
+
+    assert_eq!("Unit    Objects        Total\n  KB          1      1.5  KB", report.to_string());
+    assert_eq!("Unit    Objects        Total", SizeByUnitReport::new(UnitType::Both).to_string());
+}
+
+#[test]
+fn appropriate_unit_with_options() {
+    use byte_unit::AppropriateUnitOptions;
+
+    let default = AppropriateUnitOptions::new(UnitType::Binary);
+    let ratio = default.threshold(0.95);
+    let max_value = default.max_value(1000);
+
+    let cases = [
+        (&default, 1023, Unit::B),
+        (&default, 1024, Unit::KiB),
+        (&default, (1 << 30) - 1, Unit::MiB),
+        (&ratio, 972, Unit::B),
+        (&ratio, 973, Unit::KiB),
+        (&ratio, 1020 << 20, Unit::GiB),
+        (&max_value, 999, Unit::B),
+        (&max_value, 1000, Unit::KiB),
+        (&max_value, 1003 << 20, Unit::GiB),
+    ];
+
+    for (i, (options, n, unit)) in cases.iter().enumerate() {
+        let adjusted_byte = Byte::from_u64(*n).get_appropriate_unit_with_options(options);
+
+        assert_eq!(*unit, adjusted_byte.get_unit(), "{i}");
+    }
+
+    let options = AppropriateUnitOptions::default().max_value(1000);
+
+    // KiB is larger than KB, and both of them are reached at 1000 bytes
+    assert_eq!(
+        Unit::KiB,
+        Byte::from_u64(1000).get_appropriate_unit_with_options(&options).get_unit()
+    );
+    assert_eq!(
+        Unit::MB,
+        Byte::from_u64(1_000_000).get_appropriate_unit_with_options(&options).get_unit()
+    );
+
+    let options = options.unit_type(UnitType::Decimal);
+
+    assert_eq!(Unit::B, Byte::from_u64(999).get_appropriate_unit_with_options(&options).get_unit());
+
+    for n in [0, 1, 1000, 1 << 40, u64::MAX] {
+        let byte = Byte::from_u64(n);
+
+        for unit_type in [UnitType::Binary, UnitType::Decimal, UnitType::Both] {
+            assert_eq!(
+                byte.get_appropriate_unit(unit_type).get_unit(),
+                byte.get_appropriate_unit_with_options(&AppropriateUnitOptions::new(unit_type))
+                    .get_unit(),
+                "{n}"
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn appropriate_unit_options_invalid_threshold() {
+    byte_unit::AppropriateUnitOptions::default().threshold(f64::NAN);
+}
+
+#[test]
+fn rate_overhead() {
+    use byte_unit::{ByteRate, OverheadProfile};
+
+    let line_rate = ByteRate::per_second(Byte::from_u64(1538 * 1000 + 1537));
+    let goodput = line_rate.apply_overhead(OverheadProfile::ETHERNET_IPV4_TCP);
+
+    assert_eq!(Byte::from_u64(1460 * 1000 + 1459), goodput.get_size());
+    assert_eq!(
+        Some(ByteRate::per_second(Byte::from_u64(1538 * 1000 + 1537))),
+        goodput.required_line_rate(OverheadProfile::ETHERNET_IPV4_TCP)
+    );
+
+    assert_eq!(
+        ByteRate::per_second(Byte::MAX),
+        ByteRate::per_second(Byte::MAX).apply_overhead(OverheadProfile::new(7, 7))
+    );
+    assert_eq!(None, ByteRate::per_second(Byte::MAX).required_line_rate(OverheadProfile::QUIC));
+}
+
+#[test]
+fn byte_counter() {
+    use byte_unit::{AtomicByteCounter, ByteCounter};
+
+    let mut counter = ByteCounter::new();
+
+    counter.add(u64::MAX - 1);
+    counter.add(2);
+
+    assert_eq!(Byte::from_u64(u64::MAX), counter.get());
+    assert_eq!(Byte::from_u64(u64::MAX), counter.reset());
+    assert_eq!(Byte::MIN, Byte::from(counter));
+
+    let counter = AtomicByteCounter::new();
+
+    counter.add(3);
+    counter.add(4);
+
+    assert_eq!(Byte::from_u64(7), counter.reset());
+    assert_eq!(Byte::MIN, counter.get());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn counting_io() {
+    use std::{
+        io::{self, Read, Write},
+        sync::Arc,
+    };
+
+    use byte_unit::{AtomicByteCounter, CountingReader, CountingWriter};
+
+    let mut reader = CountingReader::new(&[0u8; 10000][..]);
+    let mut writer = CountingWriter::new(Vec::new());
+
+    let mut buf = [0u8; 3];
+
+    assert_eq!(3, reader.read(&mut buf).unwrap());
+    assert_eq!(Byte::from_u64(3), reader.get_count());
+
+    io::copy(&mut reader, &mut writer).unwrap();
+
+    assert_eq!(Byte::from_u64(10000), reader.get_count());
+    assert_eq!(Byte::from_u64(9997), writer.get_count());
+    assert_eq!(9997, writer.get_ref().len());
+
+    let counter = Arc::new(AtomicByteCounter::new());
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            let counter = counter.clone();
+
+            s.spawn(move || {
+                let mut writer = CountingWriter::with_counter(io::sink(), counter);
+
+                for _ in 0..100 {
+                    writer.write_all(b"0123456789").unwrap();
+                }
+            });
+        }
+    });
+
+    assert_eq!(Byte::from_u64(4000), counter.get());
+
+    let shared = AtomicByteCounter::new();
+
+    let mut writer = CountingWriter::with_counter(Vec::new(), &shared);
+
+    writer.write_all(b"abc").unwrap();
+
+    let (inner, _) = writer.into_parts();
+
+    assert_eq!(b"abc", inner.as_slice());
+    assert_eq!(Byte::from_u64(3), shared.get());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_wire_format() {
+    let cases: [(u64, &str, &[u8]); 6] = [
+        (0, r#""0 B""#, &[0x00]),
+        (999, r#""999 B""#, &[0xE7, 0x07]),
+        (1024, r#""1 KiB""#, &[0x80, 0x08]),
+        (1536, r#""1.5 KiB""#, &[0x80, 0x0C]),
+        (3_211_776, r#""3136.5 KiB""#, &[0x80, 0x84, 0xC4, 0x01]),
+        (u64::MAX, r#""18446744073709551.615 KB""#, &[
+            0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01,
+        ]),
+    ];
+
+    for (i, (n, json, postcard)) in cases.iter().enumerate() {
+        let byte = Byte::from_u64(*n);
+
+        assert_eq!(*json, serde_json::to_string(&byte).unwrap(), "{i}");
+        assert_eq!(*postcard, postcard::to_allocvec(&byte).unwrap(), "{i}");
+
+        assert_eq!(byte, serde_json::from_str::<Byte>(json).unwrap(), "{i}");
+        assert_eq!(byte, postcard::from_bytes::<Byte>(postcard).unwrap(), "{i}");
+    }
+
+    let encoded = bincode::serialize(&Byte::from_u64(0x0102)).unwrap();
+
+    assert_eq!(vec![0x02, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], encoded);
+}
+
+#[test]
+fn parse_composite() {
+    use byte_unit::{ParseError, ParseOptions, ValueParseError};
+
+    let options = ParseOptions::new().composite(true);
+
+    let cases = [
+        (Ok((1 << 30) + (512 << 20) + (10 << 10)), "1 GiB 512 MiB 10 KiB"),
+        (Ok((1 << 30) + (512 << 20)), "  1GiB   512MiB  "),
+        (Ok(1_500_000_100), "1 GB 500 MB 100"),
+        (Ok(2048), "1 KiB 1 KiB"),
+        (Ok(3), "0.0001 KB 0.0001 KB 0.0001 KB"),
+        (Ok(1), "1"),
+        (Err(()), "1 2 MiB"),
+        (Err(()), "1 GiB, 512 MiB"),
+        (Err(()), "1 GiB MiB"),
+        (Err(()), ""),
+    ];
+
+    for (i, (expected, input)) in cases.iter().enumerate() {
+        let result = Byte::parse_str_with_options(input, &options);
+
+        assert_eq!(*expected, result.map(|b| b.as_u64()).map_err(|_| ()), "{i}");
+    }
+
+    assert!(Byte::parse_str_with_options("1 GiB 512 MiB", &ParseOptions::new()).is_err());
+
+    assert!(matches!(
+        Byte::parse_str_with_options(format!("{} B 1 B", Byte::MAX.as_u128()), &options),
+        Err(ParseError::Value(ValueParseError::ExceededBounds(_)))
+    ));
+}
+
+#[test]
+fn fixed_adjusted() {
+    use byte_unit::FixedAdjustedByte;
+
+    let cases = [
+        ("0.00 B", 0, Unit::B),
+        ("1.00 KiB", 1024, Unit::KiB),
+        ("1.02 KB", 1024, Unit::KB),
+        ("1.01 KiB", 1030, Unit::KiB),
+        ("1.00 KiB", 1029, Unit::KiB),
+        ("0.01 KiB", 6, Unit::KiB),
+        ("0.00 KiB", 5, Unit::KiB),
+        ("8.00 b", 1, Unit::Bit),
+        ("0.01 Kb", 1, Unit::Kbit),
+        ("16.00 EiB", u64::MAX, Unit::EiB),
+    ];
+
+    for (i, (expected, n, unit)) in cases.iter().enumerate() {
+        let adjusted: FixedAdjustedByte<2> = Byte::from_u64(*n).get_adjusted_unit_fixed(*unit);
+
+        assert_eq!(*expected, adjusted.to_string(), "{i}");
+    }
+
+    let a = Byte::from_u64(1000).get_adjusted_unit_fixed::<3>(Unit::KB);
+    let b = Byte::from_u64(1000).get_adjusted_unit_fixed::<3>(Unit::B);
+    let c = Byte::from_u64(1023).get_adjusted_unit_fixed::<3>(Unit::KiB);
+
+    assert_ne!(a, b);
+    assert!(b < a);
+    assert!(a < c);
+    assert!(Byte::from_u64(1001).get_adjusted_unit_fixed::<3>(Unit::B) > a);
+
+    assert_eq!(
+        "9 EiB",
+        Byte::from_u64(9 << 60).get_appropriate_unit_fixed::<0>(UnitType::Binary).to_string()
+    );
+    assert_eq!(
+        "18446744073709551615.000000000 B",
+        Byte::from_u64(u64::MAX).get_adjusted_unit_fixed::<9>(Unit::B).to_string()
+    );
+}
+
+#[test]
+fn integer_radix_formatting() {
+    let byte = Byte::from_u64(0xBEEF);
+
+    assert_eq!("beef", format!("{byte:x}"));
+    assert_eq!("0xBEEF", format!("{byte:#X}"));
+    assert_eq!("0x0000beef", format!("{byte:#010x}"));
+    assert_eq!("    137357", format!("{byte:>10o}"));
+    assert_eq!("0b1011111011101111", format!("{byte:#b}"));
+    assert_eq!(format!("{:x}", u64::MAX), format!("{:x}", Byte::from_u64(u64::MAX)));
+}
+
+#[test]
+fn bulk_conversion() {
+    use byte_unit::bulk::{to_unit_decimals, to_unit_values};
+
+    let sizes = [0, 1, 1000, 1024, 1_500_000, u64::MAX].map(Byte::from_u64);
+
+    for unit in [Unit::Bit, Unit::B, Unit::Kbit, Unit::KiB, Unit::MB, Unit::GiB] {
+        let mut values = [0.0; 6];
+        let mut decimals = [Decimal::ZERO; 6];
+
+        to_unit_values(&sizes, unit, &mut values);
+        to_unit_decimals(&sizes, unit, &mut decimals);
+
+        for (i, size) in sizes.iter().enumerate() {
+            let adjusted = size.get_adjusted_unit(unit);
+
+            assert_eq!(adjusted.get_value().to_bits(), values[i].to_bits(), "{unit} {i}");
+            assert_eq!(size.get_adjusted_unit_exact(unit).0, decimals[i], "{unit} {i}");
+        }
+    }
+
+    let mut empty: [f64; 0] = [];
+
+    to_unit_values(&[], Unit::KB, &mut empty);
+}
+
+#[test]
+#[should_panic(expected = "the input and the output must have the same length")]
+fn bulk_conversion_length_mismatch() {
+    let mut values = [0.0; 1];
+
+    byte_unit::bulk::to_unit_values(&[Byte::MIN, Byte::MIN], Unit::KB, &mut values);
+}
+
+#[test]
+fn alignment() {
+    let page = Byte::from_u64(4096);
+    let sector = Byte::from_u64(512);
+    let odd = Byte::from_u64(1000);
+
+    let cases = [
+        (0, page, Some(0), Some(0), true),
+        (1, page, Some(4096), Some(0), false),
+        (4096, page, Some(4096), Some(4096), true),
+        (4097, page, Some(8192), Some(4096), false),
+        (1535, sector, Some(1536), Some(1024), false),
+        (2500, odd, Some(3000), Some(2000), false),
+        (3000, odd, Some(3000), Some(3000), true),
+        (5, Byte::MIN, None, None, false),
+    ];
+
+    for (i, (n, alignment, up, down, aligned)) in cases.iter().enumerate() {
+        let byte = Byte::from_u64(*n);
+
+        assert_eq!(*up, byte.align_up(*alignment).map(|b| b.as_u64()), "{i}");
+        // padding and rounding up to huge pages share the implementation of align_up
+        assert_eq!(byte.align_up(*alignment), byte.padded_to(*alignment), "{i}");
+        assert_eq!(
+            byte.align_up(*alignment),
+            byte.round_to_hugepages(*alignment, byte_unit::RoundingMode::Up),
+            "{i}"
+        );
+        assert_eq!(*down, byte.align_down(*alignment).map(|b| b.as_u64()), "{i}");
+        assert_eq!(*aligned, byte.is_aligned_to(*alignment), "{i}");
+    }
+
+    assert_eq!(None, Byte::MAX.align_up(Byte::from_u64(2)));
+    assert_eq!(Some(Byte::MAX), Byte::MAX.align_down(Byte::from_u64(1)));
+
+    assert_eq!(Some(Byte::from_u64(1 << 20)), Byte::from_u64((1 << 19) + 1).next_power_of_two());
+    assert_eq!(Some(Byte::from_u64(1 << 63)), Byte::from_u64(1 << 63).next_power_of_two());
+    #[cfg(not(feature = "u128"))]
+    assert_eq!(None, Byte::from_u64((1 << 63) + 1).next_power_of_two());
+    #[cfg(feature = "u128")]
+    assert_eq!(
+        Some(Byte::from_u128(1 << 64).unwrap()),
+        Byte::from_u64((1 << 63) + 1).next_power_of_two()
+    );
+}
+
+#[cfg(feature = "arrow")]
+#[test]
+fn arrow_conversion() {
+    use arrow_array::Decimal128Array;
+    use byte_unit::arrow::*;
+
+    let sizes = [0, 1, 1024, u64::MAX].map(Byte::from_u64);
+
+    let array = to_uint64_array(&sizes).unwrap();
+
+    assert_eq!(4, array.len());
+    assert_eq!(sizes.map(Some).to_vec(), from_uint64_array(&array));
+
+    let array = to_decimal128_array(&sizes);
+
+    assert_eq!(DECIMAL128_PRECISION, array.precision());
+    assert_eq!(sizes.map(Some).to_vec(), from_decimal128_array(&array).unwrap());
+
+    #[cfg(feature = "u128")]
+    {
+        let sizes = [Byte::MAX];
+
+        assert!(to_uint64_array(&sizes).is_err());
+        assert_eq!(
+            vec![Some(Byte::MAX)],
+            from_decimal128_array(&to_decimal128_array(&sizes)).unwrap()
+        );
+    }
+
+    let cases = [
+        (Some(Byte::from_u64(2)), 15, 1),
+        (Some(Byte::from_u64(1500)), 15, -2),
+        (None, -1, 0),
+        (None, 1, 29),
+    ];
+
+    for (i, (expected, value, scale)) in cases.into_iter().enumerate() {
+        let array = Decimal128Array::from(vec![Some(value), None])
+            .with_precision_and_scale(38, scale)
+            .unwrap();
+
+        match expected {
+            Some(byte) => {
+                assert_eq!(vec![Some(byte), None], from_decimal128_array(&array).unwrap(), "{i}")
+            },
+            None => assert!(from_decimal128_array(&array).is_err(), "{i}"),
+        }
+    }
+}
+
+#[cfg(feature = "polars")]
+#[test]
+fn polars_any_value() {
+    use byte_unit::AnyValueError;
+    use polars_core::prelude::AnyValue;
+
+    let byte = Byte::from_u64(1024);
+
+    #[cfg(not(feature = "u128"))]
+    assert_eq!(AnyValue::UInt64(1024), AnyValue::from(byte));
+    #[cfg(feature = "u128")]
+    assert_eq!(AnyValue::Int128(1024), AnyValue::from(byte));
+
+    assert_eq!(Ok(byte), Byte::try_from(AnyValue::from(byte)));
+    assert_eq!(Ok(byte), Byte::try_from(AnyValue::UInt16(1024)));
+    assert_eq!(Ok(byte), Byte::try_from(AnyValue::Int32(1024)));
+    assert_eq!(Ok(Byte::MAX), Byte::try_from(AnyValue::from(Byte::MAX)));
+
+    assert_eq!(Err(AnyValueError::ExceededBounds), Byte::try_from(AnyValue::Int64(-1)));
+    assert_eq!(
+        Err(AnyValueError::InvalidType("a float")),
+        Byte::try_from(AnyValue::Float64(1024.0))
+    );
+    assert_eq!(
+        "expected an integer, found null",
+        Byte::try_from(AnyValue::Null).unwrap_err().to_string()
+    );
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn from_json_value() {
+    use byte_unit::JsonValueError;
+    use serde_json::json;
+
+    let cases = [
+        (json!(0), Some(0)),
+        (json!(1024), Some(1024)),
+        (json!(u64::MAX), Some(u64::MAX)),
+        (json!(1.5), Some(2)),
+        (json!("1 KiB"), Some(1024)),
+        (json!("15 MB"), Some(15_000_000)),
+    ];
+
+    for (i, (value, expected)) in cases.iter().enumerate() {
+        assert_eq!(expected.map(Byte::from_u64), Byte::from_json_value(value).ok(), "{i}");
+        assert_eq!(expected.map(Byte::from_u64), Byte::try_from(value).ok(), "{i}");
+    }
+
+    assert!(matches!(Byte::from_json_value(&json!(-1)), Err(JsonValueError::ExceededBounds)));
+    assert!(matches!(Byte::from_json_value(&json!(-0.5)), Err(JsonValueError::ExceededBounds)));
+    #[cfg(not(feature = "u128"))]
+    assert!(matches!(Byte::from_json_value(&json!(1e20)), Err(JsonValueError::ExceededBounds)));
+    assert!(matches!(Byte::from_json_value(&json!(1e30)), Err(JsonValueError::ExceededBounds)));
+    assert!(matches!(Byte::from_json_value(&json!("1 XB")), Err(JsonValueError::Parse(_))));
+    assert!(matches!(Byte::from_json_value(&json!(true)), Err(JsonValueError::InvalidType(_))));
+    assert_eq!(
+        "expected a number or a string, found an array",
+        Byte::from_json_value(&json!([1024])).unwrap_err().to_string()
+    );
+}
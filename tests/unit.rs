@@ -69,6 +69,19 @@ fn parse_str() {
         (Ok(Unit::EiB), "Eib", true, false),
         (Ok(Unit::EB), "EB", true, false),
         (Ok(Unit::EiB), "EiB", true, false),
+        // full names
+        (Ok(Unit::B), "byte", false, false),
+        (Ok(Unit::B), "Bytes", false, false),
+        (Ok(Unit::Bit), "bits", false, true),
+        (Ok(Unit::MB), "megabytes", false, false),
+        (Ok(Unit::MB), "MEGABYTE", false, false),
+        (Ok(Unit::GiB), "gibibytes", false, false),
+        (Ok(Unit::Kbit), "kilobits", true, true),
+        (Ok(Unit::Tibit), "tebibit", false, true),
+        (Ok(Unit::EB), "exabytes", false, false),
+        (Err(()), "megabytess", false, false),
+        (Err(()), "megabyt", false, false),
+        (Err(()), "megabites", false, false),
     ];
 
     #[cfg(feature = "u128")]
@@ -87,6 +100,8 @@ fn parse_str() {
         (Ok(Unit::YiB), "Yib", true, false),
         (Ok(Unit::YB), "YB", true, false),
         (Ok(Unit::YiB), "YiB", true, false),
+        (Ok(Unit::ZB), "zettabytes", false, false),
+        (Ok(Unit::Yibit), "yobibits", false, false),
     ]);
 
     for (i, case) in cases.iter().enumerate() {
@@ -108,6 +123,26 @@ fn parse_str() {
     }
 }
 
+#[test]
+fn parse_str_error_character() {
+    let cases = [
+        ('c', "c", false),
+        ('é', "é", false),
+        ('中', "K中", false),
+        ('😀', "Ki😀", false),
+        ('μ', "μB", true),
+        ('µ', "µB", true),
+    ];
+
+    for (i, (character, s, micro)) in cases.iter().enumerate() {
+        let error = Unit::parse_str(s, true, true).unwrap_err();
+
+        assert_eq!(*character, error.character, "{i}");
+        assert_eq!(*micro, error.is_micro_prefix(), "{i}");
+        assert_eq!(*micro, error.to_string().contains("micro prefix"), "{i}");
+    }
+}
+
 #[cfg(feature = "serde")]
 #[test]
 fn tests() {
@@ -123,3 +158,73 @@ fn tests() {
         assert_eq!(case.1, serde_json::from_str(case.0).unwrap(), "{i}");
     }
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn unit_as_factor() {
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Wrapper(#[serde(with = "byte_unit::serde::unit_as_factor")] Unit);
+
+    let cases = [("1", Unit::B), ("1000", Unit::KB), ("1024", Unit::KiB), ("1048576", Unit::MiB)];
+
+    for (i, case) in cases.iter().enumerate() {
+        assert_eq!(case.0, serde_json::to_string(&Wrapper(case.1)).unwrap(), "{i}");
+        assert_eq!(Wrapper(case.1), serde_json::from_str(case.0).unwrap(), "{i}");
+    }
+
+    assert_eq!(Wrapper(Unit::GB), serde_json::from_str("\"GB\"").unwrap());
+    assert!(serde_json::from_str::<Wrapper>("4096").is_err());
+    assert!(serde_json::from_str::<Wrapper>("-1").is_err());
+    assert!(serde_json::to_string(&Wrapper(Unit::Bit)).is_err());
+    assert!(serde_json::to_string(&Wrapper(Unit::Kbit)).is_err());
+    assert!(serde_json::to_string(&Wrapper(Unit::Mibit)).is_err());
+}
+
+#[test]
+fn parts() {
+    use byte_unit::{UnitBase, UnitKind};
+
+    let max_exponent = if cfg!(feature = "u128") { 8 } else { 6 };
+
+    for kind in [UnitKind::Bit, UnitKind::Byte] {
+        for base in [UnitBase::Decimal, UnitBase::Binary] {
+            for exponent in 0..=max_exponent {
+                let unit = Unit::from_parts(kind, base, exponent).unwrap();
+
+                let factor: u128 = match base {
+                    UnitBase::Decimal => 1000,
+                    UnitBase::Binary => 1024,
+                };
+                let bits = factor.pow(exponent as u32) * if kind == UnitKind::Byte { 8 } else { 1 };
+
+                assert_eq!(bits, unit.as_bits_u128(), "{unit}");
+
+                let parts = unit.to_parts();
+
+                assert_eq!(Some(unit), Unit::from_parts(parts.0, parts.1, parts.2), "{unit}");
+
+                if exponent > 0 {
+                    assert_eq!((kind, base, exponent), parts, "{unit}");
+                }
+            }
+
+            assert_eq!(None, Unit::from_parts(kind, base, max_exponent + 1));
+            assert_eq!(None, Unit::from_parts(kind, base, u8::MAX));
+        }
+    }
+}
+
+#[test]
+fn order_by_size() {
+    use Unit::*;
+
+    let units = [
+        Bit, B, Kbit, Kibit, KB, KiB, Mbit, Mibit, MB, MiB, Gbit, Gibit, GB, GiB, Tbit, Tibit, TB,
+        TiB, Pbit, Pibit, PB, PiB, Ebit, Eibit, EB, EiB,
+    ];
+
+    for pair in units.windows(2) {
+        assert!(pair[0] < pair[1], "{} {}", pair[0], pair[1]);
+        assert!(pair[0].as_bits_u128() < pair[1].as_bits_u128(), "{} {}", pair[0], pair[1]);
+    }
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "byte")]
+
+use byte_unit::{Byte, UnitPreference, UnitPreferenceAlreadySetError};
+
+// the global preference can only be set once in a process, so everything is tested in one function
+#[test]
+fn global() {
+    let byte = Byte::from_u64(2_048_000);
+
+    assert_eq!(UnitPreference::Largest, UnitPreference::global());
+    assert_eq!("2.048 MB", format!("{byte:#}"));
+
+    UnitPreference::Binary.set_global().unwrap();
+
+    assert_eq!(UnitPreference::Binary, UnitPreference::global());
+    assert_eq!(Err(UnitPreferenceAlreadySetError), UnitPreference::Decimal.set_global());
+    assert_eq!(Err(UnitPreferenceAlreadySetError), UnitPreference::Binary.set_global());
+
+    assert_eq!("2000 KiB", format!("{byte:#}"));
+    assert_eq!("2000 KiB", byte.display().to_string());
+    assert_eq!("-2000 KiB", format!("{:#}", -byte_unit::SignedByte::from(byte)));
+    assert_eq!("1000.001 KB", format!("{:#}", Byte::from_u64(1_000_001)));
+
+    // the explicit methods are not affected
+    assert_eq!("2.048", byte.get_recoverable_unit(false, 3).0.normalize().to_string());
+
+    // neither are the serialized forms, which follow the wire contract
+    #[cfg(feature = "serde")]
+    {
+        use std::collections::BTreeMap;
+
+        use serde::Serialize;
+
+        #[derive(Serialize)]
+        struct Sizes {
+            size:    Byte,
+            #[serde(with = "byte_unit::serde::bytes_as_string")]
+            string:  Byte,
+            #[serde(with = "byte_unit::serde::as_map_key")]
+            by_size: BTreeMap<Byte, u8>,
+        }
+
+        let sizes = Sizes {
+            size: byte, string: byte, by_size: BTreeMap::from([(byte, 1)])
+        };
+
+        assert_eq!(
+            r#"{"size":"2.048 MB","string":"2.048 MB","by_size":{"2.048 MB":1}}"#,
+            serde_json::to_string(&sizes).unwrap()
+        );
+    }
+}